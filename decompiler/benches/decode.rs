@@ -0,0 +1,125 @@
+//! Compares decoding a text section once up front with decoding each instruction on demand, the
+//! way every analysis pass used to.
+//!
+//! Run from the `decompiler` directory with the Melee disc image alongside, like the decompiler
+//! itself. Without it, a synthetic DOL of the same shape is decoded instead.
+
+use std::fs::File;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use memmap::MmapOptions;
+use powerpc::{DecodedSection, EncodedInstruction};
+
+const DISC_IMAGE_PATH: &str = "Super Smash Bros. Melee (v1.02).iso";
+
+/// The size of the synthetic DOL's main text section, close to Melee's.
+const SYNTHETIC_TEXT_SIZE: u32 = 0x0038_0000;
+
+/// Builds a DOL with a small `.init` text section, a large text section of common instructions,
+/// and a data section, laid out like a game's.
+fn synthetic_dol() -> Vec<u8> {
+    const WORDS: [u32; 8] = [
+        0x9421fff0, // stwu r1, -0x10(r1)
+        0x7c0802a6, // mflr r0
+        0x90010014, // stw r0, 0x14(r1)
+        0x38630001, // addi r3, r3, 1
+        0x80010014, // lwz r0, 0x14(r1)
+        0x7c0803a6, // mtlr r0
+        0x38210010, // addi r1, r1, 0x10
+        0x4e800020, // blr
+    ];
+    let text_end = 0x2100 + SYNTHETIC_TEXT_SIZE;
+    // (header slot, file offset, load address, size)
+    let sections = [
+        (0, 0x100, 0x80003100, 0x2000),
+        (1, 0x2100, 0x80005100, SYNTHETIC_TEXT_SIZE),
+        (7, text_end, 0x80003000 + text_end, 0x1000),
+    ];
+
+    let mut data = vec![0; text_end as usize + 0x1000];
+    let mut put = |at: usize, word: u32| data[at..at + 4].copy_from_slice(&word.to_be_bytes());
+    for &(slot, offset, address, size) in sections.iter() {
+        put(4 * slot, offset);
+        put(0x48 + 4 * slot, address);
+        put(0x90 + 4 * slot, size);
+        for (i, at) in (offset..offset + size).step_by(4).enumerate() {
+            put(at as usize, WORDS[i % WORDS.len()]);
+        }
+    }
+    put(0xe0, 0x80003100);
+    data
+}
+
+/// Reads a word the way `dol::Reader::read` did before decoding was cached: by walking the
+/// sections in order until one holds the address.
+fn read_linearly(dol: dol::Reader, address: u32) -> u32 {
+    for section in dol.iter_sections() {
+        if let Some(offset) = section.offset_for_dword_address(address) {
+            let at = (offset - section.offset) as usize;
+            let bytes = &dol.section_data(section)[at..at + 4];
+            return u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+    }
+    panic!("address not mapped: {:08x}", address);
+}
+
+fn decode_text_section(c: &mut Criterion) {
+    let disc_image =
+        File::open(DISC_IMAGE_PATH).and_then(|file| unsafe { MmapOptions::new().map(&file) });
+    let synthetic;
+    let dol = match &disc_image {
+        Ok(disc_image) => gamecube_disc::Reader::new(disc_image)
+            .main_executable()
+            .unwrap(),
+        Err(e) => {
+            eprintln!("{}: {}; decoding a synthetic DOL", DISC_IMAGE_PATH, e);
+            synthetic = synthetic_dol();
+            dol::Reader::parse(&synthetic).unwrap()
+        }
+    };
+
+    // The largest text section holds nearly all of the game's code.
+    let section = dol
+        .iter_text_sections()
+        .max_by_key(|section| section.size)
+        .unwrap();
+    let addrs = (section.load_address..section.load_address + section.size).step_by(4);
+
+    let mut group = c.benchmark_group("decode text section");
+    group.throughput(Throughput::Elements(u64::from(section.size / 4)));
+
+    group.bench_function("read and parse each address", |b| {
+        b.iter(|| {
+            for addr in addrs.clone() {
+                black_box(
+                    EncodedInstruction(read_linearly(dol, addr))
+                        .parse(addr)
+                        .ok(),
+                );
+            }
+        })
+    });
+
+    group.bench_function("build decoded section", |b| {
+        b.iter(|| {
+            black_box(DecodedSection::new(
+                section.load_address,
+                dol.section_data(section),
+            ))
+        })
+    });
+
+    let decoded = DecodedSection::new(section.load_address, dol.section_data(section));
+    group.bench_function("look up each address in decoded section", |b| {
+        b.iter(|| {
+            for addr in addrs.clone() {
+                black_box(decoded.get(addr));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, decode_text_section);
+criterion_main!(benches);
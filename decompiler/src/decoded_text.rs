@@ -0,0 +1,42 @@
+use powerpc::{DecodedInstruction, DecodedSection, EncodedInstruction, ParseError};
+
+use crate::memory_map::{MemoryMap, RegionKind};
+
+/// The program's code regions, decoded once so that every pass can look instructions up without
+/// decoding them again.
+pub struct DecodedText<'a, 'data> {
+    memory: &'a MemoryMap<'data>,
+    /// Sorted by address.
+    sections: Vec<DecodedSection>,
+}
+
+impl<'a, 'data> DecodedText<'a, 'data> {
+    pub fn new(memory: &'a MemoryMap<'data>) -> Self {
+        let sections = memory
+            .regions()
+            .iter()
+            .filter(|region| region.kind == RegionKind::Code)
+            .filter_map(|region| Some(DecodedSection::new(region.start, region.data?)))
+            .collect();
+        Self { memory, sections }
+    }
+
+    pub fn memory(&self) -> &'a MemoryMap<'data> {
+        self.memory
+    }
+
+    /// Returns the instruction at `addr`, or `None` if nothing is known to be loaded there.
+    /// Addresses outside the code regions are decoded on demand.
+    pub fn decode(&self, addr: u32) -> Option<Result<DecodedInstruction, ParseError>> {
+        let index = self
+            .sections
+            .partition_point(|section| section.address() <= addr);
+        index
+            .checked_sub(1)
+            .and_then(|index| self.sections[index].get(addr))
+            .or_else(|| {
+                let word = self.memory.read_u32(addr)?;
+                Some(EncodedInstruction(word).parse(addr))
+            })
+    }
+}
@@ -1,12 +1,16 @@
 use std::any::Any;
 use std::fmt::Display;
 
+pub mod address;
 pub mod basic_block;
 pub mod basic_block_end;
 pub mod branch_target;
+pub mod indirect_call;
+pub mod indirect_jump;
 pub mod parse_error;
 pub mod subroutine;
 pub mod subroutine_call;
+pub mod symbol;
 
 pub trait Fact: Any + 'static {
     fn as_any(&self) -> &dyn Any;
@@ -23,6 +27,7 @@ pub trait DefaultFact: Fact {
 }
 
 #[deprecated]
+#[allow(dead_code)]
 pub trait ConstructibleFact: Fact {
     type Args;
 
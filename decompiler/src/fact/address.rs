@@ -0,0 +1,30 @@
+use std::any::Any;
+
+use crate::fact::Fact;
+
+/// This instruction builds half of an address, like the `lis` and `addi` of a pair that loads a
+/// pointer into a register.
+#[derive(Debug)]
+pub struct AddressFact {
+    address: u32,
+}
+
+impl AddressFact {
+    pub fn new(address: u32) -> Self {
+        Self { address }
+    }
+
+    pub fn address(&self) -> u32 {
+        self.address
+    }
+}
+
+impl Fact for AddressFact {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -15,6 +15,7 @@ pub struct BasicBlockFact {
     predecessors: Vec<u32>,
     successors: Vec<u32>,
     writes: Vec<Write>,
+    #[allow(dead_code)]
     registers_leaving: HashMap<Register, ExprRef>,
 }
 
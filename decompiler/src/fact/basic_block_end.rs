@@ -29,6 +29,6 @@ impl Fact for BasicBlockEndFact {
 
 impl DefaultFact for BasicBlockEndFact {
     fn default() -> Box<Self> {
-        Box::new(Default::default())
+        Box::default()
     }
 }
@@ -35,7 +35,7 @@ impl Fact for BranchTargetFact {
 
 impl DefaultFact for BranchTargetFact {
     fn default() -> Box<Self> {
-        Box::new(Default::default())
+        Box::default()
     }
 }
 
@@ -43,7 +43,7 @@ impl Display for BranchTargetFact {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "#[branch_target(sources = [")?;
         let mut first = true;
-        for source in self.sources.iter().copied() {
+        for source in self.sources.iter() {
             if first {
                 first = false;
             } else {
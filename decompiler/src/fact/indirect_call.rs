@@ -0,0 +1,29 @@
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+
+use crate::fact::Fact;
+
+/// This address calls a subroutine whose address is only known at run time, like a function pointer
+/// or virtual method. Execution continues at the next instruction.
+#[derive(Default, Debug)]
+pub struct IndirectCallFact;
+
+impl Fact for IndirectCallFact {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn as_display(&self) -> Option<&dyn Display> {
+        Some(self)
+    }
+}
+
+impl Display for IndirectCallFact {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "#[indirect_call]")
+    }
+}
@@ -0,0 +1,29 @@
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+
+use crate::fact::Fact;
+
+/// This address branches to a target that is only known at run time, like a switch statement's jump
+/// table. Unlike a return, the basic block it ends has successors that haven't been found yet.
+#[derive(Default, Debug)]
+pub struct IndirectJumpFact;
+
+impl Fact for IndirectJumpFact {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn as_display(&self) -> Option<&dyn Display> {
+        Some(self)
+    }
+}
+
+impl Display for IndirectJumpFact {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "#[indirect_jump]")
+    }
+}
@@ -4,6 +4,7 @@ use crate::fact::Fact;
 
 #[derive(Default, Debug)]
 pub struct SubroutineCallFact {
+    #[allow(dead_code)]
     target: u32,
 }
 
@@ -0,0 +1,66 @@
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+
+use elf::SymbolKind;
+
+use crate::fact::Fact;
+
+/// A name for the code or data starting at this address, either imported or made up for a
+/// discovered subroutine.
+#[derive(Debug)]
+pub struct SymbolFact {
+    name: String,
+    size: Option<u32>,
+    /// Whether the symbol names code or data, if known.
+    kind: Option<SymbolKind>,
+}
+
+impl SymbolFact {
+    pub fn new(name: impl Into<String>, size: Option<u32>, kind: Option<SymbolKind>) -> Self {
+        Self {
+            name: name.into(),
+            size,
+            kind,
+        }
+    }
+
+    /// Names a subroutine found by scanning, which has no known size.
+    pub fn discovered(addr: u32) -> Self {
+        Self::new(format!("fn_{:08x}", addr), None, Some(SymbolKind::Function))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> Option<u32> {
+        self.size
+    }
+}
+
+impl Fact for SymbolFact {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn as_display(&self) -> Option<&dyn Display> {
+        Some(self)
+    }
+}
+
+impl Display for SymbolFact {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "#[symbol(name = {:?}", self.name)?;
+        if let Some(size) = self.size {
+            write!(f, ", size = 0x{:x}", size)?;
+        }
+        if let Some(kind) = self.kind {
+            write!(f, ", kind = {:?}", kind)?;
+        }
+        write!(f, ")]")
+    }
+}
@@ -29,6 +29,16 @@ impl FactDatabase {
         )
     }
 
+    /// Finds the fact of type `T` at the highest address not above `addr`.
+    pub fn get_fact_at_or_before<T: Fact>(&self, addr: u32) -> Option<(u32, &T)> {
+        let fact_addr = *self
+            .facts_by_type_id
+            .get(&TypeId::of::<T>())?
+            .range(..=addr)
+            .next_back()?;
+        Some((fact_addr, self.get_fact(fact_addr)?))
+    }
+
     pub fn get_fact_mut<T: Fact>(&mut self, addr: u32) -> Option<&mut T> {
         Some(
             self.facts_by_addr
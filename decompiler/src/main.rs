@@ -1,42 +1,171 @@
+use std::cmp::Reverse;
 use std::collections::{BTreeMap, BTreeSet};
-use std::fs::File;
+use std::convert::TryFrom;
+use std::env;
+use std::fs::{self, File};
 use std::io::BufWriter;
+use std::ops::Range;
 
+use dol::Image;
 use memmap::MmapOptions;
 use powerpc::gpr_constants::*;
-use powerpc::EncodedInstruction;
+use powerpc::{
+    DecodedInstruction, EffectiveAddress, Formatter, Gpr, ParseError, ParseErrorKind, Syntax,
+};
 use powerpc_symbolic::Variable;
 use symbolic::{Expr, ExprRef, NumberedVariable};
 use work_set::WorkSet;
 
+use crate::decoded_text::DecodedText;
+use crate::fact::address::AddressFact;
 use crate::fact::basic_block::{BasicBlockFact, BasicBlockFactBuilder};
 use crate::fact::basic_block_end::BasicBlockEndFact;
 use crate::fact::branch_target::BranchTargetFact;
+use crate::fact::indirect_call::IndirectCallFact;
+use crate::fact::indirect_jump::IndirectJumpFact;
 use crate::fact::parse_error::ParseErrorFact;
 use crate::fact::subroutine::SubroutineFact;
 use crate::fact::subroutine_call::SubroutineCallFact;
+use crate::fact::symbol::SymbolFact;
 use crate::fact_database::FactDatabase;
 use crate::iter_singleton::IteratorExt;
 use crate::locale::LocaleFormat;
+use crate::memory_map::{MemoryMap, RegionKind};
 use crate::powerpc_symbolic::{Context, MachineState};
+use crate::symbol_resolver::SymbolResolver;
 
+mod decoded_text;
 mod fact;
 mod fact_database;
 mod iter_singleton;
 mod locale;
+mod memory_map;
 mod powerpc_symbolic;
+mod symbol_resolver;
+
+const USAGE: &str = "usage: decompiler [--symbols <path>] [--rel <path>]... \
+                     [--region <kind> <address> <path>]... [<executable> [<address>]]";
+
+/// The GameCube's hardware registers.
+const HARDWARE_REGISTERS: Range<u32> = 0xcc000000..0xcc008000;
+
+/// What to analyze, given on the command line.
+struct Options {
+    /// A DOL, an ELF or a disc image, whose main executable is analyzed. Without one, the Melee
+    /// disc image is.
+    executable: Option<String>,
+    /// Where to start scanning, instead of the executable's entry point.
+    entry_point: Option<u32>,
+    /// REL modules to load after the executable.
+    rels: Vec<String>,
+    /// Files to load at fixed addresses beneath everything else, such as a MEM1 dump.
+    regions: Vec<(RegionKind, u32, String)>,
+    /// A list of symbols to import, overriding the executable's own.
+    symbols: Option<String>,
+}
+
+/// Analyzed when no executable is given.
+const DEFAULT_DISC_IMAGE_PATH: &str = "Super Smash Bros. Melee (v1.02).iso";
 
 fn main() {
-    let file = File::open("Super Smash Bros. Melee (v1.02).iso").unwrap();
-    let disc_image = unsafe { MmapOptions::new().map(&file) }.unwrap();
+    let options = parse_options(env::args().skip(1));
+    let path = options
+        .executable
+        .as_deref()
+        .unwrap_or(DEFAULT_DISC_IMAGE_PATH);
+    analyze_file(path, &options);
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Options {
+    let mut options = Options {
+        executable: None,
+        entry_point: None,
+        rels: Vec::new(),
+        regions: Vec::new(),
+        symbols: None,
+    };
+    let value = |args: &mut dyn Iterator<Item = String>| args.next().expect(USAGE);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--symbols" => options.symbols = Some(value(&mut args)),
+            "--rel" => options.rels.push(value(&mut args)),
+            "--region" => {
+                let kind = match value(&mut args).as_str() {
+                    "code" => RegionKind::Code,
+                    "rodata" => RegionKind::ReadOnlyData,
+                    "data" => RegionKind::Data,
+                    "bss" => RegionKind::Bss,
+                    "mmio" => RegionKind::Mmio,
+                    kind => panic!(
+                        "unknown region kind {:?}; expected code, rodata, data, bss or mmio",
+                        kind
+                    ),
+                };
+                let address = parse_address(&value(&mut args));
+                options.regions.push((kind, address, value(&mut args)));
+            }
+            _ if arg.starts_with("--") => panic!("unknown option {}\n{}", arg, USAGE),
+            _ if options.executable.is_none() => options.executable = Some(arg),
+            _ if options.entry_point.is_none() => options.entry_point = Some(parse_address(&arg)),
+            _ => panic!("{}", USAGE),
+        }
+    }
+    options
+}
+
+fn parse_address(text: &str) -> u32 {
+    u32::from_str_radix(text.trim_start_matches("0x"), 16)
+        .unwrap_or_else(|_| panic!("bad address: {}", text))
+}
+
+/// Analyzes a DOL, an ELF or a disc image's main executable. An ELF's symbol table is imported.
+fn analyze_file(path: &str, options: &Options) {
+    let file = File::open(path).unwrap_or_else(|e| panic!("couldn't open {}: {}", path, e));
+    let data = unsafe { MmapOptions::new().map(&file) }.unwrap();
+
+    if data.starts_with(b"\x7fELF") {
+        let elf = elf::Reader::parse(&data).unwrap_or_else(|e| panic!("{}: {}", path, e));
+        let symbols = elf
+            .symbols()
+            .filter_map(|symbol| {
+                let name = std::str::from_utf8(symbol.name).ok()?;
+                let size = (symbol.size != 0).then_some(symbol.size);
+                let fact = SymbolFact::new(name, size, Some(symbol.kind));
+                Some((symbol.address, fact))
+            })
+            .collect();
+        let entry_point = options.entry_point.unwrap_or(elf.entry_point());
+        load_and_analyze(&elf, elf.bss(), symbols, entry_point, options);
+    } else if gamecube_disc::HeaderReader::parse(&data).is_ok() {
+        analyze_disc(path, &data, options);
+    } else {
+        let dol = dol::Reader::parse(&data).unwrap_or_else(|e| panic!("{}: {}", path, e));
+        let entry_point = options.entry_point.unwrap_or(dol.entry_point());
+        load_and_analyze(&dol, Some(dol.bss()), Vec::new(), entry_point, options);
+    }
+}
 
-    let disc = gamecube_disc::Reader::new(&*disc_image);
-    assert_eq!(disc.header().game_code(), "GALE");
-    assert_eq!(disc.header().maker_code(), "01");
-    assert_eq!(disc.header().disc_id(), 0);
-    assert_eq!(disc.header().version(), 2);
+fn analyze_disc(path: &str, disc_image: &[u8], options: &Options) {
+    let disc = gamecube_disc::Reader::new(disc_image);
+    let fail = |e: &dyn std::fmt::Display| -> ! { panic!("{}: {}", path, e) };
+    let header = disc.header().unwrap_or_else(|e| fail(&e));
+    let region = disc
+        .bi2()
+        .and_then(|bi2| bi2.region())
+        .unwrap_or_else(|e| fail(&e));
+    let apploader = disc.apploader().unwrap_or_else(|e| fail(&e));
+    println!(
+        "disc: {} ({}{}), disc {}, version {}, {:?}, apploader {}",
+        header.game_name(),
+        header.game_code(),
+        header.maker_code(),
+        header.disc_id(),
+        header.version(),
+        region,
+        apploader.build_date(),
+    );
 
-    let dol = disc.main_executable();
+    let dol = disc.main_executable().unwrap_or_else(|e| fail(&e));
 
     for section in dol.iter_sections() {
         println!(
@@ -45,11 +174,121 @@ fn main() {
         );
     }
 
-    analyze(dol, 0x803631e4);
+    // Start from a subroutine of interest in Melee v1.02, or the entry point in anything else.
+    let is_melee = header.game_code() == "GALE" && header.maker_code() == "01";
+    let default_entry_point = if is_melee && header.version() == 2 {
+        0x803631e4
+    } else {
+        dol.entry_point()
+    };
+    let entry_point = options.entry_point.unwrap_or(default_entry_point);
+    load_and_analyze(&dol, Some(dol.bss()), Vec::new(), entry_point, options);
+}
+
+/// Lays out memory around `image`, which uses `bss`, and analyzes it.
+///
+/// The extra regions go beneath the executable, and the RELs are loaded after it.
+fn load_and_analyze(
+    image: &dyn Image<'_>,
+    bss: Option<Range<u32>>,
+    symbols: Vec<(u32, SymbolFact)>,
+    entry_point: u32,
+    options: &Options,
+) {
+    let regions: Vec<(RegionKind, u32, Vec<u8>)> = options
+        .regions
+        .iter()
+        .map(|(kind, address, path)| {
+            let data = fs::read(path).unwrap_or_else(|e| panic!("couldn't read {}: {}", path, e));
+            (*kind, *address, data)
+        })
+        .collect();
+    let image_end = image
+        .sections()
+        .iter()
+        .map(|section| section.end_address())
+        .chain(bss.as_ref().map(|bss| bss.end))
+        .max()
+        .unwrap_or(0);
+    let rels = load_rels(&options.rels, image_end).unwrap_or_else(|message| panic!("{}", message));
+
+    let mut memory = MemoryMap::new();
+    for ((kind, address, data), (_, _, path)) in regions.iter().zip(&options.regions) {
+        memory
+            .add_data(*address, *kind, data)
+            .unwrap_or_else(|e| panic!("{}: {}", path, e));
+    }
+    // The BSS may span sections with data, such as `.sdata` between `.bss` and `.sbss`, so it
+    // goes beneath them.
+    if let Some(bss) = bss {
+        memory.add_empty(bss, RegionKind::Bss);
+    }
+    memory.add_image(image).unwrap_or_else(|e| panic!("{}", e));
+    for (module, path) in rels.iter().zip(&options.rels) {
+        memory
+            .add_image(&module)
+            .unwrap_or_else(|e| panic!("{}: {}", path, e));
+        memory.add_empty(module.bss(), RegionKind::Bss);
+    }
+    memory.add_empty(HARDWARE_REGISTERS, RegionKind::Mmio);
+
+    analyze(&memory, symbols, entry_point, options.symbols.as_deref());
+}
+
+/// Loads each REL module after the one before it, starting at `address`. Each module's BSS
+/// follows its code and data.
+///
+/// Only relocations against the module itself and the DOL are applied.
+fn load_rels(paths: &[String], mut address: u32) -> Result<Vec<rel::LoadedModule>, String> {
+    paths
+        .iter()
+        .map(|path| {
+            let data = fs::read(path).map_err(|e| format!("couldn't read {}: {}", path, e))?;
+            let reader = rel::Reader::parse(&data).map_err(|e| format!("{}: {}", path, e))?;
+            let header = reader.header();
+            let base = address.checked_next_multiple_of(header.align.max(1));
+            let bss = base.and_then(|base| {
+                u32::try_from(data.len())
+                    .ok()
+                    .and_then(|len| base.checked_add(len))?
+                    .checked_next_multiple_of(header.bss_align.max(1))
+            });
+            let module = base
+                .zip(bss)
+                .ok_or(rel::LoadError::OutOfAddressSpace { address })
+                .and_then(|(base, bss)| reader.load(base, bss))
+                .map_err(|e| format!("{}: {}", path, e))?;
+            println!(
+                "rel: {}, id = {}, base = 0x{:08x}, bss = 0x{:08x}",
+                path,
+                reader.id(),
+                module.base(),
+                module.bss().start
+            );
+            address = module.bss().end;
+            Ok(module)
+        })
+        .collect()
 }
 
-fn analyze(dol: dol::Reader, entry_point: u32) {
+/// Scans from `entry_point` and prints what was found. `symbols` come from the executable itself,
+/// and are overridden by those imported from `symbols_path`, if given.
+fn analyze(
+    memory: &MemoryMap,
+    symbols: Vec<(u32, SymbolFact)>,
+    entry_point: u32,
+    symbols_path: Option<&str>,
+) {
     let mut db = FactDatabase::new();
+    let text = DecodedText::new(memory);
+
+    // Import known names first so they take precedence over made-up ones.
+    if let Some(path) = symbols_path {
+        import_symbols(&mut db, path);
+    }
+    for (addr, symbol) in symbols {
+        db.insert_fact_with(addr, || symbol);
+    }
 
     // Mark the entry point.
     db.insert_fact_with(entry_point, || SubroutineFact);
@@ -57,23 +296,55 @@ fn analyze(dol: dol::Reader, entry_point: u32) {
     let mut addrs_to_scan = WorkSet::new();
     addrs_to_scan.insert(entry_point);
     while let Some(addr) = addrs_to_scan.peek().copied() {
-        scan_and_close_addrs(dol, addr, &mut db, &mut addrs_to_scan);
+        scan_and_close_addrs(&text, addr, &mut db, &mut addrs_to_scan);
     }
 
-    print_annotated_assembly(dol, &db);
+    // Name the subroutines that weren't imported.
+    let subroutines: Vec<u32> = db.iter_facts_with_type::<SubroutineFact>().collect();
+    for addr in subroutines {
+        db.insert_fact_with(addr, || SymbolFact::discovered(addr));
+    }
+
+    let scanned: BTreeSet<u32> = addrs_to_scan.iter_known().copied().collect();
+    print_annotated_assembly(&text, &db, &scanned);
 
     println!();
-    println!(
-        "scanned {} instructions",
-        LocaleFormat(&addrs_to_scan.iter_known().count()),
-    );
+    println!("scanned {} instructions", LocaleFormat(&scanned.len()),);
 
     print_errors(&db);
 
     build_basic_blocks(&mut db, entry_point);
-    write_graphviz_basic_blocks(dol, &db);
+    write_graphviz_basic_blocks(&text, &db);
+
+    build_expressions(&text, &mut db, entry_point);
+}
 
-    build_expressions(dol, &mut db, entry_point);
+/// Records a [`SymbolFact`] for each line of `path`.
+///
+/// Each line holds a hex address, an optional hex size and a name, separated by whitespace. Blank
+/// lines and lines starting with `#` are ignored.
+fn import_symbols(db: &mut FactDatabase, path: &str) {
+    let text = fs::read_to_string(path).unwrap_or_else(|e| panic!("couldn't read {}: {}", path, e));
+
+    let parse_hex = |word: &str| u32::from_str_radix(word.trim_start_matches("0x"), 16).ok();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let symbol = match words[..] {
+            [addr, name] => parse_hex(addr).map(|addr| (addr, None, name)),
+            [addr, size, name] => parse_hex(addr)
+                .zip(parse_hex(size))
+                .map(|(addr, size)| (addr, Some(size), name)),
+            _ => None,
+        };
+        let (addr, size, name) = symbol
+            .unwrap_or_else(|| panic!("{}:{}: expected `address [size] name`", path, index + 1));
+        db.insert_fact_with(addr, || SymbolFact::new(name, size, None));
+    }
 }
 
 /// Scans instructions and records facts until the first diverging branch or closed address is
@@ -82,28 +353,38 @@ fn analyze(dol: dol::Reader, entry_point: u32) {
 /// Closes addresses in `addrs_to_scan` as it goes. Inserts branch targets (both local branches and
 /// subroutine calls) into `addrs_to_scan`.
 fn scan_and_close_addrs(
-    dol: dol::Reader,
+    text: &DecodedText,
     mut addr: u32,
     db: &mut FactDatabase,
     addrs_to_scan: &mut WorkSet<u32>,
 ) {
+    // The high halves of addresses loaded by `lis`, by GPR.
+    let mut high_halves = [None; 32];
+
     loop {
         // Fetch and parse the instruction.
         if !addrs_to_scan.close(addr) {
             break;
         }
-        let data = dol.read(addr);
-        let instruction = match EncodedInstruction(data).parse(addr) {
-            Ok(instruction) => instruction,
-            Err(e) => {
+        let instruction = match text.decode(addr) {
+            Some(Ok(instruction)) => instruction,
+            Some(Err(e)) => {
                 // Parse error. Record the error and abort scanning.
                 db.insert_fact_with(addr, || ParseErrorFact::new(e));
                 break;
             }
+            // Nothing is known to be loaded here, like in a REL that isn't loaded.
+            None => break,
         };
 
+        record_address_halves(db, addr, instruction, &mut high_halves);
+
         // Handle branch instructions.
         if let Some(branch_info) = instruction.branch_info() {
+            // Other paths may join at the target or after a call, so forget what the registers
+            // hold.
+            high_halves = [None; 32];
+
             if let Some(target) = branch_info.target {
                 // This branch has a static target. It's either a subroutine call or a local branch.
 
@@ -125,7 +406,20 @@ fn scan_and_close_addrs(
                         successor_fact.record_successor(addr + 4);
                     }
                 }
-            } else if !branch_info.link {
+            } else if branch_info.link {
+                // This is a subroutine call through a function pointer. Scanning continues after it
+                // just like a static call.
+                db.insert_fact_with(addr, || IndirectCallFact);
+            } else if let DecodedInstruction::Bcctr { .. } = instruction {
+                // This is a jump through CTR, typically into a switch statement's jump table. Its
+                // targets are unknown, so mark the end of a basic block whose only known successor
+                // is the fall-through path, if any.
+                db.insert_fact_with(addr, || IndirectJumpFact);
+                let successor_fact = db.fact_or_default::<BasicBlockEndFact>(addr);
+                if branch_info.is_conditional() {
+                    successor_fact.record_successor(addr + 4);
+                }
+            } else {
                 // This branch has a dynamic target and it's not a subroutine call. Assume it's a
                 // return. Mark the end of a basic block with no successors.
                 db.fact_or_default::<BasicBlockEndFact>(addr);
@@ -139,10 +433,96 @@ fn scan_and_close_addrs(
     }
 }
 
-fn print_annotated_assembly(dol: dol::Reader, db: &FactDatabase) {
+/// Records [`AddressFact`]s for `lis` instructions and the instructions that add the low half of
+/// the address to their result, which is the usual way of loading a pointer or accessing a global.
+///
+/// `high_halves` holds the address and high half of the last `lis` into each GPR still holding it.
+fn record_address_halves(
+    db: &mut FactDatabase,
+    addr: u32,
+    instruction: DecodedInstruction,
+    high_halves: &mut [Option<(u32, u32)>; 32],
+) {
+    let high_half = |gpr: u32| high_halves[gpr as usize];
+    let low_half = match instruction {
+        DecodedInstruction::Addi { src, immediate, .. } if src.is_gpr() => high_half(src.as_u32())
+            .map(|(lis_addr, high)| (lis_addr, (high << 16).wrapping_add(immediate as i32 as u32))),
+        DecodedInstruction::Ori { src, immediate, .. } => high_half(src.as_u32())
+            .map(|(lis_addr, high)| (lis_addr, high << 16 | immediate as u32)),
+        _ => match instruction.memory_access().map(|access| access.address) {
+            Some(EffectiveAddress::Offset { base, offset }) if base.is_gpr() => {
+                high_half(base.as_u32()).map(|(lis_addr, high)| {
+                    (lis_addr, (high << 16).wrapping_add(offset as i32 as u32))
+                })
+            }
+            _ => None,
+        },
+    };
+    if let Some((lis_addr, address)) = low_half {
+        db.insert_fact_with(lis_addr, || AddressFact::new(address));
+        db.insert_fact_with(addr, || AddressFact::new(address));
+    }
+
+    let defs = instruction.defs();
+    for (index, high_half) in high_halves.iter_mut().enumerate() {
+        if defs.contains(Gpr::new(index as u32).unwrap()) {
+            *high_half = None;
+        }
+    }
+    if let DecodedInstruction::Addis {
+        dst,
+        src,
+        immediate,
+    } = instruction
+    {
+        if src.is_zero() {
+            high_halves[dst.as_u32() as usize] = Some((addr, immediate as u16 as u32));
+        }
+    }
+}
+
+/// Formats an instruction, naming the address it builds half of, if any.
+fn format_instruction(
+    formatter: &Formatter,
+    db: &FactDatabase,
+    addr: u32,
+    instruction: DecodedInstruction,
+) -> String {
+    match db.get_fact::<AddressFact>(addr) {
+        Some(fact) => formatter.format_address(instruction, fact.address()),
+        None => formatter.format(instruction),
+    }
+}
+
+/// Returns the string at the address that the instruction at `addr` builds half of, if it's
+/// printable text in a data section.
+fn referenced_string<'data>(
+    memory: &MemoryMap<'data>,
+    db: &FactDatabase,
+    addr: u32,
+) -> Option<&'data str> {
+    let address = db.get_fact::<AddressFact>(addr)?.address();
+    match memory.region_containing(address)?.kind {
+        RegionKind::ReadOnlyData | RegionKind::Data => {}
+        _ => return None,
+    }
+    let string = memory.read_c_string(address)?.to_str().ok()?;
+    let printable = !string.is_empty()
+        && string
+            .chars()
+            .all(|c| c.is_ascii_graphic() || c.is_ascii_whitespace());
+    printable.then_some(string)
+}
+
+/// Prints the scanned instructions with their facts. Facts at other addresses, such as the symbols
+/// of data, are left out.
+fn print_annotated_assembly(text: &DecodedText, db: &FactDatabase, scanned: &BTreeSet<u32>) {
     println!("# annotated assembly");
 
-    for (addr, facts) in db.iter_facts() {
+    let resolver = SymbolResolver::new(db, text.memory());
+    let formatter = Formatter::new(Syntax::CodeWarrior).resolver(&resolver);
+
+    for (addr, facts) in db.iter_facts().filter(|(addr, _)| scanned.contains(addr)) {
         // Add a space before starting a subroutine.
         if db.get_fact::<SubroutineFact>(addr).is_some() {
             println!();
@@ -158,31 +538,75 @@ fn print_annotated_assembly(dol: dol::Reader, db: &FactDatabase) {
         }
 
         // Print the assembly listing.
-        let data = dol.read(addr);
-        print!("0x{:08x}  0x{:08x}  ", addr, data);
-        match EncodedInstruction(data).parse(addr) {
-            Ok(instruction) => println!("{}", instruction),
-            Err(e) => println!("; ERROR: {}", e),
+        let word = match text.memory().read_u32(addr) {
+            Some(word) => word,
+            None => {
+                println!("0x{:08x}  (not loaded)", addr);
+                continue;
+            }
+        };
+        print!("0x{:08x}  0x{:08x}  ", addr, word);
+        match text.decode(addr).unwrap() {
+            Ok(instruction) => {
+                print!("{}", format_instruction(&formatter, db, addr, instruction));
+                match referenced_string(text.memory(), db, addr) {
+                    Some(string) => println!("  # {:?}", string),
+                    None => println!(),
+                }
+            }
+            Err(e) => println!("; ERROR: {}", e.kind),
         };
     }
 }
 
+/// Prints a census of parse errors, grouped by opcode, extended opcode and kind, most frequent
+/// first.
 fn print_errors(db: &FactDatabase) {
-    let mut errors = BTreeSet::new();
+    const EXAMPLE_COUNT: usize = 3;
+
+    let mut groups: BTreeMap<(u32, Option<u32>, ParseErrorKind), Vec<&ParseError>> =
+        BTreeMap::new();
     for addr in db.iter_facts_with_type::<ParseErrorFact>() {
-        errors.insert(format!(
-            "{}",
-            db.get_fact::<ParseErrorFact>(addr).unwrap().parse_error(),
-        ));
+        let error = db.get_fact::<ParseErrorFact>(addr).unwrap().parse_error();
+        groups
+            .entry((error.opcode(), error.extended_opcode(), error.kind))
+            .or_default()
+            .push(error);
+    }
+    if groups.is_empty() {
+        return;
     }
 
-    if !errors.is_empty() {
-        println!();
-        println!("# errors");
-        println!();
-        for error in errors {
-            println!("{}", error);
-        }
+    let mut groups: Vec<_> = groups.into_iter().collect();
+    groups.sort_by_key(|(_, errors)| Reverse(errors.len()));
+
+    println!();
+    println!("# errors");
+    println!();
+    println!("{:>8}  {:>6}  {:>8}  error", "count", "opcode", "extended");
+    for ((opcode, extended_opcode, kind), errors) in groups {
+        let extended_opcode = match extended_opcode {
+            Some(extended_opcode) => extended_opcode.to_string(),
+            None => "-".to_string(),
+        };
+        let examples: Vec<_> = errors
+            .iter()
+            .take(EXAMPLE_COUNT)
+            .map(|error| format!("0x{:08x}", error.address))
+            .collect();
+        println!(
+            "{:>8}  {:>6}  {:>8}  {} (e.g. {}{})",
+            LocaleFormat(&errors.len()).to_string(),
+            opcode,
+            extended_opcode,
+            kind,
+            examples.join(", "),
+            if errors.len() > EXAMPLE_COUNT {
+                ", ..."
+            } else {
+                ""
+            },
+        );
     }
 }
 
@@ -226,7 +650,7 @@ fn build_basic_blocks(db: &mut FactDatabase, entry_point: u32) {
     );
 }
 
-fn write_graphviz_basic_blocks(dol: dol::Reader, db: &FactDatabase) {
+fn write_graphviz_basic_blocks(text: &DecodedText, db: &FactDatabase) {
     use std::io::Write;
 
     let mut dot = BufWriter::new(File::create("graph.dot").unwrap());
@@ -238,6 +662,9 @@ fn write_graphviz_basic_blocks(dol: dol::Reader, db: &FactDatabase) {
     )
     .unwrap();
 
+    let resolver = SymbolResolver::new(db, text.memory());
+    let formatter = Formatter::new(Syntax::CodeWarrior).resolver(&resolver);
+
     for addr in db.iter_facts_with_type::<BasicBlockFact>() {
         let basic_block = db.get_fact::<BasicBlockFact>(addr).unwrap();
 
@@ -250,13 +677,19 @@ fn write_graphviz_basic_blocks(dol: dol::Reader, db: &FactDatabase) {
         )
         .unwrap();
         for addr in (addr..basic_block.end_addr()).step_by(4) {
-            let instruction = EncodedInstruction(dol.read(addr)).parse(addr).unwrap();
-            write!(dot, "0x{:08x}  {}\\l", addr, instruction).unwrap();
+            let instruction = text.decode(addr).unwrap().unwrap();
+            write!(
+                dot,
+                "0x{:08x}  {}\\l",
+                addr,
+                format_instruction(&formatter, db, addr, instruction),
+            )
+            .unwrap();
         }
         write!(dot, "\"];").unwrap();
 
         // Emit edges to all successors.
-        for target in basic_block.successors().iter().copied() {
+        for target in basic_block.successors() {
             writeln!(dot, "    \"0x{:08x}\" -> \"0x{:08x}\";", addr, target).unwrap();
         }
     }
@@ -265,7 +698,7 @@ fn write_graphviz_basic_blocks(dol: dol::Reader, db: &FactDatabase) {
 }
 
 /// Builds expressions.
-fn build_expressions(dol: dol::Reader, db: &mut FactDatabase, entry_point: u32) {
+fn build_expressions(text: &DecodedText, db: &mut FactDatabase, entry_point: u32) {
     println!();
     println!("# first expression pass (local symbolic execution)");
 
@@ -357,7 +790,7 @@ fn build_expressions(dol: dol::Reader, db: &mut FactDatabase, entry_point: u32)
         // machine_state.write_memory_base_offset(r31, 0, r31_word0);
 
         for addr in (basic_block_addr..basic_block.end_addr()).step_by(4) {
-            let instruction = EncodedInstruction(dol.read(addr)).parse(addr).unwrap();
+            let instruction = text.decode(addr).unwrap().unwrap();
             let update = machine_state.prepare_update(addr, &instruction);
 
             // Don't print anything for calls. It's always the same verbose thing.
@@ -471,7 +904,7 @@ fn build_expressions(dol: dol::Reader, db: &mut FactDatabase, entry_point: u32)
             exprs_to_visit.insert(write.addr);
             exprs_to_visit.insert(write.data);
         }
-        if basic_block.successors().is_empty() {
+        if returns(db, basic_block) {
             let variable = ctx.variable_expr(Variable::RegisterLeaving {
                 basic_block_addr,
                 register: R3.into(),
@@ -598,7 +1031,7 @@ fn build_expressions(dol: dol::Reader, db: &mut FactDatabase, entry_point: u32)
             );
         }
 
-        if basic_block.successors().is_empty() {
+        if returns(db, basic_block) {
             let expr = ctx.variable_expr(Variable::RegisterLeaving {
                 basic_block_addr,
                 register: R3.into(),
@@ -609,6 +1042,16 @@ fn build_expressions(dol: dol::Reader, db: &mut FactDatabase, entry_point: u32)
     }
 }
 
+/// True if the basic block ends by returning from its subroutine.
+///
+/// Basic blocks ending in an indirect jump also have no known successors, but they don't return.
+fn returns(db: &FactDatabase, basic_block: &BasicBlockFact) -> bool {
+    basic_block.successors().is_empty()
+        && db
+            .get_fact::<IndirectJumpFact>(basic_block.end_addr() - 4)
+            .is_none()
+}
+
 fn resolve_variables(
     ctx: &mut symbolic::Context<NumberedVariable<Variable>>,
     mut expr: ExprRef,
@@ -0,0 +1,336 @@
+use std::convert::{TryFrom, TryInto};
+use std::ffi::CStr;
+use std::ops::Range;
+
+use dol::Image;
+use thiserror::Error;
+
+/// What a region of memory holds, which says how it may be used.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RegionKind {
+    Code,
+    ReadOnlyData,
+    Data,
+    /// Zero-initialized memory, whose contents aren't known ahead of time.
+    Bss,
+    /// Hardware registers, which can't be read ahead of time either.
+    Mmio,
+}
+
+/// A range of addresses and the bytes loaded there, if they're known.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Region<'data> {
+    pub start: u32,
+    pub end: u32,
+    pub kind: RegionKind,
+    /// Exactly `end - start` bytes, or `None` for BSS and MMIO.
+    pub data: Option<&'data [u8]>,
+}
+
+impl<'data> Region<'data> {
+    pub fn contains(&self, address: u32) -> bool {
+        (self.start..self.end).contains(&address)
+    }
+
+    /// The part of the region within `range`, which must overlap it.
+    fn clip(self, range: Range<u32>) -> Region<'data> {
+        let start = self.start.max(range.start);
+        let end = self.end.min(range.end);
+        Region {
+            start,
+            end,
+            data: self
+                .data
+                .map(|data| &data[(start - self.start) as usize..(end - self.start) as usize]),
+            ..self
+        }
+    }
+}
+
+/// The program's address space, assembled from the DOL, its BSS, loaded RELs and any other
+/// regions, such as a memory dump.
+///
+/// Regions are layered in the order they're added, so where two overlap, the later one is seen.
+/// Like [`Image`], reads never cross from one region into another.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryMap<'data> {
+    /// The visible parts of each region, sorted by address and not overlapping.
+    regions: Vec<Region<'data>>,
+}
+
+impl<'data> MemoryMap<'data> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a region of `kind` holding `data` at `start`.
+    pub fn add_data(
+        &mut self,
+        start: u32,
+        kind: RegionKind,
+        data: &'data [u8],
+    ) -> Result<(), OutOfAddressSpace> {
+        let end = u32::try_from(data.len())
+            .ok()
+            .and_then(|len| start.checked_add(len))
+            .ok_or(OutOfAddressSpace {
+                start,
+                len: data.len(),
+            })?;
+        self.add(Region {
+            start,
+            end,
+            kind,
+            data: Some(data),
+        });
+        Ok(())
+    }
+
+    /// Adds a region of `kind` whose contents aren't known, such as BSS or hardware registers.
+    pub fn add_empty(&mut self, range: Range<u32>, kind: RegionKind) {
+        self.add(Region {
+            start: range.start,
+            end: range.end,
+            kind,
+            data: None,
+        });
+    }
+
+    /// Adds each section of `image` as code, read-only data or data.
+    pub fn add_image<'image: 'data>(
+        &mut self,
+        image: &dyn Image<'image>,
+    ) -> Result<(), OutOfAddressSpace> {
+        for section in image.sections() {
+            let kind = match section.kind {
+                dol::SectionKind::Text => RegionKind::Code,
+                dol::SectionKind::Data => RegionKind::Data,
+                dol::SectionKind::ReadOnlyData => RegionKind::ReadOnlyData,
+            };
+            self.add_data(section.load_address, kind, image.section_data(section))?;
+        }
+        Ok(())
+    }
+
+    fn add(&mut self, region: Region<'data>) {
+        if region.start >= region.end {
+            return;
+        }
+        let mut regions = Vec::with_capacity(self.regions.len() + 2);
+        for &other in &self.regions {
+            if other.end <= region.start || region.end <= other.start {
+                regions.push(other);
+                continue;
+            }
+            // Keep whatever sticks out from under the new region.
+            if other.start < region.start {
+                regions.push(other.clip(other.start..region.start));
+            }
+            if region.end < other.end {
+                regions.push(other.clip(region.end..other.end));
+            }
+        }
+        regions.push(region);
+        regions.sort_unstable_by_key(|region| region.start);
+        self.regions = regions;
+    }
+
+    pub fn regions(&self) -> &[Region<'data>] {
+        &self.regions
+    }
+
+    /// Returns the visible part of the region holding `address`, if any.
+    pub fn region_containing(&self, address: u32) -> Option<Region<'data>> {
+        let index = self
+            .regions
+            .partition_point(|region| region.start <= address);
+        let region = self.regions[..index].last().copied()?;
+        region.contains(address).then_some(region)
+    }
+
+    /// Returns the bytes loaded at `range`, or `None` unless one region holds all of them and
+    /// their contents are known.
+    pub fn read_bytes(&self, range: Range<u32>) -> Option<&'data [u8]> {
+        let region = self.region_containing(range.start)?;
+        let len = range.end.checked_sub(range.start)?;
+        let start = (range.start - region.start) as usize;
+        region.data?.get(start..start + len as usize)
+    }
+
+    pub fn read_u32(&self, address: u32) -> Option<u32> {
+        let bytes = self.read_bytes(address..address.checked_add(4)?)?;
+        Some(u32::from_be_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// Returns the NUL-terminated string at `address`, or `None` if the region holding it ends
+    /// first.
+    pub fn read_c_string(&self, address: u32) -> Option<&'data CStr> {
+        let region = self.region_containing(address)?;
+        let data = &region.data?[(address - region.start) as usize..];
+        let len = data.iter().position(|&byte| byte == 0)?;
+        Some(CStr::from_bytes_with_nul(&data[..=len]).unwrap())
+    }
+}
+
+/// Data that would run past the end of the address space if loaded where it was asked to be.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[error("0x{len:x} bytes at 0x{start:08x} extend past the end of the address space")]
+pub struct OutOfAddressSpace {
+    pub start: u32,
+    pub len: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryMap, OutOfAddressSpace, Region, RegionKind};
+
+    const DATA: [u8; 0x40] = {
+        let mut data = [0; 0x40];
+        let mut i = 0;
+        while i < data.len() {
+            data[i] = i as u8;
+            i += 1;
+        }
+        data
+    };
+
+    #[test]
+    fn fully_covered() {
+        let mut memory = MemoryMap::new();
+        memory.add_data(0x100, RegionKind::Data, &DATA).unwrap();
+        memory.add_empty(0xf0..0x150, RegionKind::Bss);
+        assert_eq!(
+            memory.regions(),
+            [Region {
+                start: 0xf0,
+                end: 0x150,
+                kind: RegionKind::Bss,
+                data: None,
+            }]
+        );
+        assert_eq!(memory.read_u32(0x100), None);
+    }
+
+    #[test]
+    fn partly_covered() {
+        // Covered on each side.
+        let mut memory = MemoryMap::new();
+        memory.add_data(0x100, RegionKind::Data, &DATA).unwrap();
+        memory
+            .add_data(0xf0, RegionKind::Code, &[0xaa; 0x20])
+            .unwrap();
+        memory.add_empty(0x130..0x150, RegionKind::Bss);
+        assert_eq!(
+            memory.regions(),
+            [
+                Region {
+                    start: 0xf0,
+                    end: 0x110,
+                    kind: RegionKind::Code,
+                    data: Some(&[0xaa; 0x20][..]),
+                },
+                Region {
+                    start: 0x110,
+                    end: 0x130,
+                    kind: RegionKind::Data,
+                    data: Some(&DATA[0x10..0x30]),
+                },
+                Region {
+                    start: 0x130,
+                    end: 0x150,
+                    kind: RegionKind::Bss,
+                    data: None,
+                },
+            ]
+        );
+
+        // Covered in the middle, which leaves a piece on each side.
+        let mut memory = MemoryMap::new();
+        memory.add_data(0x100, RegionKind::Data, &DATA).unwrap();
+        memory.add_empty(0x110..0x120, RegionKind::Mmio);
+        assert_eq!(
+            memory.regions(),
+            [
+                Region {
+                    start: 0x100,
+                    end: 0x110,
+                    kind: RegionKind::Data,
+                    data: Some(&DATA[..0x10]),
+                },
+                Region {
+                    start: 0x110,
+                    end: 0x120,
+                    kind: RegionKind::Mmio,
+                    data: None,
+                },
+                Region {
+                    start: 0x120,
+                    end: 0x140,
+                    kind: RegionKind::Data,
+                    data: Some(&DATA[0x20..]),
+                },
+            ]
+        );
+        assert_eq!(memory.read_u32(0x10c), Some(0x0c0d0e0f));
+        assert_eq!(memory.read_u32(0x120), Some(0x20212223));
+    }
+
+    #[test]
+    fn reads() {
+        let mut memory = MemoryMap::new();
+        memory
+            .add_data(0x100, RegionKind::Data, &DATA[..0x10])
+            .unwrap();
+        memory
+            .add_data(0x110, RegionKind::Data, &DATA[0x10..])
+            .unwrap();
+        memory.add_empty(0x140..0x160, RegionKind::Bss);
+        memory.add_empty(0x160..0x180, RegionKind::Mmio);
+
+        assert_eq!(memory.read_bytes(0x108..0x110), Some(&DATA[0x08..0x10]));
+        // Reads that cross from one region into the next.
+        assert_eq!(memory.read_bytes(0x108..0x118), None);
+        assert_eq!(memory.read_u32(0x10e), None);
+        assert_eq!(memory.read_u32(0x13e), None);
+        // Reads from regions without data, and from outside every region.
+        assert_eq!(
+            memory.region_containing(0x140).unwrap().kind,
+            RegionKind::Bss
+        );
+        assert_eq!(memory.read_u32(0x140), None);
+        assert_eq!(memory.read_u32(0x170), None);
+        assert_eq!(memory.read_u32(0x180), None);
+        assert_eq!(memory.read_u32(0xfc), None);
+        assert_eq!(memory.read_u32(u32::MAX), None);
+    }
+
+    #[test]
+    fn c_strings() {
+        let mut memory = MemoryMap::new();
+        memory.add_data(0x100, RegionKind::Data, b"abc").unwrap();
+        memory
+            .add_data(0x103, RegionKind::ReadOnlyData, b"def\0ghi")
+            .unwrap();
+        // The first string runs into the next region before its NUL.
+        assert_eq!(memory.read_c_string(0x100), None);
+        assert_eq!(memory.read_c_string(0x104).unwrap().to_bytes(), b"ef");
+        assert_eq!(memory.read_c_string(0x107), None);
+    }
+
+    #[test]
+    fn out_of_address_space() {
+        let mut memory = MemoryMap::new();
+        assert_eq!(
+            memory.add_data(0xffff_fff0, RegionKind::Data, &DATA),
+            Err(OutOfAddressSpace {
+                start: 0xffff_fff0,
+                len: 0x40,
+            })
+        );
+        assert!(memory.regions().is_empty());
+        // Regions end before the last address.
+        memory
+            .add_data(0xffff_ffbf, RegionKind::Data, &DATA)
+            .unwrap();
+    }
+}
@@ -3,11 +3,18 @@ use std::fmt::{self, Display, Formatter};
 
 use powerpc::{cr_constants::*, Spr};
 use powerpc::{gpr_constants::*, Gpr};
-use powerpc::{ConditionBit, DecodedInstruction, Register};
+use powerpc::{
+    AccessWidth, ConditionBit, Crf, DecodedInstruction, EffectiveAddress, Fpr, MemoryAccess,
+    Register, Sr,
+};
 use symbolic::ExprRef;
 
 pub type Context = symbolic::NumberedContext<Variable>;
 
+/// The sticky exception bits of the FPSCR: FX, OX, UX, ZX, XX, the VXSNAN to VXVC invalid
+/// operation bits, VXSOFT, VXSQRT and VXCVI.
+const FPSCR_EXCEPTION_BITS: u32 = 0x9ff8_0700;
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Variable {
     /// An unknown value. Referencing a garbage value means a program is violating the C ABI.
@@ -51,6 +58,7 @@ impl Display for Variable {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct MemoryBlockRef(usize);
 
@@ -76,16 +84,31 @@ impl<'ctx> MachineState<'ctx> {
     }
 
     pub fn ctx(&self) -> &Context {
-        &self.ctx
+        self.ctx
     }
 
     pub fn ctx_mut(&mut self) -> &mut Context {
-        &mut self.ctx
+        self.ctx
     }
 
     pub fn get_register<T: Into<Register>>(&mut self, register: T) -> ExprRef {
         match register.into() {
             Register::Zero => self.ctx.literal_expr(0),
+            // Condition bits are tracked individually, so a field is assembled from its four bits,
+            // LT being the most significant.
+            Register::ConditionField(crf) => {
+                let terms = crf
+                    .condition_bits()
+                    .iter()
+                    .zip([8, 4, 2, 1])
+                    .map(|(bit, weight)| {
+                        let bit_expr = self.get_register(*bit);
+                        let weight_expr = self.ctx.literal_expr(weight);
+                        self.ctx.mul_expr(vec![bit_expr, weight_expr])
+                    })
+                    .collect();
+                self.ctx.bit_or_expr(terms)
+            }
             register => {
                 let current_basic_block = &self.basic_block;
                 let ctx = &mut self.ctx;
@@ -264,6 +287,15 @@ impl<'ctx> MachineState<'ctx> {
 
     pub fn prepare_update(&mut self, cia: u32, instruction: &DecodedInstruction) -> Update {
         match instruction {
+            DecodedInstruction::Add {
+                dst, srcs, record, ..
+            } => {
+                // TODO: Support the overflow-enabled forms.
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let add_expr = self.ctx.add_expr(vec![src0_expr, src1_expr]);
+                self.update_with_result(*dst, add_expr, *record)
+            }
             DecodedInstruction::Addi {
                 dst,
                 src,
@@ -278,15 +310,48 @@ impl<'ctx> MachineState<'ctx> {
                 dst,
                 src,
                 immediate,
-            } => todo!(),
-            DecodedInstruction::Addze {
+            } => {
+                let src_expr = self.get_register(*src);
+                let immediate_expr = self.ctx.literal_expr((*immediate as u32) << 16);
+                let add_expr = self.ctx.add_expr(vec![src_expr, immediate_expr]);
+                Update::one_register(*dst, add_expr)
+            }
+            DecodedInstruction::And { dst, srcs, record } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let bit_and_expr = self.ctx.bit_and_expr(vec![src0_expr, src1_expr]);
+                self.update_with_result(*dst, bit_and_expr, *record)
+            }
+            DecodedInstruction::Andc { dst, srcs, record } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let not_expr = self.ctx.not_expr(src1_expr);
+                let bit_and_expr = self.ctx.bit_and_expr(vec![src0_expr, not_expr]);
+                self.update_with_result(*dst, bit_and_expr, *record)
+            }
+            DecodedInstruction::Andi {
+                dst,
+                src,
+                immediate,
+            } => {
+                let src_expr = self.get_register(*src);
+                let immediate_expr = self.ctx.literal_expr(*immediate as u32);
+                let bit_and_expr = self.ctx.bit_and_expr(vec![src_expr, immediate_expr]);
+                self.update_with_result(*dst, bit_and_expr, true)
+            }
+            DecodedInstruction::Andis {
                 dst,
                 src,
-                overflow_enable,
-                record,
-            } => todo!(),
+                immediate,
+            } => {
+                let src_expr = self.get_register(*src);
+                let immediate_expr = self.ctx.literal_expr((*immediate as u32) << 16);
+                let bit_and_expr = self.ctx.bit_and_expr(vec![src_expr, immediate_expr]);
+                self.update_with_result(*dst, bit_and_expr, true)
+            }
             DecodedInstruction::B { .. }
             | DecodedInstruction::Bc { .. }
+            | DecodedInstruction::Bcctr { .. }
             | DecodedInstruction::Bclr { .. } => {
                 let branch_info = instruction.branch_info().unwrap();
                 let mut update = Update::new();
@@ -297,6 +362,10 @@ impl<'ctx> MachineState<'ctx> {
                     for gpr in 5..=12 {
                         update.set_register(Gpr::new(gpr).unwrap(), garbage);
                     }
+                    for fpr in 0..=13 {
+                        update.set_register(Fpr::new(fpr).unwrap(), garbage);
+                    }
+                    update.set_register(Register::Fpscr, garbage);
                     for spr in [Spr::Link, Spr::Count, Spr::IntegerException]
                         .iter()
                         .copied()
@@ -314,12 +383,25 @@ impl<'ctx> MachineState<'ctx> {
                 }
                 update
             }
+            DecodedInstruction::Cmp { crf, srcs } => {
+                let lhs_expr = self.get_register(srcs[0]);
+                let rhs_expr = self.get_register(srcs[1]);
+                self.compare(*crf, lhs_expr, rhs_expr, true)
+            }
             DecodedInstruction::Cmpi {
                 crf,
                 src,
                 immediate,
-            } => todo!(),
-            DecodedInstruction::Cmpl { crf, srcs } => todo!(),
+            } => {
+                let lhs_expr = self.get_register(*src);
+                let rhs_expr = self.ctx.literal_expr(*immediate as u32);
+                self.compare(*crf, lhs_expr, rhs_expr, true)
+            }
+            DecodedInstruction::Cmpl { crf, srcs } => {
+                let lhs_expr = self.get_register(srcs[0]);
+                let rhs_expr = self.get_register(srcs[1]);
+                self.compare(*crf, lhs_expr, rhs_expr, false)
+            }
             DecodedInstruction::Cmpli {
                 crf,
                 src,
@@ -327,50 +409,307 @@ impl<'ctx> MachineState<'ctx> {
             } => {
                 let lhs_expr = self.get_register(*src);
                 let rhs_expr = self.ctx.literal_expr(*immediate as u32);
-                let lt_expr = self.ctx.less_unsigned_expr(lhs_expr, rhs_expr);
-                let gt_expr = self.ctx.less_unsigned_expr(rhs_expr, lhs_expr);
-                let eq_expr = self.ctx.equal_expr(lhs_expr, rhs_expr);
+                self.compare(*crf, lhs_expr, rhs_expr, false)
+            }
+            DecodedInstruction::Crand { dst, srcs } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let bit_and_expr = self.ctx.bit_and_expr(vec![src0_expr, src1_expr]);
+                Update::one_register(*dst, bit_and_expr)
+            }
+            DecodedInstruction::Crandc { dst, srcs } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let not_expr = self.logical_not(src1_expr);
+                let bit_and_expr = self.ctx.bit_and_expr(vec![src0_expr, not_expr]);
+                Update::one_register(*dst, bit_and_expr)
+            }
+            DecodedInstruction::Creqv { dst, srcs } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let equal_expr = self.ctx.equal_expr(src0_expr, src1_expr);
+                Update::one_register(*dst, equal_expr)
+            }
+            DecodedInstruction::Crnand { dst, srcs } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let bit_and_expr = self.ctx.bit_and_expr(vec![src0_expr, src1_expr]);
+                let not_expr = self.logical_not(bit_and_expr);
+                Update::one_register(*dst, not_expr)
+            }
+            DecodedInstruction::Crnor { dst, srcs } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let bit_or_expr = self.ctx.bit_or_expr(vec![src0_expr, src1_expr]);
+                let not_expr = self.logical_not(bit_or_expr);
+                Update::one_register(*dst, not_expr)
+            }
+            DecodedInstruction::Cror { dst, srcs } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let bit_or_expr = self.ctx.bit_or_expr(vec![src0_expr, src1_expr]);
+                Update::one_register(*dst, bit_or_expr)
+            }
+            DecodedInstruction::Crorc { dst, srcs } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let not_expr = self.logical_not(src1_expr);
+                let bit_or_expr = self.ctx.bit_or_expr(vec![src0_expr, not_expr]);
+                Update::one_register(*dst, bit_or_expr)
+            }
+            DecodedInstruction::Crxor { dst, srcs } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let equal_expr = self.ctx.equal_expr(src0_expr, src1_expr);
+                let not_expr = self.logical_not(equal_expr);
+                Update::one_register(*dst, not_expr)
+            }
+            DecodedInstruction::Fmr {
+                dst,
+                src,
+                record: false,
+            } => {
+                let src_expr = self.get_register(*src);
+                let mut update = Update::new();
+                update.set_register(*dst, src_expr);
+                update
+            }
+            DecodedInstruction::Lmw { .. } => {
+                let access = instruction.memory_access().unwrap();
+                let start_addr_expr = self.effective_address(access.address);
+                let mut update = Update::new();
+                for i in 0..access.count {
+                    let displacement_expr = self.ctx.literal_expr(4 * i);
+                    let addr_expr = self.ctx.add_expr(vec![start_addr_expr, displacement_expr]);
+                    let data_expr = self.read_word(addr_expr);
+                    update.set_register(Gpr::new(32 - access.count + i).unwrap(), data_expr);
+                }
+                update
+            }
+            DecodedInstruction::Lwz { .. }
+            | DecodedInstruction::Lwzu { .. }
+            | DecodedInstruction::Lwzux { .. }
+            | DecodedInstruction::Lwzx { .. } => {
+                self.load_word(instruction.memory_access().unwrap())
+            }
+            DecodedInstruction::Mcrf { dst, src } => {
+                let mut update = Update::new();
+                for (dst_bit, src_bit) in dst.condition_bits().iter().zip(src.condition_bits()) {
+                    let src_expr = self.get_register(src_bit);
+                    update.set_register(*dst_bit, src_expr);
+                }
+                update
+            }
+            DecodedInstruction::Mcrfs { dst, src } => {
+                // The FPSCR is changed by floating-point operations that aren't modeled, so the
+                // copied bits are unknown. The exception bits among them are cleared, except for
+                // the FEX and VX summaries.
+                let mut update = Update::new();
+                for bit in dst.condition_bits().iter() {
+                    update.set_register(*bit, self.ctx.next_numbered_variable_expr());
+                }
+                let field_mask = 0xf000_0000 >> (4 * src.get());
+                let fpscr_expr = self.get_register(Register::Fpscr);
+                let keep_expr = self.ctx.literal_expr(!(field_mask & FPSCR_EXCEPTION_BITS));
+                let cleared_expr = self.ctx.bit_and_expr(vec![fpscr_expr, keep_expr]);
+                update.set_register(Register::Fpscr, cleared_expr);
+                update
+            }
+            DecodedInstruction::Mcrxr { dst } => {
+                // XER[SO, OV, CA] are its three most significant bits. They move into the LT, GT
+                // and EQ bits of the field, and are cleared in XER.
+                let xer_expr = self.get_register(Spr::IntegerException);
+                let [lt, gt, eq, so] = dst.condition_bits();
                 let mut update = Update::new();
-                update.set_register(ConditionBit::from_crf_and_condition(*crf, LT), lt_expr);
-                update.set_register(ConditionBit::from_crf_and_condition(*crf, GT), gt_expr);
-                update.set_register(ConditionBit::from_crf_and_condition(*crf, EQ), eq_expr);
+                for (bit, mask) in [(lt, 1 << 31), (gt, 1 << 30), (eq, 1 << 29)] {
+                    let bit_expr = self.test_bits(xer_expr, mask);
+                    update.set_register(bit, bit_expr);
+                }
+                update.set_register(so, self.ctx.literal_expr(0));
+                let mask_expr = self.ctx.literal_expr(0x0fff_ffff);
+                let cleared_expr = self.ctx.bit_and_expr(vec![xer_expr, mask_expr]);
+                update.set_register(Spr::IntegerException, cleared_expr);
+                update
+            }
+            DecodedInstruction::Mfcr { dst } => {
+                // CR bit 0 is the most significant bit of the word.
+                let terms = (0..32)
+                    .map(|bit| {
+                        let bit_expr = self.get_register(ConditionBit::new(bit).unwrap());
+                        let weight_expr = self.ctx.literal_expr(1 << (31 - bit));
+                        self.ctx.mul_expr(vec![bit_expr, weight_expr])
+                    })
+                    .collect();
+                Update::one_register(*dst, self.ctx.bit_or_expr(terms))
+            }
+            DecodedInstruction::Mffs { dst, record } => {
+                // Like `mcrfs`, the FPSCR bits are unknown.
+                let mut update = Update::one_register(*dst, self.ctx.next_numbered_variable_expr());
+                if *record {
+                    let fpscr_expr = self.get_register(Register::Fpscr);
+                    self.set_cr1_from_fpscr(&mut update, fpscr_expr);
+                }
                 update
             }
-            DecodedInstruction::Crxor { dst, srcs } => todo!(),
-            DecodedInstruction::Lbz { dst, offset, base } => todo!(),
-            DecodedInstruction::Lha { dst, offset, base } => todo!(),
-            DecodedInstruction::Lwz { dst, offset, base } => {
-                let offset_expr = self.ctx.literal_expr(*offset as u32);
-                let base_expr = self.get_register(*base);
-                let addr_expr = self.ctx.add_expr(vec![offset_expr, base_expr]);
-                // TODO: Attach some kind of sequencing information for keeping sensitive memory
-                // operations ordered.
-                let read_variable = self.ctx.next_numbered_variable_expr();
-                let data_expr = self.ctx.read_expr(addr_expr);
-                self.ctx.assign_variable(read_variable, data_expr);
-                Update::one_register(*dst, read_variable)
+            DecodedInstruction::Mfmsr { dst } => {
+                Update::one_register(*dst, self.get_register(Register::Msr))
             }
             DecodedInstruction::Mfspr { spr, dst } => {
                 Update::one_register(*dst, self.get_register(*spr))
             }
+            DecodedInstruction::Mfsr { dst, sr } => {
+                Update::one_register(*dst, self.get_register(*sr))
+            }
+            DecodedInstruction::Mfsrin { dst, .. } => {
+                // The segment register is selected by a value that isn't known here.
+                Update::one_register(*dst, self.ctx.next_numbered_variable_expr())
+            }
+            DecodedInstruction::Mftb { dst, .. } => {
+                // The time base advances on its own, so every read is a new unknown value.
+                Update::one_register(*dst, self.ctx.next_numbered_variable_expr())
+            }
+            DecodedInstruction::Mtcrf { fields, src } => {
+                let src_expr = self.get_register(*src);
+                let mut update = Update::new();
+                for crf in (0..8).filter(|crf| fields & (0x80 >> crf) != 0) {
+                    for bit in Crf::new(crf).unwrap().condition_bits().iter() {
+                        let bit_expr = self.test_bits(src_expr, 1 << (31 - bit.get()));
+                        update.set_register(*bit, bit_expr);
+                    }
+                }
+                update
+            }
+            DecodedInstruction::Mtfsb0 { bit, record } => {
+                let fpscr_expr = self.get_register(Register::Fpscr);
+                let mask_expr = self.ctx.literal_expr(!(1 << (31 - *bit as u32)));
+                let fpscr_expr = self.ctx.bit_and_expr(vec![fpscr_expr, mask_expr]);
+                self.update_fpscr(fpscr_expr, *record)
+            }
+            DecodedInstruction::Mtfsb1 { bit, record } => {
+                let fpscr_expr = self.get_register(Register::Fpscr);
+                let bit_expr = self.ctx.literal_expr(1 << (31 - *bit as u32));
+                let fpscr_expr = self.ctx.bit_or_expr(vec![fpscr_expr, bit_expr]);
+                self.update_fpscr(fpscr_expr, *record)
+            }
+            DecodedInstruction::Mtfsf { fields, record, .. } => {
+                // Only the low word of the source is copied, but FPR values aren't split into
+                // words, so the copied fields are unknown.
+                let mask = (0..8)
+                    .filter(|field| fields & (0x80 >> field) != 0)
+                    .fold(0, |mask, field| mask | 0xf000_0000 >> (4 * field));
+                let fpscr_expr = self.get_register(Register::Fpscr);
+                let keep_expr = self.ctx.literal_expr(!mask);
+                let kept_expr = self.ctx.bit_and_expr(vec![fpscr_expr, keep_expr]);
+                let src_expr = self.ctx.next_numbered_variable_expr();
+                let mask_expr = self.ctx.literal_expr(mask);
+                let copied_expr = self.ctx.bit_and_expr(vec![src_expr, mask_expr]);
+                let fpscr_expr = self.ctx.bit_or_expr(vec![kept_expr, copied_expr]);
+                self.update_fpscr(fpscr_expr, *record)
+            }
+            DecodedInstruction::Mtfsfi {
+                crf,
+                immediate,
+                record,
+            } => {
+                let shift = 28 - 4 * crf.get();
+                let fpscr_expr = self.get_register(Register::Fpscr);
+                let keep_expr = self.ctx.literal_expr(!(0xf << shift));
+                let kept_expr = self.ctx.bit_and_expr(vec![fpscr_expr, keep_expr]);
+                let immediate_expr = self.ctx.literal_expr((*immediate as u32) << shift);
+                let fpscr_expr = self.ctx.bit_or_expr(vec![kept_expr, immediate_expr]);
+                self.update_fpscr(fpscr_expr, *record)
+            }
+            DecodedInstruction::Mtmsr { src } => {
+                Update::one_register(Register::Msr, self.get_register(*src))
+            }
             DecodedInstruction::Mtspr { spr, src } => {
                 Update::one_register(*spr, self.get_register(*src))
             }
+            DecodedInstruction::Mtsr { sr, src } => {
+                Update::one_register(*sr, self.get_register(*src))
+            }
+            DecodedInstruction::Mtsrin { .. } => {
+                // Any of the segment registers may have been written.
+                let mut update = Update::new();
+                for sr in 0..16 {
+                    let unknown = self.ctx.next_numbered_variable_expr();
+                    update.set_register(Sr::new(sr).unwrap(), unknown);
+                }
+                update
+            }
+            DecodedInstruction::Mulli {
+                dst,
+                src,
+                immediate,
+            } => {
+                let src_expr = self.get_register(*src);
+                let immediate_expr = self.ctx.literal_expr(*immediate as u32);
+                let mul_expr = self.ctx.mul_expr(vec![src_expr, immediate_expr]);
+                Update::one_register(*dst, mul_expr)
+            }
+            DecodedInstruction::Mullw {
+                dst, srcs, record, ..
+            } => {
+                // TODO: Support the overflow-enabled forms.
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let mul_expr = self.ctx.mul_expr(vec![src0_expr, src1_expr]);
+                self.update_with_result(*dst, mul_expr, *record)
+            }
+            DecodedInstruction::Nand { dst, srcs, record } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let bit_and_expr = self.ctx.bit_and_expr(vec![src0_expr, src1_expr]);
+                let not_expr = self.ctx.not_expr(bit_and_expr);
+                self.update_with_result(*dst, not_expr, *record)
+            }
+            DecodedInstruction::Neg {
+                dst, src, record, ..
+            } => {
+                // TODO: Support the overflow-enabled forms.
+                let src_expr = self.get_register(*src);
+                let neg_expr = self.negate(src_expr);
+                self.update_with_result(*dst, neg_expr, *record)
+            }
+            DecodedInstruction::Nor { dst, srcs, record } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let bit_or_expr = self.ctx.bit_or_expr(vec![src0_expr, src1_expr]);
+                let not_expr = self.ctx.not_expr(bit_or_expr);
+                self.update_with_result(*dst, not_expr, *record)
+            }
             DecodedInstruction::Or { dst, srcs, record } => {
                 let src0_expr = self.get_register(srcs[0]);
                 let src1_expr = self.get_register(srcs[1]);
                 let bit_or_expr = self.ctx.bit_or_expr(vec![src0_expr, src1_expr]);
-
-                let mut update = Update::new();
-                update.set_register(*dst, bit_or_expr);
-                if *record {
-                    let zero = self.ctx.literal_expr(0);
-                    update.set_register(CR0LT, self.ctx.less_signed_expr(bit_or_expr, zero));
-                    update.set_register(CR0GT, self.ctx.less_signed_expr(zero, bit_or_expr));
-                    update.set_register(CR0EQ, self.ctx.equal_expr(bit_or_expr, zero));
-                    // TODO: Support the SO bit.
-                }
-                update
+                self.update_with_result(*dst, bit_or_expr, *record)
+            }
+            DecodedInstruction::Orc { dst, srcs, record } => {
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let not_expr = self.ctx.not_expr(src1_expr);
+                let bit_or_expr = self.ctx.bit_or_expr(vec![src0_expr, not_expr]);
+                self.update_with_result(*dst, bit_or_expr, *record)
+            }
+            DecodedInstruction::Ori {
+                dst,
+                src,
+                immediate,
+            } => {
+                let src_expr = self.get_register(*src);
+                let immediate_expr = self.ctx.literal_expr(*immediate as u32);
+                let bit_or_expr = self.ctx.bit_or_expr(vec![src_expr, immediate_expr]);
+                Update::one_register(*dst, bit_or_expr)
+            }
+            DecodedInstruction::Oris {
+                dst,
+                src,
+                immediate,
+            } => {
+                let src_expr = self.get_register(*src);
+                let immediate_expr = self.ctx.literal_expr((*immediate as u32) << 16);
+                let bit_or_expr = self.ctx.bit_or_expr(vec![src_expr, immediate_expr]);
+                Update::one_register(*dst, bit_or_expr)
             }
             DecodedInstruction::Rlwinm {
                 dst,
@@ -379,33 +718,333 @@ impl<'ctx> MachineState<'ctx> {
                 mask_begin,
                 mask_end,
                 record,
-            } => todo!(),
-            DecodedInstruction::Srawi {
-                dst,
-                src,
-                shift,
-                record,
-            } => todo!(),
-            DecodedInstruction::Stmw { src, offset, base } => todo!(),
-            DecodedInstruction::Stw { src, offset, base } => {
-                let offset_expr = self.ctx.literal_expr(*offset as u32);
-                let base_expr = self.get_register(*base);
-                let addr_expr = self.ctx.add_expr(vec![offset_expr, base_expr]);
-                let data_expr = self.get_register(*src);
-                Update::one_write(AccessWidth::Word, addr_expr, data_expr)
-            }
-            DecodedInstruction::Stwu { src, offset, base } => {
-                let offset_expr = self.ctx.literal_expr(*offset as u32);
-                let base_expr = self.get_register(*base);
-                let addr_expr = self.ctx.add_expr(vec![offset_expr, base_expr]);
-                let data_expr = self.get_register(*src);
-
+            } if mask_begin == mask_end => {
+                // Extracting a single bit, as is done to turn a CR bit copied by `mfcr` into a
+                // boolean. After rotating left by `shift`, bit `mask_begin` holds source bit
+                // `mask_begin + shift`.
+                let src_expr = self.get_register(*src);
+                let src_bit = (*mask_begin as u32 + *shift as u32) % 32;
+                let bit_expr = self.test_bits(src_expr, 1 << (31 - src_bit));
+                let weight_expr = self.ctx.literal_expr(1 << (31 - *mask_begin as u32));
+                let result_expr = self.ctx.mul_expr(vec![bit_expr, weight_expr]);
+                self.update_with_result(*dst, result_expr, *record)
+            }
+            DecodedInstruction::Stb { .. }
+            | DecodedInstruction::Stbu { .. }
+            | DecodedInstruction::Stbux { .. }
+            | DecodedInstruction::Stbx { .. }
+            | DecodedInstruction::Sth { .. }
+            | DecodedInstruction::Sthu { .. }
+            | DecodedInstruction::Sthux { .. }
+            | DecodedInstruction::Sthx { .. }
+            | DecodedInstruction::Stw { .. }
+            | DecodedInstruction::Stwu { .. }
+            | DecodedInstruction::Stwux { .. }
+            | DecodedInstruction::Stwx { .. } => self.store(instruction.memory_access().unwrap()),
+            DecodedInstruction::Stmw { .. } => {
+                let access = instruction.memory_access().unwrap();
+                let start_addr_expr = self.effective_address(access.address);
                 let mut update = Update::new();
-                update.set_register(*base, addr_expr);
-                update.add_write(AccessWidth::Word, addr_expr, data_expr);
+                for i in 0..access.count {
+                    let displacement_expr = self.ctx.literal_expr(4 * i);
+                    let addr_expr = self.ctx.add_expr(vec![start_addr_expr, displacement_expr]);
+                    let data_expr = self.get_register(Gpr::new(32 - access.count + i).unwrap());
+                    update.add_write(AccessWidth::Word, addr_expr, data_expr);
+                }
                 update
             }
+            DecodedInstruction::Subf {
+                dst, srcs, record, ..
+            } => {
+                // TODO: Support the overflow-enabled forms.
+                let src0_expr = self.get_register(srcs[0]);
+                let src1_expr = self.get_register(srcs[1]);
+                let neg_expr = self.negate(src0_expr);
+                let add_expr = self.ctx.add_expr(vec![neg_expr, src1_expr]);
+                self.update_with_result(*dst, add_expr, *record)
+            }
+
+            // Cache maintenance has no effect on the program's view of registers or memory.
+            DecodedInstruction::Dcbf { .. }
+            | DecodedInstruction::Dcbi { .. }
+            | DecodedInstruction::Dcbst { .. }
+            | DecodedInstruction::Dcbt { .. }
+            | DecodedInstruction::Dcbtst { .. }
+            | DecodedInstruction::Icbi { .. } => Update::new(),
+
+            // Neither do barriers, nor traps when they aren't taken.
+            DecodedInstruction::Eieio
+            | DecodedInstruction::Isync
+            | DecodedInstruction::Sync
+            | DecodedInstruction::Tw { .. }
+            | DecodedInstruction::Twi { .. } => Update::new(),
+
+            // Like local branches, these end the basic block. The interrupt handler entered by `sc`
+            // returns with the program's registers intact.
+            DecodedInstruction::Rfi | DecodedInstruction::Sc => Update::new(),
+
+            // These can't be expressed yet. Carries need a model of XER[CA], sub-word and
+            // floating-point loads need sized reads, and the rest need expression types that don't
+            // exist yet.
+            DecodedInstruction::Addc { .. }
+            | DecodedInstruction::Adde { .. }
+            | DecodedInstruction::Addic { .. }
+            | DecodedInstruction::Addme { .. }
+            | DecodedInstruction::Addze { .. }
+            | DecodedInstruction::Cntlzw { .. }
+            | DecodedInstruction::Dcbz { .. }
+            | DecodedInstruction::DcbzL { .. }
+            | DecodedInstruction::Divw { .. }
+            | DecodedInstruction::Divwu { .. }
+            | DecodedInstruction::Eqv { .. }
+            | DecodedInstruction::Extsb { .. }
+            | DecodedInstruction::Extsh { .. }
+            | DecodedInstruction::Fabs { .. }
+            | DecodedInstruction::Fadd { .. }
+            | DecodedInstruction::Fadds { .. }
+            | DecodedInstruction::Fcmpo { .. }
+            | DecodedInstruction::Fcmpu { .. }
+            | DecodedInstruction::Fctiw { .. }
+            | DecodedInstruction::Fctiwz { .. }
+            | DecodedInstruction::Fdiv { .. }
+            | DecodedInstruction::Fdivs { .. }
+            | DecodedInstruction::Fmadd { .. }
+            | DecodedInstruction::Fmadds { .. }
+            | DecodedInstruction::Fmr { record: true, .. }
+            | DecodedInstruction::Fmsub { .. }
+            | DecodedInstruction::Fmsubs { .. }
+            | DecodedInstruction::Fmul { .. }
+            | DecodedInstruction::Fmuls { .. }
+            | DecodedInstruction::Fnabs { .. }
+            | DecodedInstruction::Fneg { .. }
+            | DecodedInstruction::Fnmadd { .. }
+            | DecodedInstruction::Fnmadds { .. }
+            | DecodedInstruction::Fnmsub { .. }
+            | DecodedInstruction::Fnmsubs { .. }
+            | DecodedInstruction::Fres { .. }
+            | DecodedInstruction::Frsp { .. }
+            | DecodedInstruction::Frsqrte { .. }
+            | DecodedInstruction::Fsel { .. }
+            | DecodedInstruction::Fsub { .. }
+            | DecodedInstruction::Fsubs { .. }
+            | DecodedInstruction::Lbz { .. }
+            | DecodedInstruction::Lbzu { .. }
+            | DecodedInstruction::Lbzux { .. }
+            | DecodedInstruction::Lbzx { .. }
+            | DecodedInstruction::Lfd { .. }
+            | DecodedInstruction::Lfdu { .. }
+            | DecodedInstruction::Lfdux { .. }
+            | DecodedInstruction::Lfdx { .. }
+            | DecodedInstruction::Lfs { .. }
+            | DecodedInstruction::Lfsu { .. }
+            | DecodedInstruction::Lfsux { .. }
+            | DecodedInstruction::Lfsx { .. }
+            | DecodedInstruction::Lha { .. }
+            | DecodedInstruction::Lhau { .. }
+            | DecodedInstruction::Lhaux { .. }
+            | DecodedInstruction::Lhax { .. }
+            | DecodedInstruction::Lhbrx { .. }
+            | DecodedInstruction::Lhz { .. }
+            | DecodedInstruction::Lhzu { .. }
+            | DecodedInstruction::Lhzux { .. }
+            | DecodedInstruction::Lhzx { .. }
+            | DecodedInstruction::Lwbrx { .. }
+            | DecodedInstruction::Mulhw { .. }
+            | DecodedInstruction::Mulhwu { .. }
+            | DecodedInstruction::PsAbs { .. }
+            | DecodedInstruction::PsAdd { .. }
+            | DecodedInstruction::PsCmpo0 { .. }
+            | DecodedInstruction::PsCmpo1 { .. }
+            | DecodedInstruction::PsCmpu0 { .. }
+            | DecodedInstruction::PsCmpu1 { .. }
+            | DecodedInstruction::PsDiv { .. }
+            | DecodedInstruction::PsMadd { .. }
+            | DecodedInstruction::PsMadds0 { .. }
+            | DecodedInstruction::PsMadds1 { .. }
+            | DecodedInstruction::PsMerge00 { .. }
+            | DecodedInstruction::PsMerge01 { .. }
+            | DecodedInstruction::PsMerge10 { .. }
+            | DecodedInstruction::PsMerge11 { .. }
+            | DecodedInstruction::PsMr { .. }
+            | DecodedInstruction::PsMsub { .. }
+            | DecodedInstruction::PsMul { .. }
+            | DecodedInstruction::PsMuls0 { .. }
+            | DecodedInstruction::PsMuls1 { .. }
+            | DecodedInstruction::PsNabs { .. }
+            | DecodedInstruction::PsNeg { .. }
+            | DecodedInstruction::PsNmadd { .. }
+            | DecodedInstruction::PsNmsub { .. }
+            | DecodedInstruction::PsRes { .. }
+            | DecodedInstruction::PsRsqrte { .. }
+            | DecodedInstruction::PsSel { .. }
+            | DecodedInstruction::PsSub { .. }
+            | DecodedInstruction::PsSum0 { .. }
+            | DecodedInstruction::PsSum1 { .. }
+            | DecodedInstruction::PsqL { .. }
+            | DecodedInstruction::PsqLu { .. }
+            | DecodedInstruction::PsqLux { .. }
+            | DecodedInstruction::PsqLx { .. }
+            | DecodedInstruction::PsqSt { .. }
+            | DecodedInstruction::PsqStu { .. }
+            | DecodedInstruction::PsqStux { .. }
+            | DecodedInstruction::PsqStx { .. }
+            | DecodedInstruction::Rlwimi { .. }
+            | DecodedInstruction::Rlwinm { .. }
+            | DecodedInstruction::Rlwnm { .. }
+            | DecodedInstruction::Slw { .. }
+            | DecodedInstruction::Sraw { .. }
+            | DecodedInstruction::Srawi { .. }
+            | DecodedInstruction::Srw { .. }
+            | DecodedInstruction::Stfd { .. }
+            | DecodedInstruction::Stfdu { .. }
+            | DecodedInstruction::Stfdux { .. }
+            | DecodedInstruction::Stfdx { .. }
+            | DecodedInstruction::Stfiwx { .. }
+            | DecodedInstruction::Stfs { .. }
+            | DecodedInstruction::Stfsu { .. }
+            | DecodedInstruction::Stfsux { .. }
+            | DecodedInstruction::Stfsx { .. }
+            | DecodedInstruction::Sthbrx { .. }
+            | DecodedInstruction::Stwbrx { .. }
+            | DecodedInstruction::Subfc { .. }
+            | DecodedInstruction::Subfe { .. }
+            | DecodedInstruction::Subfic { .. }
+            | DecodedInstruction::Subfme { .. }
+            | DecodedInstruction::Subfze { .. }
+            | DecodedInstruction::Xor { .. }
+            | DecodedInstruction::Xori { .. }
+            | DecodedInstruction::Xoris { .. } => todo!(),
+        }
+    }
+
+    /// Computes an effective address, `(base|0) + offset` or `(base|0) + index`.
+    fn effective_address(&mut self, address: EffectiveAddress) -> ExprRef {
+        match address {
+            EffectiveAddress::Offset { base, offset } => {
+                let offset_expr = self.ctx.literal_expr(offset as u32);
+                let base_expr = self.get_register(base);
+                self.ctx.add_expr(vec![offset_expr, base_expr])
+            }
+            EffectiveAddress::Indexed { base, index } => {
+                let base_expr = self.get_register(base);
+                let index_expr = self.get_register(index);
+                self.ctx.add_expr(vec![base_expr, index_expr])
+            }
+        }
+    }
+
+    /// Reads a word from memory into a fresh numbered variable.
+    fn read_word(&mut self, addr: ExprRef) -> ExprRef {
+        // TODO: Attach some kind of sequencing information for keeping sensitive memory
+        // operations ordered.
+        let read_variable = self.ctx.next_numbered_variable_expr();
+        let data_expr = self.ctx.read_expr(addr);
+        self.ctx.assign_variable(read_variable, data_expr);
+        read_variable
+    }
+
+    /// Builds an update for a word load, writing the effective address back to the base register
+    /// for the update forms.
+    fn load_word(&mut self, access: MemoryAccess) -> Update {
+        let addr_expr = self.effective_address(access.address);
+        let mut update = Update::new();
+        if let Some(base) = access.update_base() {
+            update.set_register(base, addr_expr);
         }
+        let data_expr = self.read_word(addr_expr);
+        update.set_register(access.data, data_expr);
+        update
+    }
+
+    /// Builds an update for a store, writing the effective address back to the base register for
+    /// the update forms.
+    fn store(&mut self, access: MemoryAccess) -> Update {
+        let addr_expr = self.effective_address(access.address);
+        let data_expr = self.get_register(access.data);
+        let mut update = Update::one_write(access.width, addr_expr, data_expr);
+        if let Some(base) = access.update_base() {
+            update.set_register(base, addr_expr);
+        }
+        update
+    }
+
+    /// Builds an update that assigns `result` to `dst`, also setting CR0 if `record` is set.
+    fn update_with_result<T: Into<Register>>(
+        &mut self,
+        dst: T,
+        result: ExprRef,
+        record: bool,
+    ) -> Update {
+        let mut update = Update::new();
+        update.set_register(dst, result);
+        if record {
+            let zero = self.ctx.literal_expr(0);
+            update.set_register(CR0LT, self.ctx.less_signed_expr(result, zero));
+            update.set_register(CR0GT, self.ctx.less_signed_expr(zero, result));
+            update.set_register(CR0EQ, self.ctx.equal_expr(result, zero));
+            // TODO: Support the SO bit.
+        }
+        update
+    }
+
+    /// Builds an update that sets the LT, GT, and EQ bits of `crf` by comparing `lhs` to `rhs`.
+    fn compare(&mut self, crf: Crf, lhs: ExprRef, rhs: ExprRef, signed: bool) -> Update {
+        let (lt_expr, gt_expr) = if signed {
+            (
+                self.ctx.less_signed_expr(lhs, rhs),
+                self.ctx.less_signed_expr(rhs, lhs),
+            )
+        } else {
+            (
+                self.ctx.less_unsigned_expr(lhs, rhs),
+                self.ctx.less_unsigned_expr(rhs, lhs),
+            )
+        };
+        let eq_expr = self.ctx.equal_expr(lhs, rhs);
+        let mut update = Update::new();
+        update.set_register(ConditionBit::from_crf_and_condition(crf, LT), lt_expr);
+        update.set_register(ConditionBit::from_crf_and_condition(crf, GT), gt_expr);
+        update.set_register(ConditionBit::from_crf_and_condition(crf, EQ), eq_expr);
+        // TODO: Support the SO bit.
+        update
+    }
+
+    /// Boolean negation of a value that is either 0 or 1, such as a condition bit.
+    fn logical_not(&mut self, expr: ExprRef) -> ExprRef {
+        let zero = self.ctx.literal_expr(0);
+        self.ctx.equal_expr(expr, zero)
+    }
+
+    /// Whether any of the bits of `expr` selected by `mask` is set, as 0 or 1.
+    fn test_bits(&mut self, expr: ExprRef, mask: u32) -> ExprRef {
+        let zero = self.ctx.literal_expr(0);
+        let mask_expr = self.ctx.literal_expr(mask);
+        let bit_and_expr = self.ctx.bit_and_expr(vec![expr, mask_expr]);
+        self.ctx.less_unsigned_expr(zero, bit_and_expr)
+    }
+
+    /// Builds an update that sets the FPSCR, and CR1 too for the record forms.
+    fn update_fpscr(&mut self, fpscr_expr: ExprRef, record: bool) -> Update {
+        let mut update = Update::one_register(Register::Fpscr, fpscr_expr);
+        if record {
+            self.set_cr1_from_fpscr(&mut update, fpscr_expr);
+        }
+        update
+    }
+
+    /// Copies FPSCR[FX, FEX, VX, OX] into CR1, as the floating-point record forms do.
+    fn set_cr1_from_fpscr(&mut self, update: &mut Update, fpscr_expr: ExprRef) {
+        for (i, bit) in CR1.condition_bits().iter().enumerate() {
+            let bit_expr = self.test_bits(fpscr_expr, 1 << (31 - i));
+            update.set_register(*bit, bit_expr);
+        }
+    }
+
+    /// Two's complement negation, expressed as `!expr + 1`.
+    fn negate(&mut self, expr: ExprRef) -> ExprRef {
+        let not_expr = self.ctx.not_expr(expr);
+        let one = self.ctx.literal_expr(1);
+        self.ctx.add_expr(vec![not_expr, one])
     }
 }
 
@@ -450,19 +1089,104 @@ pub struct Write {
     pub data: ExprRef,
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub enum AccessWidth {
-    Byte,
-    Halfword,
-    Word,
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use powerpc::EncodedInstruction;
+    use symbolic::{Expr, NumberedVariable};
 
-impl Display for AccessWidth {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match self {
-            AccessWidth::Byte => write!(f, "b"),
-            AccessWidth::Halfword => write!(f, "h"),
-            AccessWidth::Word => write!(f, "w"),
+    const BLOCK: u32 = 0x80003100;
+
+    /// Runs the instruction words of one basic block and returns the register values it leaves.
+    fn lift(ctx: &mut Context, words: &[u32]) -> HashMap<Register, ExprRef> {
+        let mut machine_state = MachineState::new(ctx, BLOCK);
+        for (i, word) in words.iter().enumerate() {
+            let cia = BLOCK + 4 * i as u32;
+            let instruction = EncodedInstruction(*word).parse(cia).unwrap();
+            let update = machine_state.prepare_update(cia, &instruction);
+            machine_state.apply(update);
         }
+        machine_state.iter_registers().collect()
+    }
+
+    /// Evaluates an expression, taking the registers entering the block from `entering`.
+    fn evaluate(ctx: &Context, expr: ExprRef, entering: &dyn Fn(Register) -> u32) -> u32 {
+        let eval = |expr| evaluate(ctx, expr, entering);
+        match ctx.get_expr(expr) {
+            Expr::Literal(value) => *value,
+            Expr::Variable(NumberedVariable::Named(Variable::RegisterEntering {
+                basic_block_addr: BLOCK,
+                register,
+            })) => entering(*register),
+            Expr::Add(exprs) => exprs.iter().fold(0, |sum, e| sum.wrapping_add(eval(*e))),
+            Expr::Mul(exprs) => exprs
+                .iter()
+                .fold(1, |product, e| product.wrapping_mul(eval(*e))),
+            Expr::BitOr(exprs) => exprs.iter().fold(0, |bits, e| bits | eval(*e)),
+            Expr::BitAnd(exprs) => exprs.iter().fold(!0, |bits, e| bits & eval(*e)),
+            Expr::Not(e) => !eval(*e),
+            Expr::Equal(lhs, rhs) => (eval(*lhs) == eval(*rhs)) as u32,
+            Expr::LessSigned(lhs, rhs) => ((eval(*lhs) as i32) < (eval(*rhs) as i32)) as u32,
+            Expr::LessUnsigned(lhs, rhs) => (eval(*lhs) < eval(*rhs)) as u32,
+            _ => panic!("cannot evaluate {}", ctx.display_expr(expr)),
+        }
+    }
+
+    #[test]
+    fn mfcr_then_extract_bit() {
+        let mut ctx = Context::new();
+        // mfcr r0
+        // rlwinm r3, r0, 3, 31, 31
+        let registers = lift(&mut ctx, &[0x7c000026, 0x54031ffe]);
+        let r3 = registers[&Register::from(R3)];
+
+        for eq in [0, 1] {
+            // Set the neighbouring bits, so a wrong shift picks up the wrong value.
+            let entering = |register| match register {
+                Register::ConditionBit(bit) if bit == CR0EQ => eq,
+                Register::ConditionBit(_) => 1 - eq,
+                register => panic!("unexpected read of {}", register),
+            };
+            assert_eq!(evaluate(&ctx, r3, &entering), eq);
+        }
+    }
+
+    #[test]
+    fn cror_combines_compare_bits() {
+        let mut ctx = Context::new();
+        // cmpw r3, r4
+        // cror eq, lt, eq
+        let registers = lift(&mut ctx, &[0x7c032000, 0x4c401382]);
+        let eq = registers[&Register::from(CR0EQ)];
+
+        for (r3, r4) in [(1, 2), (2, 2), (3, 2), (-1i32 as u32, 0), (0, -1i32 as u32)] {
+            let entering = |register| match register {
+                Register::GeneralPurpose(R3) => r3,
+                Register::GeneralPurpose(R4) => r4,
+                register => panic!("unexpected read of {}", register),
+            };
+            let less_or_equal = (r3 as i32 <= r4 as i32) as u32;
+            assert_eq!(evaluate(&ctx, eq, &entering), less_or_equal);
+        }
+    }
+
+    #[test]
+    fn mtfsfi_record_sets_cr1() {
+        let mut ctx = Context::new();
+        // mtfsfi. cr0, 9
+        let registers = lift(&mut ctx, &[0xfc00910d]);
+        let entering = |register| match register {
+            Register::Fpscr => 0x6000_00f8,
+            register => panic!("unexpected read of {}", register),
+        };
+
+        let fpscr = registers[&Register::Fpscr];
+        assert_eq!(evaluate(&ctx, fpscr, &entering), 0x9000_00f8);
+        let cr1: Vec<u32> = CR1
+            .condition_bits()
+            .iter()
+            .map(|bit| evaluate(&ctx, registers[&Register::from(*bit)], &entering))
+            .collect();
+        assert_eq!(cr1, [1, 0, 0, 1]);
     }
 }
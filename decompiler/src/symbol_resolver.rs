@@ -0,0 +1,36 @@
+use powerpc::Resolver;
+
+use crate::fact::symbol::SymbolFact;
+use crate::fact_database::FactDatabase;
+use crate::memory_map::MemoryMap;
+
+/// Names addresses after the nearest [`SymbolFact`] at or before them.
+pub struct SymbolResolver<'a, 'data> {
+    db: &'a FactDatabase,
+    memory: &'a MemoryMap<'data>,
+}
+
+impl<'a, 'data> SymbolResolver<'a, 'data> {
+    pub fn new(db: &'a FactDatabase, memory: &'a MemoryMap<'data>) -> Self {
+        Self { db, memory }
+    }
+}
+
+impl Resolver for SymbolResolver<'_, '_> {
+    fn resolve(&self, address: u32) -> Option<(&str, u32)> {
+        let (symbol_addr, symbol) = self.db.get_fact_at_or_before::<SymbolFact>(address)?;
+        let offset = address - symbol_addr;
+        match symbol.size() {
+            Some(size) if offset >= size => return None,
+            Some(_) => {}
+            // Without a size, don't let a symbol reach past the end of its region.
+            None => {
+                let region = self.memory.region_containing(symbol_addr)?;
+                if !region.contains(address) {
+                    return None;
+                }
+            }
+        }
+        Some((symbol.name(), offset))
+    }
+}
@@ -0,0 +1,308 @@
+use std::ops::Range;
+
+use thiserror::Error;
+
+use crate::header::{
+    BSS_ADDRESS_OFFSET, BSS_SIZE_OFFSET, ENTRY_POINT_OFFSET, SECTION_LOAD_ADDRESS_TABLE_OFFSET,
+    SECTION_OFFSET_TABLE_OFFSET, SECTION_SIZE_TABLE_OFFSET,
+};
+use crate::{
+    ParseError, Reader, SectionKind, DATA_SECTION_COUNT, HEADER_SIZE, SECTION_COUNT,
+    TEXT_SECTION_COUNT,
+};
+
+/// Section data is laid out on this alignment in the file, like the official tools do.
+const SECTION_FILE_ALIGNMENT: usize = 0x20;
+
+/// Writes a DOL from sections, a BSS range and an entry point.
+///
+/// ```
+/// use dol::Image;
+///
+/// let dol = dol::Builder::new()
+///     .text_section(0x80003100, vec![0x4e, 0x80, 0x00, 0x20])
+///     .entry_point(0x80003100)
+///     .build()
+///     .unwrap();
+/// let reader = dol::Reader::parse(&dol).unwrap();
+/// assert_eq!(reader.read_u32(0x80003100), Some(0x4e800020));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Builder {
+    text_sections: Vec<(u32, Vec<u8>)>,
+    data_sections: Vec<(u32, Vec<u8>)>,
+    bss: Range<u32>,
+    entry_point: u32,
+}
+
+impl Builder {
+    pub fn new() -> Builder {
+        Builder::default()
+    }
+
+    /// Adds a text section loaded at `load_address`. Sections fill header slots in the order
+    /// they're added.
+    pub fn text_section(mut self, load_address: u32, data: impl Into<Vec<u8>>) -> Builder {
+        self.text_sections.push((load_address, data.into()));
+        self
+    }
+
+    /// Adds a data section loaded at `load_address`. Sections fill header slots in the order
+    /// they're added.
+    pub fn data_section(mut self, load_address: u32, data: impl Into<Vec<u8>>) -> Builder {
+        self.data_sections.push((load_address, data.into()));
+        self
+    }
+
+    pub fn bss(self, bss: Range<u32>) -> Builder {
+        Builder { bss, ..self }
+    }
+
+    pub fn entry_point(self, entry_point: u32) -> Builder {
+        Builder {
+            entry_point,
+            ..self
+        }
+    }
+
+    /// Overwrites the bytes loaded at `address`, which must all lie in one section.
+    pub fn patch(&mut self, address: u32, bytes: &[u8]) -> Result<(), BuildError> {
+        let unmapped = BuildError::PatchOutsideSections { address };
+        let end = address.checked_add(bytes.len() as u32).ok_or(unmapped)?;
+        let data = self
+            .text_sections
+            .iter_mut()
+            .chain(&mut self.data_sections)
+            .find_map(|(load_address, data)| {
+                let start = address.checked_sub(*load_address)? as usize;
+                data.get_mut(start..start + (end - address) as usize)
+            })
+            .ok_or(unmapped)?;
+        data.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Writes the DOL, checking it the same way [`Reader::parse`] does.
+    pub fn build(&self) -> Result<Vec<u8>, BuildError> {
+        if self.text_sections.len() > TEXT_SECTION_COUNT {
+            return Err(BuildError::TooManyTextSections {
+                count: self.text_sections.len(),
+            });
+        }
+        if self.data_sections.len() > DATA_SECTION_COUNT {
+            return Err(BuildError::TooManyDataSections {
+                count: self.data_sections.len(),
+            });
+        }
+
+        if self.bss.end < self.bss.start {
+            return Err(BuildError::InvertedBss {
+                start: self.bss.start,
+                end: self.bss.end,
+            });
+        }
+
+        let mut dol = vec![0; HEADER_SIZE];
+        let slots = (0..)
+            .zip(&self.text_sections)
+            .chain((TEXT_SECTION_COUNT..SECTION_COUNT).zip(&self.data_sections));
+        for (index, (load_address, data)) in slots {
+            // Empty slots are marked by their size, so an empty section can't be written.
+            if data.is_empty() {
+                return Err(BuildError::EmptySection { index });
+            }
+            let offset = dol.len() as u32;
+            write_u32(&mut dol, SECTION_OFFSET_TABLE_OFFSET + 4 * index, offset);
+            write_u32(
+                &mut dol,
+                SECTION_LOAD_ADDRESS_TABLE_OFFSET + 4 * index,
+                *load_address,
+            );
+            write_u32(
+                &mut dol,
+                SECTION_SIZE_TABLE_OFFSET + 4 * index,
+                data.len() as u32,
+            );
+            dol.extend_from_slice(data);
+            let padded_len =
+                (dol.len() + SECTION_FILE_ALIGNMENT - 1) & !(SECTION_FILE_ALIGNMENT - 1);
+            dol.resize(padded_len, 0);
+        }
+        write_u32(&mut dol, BSS_ADDRESS_OFFSET, self.bss.start);
+        write_u32(&mut dol, BSS_SIZE_OFFSET, self.bss.end - self.bss.start);
+        write_u32(&mut dol, ENTRY_POINT_OFFSET, self.entry_point);
+
+        Reader::parse(&dol)?;
+        Ok(dol)
+    }
+}
+
+impl From<Reader<'_>> for Builder {
+    /// Copies every section, the BSS range and the entry point, so that an executable can be
+    /// patched and written again.
+    fn from(reader: Reader<'_>) -> Builder {
+        let mut builder = Builder::new()
+            .bss(reader.bss())
+            .entry_point(reader.entry_point());
+        for section in reader.iter_sections() {
+            let data = reader.section_data(section).to_vec();
+            builder = match section.kind {
+                SectionKind::Text => builder.text_section(section.load_address, data),
+                SectionKind::Data | SectionKind::ReadOnlyData => {
+                    builder.data_section(section.load_address, data)
+                }
+            };
+        }
+        builder
+    }
+}
+
+fn write_u32(data: &mut [u8], offset: usize, value: u32) {
+    data[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+}
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum BuildError {
+    #[error(
+        "{count} text sections, but a DOL holds at most {}",
+        TEXT_SECTION_COUNT
+    )]
+    TooManyTextSections { count: usize },
+
+    #[error(
+        "{count} data sections, but a DOL holds at most {}",
+        DATA_SECTION_COUNT
+    )]
+    TooManyDataSections { count: usize },
+
+    #[error("section {index} is empty, which a DOL can't hold")]
+    EmptySection { index: usize },
+
+    #[error("the BSS ends at 0x{end:08x}, before its start at 0x{start:08x}")]
+    InvertedBss { start: u32, end: u32 },
+
+    #[error("patch at 0x{address:08x} is not within one section")]
+    PatchOutsideSections { address: u32 },
+
+    #[error(transparent)]
+    Invalid(#[from] ParseError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use super::{BuildError, Builder};
+    use crate::{Image, ParseError, Reader, Section, SectionKind};
+
+    fn builder() -> Builder {
+        Builder::new()
+            .text_section(0x80003100, vec![0x4e, 0x80, 0x00, 0x20])
+            .text_section(0x80005000, vec![0x60; 0x24])
+            .data_section(0x80004000, b"mobj.c\0".to_vec())
+            .bss(0x80006000..0x80006100)
+            .entry_point(0x80003100)
+    }
+
+    #[test]
+    fn round_trip() {
+        let dol = builder().build().unwrap();
+        let reader = Reader::parse(&dol).unwrap();
+        let header = reader.header();
+        assert_eq!(
+            header.text_sections,
+            [
+                Section {
+                    kind: SectionKind::Text,
+                    offset: 0x100,
+                    load_address: 0x80003100,
+                    size: 4,
+                },
+                Section {
+                    kind: SectionKind::Text,
+                    offset: 0x120,
+                    load_address: 0x80005000,
+                    size: 0x24,
+                },
+            ]
+        );
+        assert_eq!(
+            header.data_sections,
+            [Section {
+                kind: SectionKind::Data,
+                offset: 0x160,
+                load_address: 0x80004000,
+                size: 7,
+            }]
+        );
+        assert_eq!(header.bss, 0x80006000..0x80006100);
+        assert_eq!(header.entry_point, 0x80003100);
+        assert_eq!(reader.section(7).unwrap().kind, SectionKind::Data);
+        assert_eq!(
+            reader.read_c_string(0x80004000).unwrap().to_bytes(),
+            b"mobj.c"
+        );
+
+        assert_eq!(Builder::from(reader), builder());
+        assert_eq!(Builder::from(reader).build().unwrap(), dol);
+    }
+
+    #[test]
+    fn patch() {
+        let mut builder = builder();
+        builder
+            .patch(0x80003100, &[0x38, 0x60, 0x00, 0x01])
+            .unwrap();
+        assert_eq!(
+            builder.patch(0x80004004, b"xyzw"),
+            Err(BuildError::PatchOutsideSections {
+                address: 0x80004004
+            })
+        );
+        let dol = builder.build().unwrap();
+        assert_eq!(
+            Reader::parse(&dol).unwrap().read_u32(0x80003100),
+            Some(0x38600001)
+        );
+    }
+
+    #[test]
+    fn build_errors() {
+        let too_many = (0..8).fold(Builder::new(), |builder, i| {
+            builder.text_section(0x80003000 + 0x100 * i, vec![0; 4])
+        });
+        assert_eq!(
+            too_many.build(),
+            Err(BuildError::TooManyTextSections { count: 8 })
+        );
+        assert_eq!(
+            builder().data_section(0x80003100, vec![0; 4]).build(),
+            Err(BuildError::Invalid(ParseError::OverlappingSections {
+                first: 0,
+                second: 8,
+            }))
+        );
+        assert_eq!(
+            builder().data_section(0x80007000, Vec::new()).build(),
+            Err(BuildError::EmptySection { index: 8 })
+        );
+        assert_eq!(
+            builder()
+                .bss(Range {
+                    start: 0x80006100,
+                    end: 0x80006000,
+                })
+                .build(),
+            Err(BuildError::InvertedBss {
+                start: 0x80006100,
+                end: 0x80006000,
+            })
+        );
+        assert_eq!(
+            Builder::new().text_section(0x80003100, vec![0; 3]).build(),
+            Err(BuildError::Invalid(ParseError::MisalignedSection {
+                index: 0
+            }))
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use std::ops::Range;
+
+use crate::Section;
+
+pub(crate) const BSS_ADDRESS_OFFSET: usize = 0xd8;
+pub(crate) const BSS_SIZE_OFFSET: usize = 0xdc;
+pub(crate) const ENTRY_POINT_OFFSET: usize = 0xe0;
+pub(crate) const SECTION_OFFSET_TABLE_OFFSET: usize = 0;
+pub(crate) const SECTION_LOAD_ADDRESS_TABLE_OFFSET: usize = 0x48;
+pub(crate) const SECTION_SIZE_TABLE_OFFSET: usize = 0x90;
+
+/// The contents of a DOL header. Empty section slots are left out.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Header {
+    pub text_sections: Vec<Section>,
+    pub data_sections: Vec<Section>,
+    /// The zero-initialized memory cleared before the entry point runs. It usually spans the small
+    /// data sections too.
+    pub bss: Range<u32>,
+    pub entry_point: u32,
+}
@@ -0,0 +1,68 @@
+use std::convert::TryInto;
+use std::ffi::CStr;
+use std::ops::Range;
+
+use crate::Section;
+
+/// An executable whose sections are loaded at fixed addresses, such as a DOL or an ELF.
+///
+/// Reads never cross from one section into another, even when the sections are adjacent in
+/// memory.
+pub trait Image<'data> {
+    /// The non-empty sections, in the order the file lists them.
+    fn sections(&self) -> Vec<Section>;
+
+    /// Returns the section loaded at `address`, if any.
+    fn section_containing(&self, address: u32) -> Option<Section>;
+
+    /// The bytes loaded for `section`.
+    fn section_data(&self, section: Section) -> &'data [u8];
+
+    fn entry_point(&self) -> u32;
+
+    /// Returns the bytes loaded at `range`, or `None` unless one section holds all of them.
+    fn read_bytes(&self, range: Range<u32>) -> Option<&'data [u8]> {
+        let section = self.section_containing(range.start)?;
+        let len = range.end.checked_sub(range.start)?;
+        let start = range.start - section.load_address;
+        let data = self.section_data(section);
+        data.get(start as usize..start as usize + len as usize)
+    }
+
+    fn read_u8(&self, address: u32) -> Option<u8> {
+        Some(read_array::<1>(self, address)?[0])
+    }
+
+    fn read_u16(&self, address: u32) -> Option<u16> {
+        Some(u16::from_be_bytes(read_array(self, address)?))
+    }
+
+    fn read_u32(&self, address: u32) -> Option<u32> {
+        Some(u32::from_be_bytes(read_array(self, address)?))
+    }
+
+    fn read_f32(&self, address: u32) -> Option<f32> {
+        Some(f32::from_be_bytes(read_array(self, address)?))
+    }
+
+    fn read_f64(&self, address: u32) -> Option<f64> {
+        Some(f64::from_be_bytes(read_array(self, address)?))
+    }
+
+    /// Returns the NUL-terminated string at `address`, or `None` if the section holding it ends
+    /// first.
+    fn read_c_string(&self, address: u32) -> Option<&'data CStr> {
+        let section = self.section_containing(address)?;
+        let data = &self.section_data(section)[(address - section.load_address) as usize..];
+        let len = data.iter().position(|&byte| byte == 0)?;
+        Some(CStr::from_bytes_with_nul(&data[..=len]).unwrap())
+    }
+}
+
+fn read_array<'data, const N: usize>(
+    image: &(impl Image<'data> + ?Sized),
+    address: u32,
+) -> Option<[u8; N]> {
+    let end = address.checked_add(N as u32)?;
+    Some(image.read_bytes(address..end)?.try_into().unwrap())
+}
@@ -1,9 +1,26 @@
+mod builder;
+mod header;
+mod image;
+mod parse_error;
 mod reader;
 mod section;
 mod sections_iter;
 
+pub use crate::builder::{BuildError, Builder};
+pub use crate::header::Header;
+pub use crate::image::Image;
+pub use crate::parse_error::ParseError;
 pub use crate::reader::Reader;
-pub use crate::section::Section;
+pub use crate::section::{Section, SectionKind};
 pub use crate::sections_iter::SectionsIter;
 
+/// The size in bytes of the header, which holds the section table, the BSS range and the entry
+/// point.
+pub const HEADER_SIZE: usize = 0x100;
+
 pub const SECTION_COUNT: usize = 18;
+
+/// The first sections hold code. The rest hold data.
+pub const TEXT_SECTION_COUNT: usize = 7;
+
+pub const DATA_SECTION_COUNT: usize = SECTION_COUNT - TEXT_SECTION_COUNT;
@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+use crate::HEADER_SIZE;
+
+/// Why a DOL can't be read. Sections are numbered by header slot, text sections first.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ParseError {
+    #[error("truncated header: {len} bytes, expected at least {}", HEADER_SIZE)]
+    TruncatedHeader { len: usize },
+
+    #[error("section {index} is not word-aligned")]
+    MisalignedSection { index: usize },
+
+    #[error("section {index} extends past the end of the file")]
+    SectionOutOfFile { index: usize },
+
+    #[error("section {index} extends past the end of the address space")]
+    SectionOutOfAddressSpace { index: usize },
+
+    #[error("the BSS extends past the end of the address space")]
+    BssOutOfAddressSpace,
+
+    #[error("sections {first} and {second} overlap")]
+    OverlappingSections { first: usize, second: usize },
+}
@@ -1,46 +1,140 @@
+use std::ops::Range;
+
 use byteorder::{BigEndian, ReadBytesExt};
 
-use crate::{Section, SectionsIter, SECTION_COUNT};
+use crate::header::{
+    BSS_ADDRESS_OFFSET, BSS_SIZE_OFFSET, ENTRY_POINT_OFFSET, SECTION_LOAD_ADDRESS_TABLE_OFFSET,
+    SECTION_OFFSET_TABLE_OFFSET, SECTION_SIZE_TABLE_OFFSET,
+};
+use crate::{
+    Header, Image, ParseError, Section, SectionKind, SectionsIter, HEADER_SIZE, SECTION_COUNT,
+    TEXT_SECTION_COUNT,
+};
 
-const ENTRY_POINT_OFFSET: usize = 0xe0;
-const SECTION_OFFSET_TABLE_OFFSET: usize = 0;
-const SECTION_LOAD_ADDRESS_TABLE_OFFSET: usize = 0x48;
-const SECTION_SIZE_TABLE_OFFSET: usize = 0x90;
+/// An empty entry for the unused end of `Reader::sections_by_address`.
+const NO_SECTION: Section = Section {
+    kind: SectionKind::Text,
+    offset: 0,
+    load_address: 0,
+    size: 0,
+};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Reader<'data> {
     data: &'data [u8],
+    /// The non-empty sections sorted by load address, for looking up addresses by binary search.
+    /// Only the first `section_count` entries are used.
+    sections_by_address: [Section; SECTION_COUNT],
+    section_count: usize,
 }
 
 impl<'data> Reader<'data> {
-    pub fn new(data: &'data [u8]) -> Reader<'data> {
-        let mut reader = Reader { data };
+    /// Reads the header and checks that every section is word-aligned, lies within `data`, and
+    /// doesn't overlap another section in memory, and that the BSS fits in the address space.
+    pub fn parse(data: &'data [u8]) -> Result<Reader<'data>, ParseError> {
+        if data.len() < HEADER_SIZE {
+            return Err(ParseError::TruncatedHeader { len: data.len() });
+        }
+        let mut reader = Reader {
+            data,
+            sections_by_address: [NO_SECTION; SECTION_COUNT],
+            section_count: 0,
+        };
+
+        let mut sections: Vec<(usize, Section)> = Vec::new();
+        for index in 0..SECTION_COUNT {
+            let section = match reader.section(index) {
+                Some(section) => section,
+                None => continue,
+            };
+
+            let misaligned = section.offset % 4 != 0
+                || section.load_address % 4 != 0
+                || (section.kind == SectionKind::Text && section.size % 4 != 0);
+            if misaligned {
+                return Err(ParseError::MisalignedSection { index });
+            }
+            if u64::from(section.offset) + u64::from(section.size) > data.len() as u64 {
+                return Err(ParseError::SectionOutOfFile { index });
+            }
+            if section.load_address.checked_add(section.size).is_none() {
+                return Err(ParseError::SectionOutOfAddressSpace { index });
+            }
+            for &(first, other) in &sections {
+                if section.load_address < other.end_address()
+                    && other.load_address < section.end_address()
+                {
+                    return Err(ParseError::OverlappingSections {
+                        first,
+                        second: index,
+                    });
+                }
+            }
+            sections.push((index, section));
+        }
+
+        let bss_address = reader.read_header_word(BSS_ADDRESS_OFFSET);
+        let bss_size = reader.read_header_word(BSS_SIZE_OFFSET);
+        if bss_address.checked_add(bss_size).is_none() {
+            return Err(ParseError::BssOutOfAddressSpace);
+        }
 
         // Bound the data slice.
-        let farthest_end = reader
-            .iter_sections()
-            .map(|section| section.offset + section.size)
-            .max()
-            .unwrap() as usize;
+        let farthest_end = sections
+            .iter()
+            .map(|(_, section)| (section.offset + section.size) as usize)
+            .fold(HEADER_SIZE, usize::max);
         reader.data = &data[..farthest_end];
-        reader
+
+        sections.sort_unstable_by_key(|(_, section)| section.load_address);
+        for (sorted, (_, section)) in reader.sections_by_address.iter_mut().zip(&sections) {
+            *sorted = *section;
+        }
+        reader.section_count = sections.len();
+        Ok(reader)
     }
 
-    pub fn section(self, index: usize) -> Section {
+    /// Returns how many bytes of the file the DOL starting with `header` spans, which is where its
+    /// last section ends. This lets a DOL be read out of a larger stream without knowing its size.
+    pub fn file_len(header: &[u8]) -> Result<usize, ParseError> {
+        if header.len() < HEADER_SIZE {
+            return Err(ParseError::TruncatedHeader { len: header.len() });
+        }
+        let word = |offset: usize| (&header[offset..]).read_u32::<BigEndian>().unwrap() as usize;
+        Ok((0..SECTION_COUNT)
+            .map(|index| match word(SECTION_SIZE_TABLE_OFFSET + 4 * index) {
+                // Empty slots don't count, whatever their offset.
+                0 => 0,
+                size => word(SECTION_OFFSET_TABLE_OFFSET + 4 * index) + size,
+            })
+            .fold(HEADER_SIZE, usize::max))
+    }
+
+    /// Returns the section in header slot `index`, or `None` if the slot is empty or out of
+    /// range. Slots below [`TEXT_SECTION_COUNT`] hold text sections.
+    pub fn section(self, index: usize) -> Option<Section> {
         if index >= SECTION_COUNT {
-            panic!("index out of range: {}", index);
+            return None;
         }
-        Section {
-            offset: (&self.data[4 * index + SECTION_OFFSET_TABLE_OFFSET..])
-                .read_u32::<BigEndian>()
-                .unwrap(),
-            load_address: (&self.data[4 * index + SECTION_LOAD_ADDRESS_TABLE_OFFSET..])
-                .read_u32::<BigEndian>()
-                .unwrap(),
-            size: (&self.data[4 * index + SECTION_SIZE_TABLE_OFFSET..])
-                .read_u32::<BigEndian>()
-                .unwrap(),
+        let size = self.read_header_word(SECTION_SIZE_TABLE_OFFSET + 4 * index);
+        if size == 0 {
+            return None;
         }
+        Some(Section {
+            kind: if index < TEXT_SECTION_COUNT {
+                SectionKind::Text
+            } else {
+                SectionKind::Data
+            },
+            offset: self.read_header_word(SECTION_OFFSET_TABLE_OFFSET + 4 * index),
+            load_address: self.read_header_word(SECTION_LOAD_ADDRESS_TABLE_OFFSET + 4 * index),
+            size,
+        })
+    }
+
+    /// The bytes loaded for `section`.
+    pub fn section_data(self, section: Section) -> &'data [u8] {
+        &self.data[section.offset as usize..][..section.size as usize]
     }
 
     pub fn iter_sections(self) -> SectionsIter<'data> {
@@ -50,20 +144,225 @@ impl<'data> Reader<'data> {
         }
     }
 
+    pub fn iter_text_sections(self) -> impl Iterator<Item = Section> + 'data {
+        self.iter_sections()
+            .filter(|section| section.kind == SectionKind::Text)
+    }
+
+    pub fn iter_data_sections(self) -> impl Iterator<Item = Section> + 'data {
+        self.iter_sections()
+            .filter(|section| section.kind == SectionKind::Data)
+    }
+
+    pub fn bss(self) -> Range<u32> {
+        let address = self.read_header_word(BSS_ADDRESS_OFFSET);
+        let size = self.read_header_word(BSS_SIZE_OFFSET);
+        address..address + size
+    }
+
     pub fn entry_point(self) -> u32 {
-        (&self.data[ENTRY_POINT_OFFSET..])
-            .read_u32::<BigEndian>()
-            .unwrap()
+        self.read_header_word(ENTRY_POINT_OFFSET)
+    }
+
+    pub fn header(self) -> Header {
+        Header {
+            text_sections: self.iter_text_sections().collect(),
+            data_sections: self.iter_data_sections().collect(),
+            bss: self.bss(),
+            entry_point: self.entry_point(),
+        }
+    }
+
+    /// Returns the section loaded at `address`, if any.
+    pub fn section_containing(self, address: u32) -> Option<Section> {
+        let sections = &self.sections_by_address[..self.section_count];
+        let index = sections.partition_point(|section| section.load_address <= address);
+        let section = sections[..index].last().copied()?;
+        section.contains(address).then_some(section)
     }
 
-    pub fn read(self, address: u32) -> u32 {
-        for section in self.iter_sections() {
-            if let Some(offset) = section.offset_for_dword_address(address) {
-                return (&self.data[offset as usize..])
-                    .read_u32::<BigEndian>()
-                    .unwrap();
+    fn read_header_word(self, offset: usize) -> u32 {
+        (&self.data[offset..]).read_u32::<BigEndian>().unwrap()
+    }
+}
+
+impl<'data> Image<'data> for Reader<'data> {
+    fn sections(&self) -> Vec<Section> {
+        self.iter_sections().collect()
+    }
+
+    fn section_containing(&self, address: u32) -> Option<Section> {
+        Reader::section_containing(*self, address)
+    }
+
+    fn section_data(&self, section: Section) -> &'data [u8] {
+        Reader::section_data(*self, section)
+    }
+
+    fn entry_point(&self) -> u32 {
+        Reader::entry_point(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reader;
+    use crate::header::{
+        BSS_ADDRESS_OFFSET, BSS_SIZE_OFFSET, ENTRY_POINT_OFFSET, SECTION_LOAD_ADDRESS_TABLE_OFFSET,
+        SECTION_OFFSET_TABLE_OFFSET, SECTION_SIZE_TABLE_OFFSET,
+    };
+    use crate::{Header, Image, ParseError, Section, SectionKind, HEADER_SIZE};
+
+    fn put(data: &mut [u8], offset: usize, value: u32) {
+        data[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+    }
+
+    /// Builds a DOL from `(slot, offset, load_address, size)` entries, with enough data to hold
+    /// every section.
+    fn dol(sections: &[(usize, u32, u32, u32)]) -> Vec<u8> {
+        let mut data = vec![0; 0x200];
+        for &(index, offset, load_address, size) in sections {
+            put(&mut data, SECTION_OFFSET_TABLE_OFFSET + 4 * index, offset);
+            put(
+                &mut data,
+                SECTION_LOAD_ADDRESS_TABLE_OFFSET + 4 * index,
+                load_address,
+            );
+            put(&mut data, SECTION_SIZE_TABLE_OFFSET + 4 * index, size);
+        }
+        put(&mut data, BSS_ADDRESS_OFFSET, 0x80004000);
+        put(&mut data, BSS_SIZE_OFFSET, 0x100);
+        put(&mut data, ENTRY_POINT_OFFSET, 0x80003100);
+        data
+    }
+
+    #[test]
+    fn header() {
+        let mut data = dol(&[(0, 0x100, 0x80003100, 0x40), (7, 0x140, 0x80003200, 0x20)]);
+        put(&mut data, 0x100, 0x4e800020);
+        let reader = Reader::parse(&data).unwrap();
+
+        let text = Section {
+            kind: SectionKind::Text,
+            offset: 0x100,
+            load_address: 0x80003100,
+            size: 0x40,
+        };
+        let data_section = Section {
+            kind: SectionKind::Data,
+            offset: 0x140,
+            load_address: 0x80003200,
+            size: 0x20,
+        };
+        assert_eq!(
+            reader.header(),
+            Header {
+                text_sections: vec![text],
+                data_sections: vec![data_section],
+                bss: 0x80004000..0x80004100,
+                entry_point: 0x80003100,
             }
+        );
+        assert_eq!(reader.section(0), Some(text));
+        assert_eq!(reader.section(1), None);
+        assert_eq!(reader.section(18), None);
+        assert_eq!(reader.iter_sections().count(), 2);
+        assert_eq!(reader.read_u32(0x80003100), Some(0x4e800020));
+        assert_eq!(Reader::file_len(&data[..HEADER_SIZE]), Ok(0x160));
+    }
+
+    #[test]
+    fn reads() {
+        let mut data = dol(&[
+            (7, 0x180, 0x80004000, 0x20),
+            (0, 0x100, 0x80003100, 0x40),
+            (8, 0x1a0, 0x80004020, 0x10),
+        ]);
+        data[0x180..0x18f].copy_from_slice(b"mobj.c\0\x3f\x80\0\0\xab\xcd\xef\x01");
+        // The last section isn't NUL-terminated.
+        data[0x1a0..0x1b0].copy_from_slice(b"tail............");
+        let reader = Reader::parse(&data).unwrap();
+
+        assert_eq!(
+            reader
+                .section_containing(0x80004024)
+                .map(|section| section.offset),
+            Some(0x1a0)
+        );
+        assert_eq!(
+            reader
+                .section_containing(0x80003100)
+                .map(|section| section.offset),
+            Some(0x100)
+        );
+        assert_eq!(reader.section_containing(0x80003140), None);
+        assert_eq!(reader.section_containing(0x80003000), None);
+        assert_eq!(reader.section_containing(0x80004030), None);
+
+        assert_eq!(
+            reader.read_c_string(0x80004000).unwrap().to_bytes(),
+            b"mobj.c"
+        );
+        assert_eq!(reader.read_c_string(0x80004004).unwrap().to_bytes(), b".c");
+        assert_eq!(reader.read_c_string(0x80004020), None);
+        assert_eq!(reader.read_u8(0x80004001), Some(b'o'));
+        assert_eq!(reader.read_u16(0x8000400b), Some(0xabcd));
+        assert_eq!(reader.read_u32(0x8000400b), Some(0xabcdef01));
+        assert_eq!(reader.read_f32(0x80004007), Some(1.0));
+        assert_eq!(reader.read_f64(0x80004018), Some(0.0));
+        assert_eq!(
+            reader.read_bytes(0x80004020..0x80004024),
+            Some(&b"tail"[..])
+        );
+
+        // Reads may not cross from one section into the next, even when they're adjacent.
+        assert_eq!(reader.read_u32(0x8000401e), None);
+        assert_eq!(reader.read_bytes(0x80004010..0x80004028), None);
+        assert_eq!(reader.read_u8(0x80004030), None);
+        assert_eq!(reader.read_u32(0xfffffffe), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Reader::parse(&[0; HEADER_SIZE - 1]),
+            Err(ParseError::TruncatedHeader {
+                len: HEADER_SIZE - 1
+            })
+        );
+        for (sections, error) in [
+            (
+                &[(0, 0x102, 0x80003100, 0x40)][..],
+                ParseError::MisalignedSection { index: 0 },
+            ),
+            (
+                &[(1, 0x100, 0x80003100, 0x3e)],
+                ParseError::MisalignedSection { index: 1 },
+            ),
+            (
+                &[(8, 0x1f0, 0x80003100, 0x20)],
+                ParseError::SectionOutOfFile { index: 8 },
+            ),
+            (
+                &[(0, 0x100, 0xfffffff0, 0x20)],
+                ParseError::SectionOutOfAddressSpace { index: 0 },
+            ),
+            (
+                &[(0, 0x100, 0x80003100, 0x40), (7, 0x140, 0x80003120, 0x20)],
+                ParseError::OverlappingSections {
+                    first: 0,
+                    second: 7,
+                },
+            ),
+        ] {
+            assert_eq!(Reader::parse(&dol(sections)), Err(error));
         }
-        panic!("address not mapped: {:08x}", address);
+
+        let mut data = dol(&[]);
+        put(&mut data, BSS_ADDRESS_OFFSET, 0xffffff00);
+        assert_eq!(Reader::parse(&data), Err(ParseError::BssOutOfAddressSpace));
+
+        // A data section may end on a byte boundary.
+        assert!(Reader::parse(&dol(&[(7, 0x100, 0x80003100, 0x3e)])).is_ok());
     }
 }
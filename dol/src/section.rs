@@ -1,11 +1,21 @@
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Section {
+    pub kind: SectionKind,
     pub offset: u32,
     pub load_address: u32,
     pub size: u32,
 }
 
 impl Section {
+    /// The address just past the end of the section.
+    pub fn end_address(self) -> u32 {
+        self.load_address + self.size
+    }
+
+    pub fn contains(self, address: u32) -> bool {
+        address >= self.load_address && address - self.load_address < self.size
+    }
+
     pub fn offset_for_dword_address(self, address: u32) -> Option<u32> {
         let offset_within_section = address.checked_sub(self.load_address)?;
         let final_offset_within_section = offset_within_section.checked_add(3)?;
@@ -17,3 +27,12 @@ impl Section {
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SectionKind {
+    Text,
+    Data,
+    /// Data that isn't written at run time, such as `.rodata`. DOLs don't tell it apart from other
+    /// data, but ELFs do.
+    ReadOnlyData,
+}
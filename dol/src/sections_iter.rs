@@ -1,7 +1,8 @@
-use std::iter::{ExactSizeIterator, FusedIterator};
+use std::iter::FusedIterator;
 
 use crate::{Reader, Section, SECTION_COUNT};
 
+/// Iterates over the non-empty sections in header order.
 pub struct SectionsIter<'data> {
     pub(crate) reader: Reader<'data>,
     pub(crate) index: usize,
@@ -11,21 +12,19 @@ impl<'data> Iterator for SectionsIter<'data> {
     type Item = Section;
 
     fn next(&mut self) -> Option<Section> {
-        if self.index < SECTION_COUNT {
-            let result = Some(self.reader.section(self.index));
+        while self.index < SECTION_COUNT {
+            let section = self.reader.section(self.index);
             self.index += 1;
-            result
-        } else {
-            None
+            if section.is_some() {
+                return section;
+            }
         }
+        None
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = SECTION_COUNT - self.index;
-        (len, Some(len))
+        (0, Some(SECTION_COUNT - self.index))
     }
 }
 
-impl<'data> ExactSizeIterator for SectionsIter<'data> {}
-
 impl<'data> FusedIterator for SectionsIter<'data> {}
@@ -0,0 +1,10 @@
+//! Reads 32-bit big-endian PowerPC ELF executables, exposing them through [`dol::Image`] like a
+//! DOL.
+
+mod parse_error;
+mod reader;
+mod symbol;
+
+pub use crate::parse_error::ParseError;
+pub use crate::reader::Reader;
+pub use crate::symbol::{Symbol, SymbolKind};
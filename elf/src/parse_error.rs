@@ -0,0 +1,47 @@
+use thiserror::Error;
+
+/// Why an ELF can't be read. Sections are numbered by their index in the section header table, and
+/// segments by theirs in the program header table.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ParseError {
+    #[error("not an ELF file")]
+    NotElf,
+
+    #[error("not a 32-bit big-endian PowerPC executable")]
+    Unsupported,
+
+    #[error("truncated header")]
+    TruncatedHeader,
+
+    #[error("section header table extends past the end of the file")]
+    SectionHeadersOutOfFile,
+
+    #[error("section {index} extends past the end of the file")]
+    SectionOutOfFile { index: usize },
+
+    #[error("section {index} extends past the end of the address space")]
+    SectionOutOfAddressSpace { index: usize },
+
+    #[error("sections {first} and {second} overlap")]
+    OverlappingSections { first: usize, second: usize },
+
+    #[error("program header table extends past the end of the file")]
+    ProgramHeadersOutOfFile,
+
+    #[error("segment {index} extends past the end of the file")]
+    SegmentOutOfFile { index: usize },
+
+    #[error("segment {index} extends past the end of the address space")]
+    SegmentOutOfAddressSpace { index: usize },
+
+    #[error("segments {first} and {second} overlap")]
+    OverlappingSegments { first: usize, second: usize },
+
+    #[error("no sections or segments with data to load")]
+    NothingLoadable,
+
+    #[error(
+        "symbol table in section {index} links to section {link}, which is not a string table"
+    )]
+    BadStringTable { index: usize, link: usize },
+}
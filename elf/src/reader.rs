@@ -0,0 +1,737 @@
+use std::convert::TryInto;
+use std::ops::Range;
+
+use dol::{Image, Section, SectionKind};
+
+use crate::{ParseError, Symbol, SymbolKind};
+
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+const ELF_CLASS_32: u8 = 1;
+const ELF_DATA_BIG_ENDIAN: u8 = 2;
+const MACHINE_PPC: u16 = 20;
+
+const HEADER_SIZE: usize = 0x34;
+const CLASS_OFFSET: usize = 4;
+const DATA_OFFSET: usize = 5;
+const MACHINE_OFFSET: usize = 18;
+const ENTRY_POINT_OFFSET: usize = 24;
+const PROGRAM_HEADER_TABLE_OFFSET: usize = 28;
+const SECTION_HEADER_TABLE_OFFSET: usize = 32;
+const PROGRAM_HEADER_SIZE_OFFSET: usize = 42;
+const PROGRAM_HEADER_COUNT_OFFSET: usize = 44;
+const SECTION_HEADER_SIZE_OFFSET: usize = 46;
+const SECTION_HEADER_COUNT_OFFSET: usize = 48;
+
+const PROGRAM_HEADER_SIZE: usize = 32;
+const SEGMENT_TYPE_OFFSET: usize = 0;
+const SEGMENT_FILE_OFFSET_OFFSET: usize = 4;
+const SEGMENT_ADDRESS_OFFSET: usize = 8;
+const SEGMENT_FILE_SIZE_OFFSET: usize = 16;
+const SEGMENT_MEMORY_SIZE_OFFSET: usize = 20;
+const SEGMENT_FLAGS_OFFSET: usize = 24;
+
+const SEGMENT_TYPE_LOAD: u32 = 1;
+
+const SEGMENT_FLAG_EXECUTE: u32 = 0x1;
+const SEGMENT_FLAG_WRITE: u32 = 0x2;
+
+const SECTION_HEADER_SIZE: usize = 40;
+const SECTION_TYPE_OFFSET: usize = 4;
+const SECTION_FLAGS_OFFSET: usize = 8;
+const SECTION_ADDRESS_OFFSET: usize = 12;
+const SECTION_FILE_OFFSET_OFFSET: usize = 16;
+const SECTION_SIZE_OFFSET: usize = 20;
+const SECTION_LINK_OFFSET: usize = 24;
+
+const SECTION_TYPE_NULL: u32 = 0;
+const SECTION_TYPE_PROGBITS: u32 = 1;
+const SECTION_TYPE_SYMTAB: u32 = 2;
+const SECTION_TYPE_STRTAB: u32 = 3;
+const SECTION_TYPE_NOBITS: u32 = 8;
+
+const SECTION_FLAG_WRITE: u32 = 0x1;
+const SECTION_FLAG_ALLOC: u32 = 0x2;
+const SECTION_FLAG_EXECINSTR: u32 = 0x4;
+
+const SYMBOL_SIZE: usize = 16;
+const SYMBOL_TYPE_OBJECT: u8 = 1;
+const SYMBOL_TYPE_FUNC: u8 = 2;
+const SYMBOL_TYPE_SECTION: u8 = 3;
+const SYMBOL_TYPE_FILE: u8 = 4;
+const SYMBOL_SECTION_UNDEFINED: u16 = 0;
+const SYMBOL_SECTION_ABSOLUTE: u16 = 0xfff1;
+
+/// Reads the loadable sections and the symbol table of an ELF executable.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reader<'data> {
+    data: &'data [u8],
+    entry_point: u32,
+    /// The sections with loaded data, in section header table order.
+    sections: Vec<Section>,
+    /// The same sections sorted by load address, for looking up addresses by binary search.
+    sections_by_address: Vec<Section>,
+    /// The span of the zero-initialized sections, if there are any.
+    bss: Option<Range<u32>>,
+    /// The entries of the first symbol table, and the string table holding their names.
+    symbols: &'data [u8],
+    symbol_names: &'data [u8],
+}
+
+impl<'data> Reader<'data> {
+    /// Reads the section header table and checks that every section lies within `data` and that
+    /// no two loaded sections overlap in memory.
+    ///
+    /// Loaded sections are those with data in the file. `.bss` and other sections without data
+    /// aren't included, but are spanned by [`Reader::bss`]. An ELF with no such sections, such as
+    /// one stripped of its section headers, is loaded from its `PT_LOAD` program headers instead,
+    /// with each segment becoming a section.
+    pub fn parse(data: &'data [u8]) -> Result<Reader<'data>, ParseError> {
+        if !data.starts_with(ELF_MAGIC) {
+            return Err(ParseError::NotElf);
+        }
+        if data.len() < HEADER_SIZE {
+            return Err(ParseError::TruncatedHeader);
+        }
+        if data[CLASS_OFFSET] != ELF_CLASS_32
+            || data[DATA_OFFSET] != ELF_DATA_BIG_ENDIAN
+            || read_u16(data, MACHINE_OFFSET) != MACHINE_PPC
+        {
+            return Err(ParseError::Unsupported);
+        }
+
+        let table = header_table(
+            data,
+            SECTION_HEADER_TABLE_OFFSET,
+            SECTION_HEADER_SIZE_OFFSET,
+            SECTION_HEADER_COUNT_OFFSET,
+            SECTION_HEADER_SIZE,
+            ParseError::SectionHeadersOutOfFile,
+        )?;
+        let header_count = table.count;
+        let section_header = |index: usize| table.header(index);
+        let file_data = |index: usize| {
+            let header = section_header(index);
+            let offset = read_u32(header, SECTION_FILE_OFFSET_OFFSET);
+            let size = read_u32(header, SECTION_SIZE_OFFSET);
+            file_range(data, offset, size).ok_or(ParseError::SectionOutOfFile { index })
+        };
+
+        let mut reader = Reader {
+            data,
+            entry_point: read_u32(data, ENTRY_POINT_OFFSET),
+            sections: Vec::new(),
+            sections_by_address: Vec::new(),
+            bss: None,
+            symbols: &[],
+            symbol_names: &[],
+        };
+        let mut indices = Vec::new();
+        for index in 0..header_count {
+            let header = section_header(index);
+            let kind = read_u32(header, SECTION_TYPE_OFFSET);
+            let flags = read_u32(header, SECTION_FLAGS_OFFSET);
+            let address = read_u32(header, SECTION_ADDRESS_OFFSET);
+            if kind == SECTION_TYPE_NOBITS && flags & SECTION_FLAG_ALLOC != 0 {
+                let size = read_u32(header, SECTION_SIZE_OFFSET);
+                let end = address
+                    .checked_add(size)
+                    .ok_or(ParseError::SectionOutOfAddressSpace { index })?;
+                reader.extend_bss(address..end);
+                continue;
+            }
+            if kind == SECTION_TYPE_NULL || kind == SECTION_TYPE_NOBITS {
+                continue;
+            }
+            let section_data = file_data(index)?;
+
+            if kind == SECTION_TYPE_SYMTAB && reader.symbols.is_empty() {
+                let link = read_u32(header, SECTION_LINK_OFFSET) as usize;
+                if link >= header_count
+                    || read_u32(section_header(link), SECTION_TYPE_OFFSET) != SECTION_TYPE_STRTAB
+                {
+                    return Err(ParseError::BadStringTable { index, link });
+                }
+                reader.symbols = section_data;
+                reader.symbol_names = file_data(link)?;
+                continue;
+            }
+
+            if kind != SECTION_TYPE_PROGBITS
+                || flags & SECTION_FLAG_ALLOC == 0
+                || section_data.is_empty()
+            {
+                continue;
+            }
+            let section = Section {
+                kind: section_kind(
+                    flags & SECTION_FLAG_EXECINSTR != 0,
+                    flags & SECTION_FLAG_WRITE != 0,
+                ),
+                offset: read_u32(header, SECTION_FILE_OFFSET_OFFSET),
+                load_address: address,
+                size: section_data.len() as u32,
+            };
+            if address.checked_add(section.size).is_none() {
+                return Err(ParseError::SectionOutOfAddressSpace { index });
+            }
+            if let Some(first) = overlapping(&indices, &reader.sections, section) {
+                return Err(ParseError::OverlappingSections {
+                    first,
+                    second: index,
+                });
+            }
+            indices.push(index);
+            reader.sections.push(section);
+        }
+
+        if reader.sections.is_empty() {
+            reader.load_segments()?;
+        }
+        if reader.sections.is_empty() {
+            return Err(ParseError::NothingLoadable);
+        }
+
+        reader.sections_by_address = reader.sections.clone();
+        reader
+            .sections_by_address
+            .sort_unstable_by_key(|section| section.load_address);
+        Ok(reader)
+    }
+
+    /// Loads each `PT_LOAD` segment with data in the file as a section, replacing the BSS with
+    /// the memory the segments reserve past their data.
+    fn load_segments(&mut self) -> Result<(), ParseError> {
+        let table = header_table(
+            self.data,
+            PROGRAM_HEADER_TABLE_OFFSET,
+            PROGRAM_HEADER_SIZE_OFFSET,
+            PROGRAM_HEADER_COUNT_OFFSET,
+            PROGRAM_HEADER_SIZE,
+            ParseError::ProgramHeadersOutOfFile,
+        )?;
+        self.bss = None;
+        let mut indices = Vec::new();
+        for index in 0..table.count {
+            let header = table.header(index);
+            if read_u32(header, SEGMENT_TYPE_OFFSET) != SEGMENT_TYPE_LOAD {
+                continue;
+            }
+            let offset = read_u32(header, SEGMENT_FILE_OFFSET_OFFSET);
+            let address = read_u32(header, SEGMENT_ADDRESS_OFFSET);
+            let file_size = read_u32(header, SEGMENT_FILE_SIZE_OFFSET);
+            let memory_size = read_u32(header, SEGMENT_MEMORY_SIZE_OFFSET);
+            if file_range(self.data, offset, file_size).is_none() {
+                return Err(ParseError::SegmentOutOfFile { index });
+            }
+            let end = address
+                .checked_add(file_size.max(memory_size))
+                .ok_or(ParseError::SegmentOutOfAddressSpace { index })?;
+            if memory_size > file_size {
+                self.extend_bss(address + file_size..end);
+            }
+            if file_size == 0 {
+                continue;
+            }
+
+            let flags = read_u32(header, SEGMENT_FLAGS_OFFSET);
+            let section = Section {
+                kind: section_kind(
+                    flags & SEGMENT_FLAG_EXECUTE != 0,
+                    flags & SEGMENT_FLAG_WRITE != 0,
+                ),
+                offset,
+                load_address: address,
+                size: file_size,
+            };
+            if let Some(first) = overlapping(&indices, &self.sections, section) {
+                return Err(ParseError::OverlappingSegments {
+                    first,
+                    second: index,
+                });
+            }
+            indices.push(index);
+            self.sections.push(section);
+        }
+        Ok(())
+    }
+
+    /// Grows the BSS to cover `range`, unless it's empty.
+    fn extend_bss(&mut self, range: Range<u32>) {
+        if range.is_empty() {
+            return;
+        }
+        self.bss = Some(match self.bss.take() {
+            Some(bss) => bss.start.min(range.start)..bss.end.max(range.end),
+            None => range,
+        });
+    }
+
+    /// The span of memory zeroed at load time, from the start of the first zero-initialized
+    /// section to the end of the last one. Like a DOL's BSS, it may also span loaded sections,
+    /// such as `.sdata` between `.bss` and `.sbss`.
+    pub fn bss(&self) -> Option<Range<u32>> {
+        self.bss.clone()
+    }
+
+    /// The defined symbols that name a location, in symbol table order. Section, file and absolute
+    /// symbols are skipped, as are symbols whose names are empty or can't be read.
+    pub fn symbols(&self) -> impl Iterator<Item = Symbol<'data>> + 'data {
+        let names = self.symbol_names;
+        self.symbols
+            .chunks_exact(SYMBOL_SIZE)
+            .filter_map(move |entry| {
+                let kind = match entry[12] & 0xf {
+                    SYMBOL_TYPE_SECTION | SYMBOL_TYPE_FILE => return None,
+                    SYMBOL_TYPE_OBJECT => SymbolKind::Object,
+                    SYMBOL_TYPE_FUNC => SymbolKind::Function,
+                    _ => SymbolKind::Other,
+                };
+                // Absolute symbols hold values such as sizes and build options, not addresses.
+                match read_u16(entry, 14) {
+                    SYMBOL_SECTION_UNDEFINED | SYMBOL_SECTION_ABSOLUTE => return None,
+                    _ => {}
+                }
+                let name = names.get(read_u32(entry, 0) as usize..)?;
+                let name = &name[..name.iter().position(|&byte| byte == 0)?];
+                if name.is_empty() {
+                    return None;
+                }
+                Some(Symbol {
+                    name,
+                    address: read_u32(entry, 4),
+                    size: read_u32(entry, 8),
+                    kind,
+                })
+            })
+    }
+}
+
+impl<'data> Image<'data> for Reader<'data> {
+    fn sections(&self) -> Vec<Section> {
+        self.sections.clone()
+    }
+
+    fn section_containing(&self, address: u32) -> Option<Section> {
+        let index = self
+            .sections_by_address
+            .partition_point(|section| section.load_address <= address);
+        let section = self.sections_by_address[..index].last().copied()?;
+        section.contains(address).then_some(section)
+    }
+
+    fn section_data(&self, section: Section) -> &'data [u8] {
+        &self.data[section.offset as usize..][..section.size as usize]
+    }
+
+    fn entry_point(&self) -> u32 {
+        self.entry_point
+    }
+}
+
+/// A table of fixed-size headers, such as the section header table.
+struct HeaderTable<'data> {
+    data: &'data [u8],
+    /// The size of each entry, which may exceed the size of the fields read from it.
+    entry_size: usize,
+    /// The size of the fields read from each entry.
+    header_size: usize,
+    count: usize,
+}
+
+impl<'data> HeaderTable<'data> {
+    fn header(&self, index: usize) -> &'data [u8] {
+        &self.data[index * self.entry_size..][..self.header_size]
+    }
+}
+
+/// Finds the table whose offset, entry size and entry count are at `offset_field`, `size_field`
+/// and `count_field` in the ELF header.
+fn header_table(
+    data: &[u8],
+    offset_field: usize,
+    size_field: usize,
+    count_field: usize,
+    header_size: usize,
+    out_of_file: ParseError,
+) -> Result<HeaderTable<'_>, ParseError> {
+    let offset = read_u32(data, offset_field) as usize;
+    let entry_size = read_u16(data, size_field) as usize;
+    let count = read_u16(data, count_field) as usize;
+    if count != 0 && entry_size < header_size {
+        return Err(ParseError::TruncatedHeader);
+    }
+    let table = offset
+        .checked_add(entry_size * count)
+        .and_then(|end| data.get(offset..end))
+        .ok_or(out_of_file)?;
+    Ok(HeaderTable {
+        data: table,
+        entry_size,
+        header_size,
+        count,
+    })
+}
+
+fn file_range(data: &[u8], offset: u32, size: u32) -> Option<&[u8]> {
+    let offset = offset as usize;
+    offset
+        .checked_add(size as usize)
+        .and_then(|end| data.get(offset..end))
+}
+
+/// Classifies a section or segment by whether it's executable and writable.
+fn section_kind(executable: bool, writable: bool) -> SectionKind {
+    if executable {
+        SectionKind::Text
+    } else if writable {
+        SectionKind::Data
+    } else {
+        SectionKind::ReadOnlyData
+    }
+}
+
+/// Returns the index of a section in `sections`, numbered by `indices`, that overlaps `section`
+/// in memory.
+fn overlapping(indices: &[usize], sections: &[Section], section: Section) -> Option<usize> {
+    indices
+        .iter()
+        .zip(sections)
+        .find(|(_, other)| {
+            section.load_address < other.end_address() && other.load_address < section.end_address()
+        })
+        .map(|(&index, _)| index)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes(data[offset..offset + 2].try_into().unwrap())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use dol::{Image, Section, SectionKind};
+
+    use super::*;
+
+    const SECTION_HEADERS: usize = 0xa0;
+
+    fn put_u16(data: &mut [u8], offset: usize, value: u16) {
+        data[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+    }
+
+    fn put_u32(data: &mut [u8], offset: usize, value: u32) {
+        data[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+    }
+
+    /// Builds an ELF with text at 0x80003100, data at 0x80004000, a BSS section and a symbol
+    /// table, then applies `edit` to the section headers, which start at `SECTION_HEADERS`.
+    fn elf(edit: impl FnOnce(&mut [u8])) -> Vec<u8> {
+        let mut data = vec![0; SECTION_HEADERS + 6 * SECTION_HEADER_SIZE];
+        data[..4].copy_from_slice(ELF_MAGIC);
+        data[CLASS_OFFSET] = ELF_CLASS_32;
+        data[DATA_OFFSET] = ELF_DATA_BIG_ENDIAN;
+        put_u16(&mut data, MACHINE_OFFSET, MACHINE_PPC);
+        put_u32(&mut data, ENTRY_POINT_OFFSET, 0x80003100);
+        put_u32(
+            &mut data,
+            SECTION_HEADER_TABLE_OFFSET,
+            SECTION_HEADERS as u32,
+        );
+        put_u16(
+            &mut data,
+            SECTION_HEADER_SIZE_OFFSET,
+            SECTION_HEADER_SIZE as u16,
+        );
+        put_u16(&mut data, SECTION_HEADER_COUNT_OFFSET, 6);
+
+        put_u32(&mut data, 0x40, 0x38600001);
+        put_u32(&mut data, 0x44, 0x4e800020);
+        data[0x48..0x50].copy_from_slice(b"mobj.c\0\0");
+        // Symbols: the null symbol, a function, a section symbol, an object and an undefined
+        // function.
+        for (index, &(name, address, size, info, section)) in [
+            (0, 0, 0, 0, 0),
+            (1, 0x80003100, 8, SYMBOL_TYPE_FUNC, 1),
+            (0, 0x80004000, 0, SYMBOL_TYPE_SECTION, 2),
+            (6, 0x80004000, 7, SYMBOL_TYPE_OBJECT, 2),
+            (11, 0, 0, SYMBOL_TYPE_FUNC, 0),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let entry = 0x50 + index * SYMBOL_SIZE;
+            put_u32(&mut data, entry, name);
+            put_u32(&mut data, entry + 4, address);
+            put_u32(&mut data, entry + 8, size);
+            data[entry + 12] = info;
+            put_u16(&mut data, entry + 14, section);
+        }
+        data[0x90..0x9f].copy_from_slice(b"\0main\0name\0OSR\0");
+
+        for (index, &(kind, flags, address, offset, size, link)) in [
+            (SECTION_TYPE_NULL, 0, 0, 0, 0, 0),
+            (
+                SECTION_TYPE_PROGBITS,
+                SECTION_FLAG_ALLOC | SECTION_FLAG_EXECINSTR,
+                0x80003100,
+                0x40,
+                8,
+                0,
+            ),
+            (
+                SECTION_TYPE_PROGBITS,
+                SECTION_FLAG_ALLOC | SECTION_FLAG_WRITE,
+                0x80004000,
+                0x48,
+                8,
+                0,
+            ),
+            (
+                SECTION_TYPE_NOBITS,
+                SECTION_FLAG_ALLOC | SECTION_FLAG_WRITE,
+                0x80004008,
+                0x50,
+                0x1000,
+                0,
+            ),
+            (SECTION_TYPE_SYMTAB, 0, 0, 0x50, 0x50, 5),
+            (SECTION_TYPE_STRTAB, 0, 0, 0x90, 0xf, 0),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let header = SECTION_HEADERS + index * SECTION_HEADER_SIZE;
+            put_u32(&mut data, header + SECTION_TYPE_OFFSET, kind);
+            put_u32(&mut data, header + SECTION_FLAGS_OFFSET, flags);
+            put_u32(&mut data, header + SECTION_ADDRESS_OFFSET, address);
+            put_u32(&mut data, header + SECTION_FILE_OFFSET_OFFSET, offset);
+            put_u32(&mut data, header + SECTION_SIZE_OFFSET, size);
+            put_u32(&mut data, header + SECTION_LINK_OFFSET, link);
+        }
+        edit(&mut data[SECTION_HEADERS..]);
+        data
+    }
+
+    /// Builds the ELF from [`elf`] without its section headers, but with program headers loading
+    /// the text and the data with the BSS after it, then applies `edit` to the program headers.
+    fn stripped_elf(edit: impl FnOnce(&mut [u8])) -> Vec<u8> {
+        let mut data = elf(|_| {});
+        put_u16(&mut data, SECTION_HEADER_COUNT_OFFSET, 0);
+        let program_headers = data.len();
+        put_u32(
+            &mut data,
+            PROGRAM_HEADER_TABLE_OFFSET,
+            program_headers as u32,
+        );
+        put_u16(
+            &mut data,
+            PROGRAM_HEADER_SIZE_OFFSET,
+            PROGRAM_HEADER_SIZE as u16,
+        );
+        put_u16(&mut data, PROGRAM_HEADER_COUNT_OFFSET, 3);
+        data.resize(program_headers + 3 * PROGRAM_HEADER_SIZE, 0);
+
+        // Segments: the text, a note and the data followed by the BSS.
+        for (index, &(kind, offset, address, file_size, memory_size, flags)) in [
+            (SEGMENT_TYPE_LOAD, 0x40, 0x80003100, 8, 8, 0x5),
+            (4, 0x48, 0, 8, 0, 0x4),
+            (SEGMENT_TYPE_LOAD, 0x48, 0x80004000, 8, 0x1008, 0x6),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let header = program_headers + index * PROGRAM_HEADER_SIZE;
+            put_u32(&mut data, header + SEGMENT_TYPE_OFFSET, kind);
+            put_u32(&mut data, header + SEGMENT_FILE_OFFSET_OFFSET, offset);
+            put_u32(&mut data, header + SEGMENT_ADDRESS_OFFSET, address);
+            put_u32(&mut data, header + SEGMENT_FILE_SIZE_OFFSET, file_size);
+            put_u32(&mut data, header + SEGMENT_MEMORY_SIZE_OFFSET, memory_size);
+            put_u32(&mut data, header + SEGMENT_FLAGS_OFFSET, flags);
+        }
+        edit(&mut data[program_headers..]);
+        data
+    }
+
+    #[test]
+    fn sections() {
+        let data = elf(|_| {});
+        let reader = Reader::parse(&data).unwrap();
+        assert_eq!(
+            reader.sections(),
+            [
+                Section {
+                    kind: SectionKind::Text,
+                    offset: 0x40,
+                    load_address: 0x80003100,
+                    size: 8,
+                },
+                Section {
+                    kind: SectionKind::Data,
+                    offset: 0x48,
+                    load_address: 0x80004000,
+                    size: 8,
+                },
+            ]
+        );
+        assert_eq!(reader.entry_point(), 0x80003100);
+        assert_eq!(reader.read_u32(0x80003104), Some(0x4e800020));
+        assert_eq!(
+            reader.read_c_string(0x80004000).unwrap().to_bytes(),
+            b"mobj.c"
+        );
+        assert_eq!(reader.section_containing(0x80003108), None);
+        assert_eq!(reader.read_u8(0x80004008), None);
+        assert_eq!(reader.bss(), Some(0x80004008..0x80005008));
+    }
+
+    #[test]
+    fn read_only_data() {
+        let flags = 2 * SECTION_HEADER_SIZE + SECTION_FLAGS_OFFSET;
+        let data = elf(|headers| put_u32(headers, flags, SECTION_FLAG_ALLOC));
+        let reader = Reader::parse(&data).unwrap();
+        assert_eq!(reader.sections()[1].kind, SectionKind::ReadOnlyData);
+
+        let flags = 2 * PROGRAM_HEADER_SIZE + SEGMENT_FLAGS_OFFSET;
+        let data = stripped_elf(|headers| put_u32(headers, flags, 0x4));
+        let reader = Reader::parse(&data).unwrap();
+        assert_eq!(reader.sections()[1].kind, SectionKind::ReadOnlyData);
+    }
+
+    #[test]
+    fn segments() {
+        let data = stripped_elf(|_| {});
+        let reader = Reader::parse(&data).unwrap();
+        assert_eq!(
+            reader.sections(),
+            Reader::parse(&elf(|_| {})).unwrap().sections()
+        );
+        assert_eq!(reader.bss(), Some(0x80004008..0x80005008));
+        assert_eq!(reader.read_u32(0x80003100), Some(0x38600001));
+        assert_eq!(reader.symbols().count(), 0);
+    }
+
+    #[test]
+    fn symbols() {
+        let data = elf(|_| {});
+        let reader = Reader::parse(&data).unwrap();
+        assert_eq!(
+            reader.symbols().collect::<Vec<_>>(),
+            [
+                Symbol {
+                    name: b"main",
+                    address: 0x80003100,
+                    size: 8,
+                    kind: SymbolKind::Function,
+                },
+                Symbol {
+                    name: b"name",
+                    address: 0x80004000,
+                    size: 7,
+                    kind: SymbolKind::Object,
+                },
+            ]
+        );
+
+        // Absolute symbols are values, not addresses.
+        let mut data = elf(|_| {});
+        put_u16(
+            &mut data,
+            0x50 + 3 * SYMBOL_SIZE + 14,
+            SYMBOL_SECTION_ABSOLUTE,
+        );
+        let reader = Reader::parse(&data).unwrap();
+        let names: Vec<_> = reader.symbols().map(|symbol| symbol.name).collect();
+        assert_eq!(names, [b"main"]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Reader::parse(b"\0asm"), Err(ParseError::NotElf));
+        assert_eq!(
+            Reader::parse(&elf(|_| {})[..0x20]),
+            Err(ParseError::TruncatedHeader)
+        );
+
+        let mut little_endian = elf(|_| {});
+        little_endian[DATA_OFFSET] = 1;
+        assert_eq!(Reader::parse(&little_endian), Err(ParseError::Unsupported));
+
+        let mut truncated = elf(|_| {});
+        truncated.pop();
+        assert_eq!(
+            Reader::parse(&truncated),
+            Err(ParseError::SectionHeadersOutOfFile)
+        );
+
+        let section_field = |index: usize, field: usize| index * SECTION_HEADER_SIZE + field;
+        for (edit, error) in [
+            (
+                (section_field(2, SECTION_SIZE_OFFSET), 0x1000),
+                ParseError::SectionOutOfFile { index: 2 },
+            ),
+            (
+                (section_field(1, SECTION_ADDRESS_OFFSET), 0xfffffffc),
+                ParseError::SectionOutOfAddressSpace { index: 1 },
+            ),
+            (
+                (section_field(2, SECTION_ADDRESS_OFFSET), 0x80003104),
+                ParseError::OverlappingSections {
+                    first: 1,
+                    second: 2,
+                },
+            ),
+            (
+                (section_field(4, SECTION_LINK_OFFSET), 2),
+                ParseError::BadStringTable { index: 4, link: 2 },
+            ),
+            (
+                (section_field(4, SECTION_LINK_OFFSET), 6),
+                ParseError::BadStringTable { index: 4, link: 6 },
+            ),
+        ] {
+            let (offset, value) = edit;
+            let data = elf(|headers| put_u32(headers, offset, value));
+            assert_eq!(Reader::parse(&data), Err(error));
+        }
+    }
+
+    #[test]
+    fn segment_parse_errors() {
+        let mut truncated = stripped_elf(|_| {});
+        truncated.pop();
+        assert_eq!(
+            Reader::parse(&truncated),
+            Err(ParseError::ProgramHeadersOutOfFile)
+        );
+
+        let mut empty = stripped_elf(|_| {});
+        put_u16(&mut empty, PROGRAM_HEADER_COUNT_OFFSET, 0);
+        assert_eq!(Reader::parse(&empty), Err(ParseError::NothingLoadable));
+
+        let segment_field = |index: usize, field: usize| index * PROGRAM_HEADER_SIZE + field;
+        for (edit, error) in [
+            (
+                (segment_field(2, SEGMENT_FILE_SIZE_OFFSET), 0x1000),
+                ParseError::SegmentOutOfFile { index: 2 },
+            ),
+            (
+                (segment_field(2, SEGMENT_ADDRESS_OFFSET), 0xfffff000),
+                ParseError::SegmentOutOfAddressSpace { index: 2 },
+            ),
+            (
+                (segment_field(2, SEGMENT_ADDRESS_OFFSET), 0x80003104),
+                ParseError::OverlappingSegments {
+                    first: 0,
+                    second: 2,
+                },
+            ),
+        ] {
+            let (offset, value) = edit;
+            let data = stripped_elf(|headers| put_u32(headers, offset, value));
+            assert_eq!(Reader::parse(&data), Err(error));
+        }
+    }
+}
@@ -0,0 +1,18 @@
+/// An entry from the symbol table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Symbol<'data> {
+    /// The raw name, which isn't necessarily UTF-8.
+    pub name: &'data [u8],
+    pub address: u32,
+    /// The size in bytes, or 0 if unknown.
+    pub size: u32,
+    pub kind: SymbolKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SymbolKind {
+    Function,
+    Object,
+    /// Any other defined symbol, such as a label.
+    Other,
+}
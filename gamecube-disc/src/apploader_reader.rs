@@ -0,0 +1,129 @@
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::HeaderError;
+
+/// The size in bytes of the apploader's header, which precedes its code.
+pub const APPLOADER_HEADER_SIZE: usize = 0x20;
+
+const BUILD_DATE_SIZE: usize = 10;
+const ENTRY_POINT_OFFSET: usize = 0x10;
+const SIZE_OFFSET: usize = 0x14;
+const TRAILER_SIZE_OFFSET: usize = 0x18;
+
+/// Reads the apploader, the program that the console's boot ROM runs to load the main executable.
+#[derive(Clone, Copy, Debug)]
+pub struct ApploaderReader<'data> {
+    /// The header, the code and the trailer.
+    data: &'data [u8],
+}
+
+impl<'data> ApploaderReader<'data> {
+    /// Checks that `data` holds the whole apploader, and that its build date is well formed.
+    pub fn parse(data: &'data [u8]) -> Result<ApploaderReader<'data>, HeaderError> {
+        let len = ApploaderReader::total_len(data)?;
+        let apploader = ApploaderReader {
+            data: data.get(..len).ok_or(HeaderError::Truncated {
+                part: "apploader",
+                len: data.len(),
+                expected: len,
+            })?,
+        };
+
+        let well_formed =
+            apploader.data[..BUILD_DATE_SIZE]
+                .iter()
+                .enumerate()
+                .all(|(i, &c)| match i {
+                    4 | 7 => c == b'/',
+                    _ => c.is_ascii_digit(),
+                });
+        if !well_formed {
+            return Err(HeaderError::BadBuildDate);
+        }
+        Ok(apploader)
+    }
+
+    /// Returns the size in bytes of the apploader starting with `header`, counting its header,
+    /// code and trailer.
+    pub fn total_len(header: &[u8]) -> Result<usize, HeaderError> {
+        let header = ApploaderReader {
+            data: header
+                .get(..APPLOADER_HEADER_SIZE)
+                .ok_or(HeaderError::Truncated {
+                    part: "apploader",
+                    len: header.len(),
+                    expected: APPLOADER_HEADER_SIZE,
+                })?,
+        };
+        Ok(APPLOADER_HEADER_SIZE + header.size() as usize + header.trailer_size() as usize)
+    }
+
+    /// When the apploader was built, like `2001/11/14`.
+    pub fn build_date(&self) -> &'data str {
+        std::str::from_utf8(&self.data[..BUILD_DATE_SIZE]).unwrap()
+    }
+
+    pub fn entry_point(&self) -> u32 {
+        self.read_u32(ENTRY_POINT_OFFSET)
+    }
+
+    /// The size in bytes of the code, not counting the header or the trailer.
+    pub fn size(&self) -> u32 {
+        self.read_u32(SIZE_OFFSET)
+    }
+
+    pub fn trailer_size(&self) -> u32 {
+        self.read_u32(TRAILER_SIZE_OFFSET)
+    }
+
+    pub fn code(&self) -> &'data [u8] {
+        &self.data[APPLOADER_HEADER_SIZE..][..self.size() as usize]
+    }
+
+    pub fn trailer(&self) -> &'data [u8] {
+        &self.data[APPLOADER_HEADER_SIZE + self.size() as usize..]
+    }
+
+    fn read_u32(&self, offset: usize) -> u32 {
+        (&self.data[offset..]).read_u32::<BigEndian>().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApploaderReader;
+    use crate::HeaderError;
+
+    fn apploader_data(date: &[u8]) -> Vec<u8> {
+        let mut data = vec![0; 0x30];
+        data[..10].copy_from_slice(date);
+        data[0x10..0x14].copy_from_slice(&0x81200258u32.to_be_bytes());
+        data[0x14..0x18].copy_from_slice(&8u32.to_be_bytes());
+        data[0x18..0x1c].copy_from_slice(&4u32.to_be_bytes());
+        data[0x20..0x2c].copy_from_slice(b"codecodetail");
+        data
+    }
+
+    #[test]
+    fn test() {
+        let data = apploader_data(b"2001/11/14");
+        let apploader = ApploaderReader::parse(&data).unwrap();
+        assert_eq!(apploader.build_date(), "2001/11/14");
+        assert_eq!(apploader.entry_point(), 0x81200258);
+        assert_eq!(apploader.code(), b"codecode");
+        assert_eq!(apploader.trailer(), b"tail");
+
+        assert_eq!(
+            ApploaderReader::parse(&data[..0x2b]).map(|_| ()),
+            Err(HeaderError::Truncated {
+                part: "apploader",
+                len: 0x2b,
+                expected: 0x2c,
+            })
+        );
+        assert_eq!(
+            ApploaderReader::parse(&apploader_data(b"2001-11-14")).map(|_| ()),
+            Err(HeaderError::BadBuildDate)
+        );
+    }
+}
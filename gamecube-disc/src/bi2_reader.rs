@@ -0,0 +1,106 @@
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::HeaderError;
+
+/// The size in bytes of the disc header information (`bi2.bin`), which follows the disc header.
+pub const BI2_SIZE: usize = 0x2000;
+
+const DEBUG_MONITOR_SIZE_OFFSET: usize = 0x0;
+const SIMULATED_MEMORY_SIZE_OFFSET: usize = 0x4;
+const ARGUMENT_OFFSET_OFFSET: usize = 0x8;
+const DEBUG_FLAG_OFFSET: usize = 0xc;
+const TRACK_LOCATION_OFFSET: usize = 0x10;
+const TRACK_SIZE_OFFSET: usize = 0x14;
+const REGION_OFFSET: usize = 0x18;
+
+/// The console regions a disc can be made for.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Region {
+    Japan,
+    NorthAmerica,
+    Europe,
+    Korea,
+}
+
+/// Reads the disc header information, `bi2.bin`, which configures the debug monitor and says which
+/// region the disc is for.
+#[derive(Clone, Copy, Debug)]
+pub struct Bi2Reader<'data> {
+    data: &'data [u8],
+}
+
+impl<'data> Bi2Reader<'data> {
+    /// Checks that `data` holds the whole of `bi2.bin` with a known region.
+    pub fn parse(data: &'data [u8]) -> Result<Bi2Reader<'data>, HeaderError> {
+        let data = data.get(..BI2_SIZE).ok_or(HeaderError::Truncated {
+            part: "bi2.bin",
+            len: data.len(),
+            expected: BI2_SIZE,
+        })?;
+        let bi2 = Bi2Reader { data };
+        bi2.region()?;
+        Ok(bi2)
+    }
+
+    pub fn debug_monitor_size(&self) -> u32 {
+        self.read_u32(DEBUG_MONITOR_SIZE_OFFSET)
+    }
+
+    /// The amount of main memory that a development kit pretends to have, which is usually the
+    /// retail console's 24 MiB.
+    pub fn simulated_memory_size(&self) -> u32 {
+        self.read_u32(SIMULATED_MEMORY_SIZE_OFFSET)
+    }
+
+    pub fn argument_offset(&self) -> u32 {
+        self.read_u32(ARGUMENT_OFFSET_OFFSET)
+    }
+
+    pub fn debug_flag(&self) -> u32 {
+        self.read_u32(DEBUG_FLAG_OFFSET)
+    }
+
+    pub fn track_location(&self) -> u32 {
+        self.read_u32(TRACK_LOCATION_OFFSET)
+    }
+
+    pub fn track_size(&self) -> u32 {
+        self.read_u32(TRACK_SIZE_OFFSET)
+    }
+
+    pub fn region(&self) -> Result<Region, HeaderError> {
+        match self.read_u32(REGION_OFFSET) {
+            0 => Ok(Region::Japan),
+            1 => Ok(Region::NorthAmerica),
+            2 => Ok(Region::Europe),
+            4 => Ok(Region::Korea),
+            region => Err(HeaderError::UnknownRegion { region }),
+        }
+    }
+
+    fn read_u32(&self, offset: usize) -> u32 {
+        (&self.data[offset..]).read_u32::<BigEndian>().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bi2Reader, Region, BI2_SIZE};
+    use crate::HeaderError;
+
+    #[test]
+    fn test() {
+        let mut data = vec![0; BI2_SIZE];
+        data[0x4..0x8].copy_from_slice(&0x01800000u32.to_be_bytes());
+        data[0x18..0x1c].copy_from_slice(&1u32.to_be_bytes());
+        let bi2 = Bi2Reader::parse(&data).unwrap();
+        assert_eq!(bi2.simulated_memory_size(), 0x01800000);
+        assert_eq!(bi2.region(), Ok(Region::NorthAmerica));
+
+        data[0x1b] = 3;
+        assert_eq!(
+            Bi2Reader::parse(&data).map(|_| ()),
+            Err(HeaderError::UnknownRegion { region: 3 })
+        );
+    }
+}
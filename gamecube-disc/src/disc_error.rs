@@ -0,0 +1,24 @@
+use std::io;
+
+use thiserror::Error;
+
+use crate::HeaderError;
+
+/// Why part of a disc image can't be read.
+#[derive(Debug, Error)]
+pub enum DiscError {
+    #[error(transparent)]
+    Header(#[from] HeaderError),
+
+    #[error("{part} at 0x{offset:x} extends past the end of the image")]
+    OutOfBounds { part: &'static str, offset: u64 },
+
+    #[error("main executable: {0}")]
+    MainExecutable(#[from] dol::ParseError),
+
+    #[error("{path} is not a file on the disc")]
+    FileNotFound { path: String },
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
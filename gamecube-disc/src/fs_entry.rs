@@ -0,0 +1,29 @@
+/// An entry in the file system table: a file, or a directory holding the entries after it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FsEntry<'data> {
+    /// The entry's position in the table. The root directory is entry 0.
+    pub index: usize,
+    /// The raw name, which isn't necessarily UTF-8. The root directory's name is empty.
+    pub name: &'data [u8],
+    pub kind: FsEntryKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FsEntryKind {
+    File {
+        /// The file's position on the disc.
+        offset: u32,
+        size: u32,
+    },
+    Directory {
+        parent: usize,
+        /// One past the index of the directory's last descendant.
+        end: usize,
+    },
+}
+
+impl FsEntry<'_> {
+    pub fn is_directory(&self) -> bool {
+        matches!(self.kind, FsEntryKind::Directory { .. })
+    }
+}
@@ -1,9 +1,15 @@
 use byteorder::{BigEndian, ReadBytesExt};
 
+use crate::{FsEntry, FsEntryKind};
+
 const ROOT_ENTRY_COUNT_OFFSET: usize = 0x8;
 
 const STRING_TABLE_ENTRY_SIZE: usize = 0xc;
 
+const DIRECTORY_FLAG: u8 = 1;
+
+/// Reads the file system table, which lists every directory and file in depth-first order,
+/// followed by a table of their names.
 #[derive(Clone, Copy, Debug)]
 pub struct FsTableReader<'data> {
     data: &'data [u8],
@@ -14,13 +20,248 @@ impl<'data> FsTableReader<'data> {
         FsTableReader { data }
     }
 
+    /// The number of entries in the table, including the root directory.
     pub fn root_entry_count(&self) -> u32 {
-        (&self.data[ROOT_ENTRY_COUNT_OFFSET..])
-            .read_u32::<BigEndian>()
-            .unwrap()
+        self.data
+            .get(ROOT_ENTRY_COUNT_OFFSET..)
+            .and_then(|mut data| data.read_u32::<BigEndian>().ok())
+            .unwrap_or(0)
     }
 
     pub fn string_table(&self) -> &'data [u8] {
-        &self.data[(self.root_entry_count() as usize * STRING_TABLE_ENTRY_SIZE)..]
+        self.data
+            .get((self.root_entry_count() as usize * STRING_TABLE_ENTRY_SIZE)..)
+            .unwrap_or_default()
+    }
+
+    /// Returns the entry at `index`, or `None` if it's out of range.
+    pub fn entry(&self, index: usize) -> Option<FsEntry<'data>> {
+        if index >= self.root_entry_count() as usize {
+            return None;
+        }
+        let mut data = self
+            .data
+            .get(index * STRING_TABLE_ENTRY_SIZE..)?
+            .get(..STRING_TABLE_ENTRY_SIZE)?;
+        let flags_and_name_offset = data.read_u32::<BigEndian>().unwrap();
+        let first = data.read_u32::<BigEndian>().unwrap();
+        let second = data.read_u32::<BigEndian>().unwrap();
+
+        let name_offset = (flags_and_name_offset & 0xffffff) as usize;
+        let name = match self.string_table().get(name_offset..) {
+            Some(name) if index != 0 => &name[..name.iter().position(|&c| c == 0)?],
+            _ => &[],
+        };
+        let kind = if (flags_and_name_offset >> 24) as u8 == DIRECTORY_FLAG {
+            FsEntryKind::Directory {
+                parent: first as usize,
+                end: second as usize,
+            }
+        } else {
+            FsEntryKind::File {
+                offset: first,
+                size: second,
+            }
+        };
+        Some(FsEntry { index, name, kind })
+    }
+
+    pub fn root(&self) -> Option<FsEntry<'data>> {
+        self.entry(0)
+    }
+
+    /// The entries directly inside `directory`, which are none if it's a file.
+    pub fn children(&self, directory: FsEntry<'data>) -> impl Iterator<Item = FsEntry<'data>> {
+        let fs_table = *self;
+        let end = match directory.kind {
+            FsEntryKind::Directory { end, .. } => end,
+            FsEntryKind::File { .. } => 0,
+        };
+        let mut index = directory.index + 1;
+        std::iter::from_fn(move || {
+            if index >= end {
+                return None;
+            }
+            let entry = fs_table.entry(index)?;
+            index = match entry.kind {
+                FsEntryKind::Directory { end, .. } => end.max(index + 1),
+                FsEntryKind::File { .. } => index + 1,
+            };
+            Some(entry)
+        })
+    }
+
+    /// Looks up a `/`-separated path from the root directory. Names are matched ignoring ASCII
+    /// case, like the game's own lookups.
+    pub fn find(&self, path: &str) -> Option<FsEntry<'data>> {
+        path.split('/')
+            .filter(|component| !component.is_empty())
+            .try_fold(self.root()?, |directory, component| {
+                self.children(directory)
+                    .find(|entry| entry.name.eq_ignore_ascii_case(component.as_bytes()))
+            })
+    }
+
+    /// Iterates over every entry but the root, depth-first, with its path.
+    pub fn iter(&self) -> FsTableIter<'data> {
+        FsTableIter {
+            fs_table: *self,
+            index: 1,
+            directories: Vec::new(),
+            path: String::new(),
+        }
+    }
+
+    /// Calls `visit` with every entry but the root and its path, depth-first. A directory's
+    /// entries are skipped if `visit` returns `false` for it.
+    pub fn walk(&self, mut visit: impl FnMut(&str, FsEntry<'data>) -> bool) {
+        let mut iter = self.iter();
+        while let Some((path, entry)) = iter.next() {
+            if let FsEntryKind::Directory { end, .. } = entry.kind {
+                if !visit(&path, entry) {
+                    iter.index = iter.index.max(end);
+                }
+            } else {
+                visit(&path, entry);
+            }
+        }
+    }
+}
+
+/// Iterates over the file system table in order, yielding each entry with its `/`-separated path
+/// from the root.
+#[derive(Clone, Debug)]
+pub struct FsTableIter<'data> {
+    fs_table: FsTableReader<'data>,
+    index: usize,
+    /// The end of each directory being iterated over, with the length of its parent's path.
+    directories: Vec<(usize, usize)>,
+    /// The path of the innermost directory being iterated over.
+    path: String,
+}
+
+impl<'data> Iterator for FsTableIter<'data> {
+    type Item = (String, FsEntry<'data>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&(end, parent_path_len)) = self.directories.last() {
+            if self.index < end {
+                break;
+            }
+            self.directories.pop();
+            self.path.truncate(parent_path_len);
+        }
+
+        let entry = self.fs_table.entry(self.index)?;
+        self.index += 1;
+        let path = format!("{}/{}", self.path, String::from_utf8_lossy(entry.name));
+        if let FsEntryKind::Directory { end, .. } = entry.kind {
+            self.directories.push((end, self.path.len()));
+            self.path.clone_from(&path);
+        }
+        Some((path, entry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FsTableReader;
+    use crate::{FsEntry, FsEntryKind};
+
+    /// Builds a table of `(is_directory, name, first, second)` entries, after the root.
+    fn fs_table(entries: &[(bool, &str, u32, u32)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut strings = vec![0];
+        let root = (true, "", 0, entries.len() as u32 + 1);
+        for &(is_directory, name, first, second) in std::iter::once(&root).chain(entries) {
+            let name_offset = if name.is_empty() {
+                0
+            } else {
+                strings.len() as u32
+            };
+            strings.extend_from_slice(name.as_bytes());
+            if !name.is_empty() {
+                strings.push(0);
+            }
+            data.extend_from_slice(&(u32::from(is_directory) << 24 | name_offset).to_be_bytes());
+            data.extend_from_slice(&first.to_be_bytes());
+            data.extend_from_slice(&second.to_be_bytes());
+        }
+        data.extend_from_slice(&strings);
+        data
+    }
+
+    fn sample() -> Vec<u8> {
+        fs_table(&[
+            (true, "audio", 0, 4),
+            (true, "us", 1, 4),
+            (false, "1padv.ssm", 0x1000, 0x10),
+            (false, "MnSlChr.dat", 0x2000, 8),
+            (false, "module.rel", 0x3000, 4),
+        ])
+    }
+
+    #[test]
+    fn entries() {
+        let data = sample();
+        let fs_table = FsTableReader::new(&data);
+        assert_eq!(fs_table.root_entry_count(), 6);
+        assert_eq!(
+            fs_table.entry(3),
+            Some(FsEntry {
+                index: 3,
+                name: b"1padv.ssm",
+                kind: FsEntryKind::File {
+                    offset: 0x1000,
+                    size: 0x10,
+                },
+            })
+        );
+        assert_eq!(fs_table.entry(6), None);
+        let root = fs_table.root().unwrap();
+        assert_eq!(root.name, b"");
+        let children: Vec<_> = fs_table.children(root).map(|entry| entry.index).collect();
+        assert_eq!(children, [1, 4, 5]);
+    }
+
+    #[test]
+    fn find() {
+        let data = sample();
+        let fs_table = FsTableReader::new(&data);
+        let find = |path| fs_table.find(path).map(|entry| entry.index);
+        assert_eq!(find("audio/us/1padv.ssm"), Some(3));
+        assert_eq!(find("/AUDIO/US/1PADV.SSM"), Some(3));
+        assert_eq!(find("audio/us"), Some(2));
+        assert_eq!(find("mnslchr.dat"), Some(4));
+        assert_eq!(find("/"), Some(0));
+        assert_eq!(find("us/1padv.ssm"), None);
+        assert_eq!(find("module.rel/x"), None);
+    }
+
+    #[test]
+    fn iter_and_walk() {
+        let data = sample();
+        let fs_table = FsTableReader::new(&data);
+        let paths: Vec<_> = fs_table.iter().map(|(path, _)| path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/audio",
+                "/audio/us",
+                "/audio/us/1padv.ssm",
+                "/MnSlChr.dat",
+                "/module.rel"
+            ]
+        );
+
+        let mut visited = Vec::new();
+        fs_table.walk(|path, entry| {
+            visited.push(path.to_string());
+            !entry.is_directory() || entry.name != b"us"
+        });
+        assert_eq!(
+            visited,
+            ["/audio", "/audio/us", "/MnSlChr.dat", "/module.rel"]
+        );
     }
 }
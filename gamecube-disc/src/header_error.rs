@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// Why a part of the disc header can't be read.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum HeaderError {
+    #[error("truncated {part}: {len} bytes, expected at least {expected}")]
+    Truncated {
+        part: &'static str,
+        len: usize,
+        expected: usize,
+    },
+
+    #[error(
+        "bad magic word 0x{magic_word:08x}, expected 0x{:08x}",
+        crate::MAGIC_WORD
+    )]
+    BadMagicWord { magic_word: u32 },
+
+    #[error("unknown region {region}")]
+    UnknownRegion { region: u32 },
+
+    #[error("apploader build date is not in YYYY/MM/DD form")]
+    BadBuildDate,
+}
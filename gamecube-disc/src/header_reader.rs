@@ -1,27 +1,56 @@
-/// The size in bytes of a GameCube disc header.
-pub const HEADER_SIZE: usize = 8;
+use byteorder::{BigEndian, ReadBytesExt};
 
+use crate::HeaderError;
+
+/// The size in bytes of a GameCube disc header (`boot.bin`).
+pub const HEADER_SIZE: usize = 0x440;
+
+/// Identifies a GameCube disc. Wii discs have a different magic word at another offset.
+pub const MAGIC_WORD: u32 = 0xc2339f3d;
+
+const AUDIO_STREAMING_OFFSET: usize = 0x8;
+const STREAM_BUFFER_SIZE_OFFSET: usize = 0x9;
+const MAGIC_WORD_OFFSET: usize = 0x1c;
+const GAME_NAME_OFFSET: usize = 0x20;
+const GAME_NAME_SIZE: usize = 0x3e0;
+const DEBUG_MONITOR_OFFSET_OFFSET: usize = 0x400;
+const DEBUG_MONITOR_LOAD_ADDRESS_OFFSET: usize = 0x404;
+pub(crate) const MAIN_EXECUTABLE_OFFSET: usize = 0x420;
+pub(crate) const FILESYSTEM_TABLE_OFFSET_OFFSET: usize = 0x424;
+pub(crate) const FILESYSTEM_TABLE_LENGTH_OFFSET: usize = 0x428;
+const MAX_FILESYSTEM_TABLE_LENGTH_OFFSET: usize = 0x42c;
+const USER_POSITION_OFFSET: usize = 0x430;
+const USER_LENGTH_OFFSET: usize = 0x434;
+
+/// Reads the disc header, `boot.bin`, which identifies the game and locates the main executable
+/// and the file system table.
 #[derive(Clone, Copy, Debug)]
 pub struct HeaderReader<'data> {
     data: &'data [u8],
 }
 
 impl<'data> HeaderReader<'data> {
-    /// # Panics
-    ///
-    /// Panics if `data.len()` is less than [`HEADER_SIZE`].
-    pub fn new(data: &'data [u8]) -> HeaderReader<'data> {
-        HeaderReader {
-            data: &data[..HEADER_SIZE],
+    /// Checks that `data` holds a whole header with the GameCube magic word.
+    pub fn parse(data: &'data [u8]) -> Result<HeaderReader<'data>, HeaderError> {
+        let data = data.get(..HEADER_SIZE).ok_or(HeaderError::Truncated {
+            part: "disc header",
+            len: data.len(),
+            expected: HEADER_SIZE,
+        })?;
+        let header = HeaderReader { data };
+        if header.magic_word() != MAGIC_WORD {
+            return Err(HeaderError::BadMagicWord {
+                magic_word: header.magic_word(),
+            });
         }
+        Ok(header)
     }
 
     pub fn game_code(&self) -> String {
         self.data[0..4]
             .iter()
             .copied()
-            .map(|c| (c as char).escape_default())
-            .flatten()
+            .flat_map(|c| (c as char).escape_default())
             .collect()
     }
 
@@ -29,8 +58,7 @@ impl<'data> HeaderReader<'data> {
         self.data[4..6]
             .iter()
             .copied()
-            .map(|c| (c as char).escape_default())
-            .flatten()
+            .flat_map(|c| (c as char).escape_default())
             .collect()
     }
 
@@ -41,19 +69,102 @@ impl<'data> HeaderReader<'data> {
     pub fn version(&self) -> u8 {
         self.data[7]
     }
+
+    pub fn audio_streaming(&self) -> bool {
+        self.data[AUDIO_STREAMING_OFFSET] != 0
+    }
+
+    pub fn stream_buffer_size(&self) -> u8 {
+        self.data[STREAM_BUFFER_SIZE_OFFSET]
+    }
+
+    pub fn magic_word(&self) -> u32 {
+        self.read_u32(MAGIC_WORD_OFFSET)
+    }
+
+    /// The game's title. Japanese titles are Shift JIS, which is shown with replacement
+    /// characters.
+    pub fn game_name(&self) -> String {
+        let name = &self.data[GAME_NAME_OFFSET..][..GAME_NAME_SIZE];
+        let len = name.iter().position(|&c| c == 0).unwrap_or(name.len());
+        String::from_utf8_lossy(&name[..len]).into_owned()
+    }
+
+    pub fn debug_monitor_offset(&self) -> u32 {
+        self.read_u32(DEBUG_MONITOR_OFFSET_OFFSET)
+    }
+
+    pub fn debug_monitor_load_address(&self) -> u32 {
+        self.read_u32(DEBUG_MONITOR_LOAD_ADDRESS_OFFSET)
+    }
+
+    pub fn main_executable_offset(&self) -> u32 {
+        self.read_u32(MAIN_EXECUTABLE_OFFSET)
+    }
+
+    pub fn fs_table_offset(&self) -> u32 {
+        self.read_u32(FILESYSTEM_TABLE_OFFSET_OFFSET)
+    }
+
+    pub fn fs_table_size(&self) -> u32 {
+        self.read_u32(FILESYSTEM_TABLE_LENGTH_OFFSET)
+    }
+
+    /// The largest file system table among the discs of a multi-disc game, which is how much
+    /// memory the game reserves for it.
+    pub fn max_fs_table_size(&self) -> u32 {
+        self.read_u32(MAX_FILESYSTEM_TABLE_LENGTH_OFFSET)
+    }
+
+    pub fn user_position(&self) -> u32 {
+        self.read_u32(USER_POSITION_OFFSET)
+    }
+
+    pub fn user_length(&self) -> u32 {
+        self.read_u32(USER_LENGTH_OFFSET)
+    }
+
+    fn read_u32(&self, offset: usize) -> u32 {
+        (&self.data[offset..]).read_u32::<BigEndian>().unwrap()
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use super::HeaderReader;
+    use super::{HeaderReader, HEADER_SIZE, MAGIC_WORD};
+    use crate::HeaderError;
 
     #[test]
     fn test() {
-        const DATA: &'static [u8] = &[0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x01, 0x02];
-        let header = HeaderReader::new(DATA);
+        let mut data = vec![0; HEADER_SIZE];
+        data[..8].copy_from_slice(&[0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x01, 0x02]);
+        data[0x1c..0x20].copy_from_slice(&MAGIC_WORD.to_be_bytes());
+        data[0x20..0x2f].copy_from_slice(b"Super Smash Bro");
+        data[0x420..0x424].copy_from_slice(&0x1e800u32.to_be_bytes());
+        data[0x428..0x42c].copy_from_slice(&0x7529u32.to_be_bytes());
+        let header = HeaderReader::parse(&data).unwrap();
         assert_eq!(header.game_code(), "ABCD");
         assert_eq!(header.maker_code(), "EF");
         assert_eq!(header.disc_id(), 1);
         assert_eq!(header.version(), 2);
+        assert_eq!(header.game_name(), "Super Smash Bro");
+        assert_eq!(header.main_executable_offset(), 0x1e800);
+        assert_eq!(header.fs_table_size(), 0x7529);
+
+        assert_eq!(
+            HeaderReader::parse(&data[..8]).map(|_| ()),
+            Err(HeaderError::Truncated {
+                part: "disc header",
+                len: 8,
+                expected: HEADER_SIZE,
+            })
+        );
+        data[0x1c] = 0x5d;
+        assert_eq!(
+            HeaderReader::parse(&data).map(|_| ()),
+            Err(HeaderError::BadMagicWord {
+                magic_word: 0x5d339f3d
+            })
+        );
     }
 }
@@ -1,10 +1,22 @@
+mod apploader_reader;
+mod bi2_reader;
+mod disc_error;
+mod fs_entry;
 mod fs_table_reader;
+mod header_error;
 mod header_reader;
 mod reader;
+mod stream_reader;
 
-pub use crate::fs_table_reader::FsTableReader;
-pub use crate::header_reader::{HeaderReader, HEADER_SIZE};
+pub use crate::apploader_reader::{ApploaderReader, APPLOADER_HEADER_SIZE};
+pub use crate::bi2_reader::{Bi2Reader, Region, BI2_SIZE};
+pub use crate::disc_error::DiscError;
+pub use crate::fs_entry::{FsEntry, FsEntryKind};
+pub use crate::fs_table_reader::{FsTableIter, FsTableReader};
+pub use crate::header_error::HeaderError;
+pub use crate::header_reader::{HeaderReader, HEADER_SIZE, MAGIC_WORD};
 pub use crate::reader::Reader;
+pub use crate::stream_reader::StreamReader;
 
-/// The size in bytes of a GameCube disc image.
+/// The size in bytes of a full GameCube disc image. Trimmed images are shorter.
 pub const SIZE: usize = 1459978240;
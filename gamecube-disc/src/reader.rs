@@ -1,52 +1,204 @@
 use std::path::Path;
 
-use byteorder::{BigEndian, ReadBytesExt};
+use crate::{
+    ApploaderReader, Bi2Reader, DiscError, FsEntry, FsEntryKind, FsTableReader, HeaderError,
+    HeaderReader, HEADER_SIZE,
+};
 
-use crate::header_reader::HeaderReader;
-use crate::{FsTableReader, SIZE};
-
-const MAIN_EXECUTABLE_OFFSET: usize = 0x420;
-const FILESYSTEM_TABLE_OFFSET_OFFSET: usize = 0x424;
-const FILESYSTEM_TABLE_LENGTH_OFFSET: usize = 0x428;
+pub(crate) const BI2_OFFSET: usize = HEADER_SIZE;
+pub(crate) const APPLOADER_OFFSET: usize = 0x2440;
 
+/// Reads a disc image held in memory, such as a memory-mapped file.
+///
+/// Images may be any length, so trimmed dumps and homebrew work too. Parts of the disc past the
+/// end of the image are reported as errors.
 #[derive(Clone, Copy, Debug)]
 pub struct Reader<'data> {
     data: &'data [u8],
 }
 
 impl<'data> Reader<'data> {
-    /// # Panics
-    ///
-    /// Panics if `data.len()` is less than [`SIZE`].
     pub fn new(data: &'data [u8]) -> Reader<'data> {
-        Reader {
-            data: &data[..SIZE],
+        Reader { data }
+    }
+
+    pub fn header(&self) -> Result<HeaderReader<'data>, HeaderError> {
+        HeaderReader::parse(self.data)
+    }
+
+    pub fn bi2(&self) -> Result<Bi2Reader<'data>, HeaderError> {
+        Bi2Reader::parse(self.data.get(BI2_OFFSET..).unwrap_or_default())
+    }
+
+    pub fn apploader(&self) -> Result<ApploaderReader<'data>, HeaderError> {
+        ApploaderReader::parse(self.data.get(APPLOADER_OFFSET..).unwrap_or_default())
+    }
+
+    pub fn main_executable(&self) -> Result<dol::Reader<'data>, DiscError> {
+        let offset = self.header()?.main_executable_offset();
+        let data = self.bytes_from("main executable", offset)?;
+        Ok(dol::Reader::parse(data)?)
+    }
+
+    pub fn fs_table(&self) -> Result<FsTableReader<'data>, DiscError> {
+        let header = self.header()?;
+        let data = self.bytes(
+            "file system table",
+            header.fs_table_offset(),
+            header.fs_table_size(),
+        )?;
+        Ok(FsTableReader::new(data))
+    }
+
+    /// The contents of `entry`, or `None` if it's a directory or lies outside the image.
+    pub fn file_data(&self, entry: FsEntry) -> Option<&'data [u8]> {
+        match entry.kind {
+            FsEntryKind::File { offset, size } => self.bytes("file", offset, size).ok(),
+            FsEntryKind::Directory { .. } => None,
         }
     }
 
-    pub fn header(&self) -> HeaderReader<'data> {
-        HeaderReader::new(self.data)
+    /// Returns the contents of the file at `path`, like `audio/us/1padv.ssm`.
+    pub fn find_file(&self, path: &Path) -> Option<&'data [u8]> {
+        let entry = self.fs_table().ok()?.find(path.to_str()?)?;
+        self.file_data(entry)
+    }
+
+    /// Returns everything from `offset` to the end of the image.
+    fn bytes_from(&self, part: &'static str, offset: u32) -> Result<&'data [u8], DiscError> {
+        self.data
+            .get(offset as usize..)
+            .ok_or_else(|| out_of_bounds(part, offset))
+    }
+
+    /// Returns the `len` bytes at `offset`.
+    fn bytes(&self, part: &'static str, offset: u32, len: u32) -> Result<&'data [u8], DiscError> {
+        self.bytes_from(part, offset)?
+            .get(..len as usize)
+            .ok_or_else(|| out_of_bounds(part, offset))
+    }
+}
+
+fn out_of_bounds(part: &'static str, offset: u32) -> DiscError {
+    DiscError::OutOfBounds {
+        part,
+        offset: u64::from(offset),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::path::Path;
+
+    use super::Reader;
+    use crate::{DiscError, FsEntryKind, HeaderError, MAGIC_WORD};
+
+    pub const DOL_OFFSET: usize = 0x3000;
+    pub const FS_TABLE_OFFSET: usize = 0x2800;
+
+    fn put(data: &mut [u8], offset: usize, value: u32) {
+        data[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
     }
 
-    pub fn main_executable(&self) -> dol::Reader<'data> {
-        let offset = (&self.data[MAIN_EXECUTABLE_OFFSET..])
-            .read_u32::<BigEndian>()
-            .unwrap() as usize;
-        dol::Reader::new(&self.data[offset..])
+    /// Builds a small disc image holding a DOL and `audio/a.ssm`.
+    pub fn disc() -> Vec<u8> {
+        let mut data = vec![0; 0x4004];
+        data[..6].copy_from_slice(b"GALE01");
+        put(&mut data, 0x1c, MAGIC_WORD);
+        put(&mut data, 0x420, DOL_OFFSET as u32);
+        put(&mut data, 0x424, FS_TABLE_OFFSET as u32);
+        put(&mut data, 0x428, 0x31);
+        // bi2.bin, for North America.
+        put(&mut data, 0x440 + 0x18, 1);
+        // The apploader, with 0x20 bytes of code.
+        data[0x2440..0x244a].copy_from_slice(b"2001/11/14");
+        put(&mut data, 0x2454, 0x20);
+
+        // The file system table: the root, `audio` and `audio/a.ssm`.
+        for (index, &(flags_and_name, first, second)) in
+            [(0x0100_0000, 0, 3), (0x0100_0001, 0, 3), (7, 0x4000, 4)]
+                .iter()
+                .enumerate()
+        {
+            let entry = FS_TABLE_OFFSET + 0xc * index;
+            put(&mut data, entry, flags_and_name);
+            put(&mut data, entry + 4, first);
+            put(&mut data, entry + 8, second);
+        }
+        data[FS_TABLE_OFFSET + 0x24..][..13].copy_from_slice(b"\0audio\0a.ssm\0");
+
+        let dol = dol::Builder::new()
+            .text_section(0x80003100, vec![0x4e, 0x80, 0x00, 0x20])
+            .entry_point(0x80003100)
+            .build()
+            .unwrap();
+        data[DOL_OFFSET..][..dol.len()].copy_from_slice(&dol);
+        data[0x4000..].copy_from_slice(b"ssm!");
+        data
     }
 
-    pub fn fs_table(&self) -> FsTableReader<'data> {
-        let offset = (&self.data[FILESYSTEM_TABLE_OFFSET_OFFSET..])
-            .read_u32::<BigEndian>()
-            .unwrap() as usize;
-        let len = (&self.data[FILESYSTEM_TABLE_LENGTH_OFFSET..])
-            .read_u32::<BigEndian>()
-            .unwrap() as usize;
-        FsTableReader::new(&self.data[offset..(offset + len)])
+    #[test]
+    fn test() {
+        let data = disc();
+        let disc = Reader::new(&data);
+        assert_eq!(disc.header().unwrap().game_code(), "GALE");
+        assert!(disc.bi2().is_ok());
+        assert_eq!(disc.apploader().unwrap().build_date(), "2001/11/14");
+        assert_eq!(disc.main_executable().unwrap().entry_point(), 0x80003100);
+        let fs_table = disc.fs_table().unwrap();
+        assert_eq!(
+            fs_table.find("audio/a.ssm").unwrap().kind,
+            FsEntryKind::File {
+                offset: 0x4000,
+                size: 4,
+            }
+        );
+        assert_eq!(disc.find_file(Path::new("audio/a.ssm")), Some(&b"ssm!"[..]));
+        assert_eq!(disc.find_file(Path::new("audio")), None);
     }
 
-    pub fn find_file(&self, _path: &Path) -> Option<&[u8]> {
-        // TODO
-        None
+    #[test]
+    fn trimmed() {
+        let data = disc();
+
+        // Everything past the DOL's header is missing.
+        let disc = Reader::new(&data[..DOL_OFFSET + 0x10]);
+        assert!(matches!(
+            disc.main_executable(),
+            Err(DiscError::MainExecutable(
+                dol::ParseError::TruncatedHeader { len: 0x10 }
+            ))
+        ));
+        assert!(disc.fs_table().is_ok());
+        assert_eq!(disc.find_file(Path::new("audio/a.ssm")), None);
+
+        // Everything past the file system table is missing.
+        let disc = Reader::new(&data[..FS_TABLE_OFFSET + 0x10]);
+        assert!(matches!(
+            disc.fs_table(),
+            Err(DiscError::OutOfBounds {
+                part: "file system table",
+                offset: 0x2800,
+            })
+        ));
+        assert!(matches!(
+            disc.main_executable(),
+            Err(DiscError::OutOfBounds {
+                part: "main executable",
+                offset: 0x3000,
+            })
+        ));
+
+        // Everything past the disc header is missing.
+        let disc = Reader::new(&data[..0x440]);
+        assert!(disc.header().is_ok());
+        assert!(matches!(
+            disc.bi2(),
+            Err(HeaderError::Truncated { len: 0, .. })
+        ));
+        assert!(matches!(
+            Reader::new(&[]).main_executable(),
+            Err(DiscError::Header(HeaderError::Truncated { .. }))
+        ));
     }
 }
@@ -0,0 +1,172 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::reader::{APPLOADER_OFFSET, BI2_OFFSET};
+use crate::{
+    ApploaderReader, Bi2Reader, DiscError, FsEntryKind, FsTableReader, HeaderError, HeaderReader,
+    APPLOADER_HEADER_SIZE,
+};
+
+/// Reads a disc image through [`Read`] and [`Seek`], so that it needn't be held in memory.
+///
+/// The disc header and `bi2.bin` are read up front. Everything else is read on demand into a
+/// buffer, which the same readers that [`Reader`](crate::Reader) returns can parse.
+#[derive(Debug)]
+pub struct StreamReader<R> {
+    inner: R,
+    /// The disc header and `bi2.bin`, or as much of them as the image holds.
+    boot: Vec<u8>,
+}
+
+impl<R: Read + Seek> StreamReader<R> {
+    /// Reads and checks the disc header.
+    pub fn new(mut inner: R) -> Result<StreamReader<R>, DiscError> {
+        let mut boot = Vec::with_capacity(APPLOADER_OFFSET);
+        inner.seek(SeekFrom::Start(0))?;
+        (&mut inner)
+            .take(APPLOADER_OFFSET as u64)
+            .read_to_end(&mut boot)?;
+        HeaderReader::parse(&boot)?;
+        Ok(StreamReader { inner, boot })
+    }
+
+    pub fn header(&self) -> HeaderReader<'_> {
+        HeaderReader::parse(&self.boot).unwrap()
+    }
+
+    pub fn bi2(&self) -> Result<Bi2Reader<'_>, HeaderError> {
+        Bi2Reader::parse(self.boot.get(BI2_OFFSET..).unwrap_or_default())
+    }
+
+    /// Reads the apploader, for [`ApploaderReader::parse`].
+    pub fn read_apploader(&mut self) -> Result<Vec<u8>, DiscError> {
+        let offset = APPLOADER_OFFSET as u64;
+        let header = self.read_at("apploader", offset, APPLOADER_HEADER_SIZE)?;
+        let len = ApploaderReader::total_len(&header)?;
+        self.read_at("apploader", offset, len)
+    }
+
+    /// Reads the main executable, for [`dol::Reader::parse`].
+    pub fn read_main_executable(&mut self) -> Result<Vec<u8>, DiscError> {
+        let offset = u64::from(self.header().main_executable_offset());
+        let header = self.read_at("main executable", offset, dol::HEADER_SIZE)?;
+        let len = dol::Reader::file_len(&header)?;
+        self.read_at("main executable", offset, len)
+    }
+
+    /// Reads the file system table, for [`FsTableReader::new`].
+    pub fn read_fs_table(&mut self) -> Result<Vec<u8>, DiscError> {
+        let header = self.header();
+        let offset = u64::from(header.fs_table_offset());
+        let len = header.fs_table_size() as usize;
+        self.read_at("file system table", offset, len)
+    }
+
+    /// Reads the file at `path`, like `audio/us/1padv.ssm`.
+    pub fn read_file(&mut self, path: &Path) -> Result<Vec<u8>, DiscError> {
+        let not_found = || DiscError::FileNotFound {
+            path: path.display().to_string(),
+        };
+        let fs_table = self.read_fs_table()?;
+        let entry = path
+            .to_str()
+            .and_then(|path| FsTableReader::new(&fs_table).find(path))
+            .ok_or_else(not_found)?;
+        match entry.kind {
+            FsEntryKind::File { offset, size } => {
+                self.read_at("file", u64::from(offset), size as usize)
+            }
+            FsEntryKind::Directory { .. } => Err(not_found()),
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads `len` bytes at `offset`. The length comes from the disc, so it's checked against the
+    /// image before anything is allocated.
+    fn read_at(
+        &mut self,
+        part: &'static str,
+        offset: u64,
+        len: usize,
+    ) -> Result<Vec<u8>, DiscError> {
+        let image_len = self.inner.seek(SeekFrom::End(0))?;
+        match offset.checked_add(len as u64) {
+            Some(end) if end <= image_len => {}
+            _ => return Err(DiscError::OutOfBounds { part, offset }),
+        }
+        let mut data = vec![0; len];
+        self.inner.seek(SeekFrom::Start(offset))?;
+        self.inner.read_exact(&mut data)?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::path::Path;
+
+    use super::StreamReader;
+    use crate::reader::tests::{disc, DOL_OFFSET};
+    use crate::{ApploaderReader, DiscError, FsTableReader};
+
+    #[test]
+    fn test() {
+        let mut disc = StreamReader::new(Cursor::new(disc())).unwrap();
+        assert_eq!(disc.header().maker_code(), "01");
+        assert!(disc.bi2().is_ok());
+        let apploader = disc.read_apploader().unwrap();
+        assert_eq!(apploader.len(), 0x40);
+        assert!(ApploaderReader::parse(&apploader).is_ok());
+
+        let dol = disc.read_main_executable().unwrap();
+        assert_eq!(dol.len(), 0x104);
+        assert_eq!(dol::Reader::parse(&dol).unwrap().entry_point(), 0x80003100);
+
+        let fs_table = disc.read_fs_table().unwrap();
+        assert_eq!(FsTableReader::new(&fs_table).root_entry_count(), 3);
+        assert_eq!(disc.read_file(Path::new("audio/a.ssm")).unwrap(), b"ssm!");
+        assert!(matches!(
+            disc.read_file(Path::new("audio")),
+            Err(DiscError::FileNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn trimmed() {
+        let mut data = disc();
+        data.truncate(DOL_OFFSET + 0x102);
+        let mut reader = StreamReader::new(Cursor::new(data)).unwrap();
+        assert!(matches!(
+            reader.read_main_executable(),
+            Err(DiscError::OutOfBounds {
+                part: "main executable",
+                offset: 0x3000,
+            })
+        ));
+        assert!(matches!(
+            reader.read_file(Path::new("audio/a.ssm")),
+            Err(DiscError::OutOfBounds { part: "file", .. })
+        ));
+
+        // A file system table far larger than the image.
+        let mut data = disc();
+        data[0x428..0x42c].copy_from_slice(&0xffff_fff0u32.to_be_bytes());
+        let mut reader = StreamReader::new(Cursor::new(data)).unwrap();
+        assert!(matches!(
+            reader.read_fs_table(),
+            Err(DiscError::OutOfBounds {
+                part: "file system table",
+                offset: 0x2800,
+            })
+        ));
+
+        assert!(matches!(
+            StreamReader::new(Cursor::new(vec![0; 0x100])),
+            Err(DiscError::Header(_))
+        ));
+    }
+}
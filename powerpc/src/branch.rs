@@ -1,3 +1,5 @@
 pub mod bo;
+pub mod branch_hint;
 pub mod condition_behavior;
 pub mod ctr_behavior;
+pub mod ignored_branch_bits;
@@ -3,6 +3,7 @@ use std::hint::unreachable_unchecked;
 use crate::{ConditionBehavior, ConditionBit, CtrBehavior};
 
 /// The 'bo' instruction field as defined in the PowerPC manual.
+#[derive(Clone, Copy)]
 pub struct Bo(u32);
 
 impl Bo {
@@ -29,4 +30,27 @@ impl Bo {
             _ => unsafe { unreachable_unchecked() },
         }
     }
+
+    /// The y bit, which reverses the static prediction of a conditional branch. Branches that are
+    /// always taken have no y bit.
+    pub fn y(self) -> bool {
+        self.0 & 0x01 != 0 && self.z_mask() & 0x01 == 0
+    }
+
+    /// The bits the processor ignores (the z bits) that are set. Toolchains always clear them,
+    /// but they're valid, so they're kept to reproduce the branch.
+    pub fn z_bits(self) -> u32 {
+        self.0 & self.z_mask()
+    }
+
+    /// The ignored bits, which depend on whether the condition and the CTR are tested:
+    /// `001zy`/`011zy`, `1z00y`/`1z01y` and `1z1zz`.
+    fn z_mask(self) -> u32 {
+        match (self.0 & 0x10 != 0, self.0 & 0x04 != 0) {
+            (false, false) => 0x00,
+            (false, true) => 0x02,
+            (true, false) => 0x08,
+            (true, true) => 0x0b,
+        }
+    }
 }
@@ -0,0 +1,32 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The static prediction of a conditional branch, printed as a `+` or `-` suffix.
+///
+/// The hint is stored in the y bit of BO, which reverses the default prediction: backward `bc`
+/// branches are predicted taken, and all other branches not taken. A branch only has a hint when
+/// the y bit is set, so the same suffix can come from different bits depending on the direction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BranchHint {
+    Taken,
+    NotTaken,
+}
+
+impl BranchHint {
+    /// The hint given by setting the y bit of a branch, which reverses the default prediction.
+    pub fn reversing(backward: bool) -> BranchHint {
+        if backward {
+            BranchHint::NotTaken
+        } else {
+            BranchHint::Taken
+        }
+    }
+}
+
+impl Display for BranchHint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            BranchHint::Taken => write!(f, "+"),
+            BranchHint::NotTaken => write!(f, "-"),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+/// The bits of `BO` and `BI` that a conditional branch sets but the processor ignores: the z bits
+/// of `BO`, and all of `BI` when no condition is tested.
+///
+/// Toolchains leave these clear, but the 750 executes the branch either way, so they're kept to
+/// encode the branch back to the same word.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IgnoredBranchBits {
+    pub bo: u8,
+    pub bi: u8,
+}
+
+impl IgnoredBranchBits {
+    pub const NONE: IgnoredBranchBits = IgnoredBranchBits { bo: 0, bi: 0 };
+
+    pub fn is_empty(self) -> bool {
+        self == IgnoredBranchBits::NONE
+    }
+}
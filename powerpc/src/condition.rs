@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod condition;
 pub mod condition_bit;
 pub mod negative_condition;
@@ -21,7 +21,9 @@ impl Condition {
         }
     }
 
-    /// SAFETY: x must be in 0..4.
+    /// # Safety
+    ///
+    /// `x` must be in `0..4`.
     pub unsafe fn new_unchecked(x: u32) -> Condition {
         match x {
             0 => Condition::Less,
@@ -15,7 +15,9 @@ impl ConditionBit {
         }
     }
 
-    /// SAFETY: x must be in 0..32.
+    /// # Safety
+    ///
+    /// `x` must be in `0..32`.
     pub const unsafe fn new_unchecked(x: u32) -> Self {
         Self(x)
     }
@@ -25,6 +27,10 @@ impl ConditionBit {
         unsafe { Self::new_unchecked(4 * crf.get() + condition.as_u32()) }
     }
 
+    pub fn get(self) -> u32 {
+        self.0
+    }
+
     pub fn crf(self) -> Crf {
         // SAFETY: self.0 is in 0..32, so shifting it right two places yields a value in 0..8.
         unsafe { Crf::new_unchecked(self.0 >> 2) }
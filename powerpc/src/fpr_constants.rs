@@ -0,0 +1,34 @@
+use crate::Fpr;
+
+pub const F0: Fpr = unsafe { Fpr::new_unchecked(0) };
+pub const F1: Fpr = unsafe { Fpr::new_unchecked(1) };
+pub const F2: Fpr = unsafe { Fpr::new_unchecked(2) };
+pub const F3: Fpr = unsafe { Fpr::new_unchecked(3) };
+pub const F4: Fpr = unsafe { Fpr::new_unchecked(4) };
+pub const F5: Fpr = unsafe { Fpr::new_unchecked(5) };
+pub const F6: Fpr = unsafe { Fpr::new_unchecked(6) };
+pub const F7: Fpr = unsafe { Fpr::new_unchecked(7) };
+pub const F8: Fpr = unsafe { Fpr::new_unchecked(8) };
+pub const F9: Fpr = unsafe { Fpr::new_unchecked(9) };
+pub const F10: Fpr = unsafe { Fpr::new_unchecked(10) };
+pub const F11: Fpr = unsafe { Fpr::new_unchecked(11) };
+pub const F12: Fpr = unsafe { Fpr::new_unchecked(12) };
+pub const F13: Fpr = unsafe { Fpr::new_unchecked(13) };
+pub const F14: Fpr = unsafe { Fpr::new_unchecked(14) };
+pub const F15: Fpr = unsafe { Fpr::new_unchecked(15) };
+pub const F16: Fpr = unsafe { Fpr::new_unchecked(16) };
+pub const F17: Fpr = unsafe { Fpr::new_unchecked(17) };
+pub const F18: Fpr = unsafe { Fpr::new_unchecked(18) };
+pub const F19: Fpr = unsafe { Fpr::new_unchecked(19) };
+pub const F20: Fpr = unsafe { Fpr::new_unchecked(20) };
+pub const F21: Fpr = unsafe { Fpr::new_unchecked(21) };
+pub const F22: Fpr = unsafe { Fpr::new_unchecked(22) };
+pub const F23: Fpr = unsafe { Fpr::new_unchecked(23) };
+pub const F24: Fpr = unsafe { Fpr::new_unchecked(24) };
+pub const F25: Fpr = unsafe { Fpr::new_unchecked(25) };
+pub const F26: Fpr = unsafe { Fpr::new_unchecked(26) };
+pub const F27: Fpr = unsafe { Fpr::new_unchecked(27) };
+pub const F28: Fpr = unsafe { Fpr::new_unchecked(28) };
+pub const F29: Fpr = unsafe { Fpr::new_unchecked(29) };
+pub const F30: Fpr = unsafe { Fpr::new_unchecked(30) };
+pub const F31: Fpr = unsafe { Fpr::new_unchecked(31) };
@@ -1,8 +1,15 @@
+pub mod assembler;
 pub mod branch_info;
 pub mod decoded_instruction;
+pub mod decoded_section;
+pub mod def_use;
 pub mod encoded_instruction;
+pub mod encoder;
 pub mod format_small_i16;
 pub mod format_small_u16;
+pub mod formatter;
+pub mod memory_access;
+pub mod resolver;
 
 #[cfg(test)]
 pub mod instruction_tests;
@@ -0,0 +1,1001 @@
+//! A small assembler for the syntax produced by `DecodedInstruction`'s `Display` impl, so that
+//! disassembly can be turned back into instructions. It also reads the raw syntax of `Formatter`,
+//! which prints instructions by reading the same forms in reverse.
+
+use thiserror::Error;
+
+use crate::instruction::encoder::branch_offset;
+use crate::instruction::formatter::{Arg, Listing};
+use crate::{
+    BranchHint, Condition, ConditionBehavior, ConditionBit, Crf, CtrBehavior, DecodedInstruction,
+    EncodeError, EncodedInstruction, Fpr, Gpr, GprOrZero, IgnoredBranchBits, ParseError, Spr, Sr,
+};
+
+/// Assembles one line of text, such as `lwz r0, -0x10(r31)`, into an instruction. The address is
+/// needed to resolve branch targets, which are printed as absolute addresses.
+pub fn assemble(text: &str, address: u32) -> Result<DecodedInstruction, AssembleError> {
+    let text = text.trim();
+    let (mnemonic, operands) = match text.find(char::is_whitespace) {
+        Some(i) => (&text[..i], text[i..].trim()),
+        None => (text, ""),
+    };
+    let operands: Vec<&str> = if operands.is_empty() {
+        Vec::new()
+    } else {
+        operands.split(',').map(str::trim).collect()
+    };
+
+    if let Some(instruction) = assemble_branch(mnemonic, &operands, address)? {
+        instruction.encode(address)?;
+        return Ok(instruction);
+    }
+
+    let word = if let Some(word) = assemble_raw_branch(mnemonic, &operands, address)? {
+        word
+    } else if let Some(word) = assemble_spr_move(mnemonic, &operands)? {
+        word
+    } else {
+        assemble_form(mnemonic, &operands)?
+    };
+    Ok(EncodedInstruction(word).parse(address)?)
+}
+
+/// Parses the branch mnemonics, which are built from a count register behavior, a condition, a
+/// target register, the link and absolute flags, and a prediction hint:
+/// `b[dnz|dz][cond][lr|ctr][l][a][+|-]`.
+fn assemble_branch(
+    mnemonic: &str,
+    operands: &[&str],
+    address: u32,
+) -> Result<Option<DecodedInstruction>, AssembleError> {
+    let mut rest = match mnemonic.strip_prefix('b') {
+        Some(rest) => rest,
+        None => return Ok(None),
+    };
+    let hint = if let Some(stripped) = rest.strip_suffix('+') {
+        rest = stripped;
+        Some(BranchHint::Taken)
+    } else if let Some(stripped) = rest.strip_suffix('-') {
+        rest = stripped;
+        Some(BranchHint::NotTaken)
+    } else {
+        None
+    };
+    // Like the GNU assembler, only set the y bit when the hint differs from the default prediction.
+    let hint_for = |backward: bool| hint.filter(|&hint| hint == BranchHint::reversing(backward));
+
+    let mut ctr = CtrBehavior::None;
+    for (name, behavior) in [
+        ("dnz", CtrBehavior::DecrementBranchNonzero),
+        ("dz", CtrBehavior::DecrementBranchZero),
+    ] {
+        if let Some(stripped) = rest.strip_prefix(name) {
+            ctr = behavior;
+            rest = stripped;
+            break;
+        }
+    }
+
+    let mut condition = None;
+    for (name, positive, bi) in CONDITIONS {
+        if let Some(stripped) = rest.strip_prefix(name) {
+            condition = Some((positive, bi));
+            rest = stripped;
+            break;
+        }
+    }
+
+    let register = if let Some(stripped) = rest.strip_prefix("lr") {
+        rest = stripped;
+        Some(false)
+    } else if let Some(stripped) = rest.strip_prefix("ctr") {
+        rest = stripped;
+        Some(true)
+    } else {
+        None
+    };
+    let link = rest.starts_with('l');
+    rest = rest.strip_prefix('l').unwrap_or(rest);
+    let absolute = register.is_none() && rest.starts_with('a');
+    rest = rest.strip_prefix('a').unwrap_or(rest);
+    if !rest.is_empty() {
+        return Ok(None);
+    }
+
+    // A conditional branch names its condition register field first, unless it is `cr0`.
+    let mut operands = operands;
+    let mut crf = Crf::new(0).unwrap();
+    if condition.is_some() {
+        if let Some(first) = operands.first().filter(|first| first.starts_with("cr")) {
+            crf = Crf::new(register_number(first, "cr", 8)?).unwrap();
+            operands = &operands[1..];
+        }
+    }
+    let condition = match condition {
+        Some((positive, bi)) => {
+            let bit = ConditionBit::from_crf_and_condition(crf, Condition::new(bi).unwrap());
+            if positive {
+                ConditionBehavior::BranchTrue(bit)
+            } else {
+                ConditionBehavior::BranchFalse(bit)
+            }
+        }
+        None => ConditionBehavior::BranchAlways,
+    };
+
+    let instruction = match register {
+        Some(false) => {
+            expect_operands(operands, 0)?;
+            DecodedInstruction::Bclr {
+                condition,
+                ctr,
+                hint: hint_for(false),
+                ignored: IgnoredBranchBits::NONE,
+                link,
+            }
+        }
+        Some(true) => {
+            expect_operands(operands, 0)?;
+            if ctr != CtrBehavior::None {
+                return Err(AssembleError::UnknownMnemonic(mnemonic.to_string()));
+            }
+            DecodedInstruction::Bcctr {
+                condition,
+                hint: hint_for(false),
+                ignored: IgnoredBranchBits::NONE,
+                link,
+            }
+        }
+        None => {
+            expect_operands(operands, 1)?;
+            let target = branch_target(operands[0])?;
+            if condition == ConditionBehavior::BranchAlways && ctr == CtrBehavior::None {
+                if hint.is_some() {
+                    return Err(AssembleError::UnknownMnemonic(mnemonic.to_string()));
+                }
+                DecodedInstruction::B {
+                    link,
+                    absolute,
+                    target,
+                }
+            } else {
+                let backward = if absolute {
+                    (target as i32) < 0
+                } else {
+                    (target.wrapping_sub(address) as i32) < 0
+                };
+                DecodedInstruction::Bc {
+                    condition,
+                    ctr,
+                    hint: hint_for(backward),
+                    ignored: IgnoredBranchBits::NONE,
+                    link,
+                    absolute,
+                    target,
+                }
+            }
+        }
+    };
+    Ok(Some(instruction))
+}
+
+/// Parses the raw conditional branches, which give `BO` and `BI` as numbers: `bc[l][a]`, `bclr[l]`
+/// and `bcctr[l]`.
+fn assemble_raw_branch(
+    mnemonic: &str,
+    operands: &[&str],
+    address: u32,
+) -> Result<Option<u32>, AssembleError> {
+    let (template, rest) = if let Some(rest) = mnemonic.strip_prefix("bclr") {
+        (x(19, 16), rest)
+    } else if let Some(rest) = mnemonic.strip_prefix("bcctr") {
+        (x(19, 528), rest)
+    } else if let Some(rest) = mnemonic.strip_prefix("bc") {
+        (d(16), rest)
+    } else {
+        return Ok(None);
+    };
+    let has_target = template == d(16);
+    let (link, rest) = match rest.strip_prefix('l') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    let absolute = has_target && rest == "a";
+    if !(rest.is_empty() || absolute) {
+        return Ok(None);
+    }
+
+    expect_operands(operands, if has_target { 3 } else { 2 })?;
+    let mut word = template | u32::from(absolute) << 1 | u32::from(link);
+    word = Operand::Unsigned(6, 10).apply(word, operands[0])?;
+    word = Operand::ConditionBit(11).apply(word, operands[1])?;
+    if has_target {
+        let target = branch_target(operands[2])?;
+        word |= branch_offset(address, absolute, target, 16)? & 0xfffc;
+    }
+    Ok(Some(word))
+}
+
+/// Parses a branch target, which is printed as an absolute `0x` address.
+fn branch_target(text: &str) -> Result<u32, AssembleError> {
+    text.strip_prefix("0x")
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .ok_or_else(|| AssembleError::BadOperand(text.to_string()))
+}
+
+/// Parses `mf<spr>` and `mt<spr>`, which carry the register name in the mnemonic.
+fn assemble_spr_move(mnemonic: &str, operands: &[&str]) -> Result<Option<u32>, AssembleError> {
+    // The time base is written through SPRs but read with `mftb`, so `mftbu` is left to the forms.
+    let (template, name, allowed): (u32, &str, fn(Spr) -> bool) =
+        if let Some(name) = mnemonic.strip_prefix("mf") {
+            (x(31, 339), name, Spr::is_readable)
+        } else if let Some(name) = mnemonic.strip_prefix("mt") {
+            (x(31, 467), name, Spr::is_writable)
+        } else {
+            return Ok(None);
+        };
+    let spr = match Spr::ALL
+        .iter()
+        .find(|&&spr| allowed(spr) && spr.to_string() == name)
+    {
+        Some(spr) => spr.as_u32(),
+        None => return Ok(None),
+    };
+    expect_operands(operands, 1)?;
+    Ok(Some(
+        Operand::Gpr(6).apply(template | spr_field(spr), operands[0])?,
+    ))
+}
+
+fn assemble_form(mnemonic: &str, operands: &[&str]) -> Result<u32, AssembleError> {
+    let (form, record, overflow) = find_form(mnemonic, operands.len())
+        .ok_or_else(|| AssembleError::UnknownMnemonic(mnemonic.to_string()))?;
+
+    // The compare instructions omit `cr0`.
+    let mut operands = operands.to_vec();
+    if form.operands.first() == Some(&Operand::OptionalCrf)
+        && operands.len() + 1 == form.operands.len()
+    {
+        operands.insert(0, "cr0");
+    }
+    expect_operands(&operands, form.operands.len())?;
+
+    let mut word = form.template;
+    if record {
+        word |= 1;
+    }
+    if overflow {
+        word |= 1 << 10;
+    }
+    for (operand, text) in form.operands.iter().zip(operands) {
+        word = operand.apply(word, text)?;
+    }
+    Ok(word)
+}
+
+/// Looks up a mnemonic, allowing for the `.` and `o` suffixes of forms that take them. Returns the
+/// form along with whether each suffix was present. A mnemonic with several forms, such as `mftb`,
+/// picks the one that takes the given number of operands.
+fn find_form(mnemonic: &str, operand_count: usize) -> Option<(&'static Form, bool, bool)> {
+    let lookup = |name: &str| {
+        let mut forms = FORMS.iter().filter(|form| form.mnemonic == name);
+        let first = forms.clone().next()?;
+        Some(
+            forms
+                .find(|form| form.takes(operand_count))
+                .unwrap_or(first),
+        )
+    };
+    if let Some(form) = lookup(mnemonic) {
+        return Some((form, false, false));
+    }
+    let (name, record) = match mnemonic.strip_suffix('.') {
+        Some(name) => (name, true),
+        None => (mnemonic, false),
+    };
+    if let Some(form) = lookup(name).filter(|form| form.record || !record) {
+        return Some((form, record, false));
+    }
+    let form = lookup(name.strip_suffix('o')?).filter(|form| form.overflow)?;
+    if record && !form.record {
+        return None;
+    }
+    Some((form, record, true))
+}
+
+fn expect_operands(operands: &[&str], expected: usize) -> Result<(), AssembleError> {
+    if operands.len() == expected {
+        Ok(())
+    } else {
+        Err(AssembleError::OperandCount {
+            expected,
+            found: operands.len(),
+        })
+    }
+}
+
+const CONDITIONS: [(&str, bool, u32); 8] = [
+    ("lt", true, 0),
+    ("gt", true, 1),
+    ("eq", true, 2),
+    ("so", true, 3),
+    ("nl", false, 0),
+    ("ng", false, 1),
+    ("ne", false, 2),
+    ("ns", false, 3),
+];
+
+/// Places `value` in bits `from..=to` of `word`, failing if it doesn't fit.
+fn place(word: u32, from: u32, to: u32, value: u32) -> Result<u32, AssembleError> {
+    let width = to - from + 1;
+    if u64::from(value) >> width != 0 {
+        return Err(AssembleError::BadOperand(value.to_string()));
+    }
+    Ok(word | value << (31 - to))
+}
+
+/// Parses a register name such as `r31`, `f0` or `cr7`.
+fn register_number(text: &str, prefix: &str, count: u32) -> Result<u32, AssembleError> {
+    text.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .filter(|&number| number < count)
+        .ok_or_else(|| AssembleError::BadOperand(text.to_string()))
+}
+
+/// Parses a decimal or `0x` hexadecimal integer with an optional minus sign.
+fn integer(text: &str) -> Result<i64, AssembleError> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    let magnitude = match digits.strip_prefix("0x") {
+        Some(hex) => i64::from_str_radix(hex, 16),
+        None => digits.parse(),
+    };
+    match magnitude {
+        Ok(magnitude) if !digits.starts_with(['+', '-']) => {
+            Ok(if negative { -magnitude } else { magnitude })
+        }
+        _ => Err(AssembleError::BadOperand(text.to_string())),
+    }
+}
+
+/// Parses a signed integer and returns it as a two's complement field `width` bits wide.
+fn signed_field(text: &str, width: u32) -> Result<u32, AssembleError> {
+    let value = integer(text)?;
+    let limit = 1i64 << (width - 1);
+    if (-limit..limit).contains(&value) {
+        Ok(value as u32 & ((1 << width) - 1))
+    } else {
+        Err(AssembleError::BadOperand(text.to_string()))
+    }
+}
+
+fn unsigned_field(text: &str) -> Result<u32, AssembleError> {
+    let value = integer(text)?;
+    if (0..=i64::from(u32::MAX)).contains(&value) {
+        Ok(value as u32)
+    } else {
+        Err(AssembleError::BadOperand(text.to_string()))
+    }
+}
+
+/// An operand in the text and the instruction field it fills. Positions are the first bit of the
+/// field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operand {
+    /// A GPR, printed as `rN`. `0` is accepted for `(rA|0)` too.
+    Gpr(u32),
+    /// The `(rA|0)` base of an address, printed as `0` when it is zero.
+    GprOrZero(u32),
+    Fpr(u32),
+    Crf(u32),
+    /// A `crN` in bits 6..=8 that may be left out when it is `cr0`.
+    OptionalCrf,
+    /// A condition register bit such as `cr7*4+lt`.
+    ConditionBit(u32),
+    SignedImmediate,
+    UnsignedImmediate,
+    Unsigned(u32, u32),
+    /// An unsigned field that holds a mask, such as the fields of `mtcrf`, which the formatter may
+    /// print in hexadecimal. Counts and bit numbers use `Unsigned`.
+    Mask(u32, u32),
+    Bit(u32),
+    /// A graphics quantization register, printed as `qrN`.
+    Gqr(u32),
+    /// A segment register, printed as `srN`.
+    Sr(u32),
+    /// A displacement and base register, printed as `d(rA)`. The displacement starts at the given
+    /// bit and runs to the end of the word.
+    Offset(u32),
+    /// The source of `mr`, which fills both `rS` and `rB`.
+    MoveSource,
+    /// An SPR or TBR number in bits 11..=20, whose two halves are swapped.
+    Spr,
+}
+
+impl Operand {
+    fn apply(self, word: u32, text: &str) -> Result<u32, AssembleError> {
+        match self {
+            Operand::Gpr(at) | Operand::GprOrZero(at) => {
+                let gpr = if text == "0" {
+                    0
+                } else {
+                    register_number(text, "r", 32)?
+                };
+                place(word, at, at + 4, gpr)
+            }
+            Operand::Fpr(at) => place(word, at, at + 4, register_number(text, "f", 32)?),
+            Operand::Crf(at) => place(word, at, at + 2, register_number(text, "cr", 8)?),
+            Operand::OptionalCrf => Operand::Crf(6).apply(word, text),
+            Operand::ConditionBit(at) => {
+                if let Ok(bit) = text.parse() {
+                    return place(word, at, at + 4, bit);
+                }
+                let (crf, condition) = match text.split_once("*4+") {
+                    Some((crf, condition)) => (register_number(crf, "cr", 8)?, condition),
+                    None => (0, text),
+                };
+                let condition = CONDITIONS[..4]
+                    .iter()
+                    .find(|(name, _, _)| *name == condition)
+                    .map(|&(_, _, bi)| bi)
+                    .ok_or_else(|| AssembleError::BadOperand(text.to_string()))?;
+                place(word, at, at + 4, 4 * crf + condition)
+            }
+            Operand::SignedImmediate => place(word, 16, 31, signed_field(text, 16)?),
+            Operand::UnsignedImmediate => place(word, 16, 31, unsigned_field(text)?),
+            Operand::Unsigned(from, to) | Operand::Mask(from, to) => {
+                place(word, from, to, unsigned_field(text)?)
+            }
+            Operand::Bit(at) => place(word, at, at, unsigned_field(text)?),
+            Operand::Gqr(at) => place(word, at, at + 2, register_number(text, "qr", 8)?),
+            Operand::Sr(at) => place(word, at, at + 3, register_number(text, "sr", 16)?),
+            Operand::Offset(from) => {
+                let (offset, base) = text
+                    .strip_suffix(')')
+                    .and_then(|text| text.split_once('('))
+                    .ok_or_else(|| AssembleError::BadOperand(text.to_string()))?;
+                let offset = if offset.is_empty() {
+                    0
+                } else {
+                    signed_field(offset, 32 - from)?
+                };
+                let word = place(word, from, 31, offset)?;
+                Operand::Gpr(11).apply(word, base)
+            }
+            Operand::MoveSource => {
+                let word = Operand::Gpr(6).apply(word, text)?;
+                Operand::Gpr(16).apply(word, text)
+            }
+            Operand::Spr => {
+                let spr = unsigned_field(text)?;
+                if spr >= 1 << 10 {
+                    return Err(AssembleError::BadOperand(text.to_string()));
+                }
+                Ok(word | spr_field(spr))
+            }
+        }
+    }
+
+    /// The bits of the instruction word the operand fills.
+    fn mask(self) -> u32 {
+        let bits = |from: u32, to: u32| (u32::MAX >> from) & (u32::MAX << (31 - to));
+        match self {
+            Operand::Gpr(at)
+            | Operand::GprOrZero(at)
+            | Operand::Fpr(at)
+            | Operand::ConditionBit(at) => bits(at, at + 4),
+            Operand::Crf(at) | Operand::Gqr(at) => bits(at, at + 2),
+            Operand::OptionalCrf => bits(6, 8),
+            Operand::SignedImmediate | Operand::UnsignedImmediate => bits(16, 31),
+            Operand::Unsigned(from, to) | Operand::Mask(from, to) => bits(from, to),
+            Operand::Bit(at) => bits(at, at),
+            Operand::Sr(at) => bits(at, at + 3),
+            Operand::Offset(from) => bits(from, 31) | bits(11, 15),
+            Operand::MoveSource => bits(6, 10) | bits(16, 20),
+            Operand::Spr => bits(11, 20),
+        }
+    }
+
+    /// Reads the operand back out of an instruction word. Fails if the word doesn't fit the
+    /// operand, such as an `or` whose sources differ read as `mr`.
+    fn read(self, word: u32) -> Option<Arg> {
+        let field = |from: u32, to: u32| (word >> (31 - to)) & (u32::MAX >> (31 - (to - from)));
+        Some(match self {
+            Operand::Gpr(at) => Arg::Gpr(Gpr::new(field(at, at + 4))?),
+            Operand::GprOrZero(at) => Arg::GprOrZero(GprOrZero::new(field(at, at + 4))?),
+            Operand::Fpr(at) => Arg::Fpr(Fpr::new(field(at, at + 4))?),
+            Operand::Crf(at) => Arg::Crf(Crf::new(field(at, at + 2))?),
+            Operand::OptionalCrf => Arg::OptionalCrf(Crf::new(field(6, 8))?),
+            Operand::ConditionBit(at) => Arg::ConditionBit(ConditionBit::new(field(at, at + 4))?),
+            Operand::SignedImmediate => Arg::Signed(field(16, 31) as i16),
+            Operand::UnsignedImmediate => Arg::Unsigned(field(16, 31) as u16),
+            Operand::Unsigned(from, to) => Arg::Number(field(from, to)),
+            Operand::Mask(from, to) => Arg::Unsigned(field(from, to) as u16),
+            Operand::Bit(at) => Arg::Number(field(at, at)),
+            Operand::Gqr(at) => Arg::Gqr(field(at, at + 2)),
+            Operand::Sr(at) => Arg::Sr(Sr::new(field(at, at + 3))?),
+            Operand::Offset(from) => Arg::Offset(
+                ((field(from, 31) << from) as i32 >> from) as i16,
+                GprOrZero::new(field(11, 15))?,
+            ),
+            Operand::MoveSource => {
+                if field(6, 10) != field(16, 20) {
+                    return None;
+                }
+                Arg::Gpr(Gpr::new(field(6, 10))?)
+            }
+            Operand::Spr => Arg::Number(field(16, 20) << 5 | field(11, 15)),
+        })
+    }
+}
+
+/// A mnemonic together with the instruction word it starts from and the operands that fill it in.
+struct Form {
+    mnemonic: &'static str,
+    template: u32,
+    operands: &'static [Operand],
+    /// Whether the mnemonic takes a `.` suffix, which sets bit 31.
+    record: bool,
+    /// Whether the mnemonic takes an `o` suffix, which sets bit 21.
+    overflow: bool,
+    /// Whether the mnemonic is a simplified spelling of another form, such as `li` for `addi`.
+    simplified: bool,
+}
+
+impl Form {
+    const fn new(mnemonic: &'static str, template: u32, operands: &'static [Operand]) -> Form {
+        Form {
+            mnemonic,
+            template,
+            operands,
+            record: false,
+            overflow: false,
+            simplified: false,
+        }
+    }
+
+    const fn record(self) -> Form {
+        Form {
+            record: true,
+            ..self
+        }
+    }
+
+    const fn overflow(self) -> Form {
+        Form {
+            overflow: true,
+            ..self
+        }
+    }
+
+    const fn simplified(self) -> Form {
+        Form {
+            simplified: true,
+            ..self
+        }
+    }
+
+    /// Whether the form can be written with the given number of operands.
+    fn takes(&self, count: usize) -> bool {
+        count == self.operands.len()
+            || self.operands.first() == Some(&Operand::OptionalCrf)
+                && count + 1 == self.operands.len()
+    }
+
+    /// The bits the template fixes, which are all but the operands and the suffix bits.
+    fn fixed_bits(&self) -> u32 {
+        let mut variable = self
+            .operands
+            .iter()
+            .fold(0, |mask, operand| mask | operand.mask());
+        if self.record {
+            variable |= 1;
+        }
+        if self.overflow {
+            variable |= 1 << 10;
+        }
+        !variable
+    }
+
+    /// Reads a word that matches the form back into its mnemonic and operands.
+    fn read(&self, word: u32) -> Option<Listing> {
+        if word & self.fixed_bits() != self.template {
+            return None;
+        }
+        let mut mnemonic = self.mnemonic.to_string();
+        if self.overflow && word & 1 << 10 != 0 {
+            mnemonic.push('o');
+        }
+        if self.record && word & 1 != 0 {
+            mnemonic.push('.');
+        }
+        let args = self
+            .operands
+            .iter()
+            .map(|operand| operand.read(word))
+            .collect::<Option<_>>()?;
+        Some(Listing { mnemonic, args })
+    }
+}
+
+/// Finds the form of an instruction word and reads its operands. Simplified forms are preferred,
+/// or skipped entirely when `raw` is set. Branches and the named SPR moves have no forms.
+pub(super) fn disassemble(word: u32, raw: bool) -> Option<Listing> {
+    let mut found = None;
+    for form in FORMS.iter().filter(|form| !(raw && form.simplified)) {
+        if let Some(listing) = form.read(word) {
+            if form.simplified {
+                return Some(listing);
+            }
+            found.get_or_insert(listing);
+        }
+    }
+    found
+}
+
+/// A D-form template.
+const fn d(opcode: u32) -> u32 {
+    opcode << 26
+}
+
+/// An X-form template. Also used for the A-form and quantized indexed instructions, whose shorter
+/// extended opcodes sit at the same end of the word.
+const fn x(opcode: u32, extended_opcode: u32) -> u32 {
+    opcode << 26 | extended_opcode << 1
+}
+
+/// An SPR or TBR number as placed in bits 11..=20, with its two halves swapped.
+const fn spr_field(spr: u32) -> u32 {
+    (spr & 0x1f) << 16 | (spr >> 5) << 11
+}
+
+const FORMS: &[Form] = {
+    use Operand::*;
+    &[
+        Form::new("add", x(31, 266), &[Gpr(6), Gpr(11), Gpr(16)])
+            .overflow()
+            .record(),
+        Form::new("addc", x(31, 10), &[Gpr(6), Gpr(11), Gpr(16)])
+            .overflow()
+            .record(),
+        Form::new("adde", x(31, 138), &[Gpr(6), Gpr(11), Gpr(16)])
+            .overflow()
+            .record(),
+        Form::new("addi", d(14), &[Gpr(6), GprOrZero(11), SignedImmediate]),
+        Form::new("addic", d(12), &[Gpr(6), Gpr(11), SignedImmediate]),
+        Form::new("addic.", d(13), &[Gpr(6), Gpr(11), SignedImmediate]),
+        Form::new("addis", d(15), &[Gpr(6), GprOrZero(11), SignedImmediate]),
+        Form::new("addme", x(31, 234), &[Gpr(6), Gpr(11)])
+            .overflow()
+            .record(),
+        Form::new("addze", x(31, 202), &[Gpr(6), Gpr(11)])
+            .overflow()
+            .record(),
+        Form::new("and", x(31, 28), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("andc", x(31, 60), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("andi.", d(28), &[Gpr(11), Gpr(6), UnsignedImmediate]),
+        Form::new("andis.", d(29), &[Gpr(11), Gpr(6), UnsignedImmediate]),
+        Form::new("cmp", x(31, 0), &[Crf(6), Bit(10), Gpr(11), Gpr(16)]),
+        Form::new("cmpi", d(11), &[Crf(6), Bit(10), Gpr(11), SignedImmediate]),
+        Form::new("cmpl", x(31, 32), &[Crf(6), Bit(10), Gpr(11), Gpr(16)]),
+        Form::new(
+            "cmpli",
+            d(10),
+            &[Crf(6), Bit(10), Gpr(11), UnsignedImmediate],
+        ),
+        Form::new("cmplw", x(31, 32), &[OptionalCrf, Gpr(11), Gpr(16)]).simplified(),
+        Form::new("cmplwi", d(10), &[OptionalCrf, Gpr(11), UnsignedImmediate]).simplified(),
+        Form::new("cmpw", x(31, 0), &[OptionalCrf, Gpr(11), Gpr(16)]).simplified(),
+        Form::new("cmpwi", d(11), &[OptionalCrf, Gpr(11), SignedImmediate]).simplified(),
+        Form::new("cntlzw", x(31, 26), &[Gpr(11), Gpr(6)]).record(),
+        Form::new(
+            "crand",
+            x(19, 257),
+            &[ConditionBit(6), ConditionBit(11), ConditionBit(16)],
+        ),
+        Form::new(
+            "crandc",
+            x(19, 129),
+            &[ConditionBit(6), ConditionBit(11), ConditionBit(16)],
+        ),
+        Form::new(
+            "creqv",
+            x(19, 289),
+            &[ConditionBit(6), ConditionBit(11), ConditionBit(16)],
+        ),
+        Form::new(
+            "crnand",
+            x(19, 225),
+            &[ConditionBit(6), ConditionBit(11), ConditionBit(16)],
+        ),
+        Form::new(
+            "crnor",
+            x(19, 33),
+            &[ConditionBit(6), ConditionBit(11), ConditionBit(16)],
+        ),
+        Form::new(
+            "cror",
+            x(19, 449),
+            &[ConditionBit(6), ConditionBit(11), ConditionBit(16)],
+        ),
+        Form::new(
+            "crorc",
+            x(19, 417),
+            &[ConditionBit(6), ConditionBit(11), ConditionBit(16)],
+        ),
+        Form::new(
+            "crxor",
+            x(19, 193),
+            &[ConditionBit(6), ConditionBit(11), ConditionBit(16)],
+        ),
+        Form::new("dcbf", x(31, 86), &[GprOrZero(11), Gpr(16)]),
+        Form::new("dcbi", x(31, 470), &[GprOrZero(11), Gpr(16)]),
+        Form::new("dcbst", x(31, 54), &[GprOrZero(11), Gpr(16)]),
+        Form::new("dcbt", x(31, 278), &[GprOrZero(11), Gpr(16)]),
+        Form::new("dcbtst", x(31, 246), &[GprOrZero(11), Gpr(16)]),
+        Form::new("dcbz", x(31, 1014), &[GprOrZero(11), Gpr(16)]),
+        Form::new("dcbz_l", x(4, 1014), &[GprOrZero(11), Gpr(16)]),
+        Form::new("divw", x(31, 491), &[Gpr(6), Gpr(11), Gpr(16)])
+            .overflow()
+            .record(),
+        Form::new("divwu", x(31, 459), &[Gpr(6), Gpr(11), Gpr(16)])
+            .overflow()
+            .record(),
+        Form::new("eieio", x(31, 854), &[]),
+        Form::new("eqv", x(31, 284), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("extsb", x(31, 954), &[Gpr(11), Gpr(6)]).record(),
+        Form::new("extsh", x(31, 922), &[Gpr(11), Gpr(6)]).record(),
+        Form::new("fabs", x(63, 264), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("fadd", x(63, 21), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("fadds", x(59, 21), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("fcmpo", x(63, 32), &[Crf(6), Fpr(11), Fpr(16)]),
+        Form::new("fcmpu", x(63, 0), &[Crf(6), Fpr(11), Fpr(16)]),
+        Form::new("fctiw", x(63, 14), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("fctiwz", x(63, 15), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("fdiv", x(63, 18), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("fdivs", x(59, 18), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("fmadd", x(63, 29), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("fmadds", x(59, 29), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("fmr", x(63, 72), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("fmsub", x(63, 28), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("fmsubs", x(59, 28), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("fmul", x(63, 25), &[Fpr(6), Fpr(11), Fpr(21)]).record(),
+        Form::new("fmuls", x(59, 25), &[Fpr(6), Fpr(11), Fpr(21)]).record(),
+        Form::new("fnabs", x(63, 136), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("fneg", x(63, 40), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("fnmadd", x(63, 31), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("fnmadds", x(59, 31), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("fnmsub", x(63, 30), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("fnmsubs", x(59, 30), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("fres", x(59, 24), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("frsp", x(63, 12), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("frsqrte", x(63, 26), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("fsel", x(63, 23), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("fsub", x(63, 20), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("fsubs", x(59, 20), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("icbi", x(31, 982), &[GprOrZero(11), Gpr(16)]),
+        Form::new("isync", x(19, 150), &[]),
+        Form::new("lbz", d(34), &[Gpr(6), Offset(16)]),
+        Form::new("lbzu", d(35), &[Gpr(6), Offset(16)]),
+        Form::new("lbzux", x(31, 119), &[Gpr(6), Gpr(11), Gpr(16)]),
+        Form::new("lbzx", x(31, 87), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("lfd", d(50), &[Fpr(6), Offset(16)]),
+        Form::new("lfdu", d(51), &[Fpr(6), Offset(16)]),
+        Form::new("lfdux", x(31, 631), &[Fpr(6), Gpr(11), Gpr(16)]),
+        Form::new("lfdx", x(31, 599), &[Fpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("lfs", d(48), &[Fpr(6), Offset(16)]),
+        Form::new("lfsu", d(49), &[Fpr(6), Offset(16)]),
+        Form::new("lfsux", x(31, 567), &[Fpr(6), Gpr(11), Gpr(16)]),
+        Form::new("lfsx", x(31, 535), &[Fpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("lha", d(42), &[Gpr(6), Offset(16)]),
+        Form::new("lhau", d(43), &[Gpr(6), Offset(16)]),
+        Form::new("lhaux", x(31, 375), &[Gpr(6), Gpr(11), Gpr(16)]),
+        Form::new("lhax", x(31, 343), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("lhbrx", x(31, 790), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("lhz", d(40), &[Gpr(6), Offset(16)]),
+        Form::new("lhzu", d(41), &[Gpr(6), Offset(16)]),
+        Form::new("lhzux", x(31, 311), &[Gpr(6), Gpr(11), Gpr(16)]),
+        Form::new("lhzx", x(31, 279), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("li", d(14), &[Gpr(6), SignedImmediate]).simplified(),
+        Form::new("lmw", d(46), &[Gpr(6), Offset(16)]),
+        Form::new("lwbrx", x(31, 534), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("lwz", d(32), &[Gpr(6), Offset(16)]),
+        Form::new("lwzu", d(33), &[Gpr(6), Offset(16)]),
+        Form::new("lwzux", x(31, 55), &[Gpr(6), Gpr(11), Gpr(16)]),
+        Form::new("lwzx", x(31, 23), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("mcrf", x(19, 0), &[Crf(6), Crf(11)]),
+        Form::new("mcrfs", x(63, 64), &[Crf(6), Crf(11)]),
+        Form::new("mcrxr", x(31, 512), &[Crf(6)]),
+        Form::new("mfcr", x(31, 19), &[Gpr(6)]),
+        Form::new("mffs", x(63, 583), &[Fpr(6)]).record(),
+        Form::new("mfmsr", x(31, 83), &[Gpr(6)]),
+        Form::new("mfspr", x(31, 339), &[Gpr(6), Spr]),
+        Form::new("mfsr", x(31, 595), &[Gpr(6), Sr(12)]),
+        Form::new("mfsrin", x(31, 659), &[Gpr(6), Gpr(16)]),
+        Form::new("mftb", x(31, 371), &[Gpr(6), Spr]),
+        Form::new("mftb", x(31, 371) | spr_field(268), &[Gpr(6)]).simplified(),
+        Form::new("mftbu", x(31, 371) | spr_field(269), &[Gpr(6)]).simplified(),
+        Form::new("mr", x(31, 444), &[Gpr(11), MoveSource])
+            .record()
+            .simplified(),
+        Form::new("mtcrf", x(31, 144), &[Mask(12, 19), Gpr(6)]),
+        Form::new("mtfsb0", x(63, 70), &[Unsigned(6, 10)]).record(),
+        Form::new("mtfsb1", x(63, 38), &[Unsigned(6, 10)]).record(),
+        Form::new("mtfsf", x(63, 711), &[Mask(7, 14), Fpr(16)]).record(),
+        Form::new("mtfsfi", x(63, 134), &[Crf(6), Unsigned(16, 19)]).record(),
+        Form::new("mtmsr", x(31, 146), &[Gpr(6)]),
+        Form::new("mtspr", x(31, 467), &[Spr, Gpr(6)]),
+        Form::new("mtsr", x(31, 210), &[Sr(12), Gpr(6)]),
+        Form::new("mtsrin", x(31, 242), &[Gpr(6), Gpr(16)]),
+        Form::new("mulhw", x(31, 75), &[Gpr(6), Gpr(11), Gpr(16)]).record(),
+        Form::new("mulhwu", x(31, 11), &[Gpr(6), Gpr(11), Gpr(16)]).record(),
+        Form::new("mulli", d(7), &[Gpr(6), Gpr(11), SignedImmediate]),
+        Form::new("mullw", x(31, 235), &[Gpr(6), Gpr(11), Gpr(16)])
+            .overflow()
+            .record(),
+        Form::new("nand", x(31, 476), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("neg", x(31, 104), &[Gpr(6), Gpr(11)])
+            .overflow()
+            .record(),
+        Form::new("nor", x(31, 124), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("or", x(31, 444), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("orc", x(31, 412), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("ori", d(24), &[Gpr(11), Gpr(6), UnsignedImmediate]),
+        Form::new("oris", d(25), &[Gpr(11), Gpr(6), UnsignedImmediate]),
+        Form::new("ps_abs", x(4, 264), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("ps_add", x(4, 21), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("ps_cmpo0", x(4, 32), &[Crf(6), Fpr(11), Fpr(16)]),
+        Form::new("ps_cmpo1", x(4, 96), &[Crf(6), Fpr(11), Fpr(16)]),
+        Form::new("ps_cmpu0", x(4, 0), &[Crf(6), Fpr(11), Fpr(16)]),
+        Form::new("ps_cmpu1", x(4, 64), &[Crf(6), Fpr(11), Fpr(16)]),
+        Form::new("ps_div", x(4, 18), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("ps_madd", x(4, 29), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("ps_madds0", x(4, 14), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("ps_madds1", x(4, 15), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("ps_merge00", x(4, 528), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("ps_merge01", x(4, 560), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("ps_merge10", x(4, 592), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("ps_merge11", x(4, 624), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("ps_mr", x(4, 72), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("ps_msub", x(4, 28), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("ps_mul", x(4, 25), &[Fpr(6), Fpr(11), Fpr(21)]).record(),
+        Form::new("ps_muls0", x(4, 12), &[Fpr(6), Fpr(11), Fpr(21)]).record(),
+        Form::new("ps_muls1", x(4, 13), &[Fpr(6), Fpr(11), Fpr(21)]).record(),
+        Form::new("ps_nabs", x(4, 136), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("ps_neg", x(4, 40), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("ps_nmadd", x(4, 31), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("ps_nmsub", x(4, 30), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("ps_res", x(4, 24), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("ps_rsqrte", x(4, 26), &[Fpr(6), Fpr(16)]).record(),
+        Form::new("ps_sel", x(4, 23), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("ps_sub", x(4, 20), &[Fpr(6), Fpr(11), Fpr(16)]).record(),
+        Form::new("ps_sum0", x(4, 10), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("ps_sum1", x(4, 11), &[Fpr(6), Fpr(11), Fpr(21), Fpr(16)]).record(),
+        Form::new("psq_l", d(56), &[Fpr(6), Offset(20), Bit(16), Gqr(17)]),
+        Form::new("psq_lu", d(57), &[Fpr(6), Offset(20), Bit(16), Gqr(17)]),
+        Form::new(
+            "psq_lux",
+            x(4, 38),
+            &[Fpr(6), Gpr(11), Gpr(16), Bit(21), Gqr(22)],
+        ),
+        Form::new(
+            "psq_lx",
+            x(4, 6),
+            &[Fpr(6), GprOrZero(11), Gpr(16), Bit(21), Gqr(22)],
+        ),
+        Form::new("psq_st", d(60), &[Fpr(6), Offset(20), Bit(16), Gqr(17)]),
+        Form::new("psq_stu", d(61), &[Fpr(6), Offset(20), Bit(16), Gqr(17)]),
+        Form::new(
+            "psq_stux",
+            x(4, 39),
+            &[Fpr(6), Gpr(11), Gpr(16), Bit(21), Gqr(22)],
+        ),
+        Form::new(
+            "psq_stx",
+            x(4, 7),
+            &[Fpr(6), GprOrZero(11), Gpr(16), Bit(21), Gqr(22)],
+        ),
+        Form::new("rfi", x(19, 50), &[]),
+        Form::new(
+            "rlwimi",
+            d(20),
+            &[
+                Gpr(11),
+                Gpr(6),
+                Unsigned(16, 20),
+                Unsigned(21, 25),
+                Unsigned(26, 30),
+            ],
+        )
+        .record(),
+        Form::new(
+            "rlwinm",
+            d(21),
+            &[
+                Gpr(11),
+                Gpr(6),
+                Unsigned(16, 20),
+                Unsigned(21, 25),
+                Unsigned(26, 30),
+            ],
+        )
+        .record(),
+        Form::new(
+            "rlwnm",
+            d(23),
+            &[Gpr(11), Gpr(6), Gpr(16), Unsigned(21, 25), Unsigned(26, 30)],
+        )
+        .record(),
+        Form::new("sc", d(17) | 2, &[]),
+        Form::new("slw", x(31, 24), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("sraw", x(31, 792), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("srawi", x(31, 824), &[Gpr(11), Gpr(6), Unsigned(16, 20)]).record(),
+        Form::new("srw", x(31, 536), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("stb", d(38), &[Gpr(6), Offset(16)]),
+        Form::new("stbu", d(39), &[Gpr(6), Offset(16)]),
+        Form::new("stbux", x(31, 247), &[Gpr(6), Gpr(11), Gpr(16)]),
+        Form::new("stbx", x(31, 215), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("stfd", d(54), &[Fpr(6), Offset(16)]),
+        Form::new("stfdu", d(55), &[Fpr(6), Offset(16)]),
+        Form::new("stfdux", x(31, 759), &[Fpr(6), Gpr(11), Gpr(16)]),
+        Form::new("stfdx", x(31, 727), &[Fpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("stfiwx", x(31, 983), &[Fpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("stfs", d(52), &[Fpr(6), Offset(16)]),
+        Form::new("stfsu", d(53), &[Fpr(6), Offset(16)]),
+        Form::new("stfsux", x(31, 695), &[Fpr(6), Gpr(11), Gpr(16)]),
+        Form::new("stfsx", x(31, 663), &[Fpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("sth", d(44), &[Gpr(6), Offset(16)]),
+        Form::new("sthbrx", x(31, 918), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("sthu", d(45), &[Gpr(6), Offset(16)]),
+        Form::new("sthux", x(31, 439), &[Gpr(6), Gpr(11), Gpr(16)]),
+        Form::new("sthx", x(31, 407), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("stmw", d(47), &[Gpr(6), Offset(16)]),
+        Form::new("stw", d(36), &[Gpr(6), Offset(16)]),
+        Form::new("stwbrx", x(31, 662), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("stwu", d(37), &[Gpr(6), Offset(16)]),
+        Form::new("stwux", x(31, 183), &[Gpr(6), Gpr(11), Gpr(16)]),
+        Form::new("stwx", x(31, 151), &[Gpr(6), GprOrZero(11), Gpr(16)]),
+        Form::new("subf", x(31, 40), &[Gpr(6), Gpr(11), Gpr(16)])
+            .overflow()
+            .record(),
+        Form::new("subfc", x(31, 8), &[Gpr(6), Gpr(11), Gpr(16)])
+            .overflow()
+            .record(),
+        Form::new("subfe", x(31, 136), &[Gpr(6), Gpr(11), Gpr(16)])
+            .overflow()
+            .record(),
+        Form::new("subfic", d(8), &[Gpr(6), Gpr(11), SignedImmediate]),
+        Form::new("subfme", x(31, 232), &[Gpr(6), Gpr(11)])
+            .overflow()
+            .record(),
+        Form::new("subfze", x(31, 200), &[Gpr(6), Gpr(11)])
+            .overflow()
+            .record(),
+        Form::new("sync", x(31, 598), &[]),
+        Form::new("tw", x(31, 4), &[Mask(6, 10), Gpr(11), Gpr(16)]),
+        Form::new("twi", d(3), &[Mask(6, 10), Gpr(11), SignedImmediate]),
+        Form::new("xor", x(31, 316), &[Gpr(11), Gpr(6), Gpr(16)]).record(),
+        Form::new("xori", d(26), &[Gpr(11), Gpr(6), UnsignedImmediate]),
+        Form::new("xoris", d(27), &[Gpr(11), Gpr(6), UnsignedImmediate]),
+    ]
+};
+
+#[derive(Debug, Error)]
+pub enum AssembleError {
+    #[error("unknown mnemonic: {0}")]
+    UnknownMnemonic(String),
+
+    #[error("expected {expected} operands, found {found}")]
+    OperandCount { expected: usize, found: usize },
+
+    #[error("bad operand: {0}")]
+    BadOperand(String),
+
+    #[error("operands do not form a valid instruction: {0}")]
+    Parse(#[from] ParseError),
+
+    #[error(transparent)]
+    Encode(#[from] EncodeError),
+}
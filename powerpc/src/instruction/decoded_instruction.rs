@@ -1,29 +1,81 @@
 use std::fmt::{self, Display, Formatter};
 
+use crate::instruction::encoder::bo_and_bi;
 use crate::instruction::format_small_i16::FormatSmallI16;
 use crate::instruction::format_small_u16::FormatSmallU16;
 use crate::{
-    BranchInfo, ConditionBehavior, ConditionBit, Crf, CtrBehavior, Gpr, GprOrZero, NonZeroGpr, Spr,
+    BranchHint, BranchInfo, ConditionBehavior, ConditionBit, Crf, CtrBehavior, Fpr, Gpr, GprOrZero,
+    IgnoredBranchBits, NonZeroGpr, Spr, Sr,
 };
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum DecodedInstruction {
+    Add {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        overflow_enable: bool,
+        record: bool,
+    },
+    Addc {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        overflow_enable: bool,
+        record: bool,
+    },
+    Adde {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        overflow_enable: bool,
+        record: bool,
+    },
     Addi {
         dst: Gpr,
         src: GprOrZero,
         immediate: i16,
     },
+    Addic {
+        dst: Gpr,
+        src: Gpr,
+        immediate: i16,
+        record: bool,
+    },
     Addis {
         dst: Gpr,
         src: GprOrZero,
         immediate: i16,
     },
+    Addme {
+        dst: Gpr,
+        src: Gpr,
+        overflow_enable: bool,
+        record: bool,
+    },
     Addze {
         dst: Gpr,
         src: Gpr,
         overflow_enable: bool,
         record: bool,
     },
+    And {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Andc {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Andi {
+        dst: Gpr,
+        src: Gpr,
+        immediate: u16,
+    },
+    Andis {
+        dst: Gpr,
+        src: Gpr,
+        immediate: u16,
+    },
     B {
         link: bool,
         absolute: bool,
@@ -32,15 +84,30 @@ pub enum DecodedInstruction {
     Bc {
         condition: ConditionBehavior,
         ctr: CtrBehavior,
+        hint: Option<BranchHint>,
+        ignored: IgnoredBranchBits,
         link: bool,
         absolute: bool,
         target: u32,
     },
+    /// Branches to the address in CTR. Decrementing CTR is not allowed, so there's no `ctr` field.
+    Bcctr {
+        condition: ConditionBehavior,
+        hint: Option<BranchHint>,
+        ignored: IgnoredBranchBits,
+        link: bool,
+    },
     Bclr {
         condition: ConditionBehavior,
         ctr: CtrBehavior,
+        hint: Option<BranchHint>,
+        ignored: IgnoredBranchBits,
         link: bool,
     },
+    Cmp {
+        crf: Crf,
+        srcs: [Gpr; 2],
+    },
     Cmpi {
         crf: Crf,
         src: Gpr,
@@ -55,258 +122,2141 @@ pub enum DecodedInstruction {
         src: Gpr,
         immediate: u16,
     },
+    Cntlzw {
+        dst: Gpr,
+        src: Gpr,
+        record: bool,
+    },
+    Crand {
+        dst: ConditionBit,
+        srcs: [ConditionBit; 2],
+    },
+    Crandc {
+        dst: ConditionBit,
+        srcs: [ConditionBit; 2],
+    },
+    Creqv {
+        dst: ConditionBit,
+        srcs: [ConditionBit; 2],
+    },
+    Crnand {
+        dst: ConditionBit,
+        srcs: [ConditionBit; 2],
+    },
+    Crnor {
+        dst: ConditionBit,
+        srcs: [ConditionBit; 2],
+    },
+    Cror {
+        dst: ConditionBit,
+        srcs: [ConditionBit; 2],
+    },
+    Crorc {
+        dst: ConditionBit,
+        srcs: [ConditionBit; 2],
+    },
     Crxor {
         dst: ConditionBit,
         srcs: [ConditionBit; 2],
     },
+    Dcbf {
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Dcbi {
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Dcbst {
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Dcbt {
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Dcbtst {
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Dcbz {
+        base: GprOrZero,
+        index: Gpr,
+    },
+    DcbzL {
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Divw {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        overflow_enable: bool,
+        record: bool,
+    },
+    Divwu {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        overflow_enable: bool,
+        record: bool,
+    },
+    Eieio,
+    Eqv {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Extsb {
+        dst: Gpr,
+        src: Gpr,
+        record: bool,
+    },
+    Extsh {
+        dst: Gpr,
+        src: Gpr,
+        record: bool,
+    },
+    Fabs {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    Fadd {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    Fadds {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    Fcmpo {
+        crf: Crf,
+        srcs: [Fpr; 2],
+    },
+    Fcmpu {
+        crf: Crf,
+        srcs: [Fpr; 2],
+    },
+    Fctiw {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    Fctiwz {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    Fdiv {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    Fdivs {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    Fmadd {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    Fmadds {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    Fmr {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    Fmsub {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    Fmsubs {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    Fmul {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    Fmuls {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    Fnabs {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    Fneg {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    Fnmadd {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    Fnmadds {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    Fnmsub {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    Fnmsubs {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    Fres {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    Frsp {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    Frsqrte {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    Fsel {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    Fsub {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    Fsubs {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    Icbi {
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Isync,
     Lbz {
         dst: Gpr,
         offset: i16,
         base: GprOrZero,
     },
+    Lbzu {
+        dst: Gpr,
+        offset: i16,
+        base: NonZeroGpr,
+    },
+    Lbzux {
+        dst: Gpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Lbzx {
+        dst: Gpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Lfd {
+        dst: Fpr,
+        offset: i16,
+        base: GprOrZero,
+    },
+    Lfdu {
+        dst: Fpr,
+        offset: i16,
+        base: NonZeroGpr,
+    },
+    Lfdux {
+        dst: Fpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Lfdx {
+        dst: Fpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Lfs {
+        dst: Fpr,
+        offset: i16,
+        base: GprOrZero,
+    },
+    Lfsu {
+        dst: Fpr,
+        offset: i16,
+        base: NonZeroGpr,
+    },
+    Lfsux {
+        dst: Fpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Lfsx {
+        dst: Fpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
     Lha {
         dst: Gpr,
         offset: i16,
         base: GprOrZero,
     },
-    Lwz {
+    Lhau {
         dst: Gpr,
         offset: i16,
+        base: NonZeroGpr,
+    },
+    Lhaux {
+        dst: Gpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Lhax {
+        dst: Gpr,
         base: GprOrZero,
+        index: Gpr,
     },
-    Mfspr {
-        spr: Spr,
+    Lhbrx {
         dst: Gpr,
+        base: GprOrZero,
+        index: Gpr,
     },
-    Mtspr {
-        spr: Spr,
-        src: Gpr,
+    Lhz {
+        dst: Gpr,
+        offset: i16,
+        base: GprOrZero,
     },
-    Or {
+    Lhzu {
         dst: Gpr,
-        srcs: [Gpr; 2],
-        record: bool,
+        offset: i16,
+        base: NonZeroGpr,
     },
-    Rlwinm {
+    Lhzux {
         dst: Gpr,
-        src: Gpr,
-        shift: u8,
-        mask_begin: u8,
-        mask_end: u8,
-        record: bool,
+        base: NonZeroGpr,
+        index: Gpr,
     },
-    Srawi {
+    Lhzx {
         dst: Gpr,
-        src: Gpr,
-        shift: u8,
-        record: bool,
+        base: GprOrZero,
+        index: Gpr,
     },
-    Stmw {
-        src: Gpr,
+    Lmw {
+        dst: Gpr,
         offset: i16,
         base: GprOrZero,
     },
-    Stw {
-        src: Gpr,
+    Lwbrx {
+        dst: Gpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Lwz {
+        dst: Gpr,
         offset: i16,
         base: GprOrZero,
     },
-    Stwu {
-        src: Gpr,
+    Lwzu {
+        dst: Gpr,
         offset: i16,
         base: NonZeroGpr,
     },
-}
-
-impl DecodedInstruction {
-    pub fn branch_info(self) -> Option<BranchInfo> {
-        match self {
-            DecodedInstruction::B { link, target, .. } => Some(BranchInfo {
-                condition: ConditionBehavior::BranchAlways,
-                ctr: CtrBehavior::None,
-                link,
-                target: Some(target),
-            }),
-            DecodedInstruction::Bc {
-                condition,
-                ctr,
+    Lwzux {
+        dst: Gpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Lwzx {
+        dst: Gpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Mcrf {
+        dst: Crf,
+        src: Crf,
+    },
+    Mcrfs {
+        dst: Crf,
+        src: Crf,
+    },
+    /// Copies XER[SO, OV, CA] into `dst` and clears them in XER.
+    Mcrxr {
+        dst: Crf,
+    },
+    Mfcr {
+        dst: Gpr,
+    },
+    Mffs {
+        dst: Fpr,
+        record: bool,
+    },
+    Mfmsr {
+        dst: Gpr,
+    },
+    Mfspr {
+        spr: Spr,
+        dst: Gpr,
+    },
+    /// Copies the CR fields selected by the `fields` mask from `src`. The MSB of the mask selects
+    /// CR field 0.
+    Mfsr {
+        dst: Gpr,
+        sr: Sr,
+    },
+    /// Reads the segment register selected by the top four bits of `index`.
+    Mfsrin {
+        dst: Gpr,
+        index: Gpr,
+    },
+    /// Reads the time base. `spr` is either `TimeBaseLower` or `TimeBaseUpper`.
+    Mftb {
+        spr: Spr,
+        dst: Gpr,
+    },
+    Mtcrf {
+        fields: u8,
+        src: Gpr,
+    },
+    /// Sets an FPSCR bit, `0..=31`, to 0.
+    Mtfsb0 {
+        bit: u8,
+        record: bool,
+    },
+    /// Sets an FPSCR bit, `0..=31`, to 1.
+    Mtfsb1 {
+        bit: u8,
+        record: bool,
+    },
+    /// Copies the FPSCR fields selected by the `fields` mask from `src`. The MSB of the mask
+    /// selects FPSCR field 0.
+    Mtfsf {
+        fields: u8,
+        src: Fpr,
+        record: bool,
+    },
+    /// Sets FPSCR field `crf` to the 4-bit `immediate`.
+    Mtfsfi {
+        crf: Crf,
+        immediate: u8,
+        record: bool,
+    },
+    Mtmsr {
+        src: Gpr,
+    },
+    Mtspr {
+        spr: Spr,
+        src: Gpr,
+    },
+    Mtsr {
+        sr: Sr,
+        src: Gpr,
+    },
+    /// Writes the segment register selected by the top four bits of `index`.
+    Mtsrin {
+        src: Gpr,
+        index: Gpr,
+    },
+    Mulhw {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Mulhwu {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Mulli {
+        dst: Gpr,
+        src: Gpr,
+        immediate: i16,
+    },
+    Mullw {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        overflow_enable: bool,
+        record: bool,
+    },
+    Nand {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Neg {
+        dst: Gpr,
+        src: Gpr,
+        overflow_enable: bool,
+        record: bool,
+    },
+    Nor {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Or {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Orc {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Ori {
+        dst: Gpr,
+        src: Gpr,
+        immediate: u16,
+    },
+    Oris {
+        dst: Gpr,
+        src: Gpr,
+        immediate: u16,
+    },
+    PsAbs {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    PsAdd {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsCmpo0 {
+        crf: Crf,
+        srcs: [Fpr; 2],
+    },
+    PsCmpo1 {
+        crf: Crf,
+        srcs: [Fpr; 2],
+    },
+    PsCmpu0 {
+        crf: Crf,
+        srcs: [Fpr; 2],
+    },
+    PsCmpu1 {
+        crf: Crf,
+        srcs: [Fpr; 2],
+    },
+    PsDiv {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsMadd {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    PsMadds0 {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    PsMadds1 {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    PsMerge00 {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsMerge01 {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsMerge10 {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsMerge11 {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsMr {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    PsMsub {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    PsMul {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsMuls0 {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsMuls1 {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsNabs {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    PsNeg {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    PsNmadd {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    PsNmsub {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    PsRes {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    PsRsqrte {
+        dst: Fpr,
+        src: Fpr,
+        record: bool,
+    },
+    PsSel {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    PsSub {
+        dst: Fpr,
+        srcs: [Fpr; 2],
+        record: bool,
+    },
+    PsSum0 {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    PsSum1 {
+        dst: Fpr,
+        srcs: [Fpr; 3],
+        record: bool,
+    },
+    /// `single` is the W field: when set, only ps0 is transferred and ps1 is loaded as 1.0. `gqr` is
+    /// the I field, selecting which of `gqr0..=gqr7` describes the quantization.
+    PsqL {
+        dst: Fpr,
+        offset: i16,
+        base: GprOrZero,
+        single: bool,
+        gqr: u8,
+    },
+    /// The update form of `PsqL`.
+    PsqLu {
+        dst: Fpr,
+        offset: i16,
+        base: NonZeroGpr,
+        single: bool,
+        gqr: u8,
+    },
+    PsqLux {
+        dst: Fpr,
+        base: NonZeroGpr,
+        index: Gpr,
+        single: bool,
+        gqr: u8,
+    },
+    PsqLx {
+        dst: Fpr,
+        base: GprOrZero,
+        index: Gpr,
+        single: bool,
+        gqr: u8,
+    },
+    /// Fields as in `PsqL`, except that with `single` set only ps0 is stored.
+    PsqSt {
+        src: Fpr,
+        offset: i16,
+        base: GprOrZero,
+        single: bool,
+        gqr: u8,
+    },
+    /// The update form of `PsqSt`.
+    PsqStu {
+        src: Fpr,
+        offset: i16,
+        base: NonZeroGpr,
+        single: bool,
+        gqr: u8,
+    },
+    PsqStux {
+        src: Fpr,
+        base: NonZeroGpr,
+        index: Gpr,
+        single: bool,
+        gqr: u8,
+    },
+    PsqStx {
+        src: Fpr,
+        base: GprOrZero,
+        index: Gpr,
+        single: bool,
+        gqr: u8,
+    },
+    /// Returns from an interrupt, restoring the MSR from SRR1 and jumping to SRR0.
+    Rfi,
+    Rlwimi {
+        dst: Gpr,
+        src: Gpr,
+        shift: u8,
+        mask_begin: u8,
+        mask_end: u8,
+        record: bool,
+    },
+    Rlwinm {
+        dst: Gpr,
+        src: Gpr,
+        shift: u8,
+        mask_begin: u8,
+        mask_end: u8,
+        record: bool,
+    },
+    Rlwnm {
+        dst: Gpr,
+        src: Gpr,
+        shift: Gpr,
+        mask_begin: u8,
+        mask_end: u8,
+        record: bool,
+    },
+    /// A system call, which saves the MSR and return address in SRR1 and SRR0.
+    Sc,
+    Slw {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Sraw {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Srawi {
+        dst: Gpr,
+        src: Gpr,
+        shift: u8,
+        record: bool,
+    },
+    Srw {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Stb {
+        src: Gpr,
+        offset: i16,
+        base: GprOrZero,
+    },
+    Stbu {
+        src: Gpr,
+        offset: i16,
+        base: NonZeroGpr,
+    },
+    Stbux {
+        src: Gpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Stbx {
+        src: Gpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Stfd {
+        src: Fpr,
+        offset: i16,
+        base: GprOrZero,
+    },
+    Stfdu {
+        src: Fpr,
+        offset: i16,
+        base: NonZeroGpr,
+    },
+    Stfdux {
+        src: Fpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Stfdx {
+        src: Fpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Stfiwx {
+        src: Fpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Stfs {
+        src: Fpr,
+        offset: i16,
+        base: GprOrZero,
+    },
+    Stfsu {
+        src: Fpr,
+        offset: i16,
+        base: NonZeroGpr,
+    },
+    Stfsux {
+        src: Fpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Stfsx {
+        src: Fpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Sth {
+        src: Gpr,
+        offset: i16,
+        base: GprOrZero,
+    },
+    Sthbrx {
+        src: Gpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Sthu {
+        src: Gpr,
+        offset: i16,
+        base: NonZeroGpr,
+    },
+    Sthux {
+        src: Gpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Sthx {
+        src: Gpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Stmw {
+        src: Gpr,
+        offset: i16,
+        base: GprOrZero,
+    },
+    Stw {
+        src: Gpr,
+        offset: i16,
+        base: GprOrZero,
+    },
+    Stwbrx {
+        src: Gpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Stwu {
+        src: Gpr,
+        offset: i16,
+        base: NonZeroGpr,
+    },
+    Stwux {
+        src: Gpr,
+        base: NonZeroGpr,
+        index: Gpr,
+    },
+    Stwx {
+        src: Gpr,
+        base: GprOrZero,
+        index: Gpr,
+    },
+    Subf {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        overflow_enable: bool,
+        record: bool,
+    },
+    Subfc {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        overflow_enable: bool,
+        record: bool,
+    },
+    Subfe {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        overflow_enable: bool,
+        record: bool,
+    },
+    Subfic {
+        dst: Gpr,
+        src: Gpr,
+        immediate: i16,
+    },
+    Subfme {
+        dst: Gpr,
+        src: Gpr,
+        overflow_enable: bool,
+        record: bool,
+    },
+    Subfze {
+        dst: Gpr,
+        src: Gpr,
+        overflow_enable: bool,
+        record: bool,
+    },
+    Sync,
+    /// Traps if comparing `srcs[0]` to `srcs[1]` meets any of the conditions in the 5-bit `to`
+    /// mask: signed less, signed greater, equal, unsigned less and unsigned greater, MSB first.
+    Tw {
+        to: u8,
+        srcs: [Gpr; 2],
+    },
+    /// Traps like `Tw`, comparing `src` to `immediate`.
+    Twi {
+        to: u8,
+        src: Gpr,
+        immediate: i16,
+    },
+    Xor {
+        dst: Gpr,
+        srcs: [Gpr; 2],
+        record: bool,
+    },
+    Xori {
+        dst: Gpr,
+        src: Gpr,
+        immediate: u16,
+    },
+    Xoris {
+        dst: Gpr,
+        src: Gpr,
+        immediate: u16,
+    },
+}
+
+impl DecodedInstruction {
+    pub fn branch_info(self) -> Option<BranchInfo> {
+        match self {
+            DecodedInstruction::B { link, target, .. } => Some(BranchInfo {
+                condition: ConditionBehavior::BranchAlways,
+                ctr: CtrBehavior::None,
+                link,
+                target: Some(target),
+            }),
+            DecodedInstruction::Bc {
+                condition,
+                ctr,
                 link,
                 target,
                 ..
             } => Some(BranchInfo {
                 condition,
-                ctr,
+                ctr,
+                link,
+                target: Some(target),
+            }),
+            DecodedInstruction::Bcctr {
+                condition, link, ..
+            } => Some(BranchInfo {
+                condition,
+                ctr: CtrBehavior::None,
+                link,
+                target: None,
+            }),
+            DecodedInstruction::Bclr {
+                condition,
+                ctr,
+                link,
+                ..
+            } => Some(BranchInfo {
+                condition,
+                ctr,
+                link,
+                target: None,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Display for DecodedInstruction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            DecodedInstruction::Add {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "add{}{} {}, {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Addc {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "addc{}{} {}, {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Adde {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "adde{}{} {}, {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Addi {
+                dst,
+                src,
+                immediate,
+            } => {
+                if src.is_gpr() {
+                    write!(f, "addi {}, {}, {}", dst, src, FormatSmallI16(immediate))
+                } else {
+                    write!(f, "li {}, {}", dst, FormatSmallI16(immediate))
+                }
+            }
+            DecodedInstruction::Addic {
+                dst,
+                src,
+                immediate,
+                record,
+            } => write!(
+                f,
+                "addic{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+                FormatSmallI16(immediate),
+            ),
+            DecodedInstruction::Addis {
+                dst,
+                src,
+                immediate,
+            } => {
+                write!(f, "addis {}, {}, {}", dst, src, FormatSmallI16(immediate))
+            }
+            DecodedInstruction::Addme {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "addme{}{} {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::Addze {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => {
+                write!(
+                    f,
+                    "addze{}{} {}, {}",
+                    if overflow_enable { "o" } else { "" },
+                    if record { "." } else { "" },
+                    dst,
+                    src,
+                )
+            }
+            DecodedInstruction::And { dst, srcs, record } => write!(
+                f,
+                "and{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Andc { dst, srcs, record } => write!(
+                f,
+                "andc{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Andi {
+                dst,
+                src,
+                immediate,
+            } => write!(f, "andi. {}, {}, {}", dst, src, FormatSmallU16(immediate)),
+            DecodedInstruction::Andis {
+                dst,
+                src,
+                immediate,
+            } => write!(f, "andis. {}, {}, {}", dst, src, FormatSmallU16(immediate)),
+            DecodedInstruction::B {
+                link,
+                absolute,
+                target,
+            } => write!(
+                f,
+                "b{}{} 0x{:08x}",
+                if link { "l" } else { "" },
+                if absolute { "a" } else { "" },
+                target
+            ),
+            DecodedInstruction::Bc {
+                condition,
+                ctr,
+                hint,
+                ignored,
+                link,
+                absolute,
+                target,
+            } => {
+                // The simplified mnemonics can't show the ignored bits, and an unconditional `bc`
+                // would print as `b`.
+                let unconditional =
+                    condition == ConditionBehavior::BranchAlways && ctr == CtrBehavior::None;
+                if !ignored.is_empty() || unconditional {
+                    let (bo, bi) = bo_and_bi(condition, ctr, hint, ignored);
+                    return write!(
+                        f,
+                        "bc{}{} {}, {}, 0x{:08x}",
+                        if link { "l" } else { "" },
+                        if absolute { "a" } else { "" },
+                        bo,
+                        bi,
+                        target
+                    );
+                }
+                write!(
+                    f,
+                    "b{}{}{}{}{} ",
+                    ctr,
+                    condition,
+                    if link { "l" } else { "" },
+                    if absolute { "a" } else { "" },
+                    FormatHint(hint),
+                )?;
+                if let Some(cr) = condition.crf().and_then(|crf| crf.nonzero()) {
+                    write!(f, "{}, ", cr)?;
+                }
+                write!(f, "0x{:08x}", target)
+            }
+            DecodedInstruction::Bcctr {
+                condition,
+                hint,
+                ignored,
                 link,
-                target: Some(target),
-            }),
+            } => {
+                if !ignored.is_empty() {
+                    let (bo, bi) = bo_and_bi(condition, CtrBehavior::None, hint, ignored);
+                    return write!(f, "bcctr{} {}, {}", if link { "l" } else { "" }, bo, bi);
+                }
+                write!(
+                    f,
+                    "b{}ctr{}{}",
+                    condition,
+                    if link { "l" } else { "" },
+                    FormatHint(hint)
+                )?;
+                if let Some(cr) = condition.crf().and_then(|crf| crf.nonzero()) {
+                    write!(f, " {}", cr)?;
+                }
+                Ok(())
+            }
             DecodedInstruction::Bclr {
                 condition,
                 ctr,
+                hint,
+                ignored,
                 link,
-            } => Some(BranchInfo {
-                condition,
-                ctr,
-                link,
-                target: None,
-            }),
-            _ => None,
-        }
-    }
-}
-
-impl Display for DecodedInstruction {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        match *self {
-            DecodedInstruction::Addi {
-                dst,
+            } => {
+                if !ignored.is_empty() {
+                    let (bo, bi) = bo_and_bi(condition, ctr, hint, ignored);
+                    return write!(f, "bclr{} {}, {}", if link { "l" } else { "" }, bo, bi);
+                }
+                write!(
+                    f,
+                    "b{}{}lr{}{}",
+                    ctr,
+                    condition,
+                    if link { "l" } else { "" },
+                    FormatHint(hint)
+                )?;
+                if let Some(cr) = condition.crf().and_then(|crf| crf.nonzero()) {
+                    write!(f, " {}", cr)?;
+                }
+                Ok(())
+            }
+            DecodedInstruction::Cmp { crf, srcs } => {
+                write!(f, "cmpw ")?;
+                if crf.get() > 0 {
+                    write!(f, "{}, ", crf)?;
+                }
+                write!(f, "{}, {}", srcs[0], srcs[1])
+            }
+            DecodedInstruction::Cmpi {
+                crf,
                 src,
                 immediate,
             } => {
-                if src.is_gpr() {
-                    write!(f, "addi {}, {}, {}", dst, src, FormatSmallI16(immediate))
-                } else {
-                    write!(f, "li {}, {}", dst, FormatSmallI16(immediate))
+                write!(f, "cmpwi ")?;
+                if crf.get() > 0 {
+                    write!(f, "{}, ", crf)?;
+                }
+                write!(f, "{}, {}", src, FormatSmallI16(immediate))
+            }
+            DecodedInstruction::Cmpl { crf, srcs } => {
+                write!(f, "cmplw ")?;
+                if crf.get() > 0 {
+                    write!(f, "{}, ", crf)?;
+                }
+                write!(f, "{}, {}", srcs[0], srcs[1])
+            }
+            DecodedInstruction::Cmpli {
+                crf,
+                src,
+                immediate,
+            } => {
+                write!(f, "cmplwi ")?;
+                if crf.get() > 0 {
+                    write!(f, "{}, ", crf)?;
+                }
+                write!(f, "{}, {}", src, FormatSmallU16(immediate))
+            }
+            DecodedInstruction::Cntlzw { dst, src, record } => write!(
+                f,
+                "cntlzw{} {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::Crand { dst, srcs } => {
+                write!(f, "crand {}, {}, {}", dst, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Crandc { dst, srcs } => {
+                write!(f, "crandc {}, {}, {}", dst, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Creqv { dst, srcs } => {
+                write!(f, "creqv {}, {}, {}", dst, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Crnand { dst, srcs } => {
+                write!(f, "crnand {}, {}, {}", dst, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Crnor { dst, srcs } => {
+                write!(f, "crnor {}, {}, {}", dst, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Cror { dst, srcs } => {
+                write!(f, "cror {}, {}, {}", dst, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Crorc { dst, srcs } => {
+                write!(f, "crorc {}, {}, {}", dst, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Crxor { dst, srcs } => {
+                write!(f, "crxor {}, {}, {}", dst, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Dcbf { base, index } => write!(f, "dcbf {}, {}", base, index),
+            DecodedInstruction::Dcbi { base, index } => write!(f, "dcbi {}, {}", base, index),
+            DecodedInstruction::Dcbst { base, index } => write!(f, "dcbst {}, {}", base, index),
+            DecodedInstruction::Dcbt { base, index } => write!(f, "dcbt {}, {}", base, index),
+            DecodedInstruction::Dcbtst { base, index } => write!(f, "dcbtst {}, {}", base, index),
+            DecodedInstruction::Dcbz { base, index } => write!(f, "dcbz {}, {}", base, index),
+            DecodedInstruction::DcbzL { base, index } => write!(f, "dcbz_l {}, {}", base, index),
+            DecodedInstruction::Divw {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "divw{}{} {}, {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Divwu {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "divwu{}{} {}, {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Eieio => write!(f, "eieio"),
+            DecodedInstruction::Eqv { dst, srcs, record } => write!(
+                f,
+                "eqv{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Extsb { dst, src, record } => {
+                write!(f, "extsb{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::Extsh { dst, src, record } => {
+                write!(f, "extsh{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::Fabs { dst, src, record } => {
+                write!(f, "fabs{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::Fadd { dst, srcs, record } => write!(
+                f,
+                "fadd{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Fadds { dst, srcs, record } => write!(
+                f,
+                "fadds{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Fcmpo { crf, srcs } => {
+                write!(f, "fcmpo {}, {}, {}", crf, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Fcmpu { crf, srcs } => {
+                write!(f, "fcmpu {}, {}, {}", crf, srcs[0], srcs[1])
+            }
+            DecodedInstruction::Fctiw { dst, src, record } => {
+                write!(f, "fctiw{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::Fctiwz { dst, src, record } => write!(
+                f,
+                "fctiwz{} {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::Fdiv { dst, srcs, record } => write!(
+                f,
+                "fdiv{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Fdivs { dst, srcs, record } => write!(
+                f,
+                "fdivs{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Fmadd { dst, srcs, record } => write!(
+                f,
+                "fmadd{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::Fmadds { dst, srcs, record } => write!(
+                f,
+                "fmadds{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::Fmr { dst, src, record } => {
+                write!(f, "fmr{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::Fmsub { dst, srcs, record } => write!(
+                f,
+                "fmsub{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::Fmsubs { dst, srcs, record } => write!(
+                f,
+                "fmsubs{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::Fmul { dst, srcs, record } => write!(
+                f,
+                "fmul{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Fmuls { dst, srcs, record } => write!(
+                f,
+                "fmuls{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Fnabs { dst, src, record } => {
+                write!(f, "fnabs{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::Fneg { dst, src, record } => {
+                write!(f, "fneg{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::Fnmadd { dst, srcs, record } => write!(
+                f,
+                "fnmadd{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::Fnmadds { dst, srcs, record } => write!(
+                f,
+                "fnmadds{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::Fnmsub { dst, srcs, record } => write!(
+                f,
+                "fnmsub{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::Fnmsubs { dst, srcs, record } => write!(
+                f,
+                "fnmsubs{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::Fres { dst, src, record } => {
+                write!(f, "fres{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::Frsp { dst, src, record } => {
+                write!(f, "frsp{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::Frsqrte { dst, src, record } => write!(
+                f,
+                "frsqrte{} {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::Fsel { dst, srcs, record } => write!(
+                f,
+                "fsel{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::Fsub { dst, srcs, record } => write!(
+                f,
+                "fsub{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Fsubs { dst, srcs, record } => write!(
+                f,
+                "fsubs{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Icbi { base, index } => write!(f, "icbi {}, {}", base, index),
+            DecodedInstruction::Isync => write!(f, "isync"),
+            DecodedInstruction::Lbz { dst, offset, base } => {
+                write!(f, "lbz {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lbzu { dst, offset, base } => {
+                write!(f, "lbzu {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lbzux { dst, base, index } => {
+                write!(f, "lbzux {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lbzx { dst, base, index } => {
+                write!(f, "lbzx {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lfd { dst, offset, base } => {
+                write!(f, "lfd {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lfdu { dst, offset, base } => {
+                write!(f, "lfdu {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lfdux { dst, base, index } => {
+                write!(f, "lfdux {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lfdx { dst, base, index } => {
+                write!(f, "lfdx {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lfs { dst, offset, base } => {
+                write!(f, "lfs {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lfsu { dst, offset, base } => {
+                write!(f, "lfsu {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lfsux { dst, base, index } => {
+                write!(f, "lfsux {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lfsx { dst, base, index } => {
+                write!(f, "lfsx {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lha { dst, offset, base } => {
+                write!(f, "lha {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
                 }
+                write!(f, "({})", base)
             }
-            DecodedInstruction::Addis {
+            DecodedInstruction::Lhau { dst, offset, base } => {
+                write!(f, "lhau {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lhaux { dst, base, index } => {
+                write!(f, "lhaux {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lhax { dst, base, index } => {
+                write!(f, "lhax {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lhbrx { dst, base, index } => {
+                write!(f, "lhbrx {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lhz { dst, offset, base } => {
+                write!(f, "lhz {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lhzu { dst, offset, base } => {
+                write!(f, "lhzu {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lhzux { dst, base, index } => {
+                write!(f, "lhzux {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lhzx { dst, base, index } => {
+                write!(f, "lhzx {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lmw { dst, offset, base } => {
+                write!(f, "lmw {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lwbrx { dst, base, index } => {
+                write!(f, "lwbrx {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lwz { dst, offset, base } => {
+                write!(f, "lwz {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lwzu { dst, offset, base } => {
+                write!(f, "lwzu {}, ", dst)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Lwzux { dst, base, index } => {
+                write!(f, "lwzux {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Lwzx { dst, base, index } => {
+                write!(f, "lwzx {}, {}, {}", dst, base, index)
+            }
+            DecodedInstruction::Mcrf { dst, src } => write!(f, "mcrf {}, {}", dst, src),
+            DecodedInstruction::Mcrfs { dst, src } => write!(f, "mcrfs {}, {}", dst, src),
+            DecodedInstruction::Mcrxr { dst } => write!(f, "mcrxr {}", dst),
+            DecodedInstruction::Mfcr { dst } => write!(f, "mfcr {}", dst),
+            DecodedInstruction::Mffs { dst, record } => {
+                write!(f, "mffs{} {}", if record { "." } else { "" }, dst)
+            }
+            DecodedInstruction::Mfmsr { dst } => write!(f, "mfmsr {}", dst),
+            DecodedInstruction::Mfspr { spr, dst } => write!(f, "mf{} {}", spr, dst),
+            DecodedInstruction::Mfsr { dst, sr } => write!(f, "mfsr {}, {}", dst, sr),
+            DecodedInstruction::Mfsrin { dst, index } => write!(f, "mfsrin {}, {}", dst, index),
+            DecodedInstruction::Mftb { spr, dst } => match spr {
+                Spr::TimeBaseUpper => write!(f, "mftbu {}", dst),
+                _ => write!(f, "mftb {}", dst),
+            },
+            DecodedInstruction::Mtcrf { fields, src } => {
+                write!(f, "mtcrf {}, {}", FormatSmallU16(fields as u16), src)
+            }
+            DecodedInstruction::Mtfsb0 { bit, record } => {
+                write!(f, "mtfsb0{} {}", if record { "." } else { "" }, bit)
+            }
+            DecodedInstruction::Mtfsb1 { bit, record } => {
+                write!(f, "mtfsb1{} {}", if record { "." } else { "" }, bit)
+            }
+            DecodedInstruction::Mtfsf {
+                fields,
+                src,
+                record,
+            } => write!(
+                f,
+                "mtfsf{} {}, {}",
+                if record { "." } else { "" },
+                FormatSmallU16(fields as u16),
+                src,
+            ),
+            DecodedInstruction::Mtfsfi {
+                crf,
+                immediate,
+                record,
+            } => write!(
+                f,
+                "mtfsfi{} {}, {}",
+                if record { "." } else { "" },
+                crf,
+                immediate
+            ),
+            DecodedInstruction::Mtmsr { src } => write!(f, "mtmsr {}", src),
+            DecodedInstruction::Mtspr { spr, src } => write!(f, "mt{} {}", spr, src),
+            DecodedInstruction::Mtsr { sr, src } => write!(f, "mtsr {}, {}", sr, src),
+            DecodedInstruction::Mtsrin { src, index } => write!(f, "mtsrin {}, {}", src, index),
+            DecodedInstruction::Mulhw { dst, srcs, record } => write!(
+                f,
+                "mulhw{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Mulhwu { dst, srcs, record } => write!(
+                f,
+                "mulhwu{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Mulli {
                 dst,
                 src,
                 immediate,
-            } => {
-                write!(f, "addis {}, {}, {}", dst, src, FormatSmallI16(immediate))
-            }
-            DecodedInstruction::Addze {
+            } => write!(f, "mulli {}, {}, {}", dst, src, FormatSmallI16(immediate)),
+            DecodedInstruction::Mullw {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "mullw{}{} {}, {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Nand { dst, srcs, record } => write!(
+                f,
+                "nand{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Neg {
                 dst,
                 src,
                 overflow_enable,
                 record,
-            } => {
-                write!(
-                    f,
-                    "addze{}{} {}, {}",
-                    if overflow_enable { "o" } else { "" },
-                    if record { "." } else { "" },
-                    dst,
-                    src,
-                )
-            }
-            DecodedInstruction::B {
-                link,
-                absolute,
-                target,
             } => write!(
                 f,
-                "b{}{} 0x{:08x}",
-                if link { "l" } else { "" },
-                if absolute { "a" } else { "" },
-                target
+                "neg{}{} {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                src,
             ),
-            DecodedInstruction::Bc {
-                condition,
-                ctr,
-                link,
-                absolute,
-                target,
-            } => {
-                write!(
-                    f,
-                    "b{}{}{}{} ",
-                    ctr,
-                    condition,
-                    if link { "l" } else { "" },
-                    if absolute { "a" } else { "" },
-                )?;
-                if let Some(cr) = condition.crf().and_then(|crf| crf.nonzero()) {
-                    write!(f, "{}, ", cr)?;
-                }
-                write!(f, "0x{:08x}", target)
-            }
-            DecodedInstruction::Bclr {
-                condition,
-                ctr,
-                link,
-            } => {
-                write!(f, "b{}{}lr{}", ctr, condition, if link { "l" } else { "" })?;
-                if let Some(cr) = condition.crf().and_then(|crf| crf.nonzero()) {
-                    write!(f, " {}", cr)?;
+            DecodedInstruction::Nor { dst, srcs, record } => write!(
+                f,
+                "nor{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Or { dst, srcs, record } => {
+                if srcs[0] != srcs[1] {
+                    write!(
+                        f,
+                        "or{} {}, {}, {}",
+                        if record { "." } else { "" },
+                        dst,
+                        srcs[0],
+                        srcs[1]
+                    )
+                } else {
+                    write!(
+                        f,
+                        "mr{} {}, {}",
+                        if record { "." } else { "" },
+                        dst,
+                        srcs[0]
+                    )
                 }
-                Ok(())
             }
-            DecodedInstruction::Cmpi {
-                crf,
+            DecodedInstruction::Orc { dst, srcs, record } => write!(
+                f,
+                "orc{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Ori {
+                dst,
                 src,
                 immediate,
-            } => {
-                write!(f, "cmpwi ")?;
-                if crf.get() > 0 {
-                    write!(f, "{}, ", crf)?;
-                }
-                write!(f, "{}, {}", src, FormatSmallI16(immediate))
+            } => write!(f, "ori {}, {}, {}", dst, src, FormatSmallU16(immediate)),
+            DecodedInstruction::Oris {
+                dst,
+                src,
+                immediate,
+            } => write!(f, "oris {}, {}, {}", dst, src, FormatSmallU16(immediate)),
+            DecodedInstruction::PsAbs { dst, src, record } => write!(
+                f,
+                "ps_abs{} {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::PsAdd { dst, srcs, record } => write!(
+                f,
+                "ps_add{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsCmpo0 { crf, srcs } => {
+                write!(f, "ps_cmpo0 {}, {}, {}", crf, srcs[0], srcs[1])
             }
-            DecodedInstruction::Cmpl { crf, srcs } => {
-                write!(f, "cmplw ")?;
-                if crf.get() > 0 {
-                    write!(f, "{}, ", crf)?;
-                }
-                write!(f, "{}, {}", srcs[0], srcs[1])
+            DecodedInstruction::PsCmpo1 { crf, srcs } => {
+                write!(f, "ps_cmpo1 {}, {}, {}", crf, srcs[0], srcs[1])
             }
-            DecodedInstruction::Cmpli {
-                crf,
+            DecodedInstruction::PsCmpu0 { crf, srcs } => {
+                write!(f, "ps_cmpu0 {}, {}, {}", crf, srcs[0], srcs[1])
+            }
+            DecodedInstruction::PsCmpu1 { crf, srcs } => {
+                write!(f, "ps_cmpu1 {}, {}, {}", crf, srcs[0], srcs[1])
+            }
+            DecodedInstruction::PsDiv { dst, srcs, record } => write!(
+                f,
+                "ps_div{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsMadd { dst, srcs, record } => write!(
+                f,
+                "ps_madd{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::PsMadds0 { dst, srcs, record } => write!(
+                f,
+                "ps_madds0{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::PsMadds1 { dst, srcs, record } => write!(
+                f,
+                "ps_madds1{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::PsMerge00 { dst, srcs, record } => write!(
+                f,
+                "ps_merge00{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsMerge01 { dst, srcs, record } => write!(
+                f,
+                "ps_merge01{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsMerge10 { dst, srcs, record } => write!(
+                f,
+                "ps_merge10{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsMerge11 { dst, srcs, record } => write!(
+                f,
+                "ps_merge11{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsMr { dst, src, record } => {
+                write!(f, "ps_mr{} {}, {}", if record { "." } else { "" }, dst, src,)
+            }
+            DecodedInstruction::PsMsub { dst, srcs, record } => write!(
+                f,
+                "ps_msub{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::PsMul { dst, srcs, record } => write!(
+                f,
+                "ps_mul{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsMuls0 { dst, srcs, record } => write!(
+                f,
+                "ps_muls0{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsMuls1 { dst, srcs, record } => write!(
+                f,
+                "ps_muls1{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsNabs { dst, src, record } => write!(
+                f,
+                "ps_nabs{} {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::PsNeg { dst, src, record } => write!(
+                f,
+                "ps_neg{} {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::PsNmadd { dst, srcs, record } => write!(
+                f,
+                "ps_nmadd{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::PsNmsub { dst, srcs, record } => write!(
+                f,
+                "ps_nmsub{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::PsRes { dst, src, record } => write!(
+                f,
+                "ps_res{} {}, {}",
+                if record { "." } else { "" },
+                dst,
                 src,
-                immediate,
+            ),
+            DecodedInstruction::PsRsqrte { dst, src, record } => write!(
+                f,
+                "ps_rsqrte{} {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::PsSel { dst, srcs, record } => write!(
+                f,
+                "ps_sel{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::PsSub { dst, srcs, record } => write!(
+                f,
+                "ps_sub{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::PsSum0 { dst, srcs, record } => write!(
+                f,
+                "ps_sum0{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::PsSum1 { dst, srcs, record } => write!(
+                f,
+                "ps_sum1{} {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+                srcs[2],
+            ),
+            DecodedInstruction::PsqL {
+                dst,
+                offset,
+                base,
+                single,
+                gqr,
             } => {
-                write!(f, "cmplwi ")?;
-                if crf.get() > 0 {
-                    write!(f, "{}, ", crf)?;
-                }
-                write!(f, "{}, {}", src, FormatSmallU16(immediate))
-            }
-            DecodedInstruction::Crxor { dst, srcs } => {
-                write!(f, "crxor {}, {}, {}", dst, srcs[0], srcs[1])
-            }
-            DecodedInstruction::Lbz { dst, offset, base } => {
-                write!(f, "lbz {}, ", dst)?;
+                write!(f, "psq_l {}, ", dst)?;
                 if offset != 0 {
                     write!(f, "{}", FormatSmallI16(offset))?;
                 }
-                write!(f, "({})", base)
+                write!(f, "({}), {}, qr{}", base, single as u8, gqr)
             }
-            DecodedInstruction::Lha { dst, offset, base } => {
-                write!(f, "lha {}, ", dst)?;
+            DecodedInstruction::PsqLu {
+                dst,
+                offset,
+                base,
+                single,
+                gqr,
+            } => {
+                write!(f, "psq_lu {}, ", dst)?;
                 if offset != 0 {
                     write!(f, "{}", FormatSmallI16(offset))?;
                 }
-                write!(f, "({})", base)
+                write!(f, "({}), {}, qr{}", base, single as u8, gqr)
             }
-            DecodedInstruction::Lwz { dst, offset, base } => {
-                write!(f, "lwz {}, ", dst)?;
+            DecodedInstruction::PsqLux {
+                dst,
+                base,
+                index,
+                single,
+                gqr,
+            } => write!(
+                f,
+                "psq_lux {}, {}, {}, {}, qr{}",
+                dst, base, index, single as u8, gqr,
+            ),
+            DecodedInstruction::PsqLx {
+                dst,
+                base,
+                index,
+                single,
+                gqr,
+            } => write!(
+                f,
+                "psq_lx {}, {}, {}, {}, qr{}",
+                dst, base, index, single as u8, gqr,
+            ),
+            DecodedInstruction::PsqSt {
+                src,
+                offset,
+                base,
+                single,
+                gqr,
+            } => {
+                write!(f, "psq_st {}, ", src)?;
                 if offset != 0 {
                     write!(f, "{}", FormatSmallI16(offset))?;
                 }
-                write!(f, "({})", base)
+                write!(f, "({}), {}, qr{}", base, single as u8, gqr)
             }
-            DecodedInstruction::Mfspr { spr, dst } => write!(f, "mf{} {}", spr, dst),
-            DecodedInstruction::Mtspr { spr, src } => write!(f, "mt{} {}", spr, src),
-            DecodedInstruction::Or { dst, srcs, record } => {
-                if srcs[0] != srcs[1] {
-                    write!(
-                        f,
-                        "or{} {}, {}, {}",
-                        if record { "." } else { "" },
-                        dst,
-                        srcs[0],
-                        srcs[1]
-                    )
-                } else {
-                    write!(
-                        f,
-                        "mr{} {}, {}",
-                        if record { "." } else { "" },
-                        dst,
-                        srcs[0]
-                    )
+            DecodedInstruction::PsqStu {
+                src,
+                offset,
+                base,
+                single,
+                gqr,
+            } => {
+                write!(f, "psq_stu {}, ", src)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
                 }
+                write!(f, "({}), {}, qr{}", base, single as u8, gqr)
             }
+            DecodedInstruction::PsqStux {
+                src,
+                base,
+                index,
+                single,
+                gqr,
+            } => write!(
+                f,
+                "psq_stux {}, {}, {}, {}, qr{}",
+                src, base, index, single as u8, gqr,
+            ),
+            DecodedInstruction::PsqStx {
+                src,
+                base,
+                index,
+                single,
+                gqr,
+            } => write!(
+                f,
+                "psq_stx {}, {}, {}, {}, qr{}",
+                src, base, index, single as u8, gqr,
+            ),
+            DecodedInstruction::Rfi => write!(f, "rfi"),
+            DecodedInstruction::Rlwimi {
+                dst,
+                src,
+                shift,
+                mask_begin,
+                mask_end,
+                record,
+            } => write!(
+                f,
+                "rlwimi{} {}, {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+                shift,
+                mask_begin,
+                mask_end,
+            ),
             DecodedInstruction::Rlwinm {
                 dst,
                 src,
@@ -324,6 +2274,40 @@ impl Display for DecodedInstruction {
                 mask_begin,
                 mask_end,
             ),
+            DecodedInstruction::Rlwnm {
+                dst,
+                src,
+                shift,
+                mask_begin,
+                mask_end,
+                record,
+            } => write!(
+                f,
+                "rlwnm{} {}, {}, {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                src,
+                shift,
+                mask_begin,
+                mask_end,
+            ),
+            DecodedInstruction::Sc => write!(f, "sc"),
+            DecodedInstruction::Slw { dst, srcs, record } => write!(
+                f,
+                "slw{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Sraw { dst, srcs, record } => write!(
+                f,
+                "sraw{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
             DecodedInstruction::Srawi {
                 dst,
                 src,
@@ -339,6 +2323,100 @@ impl Display for DecodedInstruction {
                     shift,
                 )
             }
+            DecodedInstruction::Srw { dst, srcs, record } => write!(
+                f,
+                "srw{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Stb { src, offset, base } => {
+                write!(f, "stb {}, ", src)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Stbu { src, offset, base } => {
+                write!(f, "stbu {}, ", src)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Stbux { src, base, index } => {
+                write!(f, "stbux {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Stbx { src, base, index } => {
+                write!(f, "stbx {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Stfd { src, offset, base } => {
+                write!(f, "stfd {}, ", src)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Stfdu { src, offset, base } => {
+                write!(f, "stfdu {}, ", src)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Stfdux { src, base, index } => {
+                write!(f, "stfdux {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Stfdx { src, base, index } => {
+                write!(f, "stfdx {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Stfiwx { src, base, index } => {
+                write!(f, "stfiwx {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Stfs { src, offset, base } => {
+                write!(f, "stfs {}, ", src)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Stfsu { src, offset, base } => {
+                write!(f, "stfsu {}, ", src)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Stfsux { src, base, index } => {
+                write!(f, "stfsux {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Stfsx { src, base, index } => {
+                write!(f, "stfsx {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Sth { src, offset, base } => {
+                write!(f, "sth {}, ", src)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Sthbrx { src, base, index } => {
+                write!(f, "sthbrx {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Sthu { src, offset, base } => {
+                write!(f, "sthu {}, ", src)?;
+                if offset != 0 {
+                    write!(f, "{}", FormatSmallI16(offset))?;
+                }
+                write!(f, "({})", base)
+            }
+            DecodedInstruction::Sthux { src, base, index } => {
+                write!(f, "sthux {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Sthx { src, base, index } => {
+                write!(f, "sthx {}, {}, {}", src, base, index)
+            }
             DecodedInstruction::Stmw { src, offset, base } => {
                 write!(f, "stmw {}, ", src)?;
                 if offset != 0 {
@@ -353,6 +2431,9 @@ impl Display for DecodedInstruction {
                 }
                 write!(f, "({})", base)
             }
+            DecodedInstruction::Stwbrx { src, base, index } => {
+                write!(f, "stwbrx {}, {}, {}", src, base, index)
+            }
             DecodedInstruction::Stwu { src, offset, base } => {
                 write!(f, "stwu {}, ", src)?;
                 if offset != 0 {
@@ -360,6 +2441,130 @@ impl Display for DecodedInstruction {
                 }
                 write!(f, "({})", base)
             }
+            DecodedInstruction::Stwux { src, base, index } => {
+                write!(f, "stwux {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Stwx { src, base, index } => {
+                write!(f, "stwx {}, {}, {}", src, base, index)
+            }
+            DecodedInstruction::Subf {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "subf{}{} {}, {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Subfc {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "subfc{}{} {}, {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Subfe {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "subfe{}{} {}, {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Subfic {
+                dst,
+                src,
+                immediate,
+            } => write!(f, "subfic {}, {}, {}", dst, src, FormatSmallI16(immediate)),
+            DecodedInstruction::Subfme {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "subfme{}{} {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::Subfze {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => write!(
+                f,
+                "subfze{}{} {}, {}",
+                if overflow_enable { "o" } else { "" },
+                if record { "." } else { "" },
+                dst,
+                src,
+            ),
+            DecodedInstruction::Sync => write!(f, "sync"),
+            DecodedInstruction::Tw { to, srcs } => write!(
+                f,
+                "tw {}, {}, {}",
+                FormatSmallU16(to as u16),
+                srcs[0],
+                srcs[1]
+            ),
+            DecodedInstruction::Twi { to, src, immediate } => write!(
+                f,
+                "twi {}, {}, {}",
+                FormatSmallU16(to as u16),
+                src,
+                FormatSmallI16(immediate)
+            ),
+            DecodedInstruction::Xor { dst, srcs, record } => write!(
+                f,
+                "xor{} {}, {}, {}",
+                if record { "." } else { "" },
+                dst,
+                srcs[0],
+                srcs[1],
+            ),
+            DecodedInstruction::Xori {
+                dst,
+                src,
+                immediate,
+            } => write!(f, "xori {}, {}, {}", dst, src, FormatSmallU16(immediate)),
+            DecodedInstruction::Xoris {
+                dst,
+                src,
+                immediate,
+            } => write!(f, "xoris {}, {}, {}", dst, src, FormatSmallU16(immediate)),
+        }
+    }
+}
+
+/// Formats an optional branch hint, printing nothing when there is none.
+struct FormatHint(Option<BranchHint>);
+
+impl Display for FormatHint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(hint) => write!(f, "{}", hint),
+            None => Ok(()),
         }
     }
 }
@@ -0,0 +1,103 @@
+use crate::{DecodedInstruction, EncodedInstruction, ParseError};
+
+/// A section of code decoded once up front, so that looking up an instruction again is just an
+/// index into a dense array.
+#[derive(Clone, Debug)]
+pub struct DecodedSection {
+    address: u32,
+    instructions: Vec<Result<DecodedInstruction, ParseError>>,
+}
+
+impl DecodedSection {
+    /// Decodes `data`, a section of big-endian words loaded at `address`. Bytes past the last
+    /// whole word are ignored.
+    pub fn new(address: u32, data: &[u8]) -> DecodedSection {
+        let instructions = data
+            .chunks_exact(4)
+            .zip((address..=u32::MAX).step_by(4))
+            .map(|(word, address)| {
+                let word = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+                EncodedInstruction(word).parse(address)
+            })
+            .collect();
+        DecodedSection {
+            address,
+            instructions,
+        }
+    }
+
+    pub fn address(&self) -> u32 {
+        self.address
+    }
+
+    /// The address just past the last instruction, which is 2^32 for a section that reaches the
+    /// top of the address space.
+    pub fn end_address(&self) -> u64 {
+        u64::from(self.address) + 4 * self.instructions.len() as u64
+    }
+
+    /// Returns the instruction at `address`, or `None` if the address is outside the section or
+    /// not word-aligned.
+    pub fn get(&self, address: u32) -> Option<Result<DecodedInstruction, ParseError>> {
+        let offset = address.checked_sub(self.address)?;
+        if offset % 4 != 0 {
+            return None;
+        }
+        self.instructions.get((offset / 4) as usize).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u32, Result<DecodedInstruction, ParseError>)> + '_ {
+        (self.address..=u32::MAX)
+            .step_by(4)
+            .zip(self.instructions.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DecodedSection;
+    use crate::{assemble, ParseErrorKind};
+
+    #[test]
+    fn get() {
+        let data = [
+            0x38, 0x60, 0x00, 0x01, // li r3, 1
+            0x00, 0x00, 0x00, 0x00, // unimplemented
+            0x4b, 0xff, 0xff, 0xf8, // b -8
+            0x4e, 0x80, // partial word
+        ];
+        let section = DecodedSection::new(0x80003000, &data);
+        assert_eq!(section.address(), 0x80003000);
+        assert_eq!(section.end_address(), 0x8000300c);
+
+        assert_eq!(
+            section.get(0x80003000),
+            Some(Ok(assemble("li r3, 1", 0x80003000).unwrap()))
+        );
+        assert_eq!(
+            section.get(0x80003004).unwrap().unwrap_err().kind,
+            ParseErrorKind::UnimplementedOpcode(0)
+        );
+        assert_eq!(
+            section.get(0x80003008),
+            Some(Ok(assemble("b 0x80003000", 0x80003008).unwrap()))
+        );
+        assert_eq!(section.get(0x80002ffc), None);
+        assert_eq!(section.get(0x80003002), None);
+        assert_eq!(section.get(0x8000300c), None);
+
+        let addresses: Vec<u32> = section.iter().map(|(address, _)| address).collect();
+        assert_eq!(addresses, [0x80003000, 0x80003004, 0x80003008]);
+    }
+
+    #[test]
+    fn top_of_address_space() {
+        let data = [0x60, 0x00, 0x00, 0x00, 0x60, 0x00, 0x00, 0x00]; // nop, nop
+        let section = DecodedSection::new(0xfffffff8, &data);
+        assert_eq!(section.end_address(), 1 << 32);
+        assert!(section.get(0xfffffffc).unwrap().is_ok());
+
+        let addresses: Vec<u32> = section.iter().map(|(address, _)| address).collect();
+        assert_eq!(addresses, [0xfffffff8, 0xfffffffc]);
+    }
+}
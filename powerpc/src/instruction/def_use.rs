@@ -0,0 +1,798 @@
+use crate::{
+    ConditionBehavior, Crf, CtrBehavior, DecodedInstruction, Gpr, Register, RegisterSet, Spr, Sr,
+};
+
+impl DecodedInstruction {
+    /// The registers the instruction reads, including implicit ones such as the count register of
+    /// `bdnz` and XER, whose summary overflow bit record forms copy into CR0.
+    pub fn uses(self) -> RegisterSet {
+        self.effects().uses
+    }
+
+    /// The registers the instruction writes, including implicit ones such as CR0 for record forms,
+    /// the base of update forms and the link register of `bl`.
+    ///
+    /// An instruction that writes only part of XER or the FPSCR, such as `addc` setting XER[CA],
+    /// also lists the register among its uses, since the rest of it passes through unchanged.
+    pub fn defs(self) -> RegisterSet {
+        self.effects().defs
+    }
+
+    fn effects(self) -> Effects {
+        let mut e = Effects::default();
+        match self {
+            DecodedInstruction::Add {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Divw {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Divwu {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Mullw {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Subf {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => {
+                e.read(srcs[0]).read(srcs[1]).write(dst);
+                e.overflow(overflow_enable).record(record);
+            }
+            DecodedInstruction::Addc {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Adde {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Subfc {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Subfe {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => {
+                e.read(srcs[0]).read(srcs[1]).write(dst).carry();
+                e.overflow(overflow_enable).record(record);
+            }
+            DecodedInstruction::Addi { dst, src, .. }
+            | DecodedInstruction::Addis { dst, src, .. } => {
+                e.read(src).write(dst);
+            }
+            DecodedInstruction::Addic {
+                dst, src, record, ..
+            } => {
+                e.read(src).write(dst).carry().record(record);
+            }
+            DecodedInstruction::Addme {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Addze {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Subfme {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            }
+            | DecodedInstruction::Subfze {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => {
+                e.read(src).write(dst).carry();
+                e.overflow(overflow_enable).record(record);
+            }
+            DecodedInstruction::Neg {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => {
+                e.read(src).write(dst);
+                e.overflow(overflow_enable).record(record);
+            }
+            DecodedInstruction::And { dst, srcs, record }
+            | DecodedInstruction::Andc { dst, srcs, record }
+            | DecodedInstruction::Eqv { dst, srcs, record }
+            | DecodedInstruction::Mulhw { dst, srcs, record }
+            | DecodedInstruction::Mulhwu { dst, srcs, record }
+            | DecodedInstruction::Nand { dst, srcs, record }
+            | DecodedInstruction::Nor { dst, srcs, record }
+            | DecodedInstruction::Or { dst, srcs, record }
+            | DecodedInstruction::Orc { dst, srcs, record }
+            | DecodedInstruction::Slw { dst, srcs, record }
+            | DecodedInstruction::Srw { dst, srcs, record }
+            | DecodedInstruction::Xor { dst, srcs, record } => {
+                e.read(srcs[0]).read(srcs[1]).write(dst).record(record);
+            }
+            DecodedInstruction::Sraw { dst, srcs, record } => {
+                e.read(srcs[0])
+                    .read(srcs[1])
+                    .write(dst)
+                    .carry()
+                    .record(record);
+            }
+            DecodedInstruction::Andi { dst, src, .. }
+            | DecodedInstruction::Andis { dst, src, .. } => {
+                // Always record forms.
+                e.read(src).write(dst).record(true);
+            }
+            DecodedInstruction::Ori { dst, src, .. }
+            | DecodedInstruction::Oris { dst, src, .. }
+            | DecodedInstruction::Xori { dst, src, .. }
+            | DecodedInstruction::Xoris { dst, src, .. }
+            | DecodedInstruction::Mulli { dst, src, .. } => {
+                e.read(src).write(dst);
+            }
+            DecodedInstruction::Subfic { dst, src, .. } => {
+                e.read(src).write(dst).carry();
+            }
+            DecodedInstruction::B { link, .. } => {
+                e.link(link);
+            }
+            DecodedInstruction::Bc {
+                condition,
+                ctr,
+                link,
+                ..
+            } => {
+                e.branch(condition, ctr).link(link);
+            }
+            DecodedInstruction::Bcctr {
+                condition, link, ..
+            } => {
+                e.branch(condition, CtrBehavior::None)
+                    .read(Spr::Count)
+                    .link(link);
+            }
+            DecodedInstruction::Bclr {
+                condition,
+                ctr,
+                link,
+                ..
+            } => {
+                e.branch(condition, ctr).read(Spr::Link).link(link);
+            }
+            DecodedInstruction::Cmp { crf, srcs } | DecodedInstruction::Cmpl { crf, srcs } => {
+                e.read(srcs[0]).read(srcs[1]).compare(crf);
+            }
+            DecodedInstruction::Cmpi { crf, src, .. }
+            | DecodedInstruction::Cmpli { crf, src, .. } => {
+                e.read(src).compare(crf);
+            }
+            DecodedInstruction::Cntlzw { dst, src, record }
+            | DecodedInstruction::Extsb { dst, src, record }
+            | DecodedInstruction::Extsh { dst, src, record } => {
+                e.read(src).write(dst).record(record);
+            }
+            DecodedInstruction::Crand { dst, srcs }
+            | DecodedInstruction::Crandc { dst, srcs }
+            | DecodedInstruction::Creqv { dst, srcs }
+            | DecodedInstruction::Crnand { dst, srcs }
+            | DecodedInstruction::Crnor { dst, srcs }
+            | DecodedInstruction::Cror { dst, srcs }
+            | DecodedInstruction::Crorc { dst, srcs }
+            | DecodedInstruction::Crxor { dst, srcs } => {
+                e.read(srcs[0]).read(srcs[1]).write(dst);
+            }
+            DecodedInstruction::Dcbf { base, index }
+            | DecodedInstruction::Dcbi { base, index }
+            | DecodedInstruction::Dcbst { base, index }
+            | DecodedInstruction::Dcbt { base, index }
+            | DecodedInstruction::Dcbtst { base, index }
+            | DecodedInstruction::Dcbz { base, index }
+            | DecodedInstruction::DcbzL { base, index }
+            | DecodedInstruction::Icbi { base, index } => {
+                e.read(base).read(index);
+            }
+            // Barriers only order memory accesses and instruction fetches.
+            DecodedInstruction::Eieio | DecodedInstruction::Isync | DecodedInstruction::Sync => {}
+            // Moves and sign changes don't raise exceptions, so they leave the FPSCR alone.
+            DecodedInstruction::Fabs { dst, src, record }
+            | DecodedInstruction::Fmr { dst, src, record }
+            | DecodedInstruction::Fnabs { dst, src, record }
+            | DecodedInstruction::Fneg { dst, src, record }
+            | DecodedInstruction::PsAbs { dst, src, record }
+            | DecodedInstruction::PsMr { dst, src, record }
+            | DecodedInstruction::PsNabs { dst, src, record }
+            | DecodedInstruction::PsNeg { dst, src, record } => {
+                e.read(src).write(dst).record_float(record);
+            }
+            DecodedInstruction::Fctiw { dst, src, record }
+            | DecodedInstruction::Fctiwz { dst, src, record }
+            | DecodedInstruction::Fres { dst, src, record }
+            | DecodedInstruction::Frsp { dst, src, record }
+            | DecodedInstruction::Frsqrte { dst, src, record }
+            | DecodedInstruction::PsRes { dst, src, record }
+            | DecodedInstruction::PsRsqrte { dst, src, record } => {
+                e.read(src).write(dst).update(Register::Fpscr);
+                e.record_float(record);
+            }
+            DecodedInstruction::PsMerge00 { dst, srcs, record }
+            | DecodedInstruction::PsMerge01 { dst, srcs, record }
+            | DecodedInstruction::PsMerge10 { dst, srcs, record }
+            | DecodedInstruction::PsMerge11 { dst, srcs, record } => {
+                e.read(srcs[0])
+                    .read(srcs[1])
+                    .write(dst)
+                    .record_float(record);
+            }
+            DecodedInstruction::Fadd { dst, srcs, record }
+            | DecodedInstruction::Fadds { dst, srcs, record }
+            | DecodedInstruction::Fdiv { dst, srcs, record }
+            | DecodedInstruction::Fdivs { dst, srcs, record }
+            | DecodedInstruction::Fmul { dst, srcs, record }
+            | DecodedInstruction::Fmuls { dst, srcs, record }
+            | DecodedInstruction::Fsub { dst, srcs, record }
+            | DecodedInstruction::Fsubs { dst, srcs, record }
+            | DecodedInstruction::PsAdd { dst, srcs, record }
+            | DecodedInstruction::PsDiv { dst, srcs, record }
+            | DecodedInstruction::PsMul { dst, srcs, record }
+            | DecodedInstruction::PsMuls0 { dst, srcs, record }
+            | DecodedInstruction::PsMuls1 { dst, srcs, record }
+            | DecodedInstruction::PsSub { dst, srcs, record } => {
+                e.read(srcs[0])
+                    .read(srcs[1])
+                    .write(dst)
+                    .update(Register::Fpscr);
+                e.record_float(record);
+            }
+            DecodedInstruction::Fsel { dst, srcs, record }
+            | DecodedInstruction::PsSel { dst, srcs, record } => {
+                e.read(srcs[0]).read(srcs[1]).read(srcs[2]).write(dst);
+                e.record_float(record);
+            }
+            DecodedInstruction::Fmadd { dst, srcs, record }
+            | DecodedInstruction::Fmadds { dst, srcs, record }
+            | DecodedInstruction::Fmsub { dst, srcs, record }
+            | DecodedInstruction::Fmsubs { dst, srcs, record }
+            | DecodedInstruction::Fnmadd { dst, srcs, record }
+            | DecodedInstruction::Fnmadds { dst, srcs, record }
+            | DecodedInstruction::Fnmsub { dst, srcs, record }
+            | DecodedInstruction::Fnmsubs { dst, srcs, record }
+            | DecodedInstruction::PsMadd { dst, srcs, record }
+            | DecodedInstruction::PsMadds0 { dst, srcs, record }
+            | DecodedInstruction::PsMadds1 { dst, srcs, record }
+            | DecodedInstruction::PsMsub { dst, srcs, record }
+            | DecodedInstruction::PsNmadd { dst, srcs, record }
+            | DecodedInstruction::PsNmsub { dst, srcs, record } => {
+                e.read(srcs[0]).read(srcs[1]).read(srcs[2]).write(dst);
+                e.update(Register::Fpscr).record_float(record);
+            }
+            DecodedInstruction::PsSum0 { dst, srcs, record }
+            | DecodedInstruction::PsSum1 { dst, srcs, record } => {
+                // The slot of `dst` that isn't computed is copied from the third source, so the
+                // destination isn't read.
+                e.read(srcs[0]).read(srcs[1]).read(srcs[2]).write(dst);
+                e.update(Register::Fpscr).record_float(record);
+            }
+            DecodedInstruction::Fcmpo { crf, srcs }
+            | DecodedInstruction::Fcmpu { crf, srcs }
+            | DecodedInstruction::PsCmpo0 { crf, srcs }
+            | DecodedInstruction::PsCmpo1 { crf, srcs }
+            | DecodedInstruction::PsCmpu0 { crf, srcs }
+            | DecodedInstruction::PsCmpu1 { crf, srcs } => {
+                e.read(srcs[0]).read(srcs[1]).write(crf);
+                e.update(Register::Fpscr);
+            }
+            DecodedInstruction::Lbz { dst, base, .. }
+            | DecodedInstruction::Lha { dst, base, .. }
+            | DecodedInstruction::Lhz { dst, base, .. }
+            | DecodedInstruction::Lwz { dst, base, .. } => {
+                e.read(base).write(dst);
+            }
+            DecodedInstruction::Lbzu { dst, base, .. }
+            | DecodedInstruction::Lhau { dst, base, .. }
+            | DecodedInstruction::Lhzu { dst, base, .. }
+            | DecodedInstruction::Lwzu { dst, base, .. } => {
+                e.update(base).write(dst);
+            }
+            DecodedInstruction::Lbzux { dst, base, index }
+            | DecodedInstruction::Lhaux { dst, base, index }
+            | DecodedInstruction::Lhzux { dst, base, index }
+            | DecodedInstruction::Lwzux { dst, base, index } => {
+                e.update(base).read(index).write(dst);
+            }
+            DecodedInstruction::Lbzx { dst, base, index }
+            | DecodedInstruction::Lhax { dst, base, index }
+            | DecodedInstruction::Lhbrx { dst, base, index }
+            | DecodedInstruction::Lhzx { dst, base, index }
+            | DecodedInstruction::Lwbrx { dst, base, index }
+            | DecodedInstruction::Lwzx { dst, base, index } => {
+                e.read(base).read(index).write(dst);
+            }
+            DecodedInstruction::Lfd { dst, base, .. }
+            | DecodedInstruction::Lfs { dst, base, .. } => {
+                e.read(base).write(dst);
+            }
+            DecodedInstruction::Lfdu { dst, base, .. }
+            | DecodedInstruction::Lfsu { dst, base, .. } => {
+                e.update(base).write(dst);
+            }
+            DecodedInstruction::Lfdux { dst, base, index }
+            | DecodedInstruction::Lfsux { dst, base, index } => {
+                e.update(base).read(index).write(dst);
+            }
+            DecodedInstruction::Lfdx { dst, base, index }
+            | DecodedInstruction::Lfsx { dst, base, index } => {
+                e.read(base).read(index).write(dst);
+            }
+            DecodedInstruction::Lmw { dst, base, .. } => {
+                e.read(base);
+                for i in dst.as_u32()..32 {
+                    e.write(Gpr::new(i).unwrap());
+                }
+            }
+            DecodedInstruction::Mcrf { dst, src } => {
+                e.read(src).write(dst);
+            }
+            DecodedInstruction::Mcrfs { dst, .. } => {
+                // Copying an FPSCR field clears its exception bits.
+                e.update(Register::Fpscr).write(dst);
+            }
+            DecodedInstruction::Mcrxr { dst } => {
+                // Copying XER[SO, OV, CA] clears them.
+                e.update(Spr::IntegerException).write(dst);
+            }
+            DecodedInstruction::Mfcr { dst } => {
+                for crf in 0..8 {
+                    e.read(Crf::new(crf).unwrap());
+                }
+                e.write(dst);
+            }
+            DecodedInstruction::Mffs { dst, record } => {
+                e.read(Register::Fpscr).write(dst).record_float(record);
+            }
+            DecodedInstruction::Mfmsr { dst } => {
+                e.read(Register::Msr).write(dst);
+            }
+            DecodedInstruction::Mfspr { spr, dst } => {
+                e.read(spr).write(dst);
+            }
+            DecodedInstruction::Mfsr { dst, sr } => {
+                e.read(sr).write(dst);
+            }
+            DecodedInstruction::Mfsrin { dst, index } => {
+                e.read(index).write(dst);
+                for sr in 0..16 {
+                    e.read(Sr::new(sr).unwrap());
+                }
+            }
+            DecodedInstruction::Mftb { spr, dst } => {
+                e.read(spr).write(dst);
+            }
+            DecodedInstruction::Mtcrf { fields, src } => {
+                e.read(src);
+                for crf in 0..8 {
+                    if fields & (0x80 >> crf) != 0 {
+                        e.write(Crf::new(crf).unwrap());
+                    }
+                }
+            }
+            DecodedInstruction::Mtfsb0 { record, .. }
+            | DecodedInstruction::Mtfsb1 { record, .. }
+            | DecodedInstruction::Mtfsfi { record, .. } => {
+                e.update(Register::Fpscr).record_float(record);
+            }
+            DecodedInstruction::Mtfsf { src, record, .. } => {
+                e.read(src).update(Register::Fpscr).record_float(record);
+            }
+            DecodedInstruction::Mtmsr { src } => {
+                e.read(src).write(Register::Msr);
+            }
+            DecodedInstruction::Mtspr { spr, src } => {
+                e.read(src).write(spr);
+            }
+            DecodedInstruction::Mtsr { sr, src } => {
+                e.read(src).write(sr);
+            }
+            DecodedInstruction::Mtsrin { src, index } => {
+                // Any one of the segment registers may be written, so they all pass through.
+                e.read(src).read(index);
+                for sr in 0..16 {
+                    e.update(Sr::new(sr).unwrap());
+                }
+            }
+            DecodedInstruction::PsqL { dst, base, gqr, .. } => {
+                e.read(base).read(gqr_spr(gqr)).write(dst);
+            }
+            DecodedInstruction::PsqLu { dst, base, gqr, .. } => {
+                e.update(base).read(gqr_spr(gqr)).write(dst);
+            }
+            DecodedInstruction::PsqLux {
+                dst,
+                base,
+                index,
+                gqr,
+                ..
+            } => {
+                e.update(base).read(index).read(gqr_spr(gqr)).write(dst);
+            }
+            DecodedInstruction::PsqLx {
+                dst,
+                base,
+                index,
+                gqr,
+                ..
+            } => {
+                e.read(base).read(index).read(gqr_spr(gqr)).write(dst);
+            }
+            DecodedInstruction::PsqSt { src, base, gqr, .. } => {
+                e.read(src).read(base).read(gqr_spr(gqr));
+            }
+            DecodedInstruction::PsqStu { src, base, gqr, .. } => {
+                e.read(src).update(base).read(gqr_spr(gqr));
+            }
+            DecodedInstruction::PsqStux {
+                src,
+                base,
+                index,
+                gqr,
+                ..
+            } => {
+                e.read(src).update(base).read(index).read(gqr_spr(gqr));
+            }
+            DecodedInstruction::PsqStx {
+                src,
+                base,
+                index,
+                gqr,
+                ..
+            } => {
+                e.read(src).read(base).read(index).read(gqr_spr(gqr));
+            }
+            DecodedInstruction::Rfi => {
+                e.read(Spr::Srr0).read(Spr::Srr1).write(Register::Msr);
+            }
+            DecodedInstruction::Rlwimi {
+                dst, src, record, ..
+            } => {
+                // Bits outside the mask keep their old value.
+                e.read(src).update(dst).record(record);
+            }
+            DecodedInstruction::Rlwinm {
+                dst, src, record, ..
+            } => {
+                e.read(src).write(dst).record(record);
+            }
+            DecodedInstruction::Rlwnm {
+                dst,
+                src,
+                shift,
+                record,
+                ..
+            } => {
+                e.read(src).read(shift).write(dst).record(record);
+            }
+            DecodedInstruction::Srawi {
+                dst, src, record, ..
+            } => {
+                e.read(src).write(dst).carry().record(record);
+            }
+            DecodedInstruction::Sc => {
+                e.update(Register::Msr).write(Spr::Srr0).write(Spr::Srr1);
+            }
+            DecodedInstruction::Stb { src, base, .. }
+            | DecodedInstruction::Sth { src, base, .. }
+            | DecodedInstruction::Stw { src, base, .. } => {
+                e.read(src).read(base);
+            }
+            DecodedInstruction::Stbu { src, base, .. }
+            | DecodedInstruction::Sthu { src, base, .. }
+            | DecodedInstruction::Stwu { src, base, .. } => {
+                e.read(src).update(base);
+            }
+            DecodedInstruction::Stbux { src, base, index }
+            | DecodedInstruction::Sthux { src, base, index }
+            | DecodedInstruction::Stwux { src, base, index } => {
+                e.read(src).update(base).read(index);
+            }
+            DecodedInstruction::Stbx { src, base, index }
+            | DecodedInstruction::Sthbrx { src, base, index }
+            | DecodedInstruction::Sthx { src, base, index }
+            | DecodedInstruction::Stwbrx { src, base, index }
+            | DecodedInstruction::Stwx { src, base, index } => {
+                e.read(src).read(base).read(index);
+            }
+            DecodedInstruction::Stfd { src, base, .. }
+            | DecodedInstruction::Stfs { src, base, .. } => {
+                e.read(src).read(base);
+            }
+            DecodedInstruction::Stfdu { src, base, .. }
+            | DecodedInstruction::Stfsu { src, base, .. } => {
+                e.read(src).update(base);
+            }
+            DecodedInstruction::Stfdux { src, base, index }
+            | DecodedInstruction::Stfsux { src, base, index } => {
+                e.read(src).update(base).read(index);
+            }
+            DecodedInstruction::Stfdx { src, base, index }
+            | DecodedInstruction::Stfiwx { src, base, index }
+            | DecodedInstruction::Stfsx { src, base, index } => {
+                e.read(src).read(base).read(index);
+            }
+            DecodedInstruction::Stmw { src, base, .. } => {
+                e.read(base);
+                for i in src.as_u32()..32 {
+                    e.read(Gpr::new(i).unwrap());
+                }
+            }
+            DecodedInstruction::Tw { srcs, .. } => {
+                e.read(srcs[0]).read(srcs[1]);
+            }
+            DecodedInstruction::Twi { src, .. } => {
+                e.read(src);
+            }
+        }
+        e
+    }
+}
+
+/// The SPR holding graphics quantization register `gqr`.
+fn gqr_spr(gqr: u8) -> Spr {
+    Spr::ALL[Spr::Gqr0 as usize + gqr as usize]
+}
+
+#[derive(Default)]
+struct Effects {
+    uses: RegisterSet,
+    defs: RegisterSet,
+}
+
+impl Effects {
+    fn read(&mut self, register: impl Into<Register>) -> &mut Effects {
+        self.uses.insert(register);
+        self
+    }
+
+    fn write(&mut self, register: impl Into<Register>) -> &mut Effects {
+        self.defs.insert(register);
+        self
+    }
+
+    /// Reads and writes a register, as update forms do with their base.
+    fn update(&mut self, register: impl Into<Register> + Copy) -> &mut Effects {
+        self.read(register).write(register)
+    }
+
+    /// Reads or writes XER[CA]. The extended forms read it as well, but tracking XER as a whole
+    /// makes the distinction moot.
+    fn carry(&mut self) -> &mut Effects {
+        self.update(Spr::IntegerException)
+    }
+
+    /// Compares set a CR field, including a copy of XER[SO].
+    fn compare(&mut self, crf: Crf) -> &mut Effects {
+        self.read(Spr::IntegerException).write(crf)
+    }
+
+    /// The `o` suffix sets XER[OV] and accumulates it into XER[SO].
+    fn overflow(&mut self, overflow_enable: bool) -> &mut Effects {
+        if overflow_enable {
+            self.update(Spr::IntegerException);
+        }
+        self
+    }
+
+    /// The `.` suffix of integer instructions sets CR0.
+    fn record(&mut self, record: bool) -> &mut Effects {
+        if record {
+            self.compare(Crf::new(0).unwrap());
+        }
+        self
+    }
+
+    /// The `.` suffix of floating-point instructions copies the FPSCR exception summary into CR1.
+    fn record_float(&mut self, record: bool) -> &mut Effects {
+        if record {
+            self.read(Register::Fpscr).write(Crf::new(1).unwrap());
+        }
+        self
+    }
+
+    fn branch(&mut self, condition: ConditionBehavior, ctr: CtrBehavior) -> &mut Effects {
+        match condition {
+            ConditionBehavior::BranchFalse(bit) | ConditionBehavior::BranchTrue(bit) => {
+                self.read(bit);
+            }
+            ConditionBehavior::BranchAlways => {}
+        }
+        if ctr != CtrBehavior::None {
+            self.update(Spr::Count);
+        }
+        self
+    }
+
+    fn link(&mut self, link: bool) -> &mut Effects {
+        if link {
+            self.write(Spr::Link);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod def_use_tests {
+    use crate::{assemble, RegisterSet};
+
+    /// Formats the uses and defs of an instruction given as assembly.
+    fn def_use(assembly: &str) -> (String, String) {
+        let instruction = assemble(assembly, 0x80003000).unwrap();
+        let format = |set: RegisterSet| format!("{:?}", set);
+        (format(instruction.uses()), format(instruction.defs()))
+    }
+
+    #[test]
+    fn explicit_operands() {
+        assert_eq!(
+            def_use("add r3, r4, r5"),
+            ("{r4, r5}".to_string(), "{r3}".to_string())
+        );
+        assert_eq!(
+            def_use("addi r3, 0, 1"),
+            ("{}".to_string(), "{r3}".to_string())
+        );
+        assert_eq!(def_use("mflr r0"), ("{lr}".to_string(), "{r0}".to_string()));
+    }
+
+    #[test]
+    fn record_forms_set_a_cr_field() {
+        assert_eq!(
+            def_use("add. r3, r4, r5"),
+            (
+                "{r4, r5, xer}".to_string(),
+                "{r3, lt, gt, eq, so}".to_string()
+            )
+        );
+        assert_eq!(
+            def_use("fadd. f1, f2, f3"),
+            (
+                "{f2, f3, fpscr}".to_string(),
+                "{f1, cr1*4+lt, cr1*4+gt, cr1*4+eq, cr1*4+so, fpscr}".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn update_forms_write_their_base() {
+        assert_eq!(
+            def_use("stwu r1, -0x10(r1)"),
+            ("{r1}".to_string(), "{r1}".to_string())
+        );
+        assert_eq!(
+            def_use("lwzu r3, 4(r4)"),
+            ("{r4}".to_string(), "{r3, r4}".to_string())
+        );
+    }
+
+    #[test]
+    fn multiple_word_transfers() {
+        assert_eq!(
+            def_use("lmw r29, 8(r1)"),
+            ("{r1}".to_string(), "{r29, r30, r31}".to_string())
+        );
+        assert_eq!(
+            def_use("stmw r30, 8(r1)"),
+            ("{r1, r30, r31}".to_string(), "{}".to_string())
+        );
+    }
+
+    #[test]
+    fn branches() {
+        assert_eq!(
+            def_use("bl 0x80004000"),
+            ("{}".to_string(), "{lr}".to_string())
+        );
+        assert_eq!(
+            def_use("bdnz 0x80004000"),
+            ("{ctr}".to_string(), "{ctr}".to_string())
+        );
+        assert_eq!(
+            def_use("bne cr7, 0x80004000"),
+            ("{cr7*4+eq}".to_string(), "{}".to_string())
+        );
+        assert_eq!(def_use("blr"), ("{lr}".to_string(), "{}".to_string()));
+        assert_eq!(def_use("bctrl"), ("{ctr}".to_string(), "{lr}".to_string()));
+    }
+
+    #[test]
+    fn carries() {
+        assert_eq!(
+            def_use("adde r3, r4, r5"),
+            ("{r4, r5, xer}".to_string(), "{r3, xer}".to_string())
+        );
+        assert_eq!(
+            def_use("srawi r3, r4, 2"),
+            ("{r4, xer}".to_string(), "{r3, xer}".to_string())
+        );
+    }
+
+    #[test]
+    fn condition_register_moves() {
+        assert_eq!(
+            def_use("mcrf cr1, cr7"),
+            (
+                "{cr7*4+lt, cr7*4+gt, cr7*4+eq, cr7*4+so}".to_string(),
+                "{cr1*4+lt, cr1*4+gt, cr1*4+eq, cr1*4+so}".to_string()
+            )
+        );
+        assert_eq!(
+            def_use("mtcrf 0x81, r3"),
+            (
+                "{r3}".to_string(),
+                "{lt, gt, eq, so, cr7*4+lt, cr7*4+gt, cr7*4+eq, cr7*4+so}".to_string()
+            )
+        );
+        assert_eq!(def_use("mfcr r3").0.matches(", ").count(), 31);
+        assert_eq!(
+            def_use("mcrxr cr2"),
+            (
+                "{xer}".to_string(),
+                "{cr2*4+lt, cr2*4+gt, cr2*4+eq, cr2*4+so, xer}".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn supervisor_registers() {
+        assert_eq!(
+            def_use("mtmsr r3"),
+            ("{r3}".to_string(), "{msr}".to_string())
+        );
+        assert_eq!(
+            def_use("rfi"),
+            ("{srr0, srr1}".to_string(), "{msr}".to_string())
+        );
+        assert_eq!(
+            def_use("mfsr r3, sr4"),
+            ("{sr4}".to_string(), "{r3}".to_string())
+        );
+        assert_eq!(
+            def_use("mftbu r3"),
+            ("{tbu}".to_string(), "{r3}".to_string())
+        );
+    }
+
+    #[test]
+    fn quantized_loads_read_their_gqr() {
+        assert_eq!(
+            def_use("psq_l f1, 8(r3), 0, qr2"),
+            ("{r3, gqr2}".to_string(), "{f1}".to_string())
+        );
+    }
+}
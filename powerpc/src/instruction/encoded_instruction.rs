@@ -1,6 +1,9 @@
 use thiserror::Error;
 
-use crate::{Bo, ConditionBit, Crf, DecodedInstruction, Gpr, GprOrZero, Spr};
+use crate::{
+    Bo, BranchHint, ConditionBehavior, ConditionBit, Crf, CtrBehavior, DecodedInstruction, Fpr,
+    Gpr, GprOrZero, IgnoredBranchBits, NonZeroGpr, Spr, Sr,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub struct EncodedInstruction(pub u32);
@@ -28,6 +31,31 @@ impl EncodedInstruction {
         self.bits(21, 30)
     }
 
+    /// The shorter extended opcode of A-form instructions, which keep a register in bits 21..=25.
+    fn a_form_extended_opcode(self) -> u32 {
+        self.bits(26, 30)
+    }
+
+    /// The extended opcode that decoding dispatches on, for the primary opcodes that have one.
+    /// Opcodes 4 and 63 mix A-form instructions with longer extended opcodes, and the quantized
+    /// indexed forms of opcode 4 use a 6-bit one.
+    fn dispatched_extended_opcode(self) -> Option<u32> {
+        match self.opcode() {
+            4 => Some(match self.a_form_extended_opcode() {
+                6 | 7 => self.bits(25, 30),
+                10..=15 | 18 | 20 | 21 | 23..=26 | 28..=31 => self.a_form_extended_opcode(),
+                _ => self.extended_opcode(),
+            }),
+            19 | 31 => Some(self.extended_opcode()),
+            59 => Some(self.a_form_extended_opcode()),
+            63 => Some(match self.a_form_extended_opcode() {
+                18 | 20 | 21 | 23 | 25 | 26 | 28..=31 => self.a_form_extended_opcode(),
+                _ => self.extended_opcode(),
+            }),
+            _ => None,
+        }
+    }
+
     /// A GPR index in bits 11..=15. Named `rA`.
     fn gpr_a(self) -> Gpr {
         Gpr::new(self.bits(11, 15)).unwrap()
@@ -38,6 +66,19 @@ impl EncodedInstruction {
         GprOrZero::new(self.bits(11, 15)).unwrap()
     }
 
+    /// A GPR index in bits 11..=15 used as the base of an update-form store. Named `rA`, which must
+    /// not be `r0`.
+    fn store_update_base(self) -> Option<NonZeroGpr> {
+        self.gpr_a_or_zero().try_unwrap_gpr()
+    }
+
+    /// A GPR index in bits 11..=15 used as the base of an update-form load. Named `rA`, which must
+    /// not be `r0` or the same register as `rD`.
+    fn load_update_base(self) -> Option<NonZeroGpr> {
+        self.store_update_base()
+            .filter(|base| base.as_gpr() != self.gpr_c())
+    }
+
     fn bi(self) -> ConditionBit {
         ConditionBit::new(self.bits(11, 15)).unwrap()
     }
@@ -47,6 +88,7 @@ impl EncodedInstruction {
         ConditionBit::new(self.bits(11, 15)).unwrap()
     }
 
+    /// A GPR index in bits 16..=20. Named `rB`.
     fn gpr_b(self) -> Gpr {
         Gpr::new(self.bits(16, 20)).unwrap()
     }
@@ -66,10 +108,34 @@ impl EncodedInstruction {
         Gpr::new(self.bits(6, 10)).unwrap()
     }
 
+    /// An FPR index in bits 6..=10. Named `frD` or `frS`.
+    fn fpr_d(self) -> Fpr {
+        Fpr::new(self.bits(6, 10)).unwrap()
+    }
+
+    /// An FPR index in bits 11..=15. Named `frA`.
+    fn fpr_a(self) -> Fpr {
+        Fpr::new(self.bits(11, 15)).unwrap()
+    }
+
+    /// An FPR index in bits 16..=20. Named `frB`.
+    fn fpr_b(self) -> Fpr {
+        Fpr::new(self.bits(16, 20)).unwrap()
+    }
+
+    /// An FPR index in bits 21..=25. Named `frC`.
+    fn fpr_c(self) -> Fpr {
+        Fpr::new(self.bits(21, 25)).unwrap()
+    }
+
     fn crf_d(self) -> Crf {
         Crf::new(self.bits(6, 8)).unwrap()
     }
 
+    fn crf_s(self) -> Crf {
+        Crf::new(self.bits(11, 13)).unwrap()
+    }
+
     /// A condition bit index in bits 6..=10. Named `crbD`.
     fn crb_d(self) -> ConditionBit {
         ConditionBit::new(self.bits(6, 10)).unwrap()
@@ -79,8 +145,48 @@ impl EncodedInstruction {
         Bo::new(self.bits(6, 10))
     }
 
+    /// The bits of BO and BI that the branch sets but doesn't use: the z bits of BO, and BI when
+    /// the branch doesn't test a condition.
+    fn ignored_branch_bits(self) -> IgnoredBranchBits {
+        let tests_condition =
+            self.bo().modify_condition(self.bi()) != ConditionBehavior::BranchAlways;
+        IgnoredBranchBits {
+            bo: self.bo().z_bits() as u8,
+            bi: if tests_condition {
+                0
+            } else {
+                self.bits(11, 15) as u8
+            },
+        }
+    }
+
+    /// The prediction hint given by the y bit, if it is set.
+    fn branch_hint(self, backward: bool) -> Option<BranchHint> {
+        self.bo().y().then(|| BranchHint::reversing(backward))
+    }
+
+    /// An SPR number in bits 11..=20, whose two halves are swapped.
+    fn spr_field(self) -> u32 {
+        (self.bits(16, 20) << 5) | self.bits(11, 15)
+    }
+
     fn try_spr(self) -> Option<Spr> {
-        Spr::new((self.bits(16, 20) << 5) | self.bits(11, 15))
+        Spr::new(self.spr_field())
+    }
+
+    /// The time base register `mftb` reads. Its TBR field is encoded like an SPR, but numbered
+    /// differently from the SPRs that write the time base.
+    fn try_tbr(self) -> Option<Spr> {
+        match self.spr_field() {
+            268 => Some(Spr::TimeBaseLower),
+            269 => Some(Spr::TimeBaseUpper),
+            _ => None,
+        }
+    }
+
+    /// A segment register index in bits 12..=15.
+    fn sr(self) -> Sr {
+        Sr::new(self.bits(12, 15)).unwrap()
     }
 
     fn unsigned_immediate(self) -> u16 {
@@ -91,6 +197,11 @@ impl EncodedInstruction {
         self.bits(16, 31) as i16
     }
 
+    /// A signed 12-bit displacement in bits 20..=31, used by the quantized loads and stores.
+    fn quantized_offset(self) -> i16 {
+        ((self.bits(20, 31) << 4) as i16) >> 4
+    }
+
     fn small_branch_offset(self) -> i32 {
         let mut tmp = self.0 & 0x0000fffc;
         // Sign extend.
@@ -126,218 +237,2197 @@ impl EncodedInstruction {
     }
 
     pub fn parse(self, address: u32) -> Result<DecodedInstruction, ParseError> {
-        match self.opcode() {
-            10 => {
-                // Check reserved bit and width flag, which must be clear.
-                if self.bits(9, 10) == 0 {
-                    Ok(DecodedInstruction::Cmpli {
-                        crf: self.crf_d(),
-                        src: self.gpr_a(),
-                        immediate: self.unsigned_immediate(),
+        self.decode(address).map_err(|kind| ParseError {
+            address,
+            word: self.0,
+            kind,
+        })
+    }
+
+    fn decode(self, address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        DECODERS[self.opcode() as usize](self, address)
+    }
+
+    fn decode_unimplemented(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Err(ParseErrorKind::UnimplementedOpcode(self.opcode()))
+    }
+
+    fn decode_twi(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Twi {
+            to: self.bits(6, 10) as u8,
+            src: self.gpr_a(),
+            immediate: self.signed_immediate(),
+        })
+    }
+
+    fn decode_opcode_4(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        match self.bits(26, 30) {
+            // The quantized indexed forms have a 6-bit extended opcode in bits 25..=30, where
+            // bit 25 selects the update form.
+            6 => match self.bits(25, 25) {
+                0 => {
+                    if self.bits(31, 31) == 0 {
+                        Ok(DecodedInstruction::PsqLx {
+                            dst: self.fpr_d(),
+                            base: self.gpr_a_or_zero(),
+                            index: self.gpr_b(),
+                            single: self.bits(21, 21) == 1,
+                            gqr: self.bits(22, 24) as u8,
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                _ => match (self.store_update_base(), self.bits(31, 31)) {
+                    (Some(base), 0) => Ok(DecodedInstruction::PsqLux {
+                        dst: self.fpr_d(),
+                        base,
+                        index: self.gpr_b(),
+                        single: self.bits(21, 21) == 1,
+                        gqr: self.bits(22, 24) as u8,
+                    }),
+                    _ => Err(ParseErrorKind::IllegalEncoding),
+                },
+            },
+            7 => match self.bits(25, 25) {
+                0 => {
+                    if self.bits(31, 31) == 0 {
+                        Ok(DecodedInstruction::PsqStx {
+                            src: self.fpr_d(),
+                            base: self.gpr_a_or_zero(),
+                            index: self.gpr_b(),
+                            single: self.bits(21, 21) == 1,
+                            gqr: self.bits(22, 24) as u8,
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                _ => match (self.store_update_base(), self.bits(31, 31)) {
+                    (Some(base), 0) => Ok(DecodedInstruction::PsqStux {
+                        src: self.fpr_d(),
+                        base,
+                        index: self.gpr_b(),
+                        single: self.bits(21, 21) == 1,
+                        gqr: self.bits(22, 24) as u8,
+                    }),
+                    _ => Err(ParseErrorKind::IllegalEncoding),
+                },
+            },
+            10 => Ok(DecodedInstruction::PsSum0 {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            11 => Ok(DecodedInstruction::PsSum1 {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            12 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::PsMuls0 {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_c()],
+                        record: self.update_condition_register(),
                     })
                 } else {
-                    Err(ParseError::IllegalEncoding)
+                    Err(ParseErrorKind::IllegalEncoding)
                 }
             }
-            11 => {
-                // Check reserved bit and width flag, which must be clear.
-                if self.bits(9, 10) == 0 {
-                    Ok(DecodedInstruction::Cmpi {
-                        crf: self.crf_d(),
-                        src: self.gpr_a(),
-                        immediate: self.signed_immediate(),
+            13 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::PsMuls1 {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_c()],
+                        record: self.update_condition_register(),
                     })
                 } else {
-                    Err(ParseError::IllegalEncoding)
+                    Err(ParseErrorKind::IllegalEncoding)
                 }
             }
-            14 => Ok(DecodedInstruction::Addi {
-                dst: self.gpr_c(),
-                src: self.gpr_a_or_zero(),
-                immediate: self.signed_immediate(),
+            14 => Ok(DecodedInstruction::PsMadds0 {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
             }),
-            15 => Ok(DecodedInstruction::Addis {
-                dst: self.gpr_c(),
-                src: self.gpr_a_or_zero(),
-                immediate: self.signed_immediate(),
+            15 => Ok(DecodedInstruction::PsMadds1 {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            18 => {
+                if self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::PsDiv {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_b()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            20 => {
+                if self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::PsSub {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_b()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            21 => {
+                if self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::PsAdd {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_b()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            23 => Ok(DecodedInstruction::PsSel {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            24 => {
+                if self.bits(11, 15) == 0 && self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::PsRes {
+                        dst: self.fpr_d(),
+                        src: self.fpr_b(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            25 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::PsMul {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_c()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            26 => {
+                if self.bits(11, 15) == 0 && self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::PsRsqrte {
+                        dst: self.fpr_d(),
+                        src: self.fpr_b(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            28 => Ok(DecodedInstruction::PsMsub {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            29 => Ok(DecodedInstruction::PsMadd {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            30 => Ok(DecodedInstruction::PsNmsub {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            31 => Ok(DecodedInstruction::PsNmadd {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
             }),
-            16 => Ok(DecodedInstruction::Bc {
-                condition: self.bo().modify_condition(self.bi()),
-                ctr: self.bo().ctr(),
-                link: self.link(),
-                absolute: self.absolute_address(),
-                target: if self.absolute_address() { 0 } else { address }
-                    .wrapping_add(self.small_branch_offset() as u32),
-            }),
-            18 => Ok(DecodedInstruction::B {
-                link: self.link(),
-                absolute: self.absolute_address(),
-                target: if self.absolute_address() { 0 } else { address }
-                    .wrapping_add(self.large_branch_offset() as u32),
-            }),
-            opcode @ 19 => match self.extended_opcode() {
-                16 => {
-                    if self.bits(16, 20) == 0 {
-                        Ok(DecodedInstruction::Bclr {
-                            condition: self.bo().modify_condition(self.bi()),
-                            ctr: self.bo().ctr(),
-                            link: self.link(),
+            // The remaining instructions are X-form, and none of their extended opcodes collide
+            // with the A-form ones above.
+            _ => match self.extended_opcode() {
+                0 => {
+                    if self.bits(9, 10) == 0 && self.bits(31, 31) == 0 {
+                        Ok(DecodedInstruction::PsCmpu0 {
+                            crf: self.crf_d(),
+                            srcs: [self.fpr_a(), self.fpr_b()],
                         })
                     } else {
-                        Err(ParseError::IllegalEncoding)
+                        Err(ParseErrorKind::IllegalEncoding)
                     }
                 }
-                193 => {
-                    if self.bits(31, 31) == 0 {
-                        Ok(DecodedInstruction::Crxor {
-                            dst: self.crb_d(),
-                            srcs: [self.crb_a(), self.crb_b()],
+                32 => {
+                    if self.bits(9, 10) == 0 && self.bits(31, 31) == 0 {
+                        Ok(DecodedInstruction::PsCmpo0 {
+                            crf: self.crf_d(),
+                            srcs: [self.fpr_a(), self.fpr_b()],
                         })
                     } else {
-                        Err(ParseError::IllegalEncoding)
+                        Err(ParseErrorKind::IllegalEncoding)
                     }
                 }
-                extended_opcode => Err(ParseError::UnimplementedExtendedOpcode {
-                    opcode,
-                    extended_opcode,
-                }),
-            },
-            21 => Ok(DecodedInstruction::Rlwinm {
-                dst: self.gpr_a(),
-                src: self.gpr_c(),
-                shift: self.shift(),
-                mask_begin: self.bits(21, 25) as u8,
-                mask_end: self.bits(26, 30) as u8,
-                record: self.update_condition_register(),
-            }),
-            opcode @ 31 => match self.extended_opcode() {
-                32 => {
+                40 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::PsNeg {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                64 => {
                     if self.bits(9, 10) == 0 && self.bits(31, 31) == 0 {
-                        Ok(DecodedInstruction::Cmpl {
+                        Ok(DecodedInstruction::PsCmpu1 {
                             crf: self.crf_d(),
-                            srcs: [self.gpr_a(), self.gpr_b()],
+                            srcs: [self.fpr_a(), self.fpr_b()],
                         })
                     } else {
-                        Err(ParseError::IllegalEncoding)
+                        Err(ParseErrorKind::IllegalEncoding)
                     }
                 }
-                202 | 714 => {
-                    if self.bits(16, 20) == 0 {
-                        Ok(DecodedInstruction::Addze {
-                            dst: self.gpr_c(),
-                            src: self.gpr_a(),
-                            overflow_enable: self.overflow_enable(),
+                72 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::PsMr {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
                             record: self.update_condition_register(),
                         })
                     } else {
-                        Err(ParseError::IllegalEncoding)
+                        Err(ParseErrorKind::IllegalEncoding)
                     }
                 }
-                339 => match (self.try_spr(), self.bits(31, 31)) {
-                    (Some(spr), 0) => Ok(DecodedInstruction::Mfspr {
-                        spr,
-                        dst: self.gpr_c(),
-                    }),
-                    _ => Err(ParseError::IllegalEncoding),
-                },
-                444 => Ok(DecodedInstruction::Or {
-                    dst: self.gpr_a(),
-                    srcs: [self.gpr_c(), self.gpr_b()],
+                96 => {
+                    if self.bits(9, 10) == 0 && self.bits(31, 31) == 0 {
+                        Ok(DecodedInstruction::PsCmpo1 {
+                            crf: self.crf_d(),
+                            srcs: [self.fpr_a(), self.fpr_b()],
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                136 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::PsNabs {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                264 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::PsAbs {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                528 => Ok(DecodedInstruction::PsMerge00 {
+                    dst: self.fpr_d(),
+                    srcs: [self.fpr_a(), self.fpr_b()],
                     record: self.update_condition_register(),
                 }),
-                467 => match (self.try_spr(), self.bits(31, 31)) {
-                    (Some(spr), 0) => Ok(DecodedInstruction::Mtspr {
-                        spr,
-                        src: self.gpr_c(),
-                    }),
-                    _ => Err(ParseError::IllegalEncoding),
-                },
-                824 => Ok(DecodedInstruction::Srawi {
-                    dst: self.gpr_a(),
-                    src: self.gpr_c(),
-                    shift: self.shift(),
+                560 => Ok(DecodedInstruction::PsMerge01 {
+                    dst: self.fpr_d(),
+                    srcs: [self.fpr_a(), self.fpr_b()],
+                    record: self.update_condition_register(),
+                }),
+                592 => Ok(DecodedInstruction::PsMerge10 {
+                    dst: self.fpr_d(),
+                    srcs: [self.fpr_a(), self.fpr_b()],
+                    record: self.update_condition_register(),
+                }),
+                624 => Ok(DecodedInstruction::PsMerge11 {
+                    dst: self.fpr_d(),
+                    srcs: [self.fpr_a(), self.fpr_b()],
                     record: self.update_condition_register(),
                 }),
-                extended_opcode => Err(ParseError::UnimplementedExtendedOpcode {
-                    opcode,
+                1014 => {
+                    if self.bits(6, 10) == 0 && self.bits(31, 31) == 0 {
+                        Ok(DecodedInstruction::DcbzL {
+                            base: self.gpr_a_or_zero(),
+                            index: self.gpr_b(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                extended_opcode => Err(ParseErrorKind::UnimplementedExtendedOpcode {
+                    opcode: self.opcode(),
                     extended_opcode,
                 }),
             },
-            32 => Ok(DecodedInstruction::Lwz {
-                dst: self.gpr_c(),
-                offset: self.signed_immediate(),
-                base: self.gpr_a_or_zero(),
-            }),
-            34 => Ok(DecodedInstruction::Lbz {
-                dst: self.gpr_c(),
-                offset: self.signed_immediate(),
-                base: self.gpr_a_or_zero(),
-            }),
-            36 => Ok(DecodedInstruction::Stw {
-                src: self.gpr_c(),
-                offset: self.signed_immediate(),
-                base: self.gpr_a_or_zero(),
-            }),
-            37 => {
-                if let Some(base) = self.gpr_a_or_zero().try_unwrap_gpr() {
-                    Ok(DecodedInstruction::Stwu {
-                        src: self.gpr_c(),
-                        offset: self.signed_immediate(),
-                        base,
-                    })
-                } else {
-                    Err(ParseError::IllegalEncoding)
-                }
-            }
-            42 => Ok(DecodedInstruction::Lha {
-                dst: self.gpr_c(),
-                offset: self.signed_immediate(),
-                base: self.gpr_a_or_zero(),
-            }),
-            47 => Ok(DecodedInstruction::Stmw {
-                src: self.gpr_c(),
-                offset: self.signed_immediate(),
-                base: self.gpr_a_or_zero(),
-            }),
-            opcode => Err(ParseError::UnimplementedOpcode(opcode)),
         }
     }
-}
 
-#[derive(Debug, Error)]
-pub enum ParseError {
-    #[error("unimplemented opcode: {0}")]
-    UnimplementedOpcode(u32),
+    fn decode_mulli(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Mulli {
+            dst: self.gpr_c(),
+            src: self.gpr_a(),
+            immediate: self.signed_immediate(),
+        })
+    }
 
-    #[error(
-        "unimplemented extended opcode: opcode = {opcode}, extended_opcode = {extended_opcode}"
-    )]
-    UnimplementedExtendedOpcode { opcode: u32, extended_opcode: u32 },
+    fn decode_subfic(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Subfic {
+            dst: self.gpr_c(),
+            src: self.gpr_a(),
+            immediate: self.signed_immediate(),
+        })
+    }
 
-    #[error("illegal encoding")]
-    IllegalEncoding,
-}
+    fn decode_cmpli(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        // Check reserved bit and width flag, which must be clear.
+        if self.bits(9, 10) == 0 {
+            Ok(DecodedInstruction::Cmpli {
+                crf: self.crf_d(),
+                src: self.gpr_a(),
+                immediate: self.unsigned_immediate(),
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::EncodedInstruction;
+    fn decode_cmpi(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        // Check reserved bit and width flag, which must be clear.
+        if self.bits(9, 10) == 0 {
+            Ok(DecodedInstruction::Cmpi {
+                crf: self.crf_d(),
+                src: self.gpr_a(),
+                immediate: self.signed_immediate(),
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
 
-    #[test]
-    fn bits_basic() {
-        // |xxx-----------------------------|
-        assert_eq!(EncodedInstruction(0x00000000).bits(0, 2), 0x0);
-        assert_eq!(EncodedInstruction(0x20000000).bits(0, 2), 0x1);
-        assert_eq!(EncodedInstruction(0x40000000).bits(0, 2), 0x2);
-        assert_eq!(EncodedInstruction(0x80000000).bits(0, 2), 0x4);
-        assert_eq!(EncodedInstruction(0xe0000000).bits(0, 2), 0x7);
+    fn decode_addic(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Addic {
+            dst: self.gpr_c(),
+            src: self.gpr_a(),
+            immediate: self.signed_immediate(),
+            record: self.opcode() == 13,
+        })
+    }
 
-        // |---------------------------xxxxx|
-        assert_eq!(EncodedInstruction(0xaa55aa40).bits(27, 31), 0x00);
-        assert_eq!(EncodedInstruction(0xaa55aa41).bits(27, 31), 0x01);
-        assert_eq!(EncodedInstruction(0xaa55aa42).bits(27, 31), 0x02);
-        assert_eq!(EncodedInstruction(0xaa55aa44).bits(27, 31), 0x04);
-        assert_eq!(EncodedInstruction(0xaa55aa48).bits(27, 31), 0x08);
-        assert_eq!(EncodedInstruction(0xaa55aa50).bits(27, 31), 0x10);
-        assert_eq!(EncodedInstruction(0xaa55aa5f).bits(27, 31), 0x1f);
+    fn decode_addi(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Addi {
+            dst: self.gpr_c(),
+            src: self.gpr_a_or_zero(),
+            immediate: self.signed_immediate(),
+        })
+    }
+
+    fn decode_addis(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Addis {
+            dst: self.gpr_c(),
+            src: self.gpr_a_or_zero(),
+            immediate: self.signed_immediate(),
+        })
+    }
+
+    fn decode_bc(self, address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Bc {
+            condition: self.bo().modify_condition(self.bi()),
+            ctr: self.bo().ctr(),
+            hint: self.branch_hint(self.small_branch_offset() < 0),
+            ignored: self.ignored_branch_bits(),
+            link: self.link(),
+            absolute: self.absolute_address(),
+            target: if self.absolute_address() { 0 } else { address }
+                .wrapping_add(self.small_branch_offset() as u32),
+        })
+    }
+
+    fn decode_sc(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        if self.bits(6, 29) == 0 && self.bits(30, 31) == 0b10 {
+            Ok(DecodedInstruction::Sc)
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_b(self, address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::B {
+            link: self.link(),
+            absolute: self.absolute_address(),
+            target: if self.absolute_address() { 0 } else { address }
+                .wrapping_add(self.large_branch_offset() as u32),
+        })
+    }
+
+    fn decode_opcode_19(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        match self.extended_opcode() {
+            0 => {
+                if self.bits(9, 10) == 0 && self.bits(14, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mcrf {
+                        dst: self.crf_d(),
+                        src: self.crf_s(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            16 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Bclr {
+                        condition: self.bo().modify_condition(self.bi()),
+                        ctr: self.bo().ctr(),
+                        hint: self.branch_hint(false),
+                        ignored: self.ignored_branch_bits(),
+                        link: self.link(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            33 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Crnor {
+                        dst: self.crb_d(),
+                        srcs: [self.crb_a(), self.crb_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            50 => {
+                if self.bits(6, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Rfi)
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            129 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Crandc {
+                        dst: self.crb_d(),
+                        srcs: [self.crb_a(), self.crb_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            150 => {
+                if self.bits(6, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Isync)
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            193 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Crxor {
+                        dst: self.crb_d(),
+                        srcs: [self.crb_a(), self.crb_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            225 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Crnand {
+                        dst: self.crb_d(),
+                        srcs: [self.crb_a(), self.crb_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            257 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Crand {
+                        dst: self.crb_d(),
+                        srcs: [self.crb_a(), self.crb_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            289 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Creqv {
+                        dst: self.crb_d(),
+                        srcs: [self.crb_a(), self.crb_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            417 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Crorc {
+                        dst: self.crb_d(),
+                        srcs: [self.crb_a(), self.crb_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            449 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Cror {
+                        dst: self.crb_d(),
+                        srcs: [self.crb_a(), self.crb_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            528 => {
+                // The CTR can't be both decremented and used as the branch target.
+                if self.bits(16, 20) == 0 && self.bo().ctr() == CtrBehavior::None {
+                    Ok(DecodedInstruction::Bcctr {
+                        condition: self.bo().modify_condition(self.bi()),
+                        hint: self.branch_hint(false),
+                        ignored: self.ignored_branch_bits(),
+                        link: self.link(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            extended_opcode => Err(ParseErrorKind::UnimplementedExtendedOpcode {
+                opcode: self.opcode(),
+                extended_opcode,
+            }),
+        }
+    }
+
+    fn decode_rlwimi(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Rlwimi {
+            dst: self.gpr_a(),
+            src: self.gpr_c(),
+            shift: self.shift(),
+            mask_begin: self.bits(21, 25) as u8,
+            mask_end: self.bits(26, 30) as u8,
+            record: self.update_condition_register(),
+        })
+    }
+
+    fn decode_rlwinm(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Rlwinm {
+            dst: self.gpr_a(),
+            src: self.gpr_c(),
+            shift: self.shift(),
+            mask_begin: self.bits(21, 25) as u8,
+            mask_end: self.bits(26, 30) as u8,
+            record: self.update_condition_register(),
+        })
+    }
+
+    fn decode_rlwnm(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Rlwnm {
+            dst: self.gpr_a(),
+            src: self.gpr_c(),
+            shift: self.gpr_b(),
+            mask_begin: self.bits(21, 25) as u8,
+            mask_end: self.bits(26, 30) as u8,
+            record: self.update_condition_register(),
+        })
+    }
+
+    fn decode_ori(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Ori {
+            dst: self.gpr_a(),
+            src: self.gpr_c(),
+            immediate: self.unsigned_immediate(),
+        })
+    }
+
+    fn decode_oris(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Oris {
+            dst: self.gpr_a(),
+            src: self.gpr_c(),
+            immediate: self.unsigned_immediate(),
+        })
+    }
+
+    fn decode_xori(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Xori {
+            dst: self.gpr_a(),
+            src: self.gpr_c(),
+            immediate: self.unsigned_immediate(),
+        })
+    }
+
+    fn decode_xoris(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Xoris {
+            dst: self.gpr_a(),
+            src: self.gpr_c(),
+            immediate: self.unsigned_immediate(),
+        })
+    }
+
+    fn decode_andi(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Andi {
+            dst: self.gpr_a(),
+            src: self.gpr_c(),
+            immediate: self.unsigned_immediate(),
+        })
+    }
+
+    fn decode_andis(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Andis {
+            dst: self.gpr_a(),
+            src: self.gpr_c(),
+            immediate: self.unsigned_immediate(),
+        })
+    }
+
+    fn decode_opcode_31(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        match self.extended_opcode() {
+            0 => {
+                if self.bits(9, 10) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Cmp {
+                        crf: self.crf_d(),
+                        srcs: [self.gpr_a(), self.gpr_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            4 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Tw {
+                        to: self.bits(6, 10) as u8,
+                        srcs: [self.gpr_a(), self.gpr_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            8 | 520 => Ok(DecodedInstruction::Subfc {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                overflow_enable: self.overflow_enable(),
+                record: self.update_condition_register(),
+            }),
+            10 | 522 => Ok(DecodedInstruction::Addc {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                overflow_enable: self.overflow_enable(),
+                record: self.update_condition_register(),
+            }),
+            11 => Ok(DecodedInstruction::Mulhwu {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            19 => {
+                if self.bits(11, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mfcr { dst: self.gpr_c() })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            23 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Lwzx {
+                        dst: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            24 => Ok(DecodedInstruction::Slw {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            26 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Cntlzw {
+                        dst: self.gpr_a(),
+                        src: self.gpr_c(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            28 => Ok(DecodedInstruction::And {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            32 => {
+                if self.bits(9, 10) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Cmpl {
+                        crf: self.crf_d(),
+                        srcs: [self.gpr_a(), self.gpr_b()],
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            40 | 552 => Ok(DecodedInstruction::Subf {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                overflow_enable: self.overflow_enable(),
+                record: self.update_condition_register(),
+            }),
+            54 => {
+                if self.bits(6, 10) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Dcbst {
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            55 => match (self.load_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Lwzux {
+                    dst: self.gpr_c(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            60 => Ok(DecodedInstruction::Andc {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            75 => Ok(DecodedInstruction::Mulhw {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            83 => {
+                if self.bits(11, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mfmsr { dst: self.gpr_c() })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            86 => {
+                if self.bits(6, 10) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Dcbf {
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            87 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Lbzx {
+                        dst: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            104 | 616 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Neg {
+                        dst: self.gpr_c(),
+                        src: self.gpr_a(),
+                        overflow_enable: self.overflow_enable(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            119 => match (self.load_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Lbzux {
+                    dst: self.gpr_c(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            124 => Ok(DecodedInstruction::Nor {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            136 | 648 => Ok(DecodedInstruction::Subfe {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                overflow_enable: self.overflow_enable(),
+                record: self.update_condition_register(),
+            }),
+            138 | 650 => Ok(DecodedInstruction::Adde {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                overflow_enable: self.overflow_enable(),
+                record: self.update_condition_register(),
+            }),
+            144 => {
+                if self.bits(11, 11) == 0 && self.bits(20, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mtcrf {
+                        fields: self.bits(12, 19) as u8,
+                        src: self.gpr_c(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            146 => {
+                if self.bits(11, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mtmsr { src: self.gpr_c() })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            151 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Stwx {
+                        src: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            183 => match (self.store_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Stwux {
+                    src: self.gpr_c(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            200 | 712 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Subfze {
+                        dst: self.gpr_c(),
+                        src: self.gpr_a(),
+                        overflow_enable: self.overflow_enable(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            202 | 714 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Addze {
+                        dst: self.gpr_c(),
+                        src: self.gpr_a(),
+                        overflow_enable: self.overflow_enable(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            210 => {
+                if self.bits(11, 11) == 0 && self.bits(16, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mtsr {
+                        sr: self.sr(),
+                        src: self.gpr_c(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            215 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Stbx {
+                        src: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            232 | 744 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Subfme {
+                        dst: self.gpr_c(),
+                        src: self.gpr_a(),
+                        overflow_enable: self.overflow_enable(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            234 | 746 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Addme {
+                        dst: self.gpr_c(),
+                        src: self.gpr_a(),
+                        overflow_enable: self.overflow_enable(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            235 | 747 => Ok(DecodedInstruction::Mullw {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                overflow_enable: self.overflow_enable(),
+                record: self.update_condition_register(),
+            }),
+            242 => {
+                if self.bits(11, 15) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mtsrin {
+                        src: self.gpr_c(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            246 => {
+                if self.bits(6, 10) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Dcbtst {
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            247 => match (self.store_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Stbux {
+                    src: self.gpr_c(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            266 | 778 => Ok(DecodedInstruction::Add {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                overflow_enable: self.overflow_enable(),
+                record: self.update_condition_register(),
+            }),
+            278 => {
+                if self.bits(6, 10) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Dcbt {
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            279 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Lhzx {
+                        dst: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            284 => Ok(DecodedInstruction::Eqv {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            311 => match (self.load_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Lhzux {
+                    dst: self.gpr_c(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            316 => Ok(DecodedInstruction::Xor {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            339 => match (
+                self.try_spr().filter(|spr| spr.is_readable()),
+                self.bits(31, 31),
+            ) {
+                (Some(spr), 0) => Ok(DecodedInstruction::Mfspr {
+                    spr,
+                    dst: self.gpr_c(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            343 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Lhax {
+                        dst: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            371 => match (self.try_tbr(), self.bits(31, 31)) {
+                (Some(spr), 0) => Ok(DecodedInstruction::Mftb {
+                    spr,
+                    dst: self.gpr_c(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            375 => match (self.load_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Lhaux {
+                    dst: self.gpr_c(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            407 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Sthx {
+                        src: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            412 => Ok(DecodedInstruction::Orc {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            439 => match (self.store_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Sthux {
+                    src: self.gpr_c(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            444 => Ok(DecodedInstruction::Or {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            459 | 971 => Ok(DecodedInstruction::Divwu {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                overflow_enable: self.overflow_enable(),
+                record: self.update_condition_register(),
+            }),
+            467 => match (
+                self.try_spr().filter(|spr| spr.is_writable()),
+                self.bits(31, 31),
+            ) {
+                (Some(spr), 0) => Ok(DecodedInstruction::Mtspr {
+                    spr,
+                    src: self.gpr_c(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            470 => {
+                if self.bits(6, 10) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Dcbi {
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            476 => Ok(DecodedInstruction::Nand {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            491 | 1003 => Ok(DecodedInstruction::Divw {
+                dst: self.gpr_c(),
+                srcs: [self.gpr_a(), self.gpr_b()],
+                overflow_enable: self.overflow_enable(),
+                record: self.update_condition_register(),
+            }),
+            512 => {
+                if self.bits(9, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mcrxr { dst: self.crf_d() })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            534 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Lwbrx {
+                        dst: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            535 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Lfsx {
+                        dst: self.fpr_d(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            536 => Ok(DecodedInstruction::Srw {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            567 => match (self.store_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Lfsux {
+                    dst: self.fpr_d(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            595 => {
+                if self.bits(11, 11) == 0 && self.bits(16, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mfsr {
+                        dst: self.gpr_c(),
+                        sr: self.sr(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            598 => {
+                if self.bits(6, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Sync)
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            599 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Lfdx {
+                        dst: self.fpr_d(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            631 => match (self.store_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Lfdux {
+                    dst: self.fpr_d(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            659 => {
+                if self.bits(11, 15) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Mfsrin {
+                        dst: self.gpr_c(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            662 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Stwbrx {
+                        src: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            663 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Stfsx {
+                        src: self.fpr_d(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            695 => match (self.store_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Stfsux {
+                    src: self.fpr_d(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            727 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Stfdx {
+                        src: self.fpr_d(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            759 => match (self.store_update_base(), self.bits(31, 31)) {
+                (Some(base), 0) => Ok(DecodedInstruction::Stfdux {
+                    src: self.fpr_d(),
+                    base,
+                    index: self.gpr_b(),
+                }),
+                _ => Err(ParseErrorKind::IllegalEncoding),
+            },
+            790 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Lhbrx {
+                        dst: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            792 => Ok(DecodedInstruction::Sraw {
+                dst: self.gpr_a(),
+                srcs: [self.gpr_c(), self.gpr_b()],
+                record: self.update_condition_register(),
+            }),
+            824 => Ok(DecodedInstruction::Srawi {
+                dst: self.gpr_a(),
+                src: self.gpr_c(),
+                shift: self.shift(),
+                record: self.update_condition_register(),
+            }),
+            854 => {
+                if self.bits(6, 20) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Eieio)
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            918 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Sthbrx {
+                        src: self.gpr_c(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            922 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Extsh {
+                        dst: self.gpr_a(),
+                        src: self.gpr_c(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            954 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Extsb {
+                        dst: self.gpr_a(),
+                        src: self.gpr_c(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            982 => {
+                if self.bits(6, 10) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Icbi {
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            983 => {
+                if self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Stfiwx {
+                        src: self.fpr_d(),
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            1014 => {
+                if self.bits(6, 10) == 0 && self.bits(31, 31) == 0 {
+                    Ok(DecodedInstruction::Dcbz {
+                        base: self.gpr_a_or_zero(),
+                        index: self.gpr_b(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            extended_opcode => Err(ParseErrorKind::UnimplementedExtendedOpcode {
+                opcode: self.opcode(),
+                extended_opcode,
+            }),
+        }
+    }
+
+    fn decode_lwz(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Lwz {
+            dst: self.gpr_c(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_lwzu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        if let Some(base) = self.load_update_base() {
+            Ok(DecodedInstruction::Lwzu {
+                dst: self.gpr_c(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_lbz(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Lbz {
+            dst: self.gpr_c(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_lbzu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        if let Some(base) = self.load_update_base() {
+            Ok(DecodedInstruction::Lbzu {
+                dst: self.gpr_c(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_stw(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Stw {
+            src: self.gpr_c(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_stwu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        if let Some(base) = self.store_update_base() {
+            Ok(DecodedInstruction::Stwu {
+                src: self.gpr_c(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_stb(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Stb {
+            src: self.gpr_c(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_stbu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        if let Some(base) = self.store_update_base() {
+            Ok(DecodedInstruction::Stbu {
+                src: self.gpr_c(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_lhz(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Lhz {
+            dst: self.gpr_c(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_lhzu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        if let Some(base) = self.load_update_base() {
+            Ok(DecodedInstruction::Lhzu {
+                dst: self.gpr_c(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_lha(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Lha {
+            dst: self.gpr_c(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_lhau(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        if let Some(base) = self.load_update_base() {
+            Ok(DecodedInstruction::Lhau {
+                dst: self.gpr_c(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_sth(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Sth {
+            src: self.gpr_c(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_sthu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        if let Some(base) = self.store_update_base() {
+            Ok(DecodedInstruction::Sthu {
+                src: self.gpr_c(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_lmw(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        // The base register may not be in the range of registers to be loaded.
+        if self.gpr_a_or_zero().as_u32() < self.gpr_c().as_u32() {
+            Ok(DecodedInstruction::Lmw {
+                dst: self.gpr_c(),
+                offset: self.signed_immediate(),
+                base: self.gpr_a_or_zero(),
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_stmw(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Stmw {
+            src: self.gpr_c(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_lfs(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Lfs {
+            dst: self.fpr_d(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_lfsu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        // The data register is an FPR, so only r0 is ruled out as a base.
+        if let Some(base) = self.store_update_base() {
+            Ok(DecodedInstruction::Lfsu {
+                dst: self.fpr_d(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_lfd(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Lfd {
+            dst: self.fpr_d(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_lfdu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        // The data register is an FPR, so only r0 is ruled out as a base.
+        if let Some(base) = self.store_update_base() {
+            Ok(DecodedInstruction::Lfdu {
+                dst: self.fpr_d(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_stfs(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Stfs {
+            src: self.fpr_d(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_stfsu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        // The data register is an FPR, so only r0 is ruled out as a base.
+        if let Some(base) = self.store_update_base() {
+            Ok(DecodedInstruction::Stfsu {
+                src: self.fpr_d(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_stfd(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::Stfd {
+            src: self.fpr_d(),
+            offset: self.signed_immediate(),
+            base: self.gpr_a_or_zero(),
+        })
+    }
+
+    fn decode_stfdu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        // The data register is an FPR, so only r0 is ruled out as a base.
+        if let Some(base) = self.store_update_base() {
+            Ok(DecodedInstruction::Stfdu {
+                src: self.fpr_d(),
+                offset: self.signed_immediate(),
+                base,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_psq_l(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::PsqL {
+            dst: self.fpr_d(),
+            offset: self.quantized_offset(),
+            base: self.gpr_a_or_zero(),
+            single: self.bits(16, 16) == 1,
+            gqr: self.bits(17, 19) as u8,
+        })
+    }
+
+    fn decode_psq_lu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        // The data register is an FPR, so only r0 is ruled out as a base.
+        if let Some(base) = self.store_update_base() {
+            Ok(DecodedInstruction::PsqLu {
+                dst: self.fpr_d(),
+                offset: self.quantized_offset(),
+                base,
+                single: self.bits(16, 16) == 1,
+                gqr: self.bits(17, 19) as u8,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_opcode_59(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        match self.a_form_extended_opcode() {
+            18 => {
+                if self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::Fdivs {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_b()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            20 => {
+                if self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::Fsubs {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_b()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            21 => {
+                if self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::Fadds {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_b()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            24 => {
+                if self.bits(11, 15) == 0 && self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::Fres {
+                        dst: self.fpr_d(),
+                        src: self.fpr_b(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            25 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Fmuls {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_c()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            28 => Ok(DecodedInstruction::Fmsubs {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            29 => Ok(DecodedInstruction::Fmadds {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            30 => Ok(DecodedInstruction::Fnmsubs {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            31 => Ok(DecodedInstruction::Fnmadds {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            extended_opcode => Err(ParseErrorKind::UnimplementedExtendedOpcode {
+                opcode: self.opcode(),
+                extended_opcode,
+            }),
+        }
+    }
+
+    fn decode_psq_st(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        Ok(DecodedInstruction::PsqSt {
+            src: self.fpr_d(),
+            offset: self.quantized_offset(),
+            base: self.gpr_a_or_zero(),
+            single: self.bits(16, 16) == 1,
+            gqr: self.bits(17, 19) as u8,
+        })
+    }
+
+    fn decode_psq_stu(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        // The data register is an FPR, so only r0 is ruled out as a base.
+        if let Some(base) = self.store_update_base() {
+            Ok(DecodedInstruction::PsqStu {
+                src: self.fpr_d(),
+                offset: self.quantized_offset(),
+                base,
+                single: self.bits(16, 16) == 1,
+                gqr: self.bits(17, 19) as u8,
+            })
+        } else {
+            Err(ParseErrorKind::IllegalEncoding)
+        }
+    }
+
+    fn decode_opcode_63(self, _address: u32) -> Result<DecodedInstruction, ParseErrorKind> {
+        match self.a_form_extended_opcode() {
+            18 => {
+                if self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::Fdiv {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_b()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            20 => {
+                if self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::Fsub {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_b()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            21 => {
+                if self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::Fadd {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_b()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            23 => Ok(DecodedInstruction::Fsel {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            25 => {
+                if self.bits(16, 20) == 0 {
+                    Ok(DecodedInstruction::Fmul {
+                        dst: self.fpr_d(),
+                        srcs: [self.fpr_a(), self.fpr_c()],
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            26 => {
+                if self.bits(11, 15) == 0 && self.bits(21, 25) == 0 {
+                    Ok(DecodedInstruction::Frsqrte {
+                        dst: self.fpr_d(),
+                        src: self.fpr_b(),
+                        record: self.update_condition_register(),
+                    })
+                } else {
+                    Err(ParseErrorKind::IllegalEncoding)
+                }
+            }
+            28 => Ok(DecodedInstruction::Fmsub {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            29 => Ok(DecodedInstruction::Fmadd {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            30 => Ok(DecodedInstruction::Fnmsub {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            31 => Ok(DecodedInstruction::Fnmadd {
+                dst: self.fpr_d(),
+                srcs: [self.fpr_a(), self.fpr_c(), self.fpr_b()],
+                record: self.update_condition_register(),
+            }),
+            // The remaining instructions are X-form, and all of their extended opcodes have bit 26
+            // clear.
+            _ => match self.extended_opcode() {
+                0 => {
+                    if self.bits(9, 10) == 0 && self.bits(31, 31) == 0 {
+                        Ok(DecodedInstruction::Fcmpu {
+                            crf: self.crf_d(),
+                            srcs: [self.fpr_a(), self.fpr_b()],
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                12 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::Frsp {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                14 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::Fctiw {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                15 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::Fctiwz {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                32 => {
+                    if self.bits(9, 10) == 0 && self.bits(31, 31) == 0 {
+                        Ok(DecodedInstruction::Fcmpo {
+                            crf: self.crf_d(),
+                            srcs: [self.fpr_a(), self.fpr_b()],
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                38 => {
+                    if self.bits(11, 20) == 0 {
+                        Ok(DecodedInstruction::Mtfsb1 {
+                            bit: self.bits(6, 10) as u8,
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                40 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::Fneg {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                64 => {
+                    if self.bits(9, 10) == 0 && self.bits(14, 20) == 0 && self.bits(31, 31) == 0 {
+                        Ok(DecodedInstruction::Mcrfs {
+                            dst: self.crf_d(),
+                            src: self.crf_s(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                70 => {
+                    if self.bits(11, 20) == 0 {
+                        Ok(DecodedInstruction::Mtfsb0 {
+                            bit: self.bits(6, 10) as u8,
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                72 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::Fmr {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                134 => {
+                    if self.bits(9, 15) == 0 && self.bits(20, 20) == 0 {
+                        Ok(DecodedInstruction::Mtfsfi {
+                            crf: self.crf_d(),
+                            immediate: self.bits(16, 19) as u8,
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                136 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::Fnabs {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                264 => {
+                    if self.bits(11, 15) == 0 {
+                        Ok(DecodedInstruction::Fabs {
+                            dst: self.fpr_d(),
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                583 => {
+                    if self.bits(11, 20) == 0 {
+                        Ok(DecodedInstruction::Mffs {
+                            dst: self.fpr_d(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                711 => {
+                    if self.bits(6, 6) == 0 && self.bits(15, 15) == 0 {
+                        Ok(DecodedInstruction::Mtfsf {
+                            fields: self.bits(7, 14) as u8,
+                            src: self.fpr_b(),
+                            record: self.update_condition_register(),
+                        })
+                    } else {
+                        Err(ParseErrorKind::IllegalEncoding)
+                    }
+                }
+                extended_opcode => Err(ParseErrorKind::UnimplementedExtendedOpcode {
+                    opcode: self.opcode(),
+                    extended_opcode,
+                }),
+            },
+        }
+    }
+}
+
+/// Decodes the instructions with one primary opcode.
+type Decoder = fn(EncodedInstruction, u32) -> Result<DecodedInstruction, ParseErrorKind>;
+
+/// Decoders indexed by primary opcode. Opcodes with extended opcodes dispatch again in their own
+/// decoder.
+static DECODERS: [Decoder; 64] = {
+    let mut decoders: [Decoder; 64] = [EncodedInstruction::decode_unimplemented; 64];
+    decoders[3] = EncodedInstruction::decode_twi;
+    decoders[4] = EncodedInstruction::decode_opcode_4;
+    decoders[7] = EncodedInstruction::decode_mulli;
+    decoders[8] = EncodedInstruction::decode_subfic;
+    decoders[10] = EncodedInstruction::decode_cmpli;
+    decoders[11] = EncodedInstruction::decode_cmpi;
+    decoders[12] = EncodedInstruction::decode_addic;
+    decoders[13] = EncodedInstruction::decode_addic;
+    decoders[14] = EncodedInstruction::decode_addi;
+    decoders[15] = EncodedInstruction::decode_addis;
+    decoders[16] = EncodedInstruction::decode_bc;
+    decoders[17] = EncodedInstruction::decode_sc;
+    decoders[18] = EncodedInstruction::decode_b;
+    decoders[19] = EncodedInstruction::decode_opcode_19;
+    decoders[20] = EncodedInstruction::decode_rlwimi;
+    decoders[21] = EncodedInstruction::decode_rlwinm;
+    decoders[23] = EncodedInstruction::decode_rlwnm;
+    decoders[24] = EncodedInstruction::decode_ori;
+    decoders[25] = EncodedInstruction::decode_oris;
+    decoders[26] = EncodedInstruction::decode_xori;
+    decoders[27] = EncodedInstruction::decode_xoris;
+    decoders[28] = EncodedInstruction::decode_andi;
+    decoders[29] = EncodedInstruction::decode_andis;
+    decoders[31] = EncodedInstruction::decode_opcode_31;
+    decoders[32] = EncodedInstruction::decode_lwz;
+    decoders[33] = EncodedInstruction::decode_lwzu;
+    decoders[34] = EncodedInstruction::decode_lbz;
+    decoders[35] = EncodedInstruction::decode_lbzu;
+    decoders[36] = EncodedInstruction::decode_stw;
+    decoders[37] = EncodedInstruction::decode_stwu;
+    decoders[38] = EncodedInstruction::decode_stb;
+    decoders[39] = EncodedInstruction::decode_stbu;
+    decoders[40] = EncodedInstruction::decode_lhz;
+    decoders[41] = EncodedInstruction::decode_lhzu;
+    decoders[42] = EncodedInstruction::decode_lha;
+    decoders[43] = EncodedInstruction::decode_lhau;
+    decoders[44] = EncodedInstruction::decode_sth;
+    decoders[45] = EncodedInstruction::decode_sthu;
+    decoders[46] = EncodedInstruction::decode_lmw;
+    decoders[47] = EncodedInstruction::decode_stmw;
+    decoders[48] = EncodedInstruction::decode_lfs;
+    decoders[49] = EncodedInstruction::decode_lfsu;
+    decoders[50] = EncodedInstruction::decode_lfd;
+    decoders[51] = EncodedInstruction::decode_lfdu;
+    decoders[52] = EncodedInstruction::decode_stfs;
+    decoders[53] = EncodedInstruction::decode_stfsu;
+    decoders[54] = EncodedInstruction::decode_stfd;
+    decoders[55] = EncodedInstruction::decode_stfdu;
+    decoders[56] = EncodedInstruction::decode_psq_l;
+    decoders[57] = EncodedInstruction::decode_psq_lu;
+    decoders[59] = EncodedInstruction::decode_opcode_59;
+    decoders[60] = EncodedInstruction::decode_psq_st;
+    decoders[61] = EncodedInstruction::decode_psq_stu;
+    decoders[63] = EncodedInstruction::decode_opcode_63;
+    decoders
+};
+
+/// A word that doesn't decode to a supported instruction.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[error("0x{address:08x}  0x{word:08x}  {kind}")]
+pub struct ParseError {
+    pub address: u32,
+    pub word: u32,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// The primary opcode of the word.
+    pub fn opcode(&self) -> u32 {
+        EncodedInstruction(self.word).opcode()
+    }
+
+    /// The extended opcode of the word, if its primary opcode has one.
+    pub fn extended_opcode(&self) -> Option<u32> {
+        EncodedInstruction(self.word).dispatched_extended_opcode()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Error, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ParseErrorKind {
+    #[error("unimplemented opcode: {0}")]
+    UnimplementedOpcode(u32),
+
+    #[error(
+        "unimplemented extended opcode: opcode = {opcode}, extended_opcode = {extended_opcode}"
+    )]
+    UnimplementedExtendedOpcode { opcode: u32, extended_opcode: u32 },
+
+    #[error("illegal encoding")]
+    IllegalEncoding,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EncodedInstruction, ParseError, ParseErrorKind};
+
+    #[test]
+    fn bits_basic() {
+        // |xxx-----------------------------|
+        assert_eq!(EncodedInstruction(0x00000000).bits(0, 2), 0x0);
+        assert_eq!(EncodedInstruction(0x20000000).bits(0, 2), 0x1);
+        assert_eq!(EncodedInstruction(0x40000000).bits(0, 2), 0x2);
+        assert_eq!(EncodedInstruction(0x80000000).bits(0, 2), 0x4);
+        assert_eq!(EncodedInstruction(0xe0000000).bits(0, 2), 0x7);
+
+        // |---------------------------xxxxx|
+        assert_eq!(EncodedInstruction(0xaa55aa40).bits(27, 31), 0x00);
+        assert_eq!(EncodedInstruction(0xaa55aa41).bits(27, 31), 0x01);
+        assert_eq!(EncodedInstruction(0xaa55aa42).bits(27, 31), 0x02);
+        assert_eq!(EncodedInstruction(0xaa55aa44).bits(27, 31), 0x04);
+        assert_eq!(EncodedInstruction(0xaa55aa48).bits(27, 31), 0x08);
+        assert_eq!(EncodedInstruction(0xaa55aa50).bits(27, 31), 0x10);
+        assert_eq!(EncodedInstruction(0xaa55aa5f).bits(27, 31), 0x1f);
+    }
+
+    #[test]
+    fn parse_error() {
+        let error = EncodedInstruction(0x7c000002)
+            .parse(0x80003000)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                address: 0x80003000,
+                word: 0x7c000002,
+                kind: ParseErrorKind::UnimplementedExtendedOpcode {
+                    opcode: 31,
+                    extended_opcode: 1,
+                },
+            }
+        );
+        assert_eq!(error.opcode(), 31);
+        assert_eq!(error.extended_opcode(), Some(1));
+        assert_eq!(
+            error.to_string(),
+            "0x80003000  0x7c000002  unimplemented extended opcode: opcode = 31, \
+             extended_opcode = 1"
+        );
+
+        let error = EncodedInstruction(0x00000000).parse(0).unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::UnimplementedOpcode(0));
+        assert_eq!(error.opcode(), 0);
+        assert_eq!(error.extended_opcode(), None);
+
+        // Illegal encodings still have the extended opcode of the instruction they resemble.
+        for (word, extended_opcode) in [
+            // `mfcr` with the reserved bit 31 set.
+            (0x7c600027, 19),
+            // `bclr` with bits 16..=20 set.
+            (0x4e80f020, 16),
+            // `fdivs` with bits 21..=25 set.
+            (0xec2110e4, 18),
+            // `fadd` with bits 21..=25 set.
+            (0xfc2110ea, 21),
+            // `fcmpu` with bits 9..=10 set.
+            (0xfc610800, 0),
+            // `psq_lux` updating r0.
+            (0x1060204c, 38),
+            // `ps_div` with bits 21..=25 set.
+            (0x102110e4, 18),
+        ] {
+            let error = EncodedInstruction(word).parse(0).unwrap_err();
+            assert_eq!(
+                error.kind,
+                ParseErrorKind::IllegalEncoding,
+                "0x{:08x}",
+                word
+            );
+            assert_eq!(
+                error.extended_opcode(),
+                Some(extended_opcode),
+                "0x{:08x}",
+                word
+            );
+        }
+
+        // Unimplemented extended opcodes are the ones the decoder reports.
+        for opcode in [4, 19, 31, 59, 63] {
+            for field in 0..1024 {
+                let error = match EncodedInstruction(opcode << 26 | field << 1).parse(0) {
+                    Ok(_) => continue,
+                    Err(error) => error,
+                };
+                if let ParseErrorKind::UnimplementedExtendedOpcode {
+                    extended_opcode, ..
+                } = error.kind
+                {
+                    assert_eq!(error.extended_opcode(), Some(extended_opcode));
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,1541 @@
+use thiserror::Error;
+
+use crate::{
+    BranchHint, ConditionBehavior, ConditionBit, Crf, CtrBehavior, DecodedInstruction,
+    EncodedInstruction, Fpr, Gpr, GprOrZero, IgnoredBranchBits, NonZeroGpr, Spr, Sr,
+};
+
+impl DecodedInstruction {
+    /// Encodes the instruction as a machine word. The address is only used by the relative forms of
+    /// `b` and `bc`, and must be the address the word will be placed at.
+    ///
+    /// Fails if a branch target is out of range or misaligned, or if the operands describe
+    /// something the decoder would reject, such as an update-form load whose base is also its
+    /// destination.
+    pub fn encode(self, address: u32) -> Result<EncodedInstruction, EncodeError> {
+        let word = match self {
+            DecodedInstruction::Add {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(266)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Addc {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(10)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Adde {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(138)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Addi {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(14)
+                .gpr_c(dst)
+                .gpr_a(src)
+                .signed_immediate(immediate),
+            DecodedInstruction::Addic {
+                dst,
+                src,
+                immediate,
+                record,
+            } => Builder::new(if record { 13 } else { 12 })
+                .gpr_c(dst)
+                .gpr_a(src)
+                .signed_immediate(immediate),
+            DecodedInstruction::Addis {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(15)
+                .gpr_c(dst)
+                .gpr_a(src)
+                .signed_immediate(immediate),
+            DecodedInstruction::Addme {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(234)
+                .gpr_c(dst)
+                .gpr_a(src)
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Addze {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(202)
+                .gpr_c(dst)
+                .gpr_a(src)
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::And { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(28)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Andc { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(60)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Andi {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(28)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .unsigned_immediate(immediate),
+            DecodedInstruction::Andis {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(29)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .unsigned_immediate(immediate),
+            DecodedInstruction::B {
+                link,
+                absolute,
+                target,
+            } => Builder::new(18)
+                .large_branch_offset(branch_offset(address, absolute, target, 26)?)
+                .absolute_address(absolute)
+                .link(link),
+            DecodedInstruction::Bc {
+                condition,
+                ctr,
+                hint,
+                ignored,
+                link,
+                absolute,
+                target,
+            } => Builder::new(16)
+                .branch_condition(condition, ctr, hint, ignored)
+                .small_branch_offset(branch_offset(address, absolute, target, 16)?)
+                .absolute_address(absolute)
+                .link(link),
+            DecodedInstruction::Bcctr {
+                condition,
+                hint,
+                ignored,
+                link,
+            } => Builder::new(19)
+                .extended_opcode(528)
+                .branch_condition(condition, CtrBehavior::None, hint, ignored)
+                .link(link),
+            DecodedInstruction::Bclr {
+                condition,
+                ctr,
+                hint,
+                ignored,
+                link,
+            } => Builder::new(19)
+                .extended_opcode(16)
+                .branch_condition(condition, ctr, hint, ignored)
+                .link(link),
+            DecodedInstruction::Cmp { crf, srcs } => Builder::new(31)
+                .extended_opcode(0)
+                .crf_d(crf)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1]),
+            DecodedInstruction::Cmpi {
+                crf,
+                src,
+                immediate,
+            } => Builder::new(11)
+                .crf_d(crf)
+                .gpr_a(src)
+                .signed_immediate(immediate),
+            DecodedInstruction::Cmpl { crf, srcs } => Builder::new(31)
+                .extended_opcode(32)
+                .crf_d(crf)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1]),
+            DecodedInstruction::Cmpli {
+                crf,
+                src,
+                immediate,
+            } => Builder::new(10)
+                .crf_d(crf)
+                .gpr_a(src)
+                .unsigned_immediate(immediate),
+            DecodedInstruction::Cntlzw { dst, src, record } => Builder::new(31)
+                .extended_opcode(26)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .update_condition_register(record),
+            DecodedInstruction::Crand { dst, srcs } => Builder::new(19)
+                .extended_opcode(257)
+                .crb_d(dst)
+                .crb_a(srcs[0])
+                .crb_b(srcs[1]),
+            DecodedInstruction::Crandc { dst, srcs } => Builder::new(19)
+                .extended_opcode(129)
+                .crb_d(dst)
+                .crb_a(srcs[0])
+                .crb_b(srcs[1]),
+            DecodedInstruction::Creqv { dst, srcs } => Builder::new(19)
+                .extended_opcode(289)
+                .crb_d(dst)
+                .crb_a(srcs[0])
+                .crb_b(srcs[1]),
+            DecodedInstruction::Crnand { dst, srcs } => Builder::new(19)
+                .extended_opcode(225)
+                .crb_d(dst)
+                .crb_a(srcs[0])
+                .crb_b(srcs[1]),
+            DecodedInstruction::Crnor { dst, srcs } => Builder::new(19)
+                .extended_opcode(33)
+                .crb_d(dst)
+                .crb_a(srcs[0])
+                .crb_b(srcs[1]),
+            DecodedInstruction::Cror { dst, srcs } => Builder::new(19)
+                .extended_opcode(449)
+                .crb_d(dst)
+                .crb_a(srcs[0])
+                .crb_b(srcs[1]),
+            DecodedInstruction::Crorc { dst, srcs } => Builder::new(19)
+                .extended_opcode(417)
+                .crb_d(dst)
+                .crb_a(srcs[0])
+                .crb_b(srcs[1]),
+            DecodedInstruction::Crxor { dst, srcs } => Builder::new(19)
+                .extended_opcode(193)
+                .crb_d(dst)
+                .crb_a(srcs[0])
+                .crb_b(srcs[1]),
+            DecodedInstruction::Dcbf { base, index } => Builder::new(31)
+                .extended_opcode(86)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Dcbi { base, index } => Builder::new(31)
+                .extended_opcode(470)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Dcbst { base, index } => Builder::new(31)
+                .extended_opcode(54)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Dcbt { base, index } => Builder::new(31)
+                .extended_opcode(278)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Dcbtst { base, index } => Builder::new(31)
+                .extended_opcode(246)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Dcbz { base, index } => Builder::new(31)
+                .extended_opcode(1014)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::DcbzL { base, index } => Builder::new(4)
+                .extended_opcode(1014)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Divw {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(491)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Divwu {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(459)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Eieio => Builder::new(31).extended_opcode(854),
+            DecodedInstruction::Eqv { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(284)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Extsb { dst, src, record } => Builder::new(31)
+                .extended_opcode(954)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .update_condition_register(record),
+            DecodedInstruction::Extsh { dst, src, record } => Builder::new(31)
+                .extended_opcode(922)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .update_condition_register(record),
+            DecodedInstruction::Fabs { dst, src, record } => Builder::new(63)
+                .extended_opcode(264)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Fadd { dst, srcs, record } => Builder::new(63)
+                .a_form_extended_opcode(21)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Fadds { dst, srcs, record } => Builder::new(59)
+                .a_form_extended_opcode(21)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Fcmpo { crf, srcs } => Builder::new(63)
+                .extended_opcode(32)
+                .crf_d(crf)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1]),
+            DecodedInstruction::Fcmpu { crf, srcs } => Builder::new(63)
+                .extended_opcode(0)
+                .crf_d(crf)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1]),
+            DecodedInstruction::Fctiw { dst, src, record } => Builder::new(63)
+                .extended_opcode(14)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Fctiwz { dst, src, record } => Builder::new(63)
+                .extended_opcode(15)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Fdiv { dst, srcs, record } => Builder::new(63)
+                .a_form_extended_opcode(18)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Fdivs { dst, srcs, record } => Builder::new(59)
+                .a_form_extended_opcode(18)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Fmadd { dst, srcs, record } => Builder::new(63)
+                .a_form_extended_opcode(29)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::Fmadds { dst, srcs, record } => Builder::new(59)
+                .a_form_extended_opcode(29)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::Fmr { dst, src, record } => Builder::new(63)
+                .extended_opcode(72)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Fmsub { dst, srcs, record } => Builder::new(63)
+                .a_form_extended_opcode(28)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::Fmsubs { dst, srcs, record } => Builder::new(59)
+                .a_form_extended_opcode(28)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::Fmul { dst, srcs, record } => Builder::new(63)
+                .a_form_extended_opcode(25)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Fmuls { dst, srcs, record } => Builder::new(59)
+                .a_form_extended_opcode(25)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Fnabs { dst, src, record } => Builder::new(63)
+                .extended_opcode(136)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Fneg { dst, src, record } => Builder::new(63)
+                .extended_opcode(40)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Fnmadd { dst, srcs, record } => Builder::new(63)
+                .a_form_extended_opcode(31)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::Fnmadds { dst, srcs, record } => Builder::new(59)
+                .a_form_extended_opcode(31)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::Fnmsub { dst, srcs, record } => Builder::new(63)
+                .a_form_extended_opcode(30)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::Fnmsubs { dst, srcs, record } => Builder::new(59)
+                .a_form_extended_opcode(30)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::Fres { dst, src, record } => Builder::new(59)
+                .a_form_extended_opcode(24)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Frsp { dst, src, record } => Builder::new(63)
+                .extended_opcode(12)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Frsqrte { dst, src, record } => Builder::new(63)
+                .a_form_extended_opcode(26)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Fsel { dst, srcs, record } => Builder::new(63)
+                .a_form_extended_opcode(23)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::Fsub { dst, srcs, record } => Builder::new(63)
+                .a_form_extended_opcode(20)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Fsubs { dst, srcs, record } => Builder::new(59)
+                .a_form_extended_opcode(20)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Icbi { base, index } => Builder::new(31)
+                .extended_opcode(982)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Isync => Builder::new(19).extended_opcode(150),
+            DecodedInstruction::Lbz { dst, offset, base } => Builder::new(34)
+                .gpr_c(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lbzu { dst, offset, base } => Builder::new(35)
+                .gpr_c(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lbzux { dst, base, index } => Builder::new(31)
+                .extended_opcode(119)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lbzx { dst, base, index } => Builder::new(31)
+                .extended_opcode(87)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lfd { dst, offset, base } => Builder::new(50)
+                .fpr_d(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lfdu { dst, offset, base } => Builder::new(51)
+                .fpr_d(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lfdux { dst, base, index } => Builder::new(31)
+                .extended_opcode(631)
+                .fpr_d(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lfdx { dst, base, index } => Builder::new(31)
+                .extended_opcode(599)
+                .fpr_d(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lfs { dst, offset, base } => Builder::new(48)
+                .fpr_d(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lfsu { dst, offset, base } => Builder::new(49)
+                .fpr_d(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lfsux { dst, base, index } => Builder::new(31)
+                .extended_opcode(567)
+                .fpr_d(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lfsx { dst, base, index } => Builder::new(31)
+                .extended_opcode(535)
+                .fpr_d(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lha { dst, offset, base } => Builder::new(42)
+                .gpr_c(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lhau { dst, offset, base } => Builder::new(43)
+                .gpr_c(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lhaux { dst, base, index } => Builder::new(31)
+                .extended_opcode(375)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lhax { dst, base, index } => Builder::new(31)
+                .extended_opcode(343)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lhbrx { dst, base, index } => Builder::new(31)
+                .extended_opcode(790)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lhz { dst, offset, base } => Builder::new(40)
+                .gpr_c(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lhzu { dst, offset, base } => Builder::new(41)
+                .gpr_c(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lhzux { dst, base, index } => Builder::new(31)
+                .extended_opcode(311)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lhzx { dst, base, index } => Builder::new(31)
+                .extended_opcode(279)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lmw { dst, offset, base } => Builder::new(46)
+                .gpr_c(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lwbrx { dst, base, index } => Builder::new(31)
+                .extended_opcode(534)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lwz { dst, offset, base } => Builder::new(32)
+                .gpr_c(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lwzu { dst, offset, base } => Builder::new(33)
+                .gpr_c(dst)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Lwzux { dst, base, index } => Builder::new(31)
+                .extended_opcode(55)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Lwzx { dst, base, index } => Builder::new(31)
+                .extended_opcode(23)
+                .gpr_c(dst)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Mcrf { dst, src } => {
+                Builder::new(19).extended_opcode(0).crf_d(dst).crf_s(src)
+            }
+            DecodedInstruction::Mcrfs { dst, src } => {
+                Builder::new(63).extended_opcode(64).crf_d(dst).crf_s(src)
+            }
+            DecodedInstruction::Mcrxr { dst } => Builder::new(31).extended_opcode(512).crf_d(dst),
+            DecodedInstruction::Mfcr { dst } => Builder::new(31).extended_opcode(19).gpr_c(dst),
+            DecodedInstruction::Mffs { dst, record } => Builder::new(63)
+                .extended_opcode(583)
+                .fpr_d(dst)
+                .update_condition_register(record),
+            DecodedInstruction::Mfmsr { dst } => Builder::new(31).extended_opcode(83).gpr_c(dst),
+            DecodedInstruction::Mfspr { spr, dst } => {
+                Builder::new(31).extended_opcode(339).spr(spr).gpr_c(dst)
+            }
+            DecodedInstruction::Mfsr { dst, sr } => {
+                Builder::new(31).extended_opcode(595).gpr_c(dst).sr(sr)
+            }
+            DecodedInstruction::Mfsrin { dst, index } => Builder::new(31)
+                .extended_opcode(659)
+                .gpr_c(dst)
+                .gpr_b(index),
+            DecodedInstruction::Mftb { spr, dst } => {
+                Builder::new(31).extended_opcode(371).tbr(spr).gpr_c(dst)
+            }
+            DecodedInstruction::Mtcrf { fields, src } => Builder::new(31)
+                .extended_opcode(144)
+                .bits(12, 19, fields as u32)
+                .gpr_c(src),
+            DecodedInstruction::Mtfsb0 { bit, record } => Builder::new(63)
+                .extended_opcode(70)
+                .bits(6, 10, bit as u32)
+                .update_condition_register(record),
+            DecodedInstruction::Mtfsb1 { bit, record } => Builder::new(63)
+                .extended_opcode(38)
+                .bits(6, 10, bit as u32)
+                .update_condition_register(record),
+            DecodedInstruction::Mtfsf {
+                fields,
+                src,
+                record,
+            } => Builder::new(63)
+                .extended_opcode(711)
+                .bits(7, 14, fields as u32)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::Mtfsfi {
+                crf,
+                immediate,
+                record,
+            } => Builder::new(63)
+                .extended_opcode(134)
+                .crf_d(crf)
+                .bits(16, 19, immediate as u32)
+                .update_condition_register(record),
+            DecodedInstruction::Mtmsr { src } => Builder::new(31).extended_opcode(146).gpr_c(src),
+            DecodedInstruction::Mtspr { spr, src } => {
+                Builder::new(31).extended_opcode(467).spr(spr).gpr_c(src)
+            }
+            DecodedInstruction::Mtsr { sr, src } => {
+                Builder::new(31).extended_opcode(210).sr(sr).gpr_c(src)
+            }
+            DecodedInstruction::Mtsrin { src, index } => Builder::new(31)
+                .extended_opcode(242)
+                .gpr_c(src)
+                .gpr_b(index),
+            DecodedInstruction::Mulhw { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(75)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Mulhwu { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(11)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Mulli {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(7)
+                .gpr_c(dst)
+                .gpr_a(src)
+                .signed_immediate(immediate),
+            DecodedInstruction::Mullw {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(235)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Nand { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(476)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Neg {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(104)
+                .gpr_c(dst)
+                .gpr_a(src)
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Nor { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(124)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Or { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(444)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Orc { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(412)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Ori {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(24)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .unsigned_immediate(immediate),
+            DecodedInstruction::Oris {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(25)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .unsigned_immediate(immediate),
+            DecodedInstruction::PsAbs { dst, src, record } => Builder::new(4)
+                .extended_opcode(264)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::PsAdd { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(21)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsCmpo0 { crf, srcs } => Builder::new(4)
+                .extended_opcode(32)
+                .crf_d(crf)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1]),
+            DecodedInstruction::PsCmpo1 { crf, srcs } => Builder::new(4)
+                .extended_opcode(96)
+                .crf_d(crf)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1]),
+            DecodedInstruction::PsCmpu0 { crf, srcs } => Builder::new(4)
+                .extended_opcode(0)
+                .crf_d(crf)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1]),
+            DecodedInstruction::PsCmpu1 { crf, srcs } => Builder::new(4)
+                .extended_opcode(64)
+                .crf_d(crf)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1]),
+            DecodedInstruction::PsDiv { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(18)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsMadd { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(29)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::PsMadds0 { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(14)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::PsMadds1 { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(15)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::PsMerge00 { dst, srcs, record } => Builder::new(4)
+                .extended_opcode(528)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsMerge01 { dst, srcs, record } => Builder::new(4)
+                .extended_opcode(560)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsMerge10 { dst, srcs, record } => Builder::new(4)
+                .extended_opcode(592)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsMerge11 { dst, srcs, record } => Builder::new(4)
+                .extended_opcode(624)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsMr { dst, src, record } => Builder::new(4)
+                .extended_opcode(72)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::PsMsub { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(28)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::PsMul { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(25)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsMuls0 { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(12)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsMuls1 { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(13)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsNabs { dst, src, record } => Builder::new(4)
+                .extended_opcode(136)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::PsNeg { dst, src, record } => Builder::new(4)
+                .extended_opcode(40)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::PsNmadd { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(31)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::PsNmsub { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(30)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::PsRes { dst, src, record } => Builder::new(4)
+                .a_form_extended_opcode(24)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::PsRsqrte { dst, src, record } => Builder::new(4)
+                .a_form_extended_opcode(26)
+                .fpr_d(dst)
+                .fpr_b(src)
+                .update_condition_register(record),
+            DecodedInstruction::PsSel { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(23)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::PsSub { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(20)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::PsSum0 { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(10)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::PsSum1 { dst, srcs, record } => Builder::new(4)
+                .a_form_extended_opcode(11)
+                .fpr_d(dst)
+                .fpr_a(srcs[0])
+                .fpr_c(srcs[1])
+                .fpr_b(srcs[2])
+                .update_condition_register(record),
+            DecodedInstruction::PsqL {
+                dst,
+                offset,
+                base,
+                single,
+                gqr,
+            } => Builder::new(56)
+                .fpr_d(dst)
+                .quantized_offset(offset)
+                .gpr_a(base)
+                .bits(16, 16, single as u32)
+                .bits(17, 19, gqr as u32),
+            DecodedInstruction::PsqLu {
+                dst,
+                offset,
+                base,
+                single,
+                gqr,
+            } => Builder::new(57)
+                .fpr_d(dst)
+                .quantized_offset(offset)
+                .gpr_a(base)
+                .bits(16, 16, single as u32)
+                .bits(17, 19, gqr as u32),
+            DecodedInstruction::PsqLux {
+                dst,
+                base,
+                index,
+                single,
+                gqr,
+            } => Builder::new(4)
+                .bits(25, 30, 38)
+                .fpr_d(dst)
+                .gpr_a(base)
+                .gpr_b(index)
+                .bits(21, 21, single as u32)
+                .bits(22, 24, gqr as u32),
+            DecodedInstruction::PsqLx {
+                dst,
+                base,
+                index,
+                single,
+                gqr,
+            } => Builder::new(4)
+                .bits(25, 30, 6)
+                .fpr_d(dst)
+                .gpr_a(base)
+                .gpr_b(index)
+                .bits(21, 21, single as u32)
+                .bits(22, 24, gqr as u32),
+            DecodedInstruction::PsqSt {
+                src,
+                offset,
+                base,
+                single,
+                gqr,
+            } => Builder::new(60)
+                .fpr_d(src)
+                .quantized_offset(offset)
+                .gpr_a(base)
+                .bits(16, 16, single as u32)
+                .bits(17, 19, gqr as u32),
+            DecodedInstruction::PsqStu {
+                src,
+                offset,
+                base,
+                single,
+                gqr,
+            } => Builder::new(61)
+                .fpr_d(src)
+                .quantized_offset(offset)
+                .gpr_a(base)
+                .bits(16, 16, single as u32)
+                .bits(17, 19, gqr as u32),
+            DecodedInstruction::PsqStux {
+                src,
+                base,
+                index,
+                single,
+                gqr,
+            } => Builder::new(4)
+                .bits(25, 30, 39)
+                .fpr_d(src)
+                .gpr_a(base)
+                .gpr_b(index)
+                .bits(21, 21, single as u32)
+                .bits(22, 24, gqr as u32),
+            DecodedInstruction::PsqStx {
+                src,
+                base,
+                index,
+                single,
+                gqr,
+            } => Builder::new(4)
+                .bits(25, 30, 7)
+                .fpr_d(src)
+                .gpr_a(base)
+                .gpr_b(index)
+                .bits(21, 21, single as u32)
+                .bits(22, 24, gqr as u32),
+            DecodedInstruction::Rfi => Builder::new(19).extended_opcode(50),
+            DecodedInstruction::Rlwimi {
+                dst,
+                src,
+                shift,
+                mask_begin,
+                mask_end,
+                record,
+            } => Builder::new(20)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .shift(shift)
+                .bits(21, 25, mask_begin as u32)
+                .bits(26, 30, mask_end as u32)
+                .update_condition_register(record),
+            DecodedInstruction::Rlwinm {
+                dst,
+                src,
+                shift,
+                mask_begin,
+                mask_end,
+                record,
+            } => Builder::new(21)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .shift(shift)
+                .bits(21, 25, mask_begin as u32)
+                .bits(26, 30, mask_end as u32)
+                .update_condition_register(record),
+            DecodedInstruction::Rlwnm {
+                dst,
+                src,
+                shift,
+                mask_begin,
+                mask_end,
+                record,
+            } => Builder::new(23)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .gpr_b(shift)
+                .bits(21, 25, mask_begin as u32)
+                .bits(26, 30, mask_end as u32)
+                .update_condition_register(record),
+            DecodedInstruction::Sc => Builder::new(17).bits(30, 30, 1),
+            DecodedInstruction::Slw { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(24)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Sraw { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(792)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Srawi {
+                dst,
+                src,
+                shift,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(824)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .shift(shift)
+                .update_condition_register(record),
+            DecodedInstruction::Srw { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(536)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Stb { src, offset, base } => Builder::new(38)
+                .gpr_c(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Stbu { src, offset, base } => Builder::new(39)
+                .gpr_c(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Stbux { src, base, index } => Builder::new(31)
+                .extended_opcode(247)
+                .gpr_c(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Stbx { src, base, index } => Builder::new(31)
+                .extended_opcode(215)
+                .gpr_c(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Stfd { src, offset, base } => Builder::new(54)
+                .fpr_d(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Stfdu { src, offset, base } => Builder::new(55)
+                .fpr_d(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Stfdux { src, base, index } => Builder::new(31)
+                .extended_opcode(759)
+                .fpr_d(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Stfdx { src, base, index } => Builder::new(31)
+                .extended_opcode(727)
+                .fpr_d(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Stfiwx { src, base, index } => Builder::new(31)
+                .extended_opcode(983)
+                .fpr_d(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Stfs { src, offset, base } => Builder::new(52)
+                .fpr_d(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Stfsu { src, offset, base } => Builder::new(53)
+                .fpr_d(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Stfsux { src, base, index } => Builder::new(31)
+                .extended_opcode(695)
+                .fpr_d(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Stfsx { src, base, index } => Builder::new(31)
+                .extended_opcode(663)
+                .fpr_d(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Sth { src, offset, base } => Builder::new(44)
+                .gpr_c(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Sthbrx { src, base, index } => Builder::new(31)
+                .extended_opcode(918)
+                .gpr_c(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Sthu { src, offset, base } => Builder::new(45)
+                .gpr_c(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Sthux { src, base, index } => Builder::new(31)
+                .extended_opcode(439)
+                .gpr_c(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Sthx { src, base, index } => Builder::new(31)
+                .extended_opcode(407)
+                .gpr_c(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Stmw { src, offset, base } => Builder::new(47)
+                .gpr_c(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Stw { src, offset, base } => Builder::new(36)
+                .gpr_c(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Stwbrx { src, base, index } => Builder::new(31)
+                .extended_opcode(662)
+                .gpr_c(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Stwu { src, offset, base } => Builder::new(37)
+                .gpr_c(src)
+                .signed_immediate(offset)
+                .gpr_a(base),
+            DecodedInstruction::Stwux { src, base, index } => Builder::new(31)
+                .extended_opcode(183)
+                .gpr_c(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Stwx { src, base, index } => Builder::new(31)
+                .extended_opcode(151)
+                .gpr_c(src)
+                .gpr_a(base)
+                .gpr_b(index),
+            DecodedInstruction::Subf {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(40)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Subfc {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(8)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Subfe {
+                dst,
+                srcs,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(136)
+                .gpr_c(dst)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1])
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Subfic {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(8)
+                .gpr_c(dst)
+                .gpr_a(src)
+                .signed_immediate(immediate),
+            DecodedInstruction::Subfme {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(232)
+                .gpr_c(dst)
+                .gpr_a(src)
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Subfze {
+                dst,
+                src,
+                overflow_enable,
+                record,
+            } => Builder::new(31)
+                .extended_opcode(200)
+                .gpr_c(dst)
+                .gpr_a(src)
+                .overflow_enable(overflow_enable)
+                .update_condition_register(record),
+            DecodedInstruction::Sync => Builder::new(31).extended_opcode(598),
+            DecodedInstruction::Tw { to, srcs } => Builder::new(31)
+                .extended_opcode(4)
+                .bits(6, 10, to as u32)
+                .gpr_a(srcs[0])
+                .gpr_b(srcs[1]),
+            DecodedInstruction::Twi { to, src, immediate } => Builder::new(3)
+                .bits(6, 10, to as u32)
+                .gpr_a(src)
+                .signed_immediate(immediate),
+            DecodedInstruction::Xor { dst, srcs, record } => Builder::new(31)
+                .extended_opcode(316)
+                .gpr_a(dst)
+                .gpr_c(srcs[0])
+                .gpr_b(srcs[1])
+                .update_condition_register(record),
+            DecodedInstruction::Xori {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(26)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .unsigned_immediate(immediate),
+            DecodedInstruction::Xoris {
+                dst,
+                src,
+                immediate,
+            } => Builder::new(27)
+                .gpr_a(dst)
+                .gpr_c(src)
+                .unsigned_immediate(immediate),
+        };
+
+        // The builder places fields without checking them against each other, so let the decoder
+        // reject combinations it would never produce.
+        let encoded = EncodedInstruction(word.0);
+        match encoded.parse(address) {
+            Ok(decoded) if decoded == self => Ok(encoded),
+            _ => Err(EncodeError::IllegalOperands(self)),
+        }
+    }
+}
+
+/// Returns the displacement to store in a branch's target field, which holds a signed,
+/// word-aligned value `width` bits wide.
+pub(super) fn branch_offset(
+    address: u32,
+    absolute: bool,
+    target: u32,
+    width: u32,
+) -> Result<u32, EncodeError> {
+    let offset = if absolute {
+        target
+    } else {
+        target.wrapping_sub(address)
+    };
+    let limit = 1i64 << (width - 1);
+    if offset & 3 == 0 && (-limit..limit).contains(&i64::from(offset as i32)) {
+        Ok(offset)
+    } else {
+        Err(EncodeError::BranchOutOfRange(target))
+    }
+}
+
+/// Returns the `BO` and `BI` fields of a conditional branch. An unconditional branch leaves `BI`
+/// as zero, a hint sets the y bit, and the ignored bits are set as given.
+pub(super) fn bo_and_bi(
+    condition: ConditionBehavior,
+    ctr: CtrBehavior,
+    hint: Option<BranchHint>,
+    ignored: IgnoredBranchBits,
+) -> (u32, u32) {
+    let (condition, bi) = match condition {
+        ConditionBehavior::BranchFalse(bit) => (0x00, bit.get()),
+        ConditionBehavior::BranchTrue(bit) => (0x08, bit.get()),
+        ConditionBehavior::BranchAlways => (0x10, 0),
+    };
+    let ctr = match ctr {
+        CtrBehavior::DecrementBranchNonzero => 0x00,
+        CtrBehavior::DecrementBranchZero => 0x02,
+        CtrBehavior::None => 0x04,
+    };
+    let y = if hint.is_some() { 0x01 } else { 0x00 };
+    (
+        condition | ctr | y | u32::from(ignored.bo),
+        bi | u32::from(ignored.bi),
+    )
+}
+
+/// Assembles a machine word field by field. The setters mirror the getters on
+/// `EncodedInstruction` and use the same bit numbering.
+#[derive(Clone, Copy)]
+struct Builder(u32);
+
+impl Builder {
+    fn new(opcode: u32) -> Builder {
+        Builder(0).bits(0, 5, opcode)
+    }
+
+    fn bits(self, from: u32, to: u32, value: u32) -> Builder {
+        if !(from < 32 && to < 32 && from <= to) {
+            panic!("bad indices: bits(_, {}, {}, _)", from, to);
+        }
+        let mask = (((1u64 << (to - from + 1)) - 1) as u32) << (31 - to);
+        Builder(self.0 & !mask | (value << (31 - to)) & mask)
+    }
+
+    fn extended_opcode(self, extended_opcode: u32) -> Builder {
+        self.bits(21, 30, extended_opcode)
+    }
+
+    fn a_form_extended_opcode(self, extended_opcode: u32) -> Builder {
+        self.bits(26, 30, extended_opcode)
+    }
+
+    fn gpr_a(self, gpr: impl RegisterField) -> Builder {
+        self.bits(11, 15, gpr.field())
+    }
+
+    fn gpr_b(self, gpr: impl RegisterField) -> Builder {
+        self.bits(16, 20, gpr.field())
+    }
+
+    fn gpr_c(self, gpr: impl RegisterField) -> Builder {
+        self.bits(6, 10, gpr.field())
+    }
+
+    fn fpr_d(self, fpr: Fpr) -> Builder {
+        self.bits(6, 10, fpr.as_u32())
+    }
+
+    fn fpr_a(self, fpr: Fpr) -> Builder {
+        self.bits(11, 15, fpr.as_u32())
+    }
+
+    fn fpr_b(self, fpr: Fpr) -> Builder {
+        self.bits(16, 20, fpr.as_u32())
+    }
+
+    fn fpr_c(self, fpr: Fpr) -> Builder {
+        self.bits(21, 25, fpr.as_u32())
+    }
+
+    fn crf_d(self, crf: Crf) -> Builder {
+        self.bits(6, 8, crf.get())
+    }
+
+    fn crf_s(self, crf: Crf) -> Builder {
+        self.bits(11, 13, crf.get())
+    }
+
+    fn crb_a(self, bit: ConditionBit) -> Builder {
+        self.bits(11, 15, bit.get())
+    }
+
+    fn crb_b(self, bit: ConditionBit) -> Builder {
+        self.bits(16, 20, bit.get())
+    }
+
+    fn crb_d(self, bit: ConditionBit) -> Builder {
+        self.bits(6, 10, bit.get())
+    }
+
+    /// Sets `BO` and `BI`. Whether the hint matches the branch direction, and whether the ignored
+    /// bits are really ignored, is left to the re-parse check.
+    fn branch_condition(
+        self,
+        condition: ConditionBehavior,
+        ctr: CtrBehavior,
+        hint: Option<BranchHint>,
+        ignored: IgnoredBranchBits,
+    ) -> Builder {
+        let (bo, bi) = bo_and_bi(condition, ctr, hint, ignored);
+        self.bits(6, 10, bo).bits(11, 15, bi)
+    }
+
+    fn spr(self, spr: Spr) -> Builder {
+        self.spr_field(spr.as_u32())
+    }
+
+    /// Sets the TBR field of `mftb`, which numbers the time base registers differently from the
+    /// SPRs that write them.
+    fn tbr(self, spr: Spr) -> Builder {
+        match spr {
+            Spr::TimeBaseUpper => self.spr_field(269),
+            _ => self.spr_field(268),
+        }
+    }
+
+    fn spr_field(self, spr: u32) -> Builder {
+        self.bits(11, 15, spr & 0x1f).bits(16, 20, spr >> 5)
+    }
+
+    fn sr(self, sr: Sr) -> Builder {
+        self.bits(12, 15, sr.get())
+    }
+
+    fn shift(self, shift: u8) -> Builder {
+        self.bits(16, 20, u32::from(shift))
+    }
+
+    fn unsigned_immediate(self, immediate: u16) -> Builder {
+        self.bits(16, 31, u32::from(immediate))
+    }
+
+    fn signed_immediate(self, immediate: i16) -> Builder {
+        self.bits(16, 31, immediate as u16 as u32)
+    }
+
+    fn quantized_offset(self, offset: i16) -> Builder {
+        self.bits(20, 31, offset as u16 as u32)
+    }
+
+    fn small_branch_offset(self, offset: u32) -> Builder {
+        self.bits(16, 29, offset >> 2)
+    }
+
+    fn large_branch_offset(self, offset: u32) -> Builder {
+        self.bits(6, 29, offset >> 2)
+    }
+
+    fn overflow_enable(self, overflow_enable: bool) -> Builder {
+        self.bits(21, 21, overflow_enable as u32)
+    }
+
+    fn update_condition_register(self, record: bool) -> Builder {
+        self.bits(31, 31, record as u32)
+    }
+
+    fn absolute_address(self, absolute: bool) -> Builder {
+        self.bits(30, 30, absolute as u32)
+    }
+
+    fn link(self, link: bool) -> Builder {
+        self.bits(31, 31, link as u32)
+    }
+}
+
+/// The GPR types, which all occupy a five-bit field.
+trait RegisterField {
+    fn field(self) -> u32;
+}
+
+impl RegisterField for Gpr {
+    fn field(self) -> u32 {
+        self.as_u32()
+    }
+}
+
+impl RegisterField for GprOrZero {
+    fn field(self) -> u32 {
+        self.as_u32()
+    }
+}
+
+impl RegisterField for NonZeroGpr {
+    fn field(self) -> u32 {
+        self.as_u32()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum EncodeError {
+    #[error("branch target 0x{0:08x} is out of range or misaligned")]
+    BranchOutOfRange(u32),
+
+    #[error("operands cannot be encoded: {0:?}")]
+    IllegalOperands(DecodedInstruction),
+}
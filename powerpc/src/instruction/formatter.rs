@@ -0,0 +1,714 @@
+//! Prints instructions in the syntax of other tools, so that their output can be compared line by
+//! line.
+
+use std::fmt::{self, Write};
+
+use crate::instruction::assembler::disassemble;
+use crate::instruction::encoder::bo_and_bi;
+use crate::instruction::format_small_i16::FormatSmallI16;
+use crate::instruction::format_small_u16::FormatSmallU16;
+use crate::{
+    BranchHint, ConditionBehavior, ConditionBit, Crf, CtrBehavior, DecodedInstruction, Fpr, Gpr,
+    GprOrZero, IgnoredBranchBits, Resolver, Sr,
+};
+
+/// The mnemonics and operand spellings to print.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Syntax {
+    /// Every instruction under its own mnemonic with all of its operands, such as
+    /// `addi r3, 0, 1`, `rlwinm r3, r4, 2, 0, 29` and `bc 12, 2, 0x80003100`.
+    Raw,
+    /// The simplified mnemonics printed by GNU objdump and accepted by the devkitPPC assembler,
+    /// such as `li r3,1`, `slwi r3,r4,2` and `beq 0x80003100`. Condition register bits are
+    /// written as `4*cr1+eq`.
+    Gnu,
+    /// The simplified mnemonics of the CodeWarrior toolchain. These follow GNU, except that
+    /// negative additions are written as `subi`, `subis` and `subic`, and condition register bits
+    /// as numbers.
+    CodeWarrior,
+}
+
+/// Formats instructions in a chosen syntax. `Display` prints the syntax that `assemble` reads;
+/// this is for lining output up with other disassemblers.
+///
+/// ```
+/// use powerpc::{EncodedInstruction, Formatter, Syntax};
+///
+/// let instruction = EncodedInstruction(0x5483103a).parse(0x80003000).unwrap();
+/// assert_eq!(Formatter::new(Syntax::Gnu).format(instruction), "slwi r3,r4,2");
+/// assert_eq!(Formatter::new(Syntax::Raw).format(instruction), "rlwinm r3, r4, 2, 0, 29");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Formatter<'a> {
+    syntax: Syntax,
+    hex_immediates: bool,
+    elide_cr0: bool,
+    resolver: Option<&'a dyn Resolver>,
+}
+
+impl<'a> Formatter<'a> {
+    /// A formatter with the defaults of the syntax. GNU prints immediates in decimal, and raw and
+    /// CodeWarrior in hexadecimal. Only the raw syntax spells out `cr0`.
+    pub fn new(syntax: Syntax) -> Formatter<'a> {
+        Formatter {
+            syntax,
+            hex_immediates: syntax != Syntax::Gnu,
+            elide_cr0: syntax != Syntax::Raw,
+            resolver: None,
+        }
+    }
+
+    /// Prints immediates, offsets and masks above 9 in hexadecimal. Shift counts, bit numbers and
+    /// SPR numbers are always decimal.
+    pub fn hex_immediates(self, hex_immediates: bool) -> Formatter<'a> {
+        Formatter {
+            hex_immediates,
+            ..self
+        }
+    }
+
+    /// Leaves out `cr0` where it is optional, as in `cmpwi r3, 0` and `bne 0x80003100`.
+    pub fn elide_cr0(self, elide_cr0: bool) -> Formatter<'a> {
+        Formatter { elide_cr0, ..self }
+    }
+
+    /// Prints branch targets, and the addresses passed to `format_address`, as the symbols that
+    /// `resolver` names, such as `OSReport` or `loop+0x10`.
+    pub fn resolver<'b>(self, resolver: &'b dyn Resolver) -> Formatter<'b> {
+        Formatter {
+            syntax: self.syntax,
+            hex_immediates: self.hex_immediates,
+            elide_cr0: self.elide_cr0,
+            resolver: Some(resolver),
+        }
+    }
+
+    pub fn format(&self, instruction: DecodedInstruction) -> String {
+        match self.listing(instruction) {
+            Some(listing) => self.render(listing),
+            // Only instructions the decoder would reject can't be encoded.
+            None => instruction.to_string(),
+        }
+    }
+
+    /// Formats an instruction that builds half of `address`, such as the `lis` and `addi` of a
+    /// pair, with its immediate written as a relocation: `sym@ha`, `sym@h` or `sym@l`. Falls back to
+    /// `format` if the address has no symbol or the instruction doesn't build it.
+    pub fn format_address(&self, instruction: DecodedInstruction, address: u32) -> String {
+        match self.address_listing(instruction, address) {
+            Some(listing) => self.render(listing),
+            None => self.format(instruction),
+        }
+    }
+
+    fn address_listing(&self, instruction: DecodedInstruction, address: u32) -> Option<Listing> {
+        self.resolver?.resolve(address)?;
+        let low = |immediate: u16| (immediate == address as u16).then_some("@l");
+        Some(match instruction {
+            DecodedInstruction::Addis {
+                dst,
+                src,
+                immediate,
+            } => {
+                let suffix = if immediate as u16 == (address.wrapping_add(0x8000) >> 16) as u16 {
+                    "@ha"
+                } else if immediate as u16 == (address >> 16) as u16 {
+                    "@h"
+                } else {
+                    return None;
+                };
+                let relocation = Arg::Relocation(address, suffix);
+                if src.is_zero() {
+                    Listing::new("lis", vec![Arg::Gpr(dst), relocation])
+                } else {
+                    Listing::new(
+                        "addis",
+                        vec![Arg::Gpr(dst), Arg::GprOrZero(src), relocation],
+                    )
+                }
+            }
+            DecodedInstruction::Addi {
+                dst,
+                src,
+                immediate,
+            } => Listing::new(
+                "addi",
+                vec![
+                    Arg::Gpr(dst),
+                    Arg::GprOrZero(src),
+                    Arg::Relocation(address, low(immediate as u16)?),
+                ],
+            ),
+            DecodedInstruction::Ori {
+                dst,
+                src,
+                immediate,
+            } => Listing::new(
+                "ori",
+                vec![
+                    Arg::Gpr(dst),
+                    Arg::Gpr(src),
+                    Arg::Relocation(address, low(immediate)?),
+                ],
+            ),
+            _ => {
+                let mut listing = self.listing(instruction)?;
+                let offset = listing.args.iter_mut().find_map(|arg| match *arg {
+                    Arg::Offset(offset, base) => Some((arg, offset, base)),
+                    _ => None,
+                });
+                let (arg, offset, base) = offset?;
+                *arg = Arg::RelocatedOffset(address, low(offset as u16)?, base);
+                listing
+            }
+        })
+    }
+
+    fn render(&self, listing: Listing) -> String {
+        let mut text = listing.mnemonic;
+        let separator = if self.syntax == Syntax::Gnu {
+            ","
+        } else {
+            ", "
+        };
+        let mut first = true;
+        for arg in listing.args {
+            if self.elide_cr0 && matches!(arg, Arg::OptionalCrf(crf) if crf.get() == 0) {
+                continue;
+            }
+            text.push_str(if first { " " } else { separator });
+            first = false;
+            // Writing to a `String` can't fail.
+            self.write_arg(&mut text, arg).unwrap();
+        }
+        text
+    }
+
+    fn listing(&self, instruction: DecodedInstruction) -> Option<Listing> {
+        if let Some(listing) = self.branch(instruction) {
+            return Some(listing);
+        }
+        if self.syntax != Syntax::Raw {
+            if let Some(listing) = self.simplified(instruction) {
+                return Some(listing);
+            }
+        }
+        // Everything else has a form, which the assembler reads from the encoded word. Only
+        // branches depend on the address.
+        let word = instruction.encode(0).ok()?;
+        disassemble(word.0, self.syntax == Syntax::Raw)
+    }
+
+    fn branch(&self, instruction: DecodedInstruction) -> Option<Listing> {
+        Some(match instruction {
+            DecodedInstruction::B {
+                link,
+                absolute,
+                target,
+            } => Listing::new(
+                format!("b{}{}", suffix(link, "l"), suffix(absolute, "a")),
+                vec![Arg::Target(target)],
+            ),
+            DecodedInstruction::Bc {
+                condition,
+                ctr,
+                hint,
+                ignored,
+                link,
+                absolute,
+                target,
+            } => self.conditional_branch(
+                condition,
+                ctr,
+                hint,
+                ignored,
+                format!("{}{}", suffix(link, "l"), suffix(absolute, "a")),
+                Some(target),
+            ),
+            DecodedInstruction::Bcctr {
+                condition,
+                hint,
+                ignored,
+                link,
+            } => self.conditional_branch(
+                condition,
+                CtrBehavior::None,
+                hint,
+                ignored,
+                format!("ctr{}", suffix(link, "l")),
+                None,
+            ),
+            DecodedInstruction::Bclr {
+                condition,
+                ctr,
+                hint,
+                ignored,
+                link,
+            } => self.conditional_branch(
+                condition,
+                ctr,
+                hint,
+                ignored,
+                format!("lr{}", suffix(link, "l")),
+                None,
+            ),
+            _ => return None,
+        })
+    }
+
+    /// Lists `bc`, `bclr` or `bcctr`, depending on the target register and flags that end the
+    /// mnemonic, such as `lrl`. The simplified mnemonics combine a CTR decrement with a condition
+    /// as `bdnzt` and `bdnzf`, since there's no short name for the condition then. Branches that
+    /// set ignored bits are always listed raw, since the simplified mnemonics can't show them.
+    fn conditional_branch(
+        &self,
+        condition: ConditionBehavior,
+        ctr: CtrBehavior,
+        hint: Option<BranchHint>,
+        ignored: IgnoredBranchBits,
+        register_and_flags: String,
+        target: Option<u32>,
+    ) -> Listing {
+        let mut args = Vec::new();
+        let mnemonic = if self.syntax == Syntax::Raw || !ignored.is_empty() {
+            let (bo, bi) = bo_and_bi(condition, ctr, hint, ignored);
+            args.push(Arg::Number(bo));
+            args.push(Arg::Number(bi));
+            format!("bc{}", register_and_flags)
+        } else {
+            let condition = match (condition, ctr) {
+                (ConditionBehavior::BranchAlways, _) => String::new(),
+                (condition, CtrBehavior::None) => {
+                    args.extend(condition.crf().map(Arg::OptionalCrf));
+                    condition.to_string()
+                }
+                (ConditionBehavior::BranchTrue(bit), _) => {
+                    args.push(Arg::ConditionBit(bit));
+                    "t".to_string()
+                }
+                (ConditionBehavior::BranchFalse(bit), _) => {
+                    args.push(Arg::ConditionBit(bit));
+                    "f".to_string()
+                }
+            };
+            let hint = hint.map(|hint| hint.to_string()).unwrap_or_default();
+            format!("b{}{}{}{}", ctr, condition, register_and_flags, hint)
+        };
+        args.extend(target.map(Arg::Target));
+        Listing::new(mnemonic, args)
+    }
+
+    /// The simplified mnemonics that need more than a form to recognize.
+    fn simplified(&self, instruction: DecodedInstruction) -> Option<Listing> {
+        let code_warrior = self.syntax == Syntax::CodeWarrior;
+        Some(match instruction {
+            DecodedInstruction::Addi {
+                dst,
+                src,
+                immediate,
+            } if code_warrior && src.is_gpr() && immediate < 0 && immediate != i16::MIN => {
+                Listing::new(
+                    "subi",
+                    vec![Arg::Gpr(dst), Arg::GprOrZero(src), Arg::Signed(-immediate)],
+                )
+            }
+            DecodedInstruction::Addic {
+                dst,
+                src,
+                immediate,
+                record,
+            } if code_warrior && immediate < 0 && immediate != i16::MIN => Listing::new(
+                format!("subic{}", suffix(record, ".")),
+                vec![Arg::Gpr(dst), Arg::Gpr(src), Arg::Signed(-immediate)],
+            ),
+            DecodedInstruction::Addis {
+                dst,
+                src,
+                immediate,
+            } => {
+                if src.is_zero() {
+                    Listing::new("lis", vec![Arg::Gpr(dst), Arg::Signed(immediate)])
+                } else if code_warrior && immediate < 0 && immediate != i16::MIN {
+                    Listing::new(
+                        "subis",
+                        vec![Arg::Gpr(dst), Arg::GprOrZero(src), Arg::Signed(-immediate)],
+                    )
+                } else {
+                    return None;
+                }
+            }
+            DecodedInstruction::Creqv { dst, srcs } if srcs == [dst, dst] => {
+                Listing::new("crset", vec![Arg::ConditionBit(dst)])
+            }
+            DecodedInstruction::Crnor { dst, srcs } if srcs[0] == srcs[1] => Listing::new(
+                "crnot",
+                vec![Arg::ConditionBit(dst), Arg::ConditionBit(srcs[0])],
+            ),
+            DecodedInstruction::Cror { dst, srcs } if srcs[0] == srcs[1] => Listing::new(
+                "crmove",
+                vec![Arg::ConditionBit(dst), Arg::ConditionBit(srcs[0])],
+            ),
+            DecodedInstruction::Crxor { dst, srcs } if srcs == [dst, dst] => {
+                Listing::new("crclr", vec![Arg::ConditionBit(dst)])
+            }
+            DecodedInstruction::Mfspr { spr, dst } => {
+                Listing::new(format!("mf{}", spr), vec![Arg::Gpr(dst)])
+            }
+            DecodedInstruction::Mtcrf { fields: 0xff, src } => {
+                Listing::new("mtcr", vec![Arg::Gpr(src)])
+            }
+            DecodedInstruction::Mtspr { spr, src } => {
+                Listing::new(format!("mt{}", spr), vec![Arg::Gpr(src)])
+            }
+            DecodedInstruction::Nor { dst, srcs, record } if srcs[0] == srcs[1] => Listing::new(
+                format!("not{}", suffix(record, ".")),
+                vec![Arg::Gpr(dst), Arg::Gpr(srcs[0])],
+            ),
+            DecodedInstruction::Ori {
+                dst,
+                src,
+                immediate: 0,
+            } if dst.as_u32() == 0 && src.as_u32() == 0 => Listing::new("nop", Vec::new()),
+            DecodedInstruction::Rlwinm {
+                dst,
+                src,
+                shift,
+                mask_begin,
+                mask_end,
+                record,
+            } => {
+                let (name, numbers) =
+                    rotate_and_mask(u32::from(shift), u32::from(mask_begin), u32::from(mask_end))?;
+                let mut args = vec![Arg::Gpr(dst), Arg::Gpr(src)];
+                args.extend(numbers.into_iter().map(Arg::Number));
+                Listing::new(format!("{}{}", name, suffix(record, ".")), args)
+            }
+            DecodedInstruction::Rlwnm {
+                dst,
+                src,
+                shift,
+                mask_begin: 0,
+                mask_end: 31,
+                record,
+            } => Listing::new(
+                format!("rotlw{}", suffix(record, ".")),
+                vec![Arg::Gpr(dst), Arg::Gpr(src), Arg::Gpr(shift)],
+            ),
+            DecodedInstruction::Tw { to: 31, srcs } if srcs.iter().all(|src| src.as_u32() == 0) => {
+                Listing::new("trap", Vec::new())
+            }
+            _ => return None,
+        })
+    }
+
+    fn write_arg(&self, text: &mut String, arg: Arg) -> fmt::Result {
+        match arg {
+            Arg::Gpr(gpr) => write!(text, "{}", gpr),
+            Arg::GprOrZero(gpr) => write!(text, "{}", gpr),
+            Arg::Fpr(fpr) => write!(text, "{}", fpr),
+            Arg::Crf(crf) | Arg::OptionalCrf(crf) => write!(text, "{}", crf),
+            Arg::ConditionBit(bit) => match self.syntax {
+                Syntax::Gnu if bit.crf().get() == 0 => write!(text, "{}", bit.bi()),
+                Syntax::Gnu => write!(text, "4*{}+{}", bit.crf(), bit.bi()),
+                Syntax::Raw | Syntax::CodeWarrior => write!(text, "{}", bit.get()),
+            },
+            Arg::Signed(value) => self.write_signed(text, value),
+            Arg::Unsigned(value) if self.hex_immediates => {
+                write!(text, "{}", FormatSmallU16(value))
+            }
+            Arg::Unsigned(value) => write!(text, "{}", value),
+            Arg::Number(value) => write!(text, "{}", value),
+            Arg::Gqr(gqr) => write!(text, "qr{}", gqr),
+            Arg::Sr(sr) => write!(text, "{}", sr),
+            Arg::Offset(offset, base) => {
+                self.write_signed(text, offset)?;
+                write!(text, "({})", base)
+            }
+            Arg::Target(target) => self.write_address(text, target),
+            Arg::Relocation(address, suffix) => {
+                self.write_address(text, address)?;
+                write!(text, "{}", suffix)
+            }
+            Arg::RelocatedOffset(address, suffix, base) => {
+                self.write_address(text, address)?;
+                write!(text, "{}({})", suffix, base)
+            }
+        }
+    }
+
+    fn write_address(&self, text: &mut String, address: u32) -> fmt::Result {
+        match self.resolver.and_then(|resolver| resolver.resolve(address)) {
+            Some((name, 0)) => write!(text, "{}", name),
+            Some((name, offset)) => write!(text, "{}+{:#x}", name, offset),
+            None => write!(text, "0x{:08x}", address),
+        }
+    }
+
+    fn write_signed(&self, text: &mut String, value: i16) -> fmt::Result {
+        if self.hex_immediates {
+            write!(text, "{}", FormatSmallI16(value))
+        } else {
+            write!(text, "{}", value)
+        }
+    }
+}
+
+/// A mnemonic and its operands, before they are spelled out.
+pub(super) struct Listing {
+    pub(super) mnemonic: String,
+    pub(super) args: Vec<Arg>,
+}
+
+impl Listing {
+    fn new(mnemonic: impl Into<String>, args: Vec<Arg>) -> Listing {
+        Listing {
+            mnemonic: mnemonic.into(),
+            args,
+        }
+    }
+}
+
+/// An operand whose spelling depends on the formatter's options.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Arg {
+    Gpr(Gpr),
+    GprOrZero(GprOrZero),
+    Fpr(Fpr),
+    Crf(Crf),
+    /// A condition register field that may be left out when it is `cr0`.
+    OptionalCrf(Crf),
+    ConditionBit(ConditionBit),
+    /// A signed immediate, which may be printed in hexadecimal.
+    Signed(i16),
+    /// An unsigned immediate or mask, which may be printed in hexadecimal.
+    Unsigned(u16),
+    /// A count or bit number, which is always printed in decimal.
+    Number(u32),
+    Gqr(u32),
+    Sr(Sr),
+    /// A displacement and base register, printed as `d(rA)`.
+    Offset(i16, GprOrZero),
+    Target(u32),
+    /// Half of an address, printed as a symbol with a suffix such as `@ha`.
+    Relocation(u32, &'static str),
+    /// A displacement that holds the low half of an address, printed as `sym@l(rA)`.
+    RelocatedOffset(u32, &'static str, GprOrZero),
+}
+
+fn suffix(present: bool, suffix: &str) -> &str {
+    if present {
+        suffix
+    } else {
+        ""
+    }
+}
+
+/// The simplified mnemonic for an `rlwinm` with the given shift and mask bounds, along with the
+/// numbers it takes in place of them.
+fn rotate_and_mask(shift: u32, begin: u32, end: u32) -> Option<(&'static str, Vec<u32>)> {
+    Some(match (shift, begin, end) {
+        (n, 0, 31) => ("rotlwi", vec![n]),
+        (n, 0, end) if n + end == 31 => ("slwi", vec![n]),
+        (n, begin, 31) if n + begin == 32 => ("srwi", vec![begin]),
+        (0, n, 31) => ("clrlwi", vec![n]),
+        (0, 0, end) => ("clrrwi", vec![31 - end]),
+        (b, 0, end) => ("extlwi", vec![end + 1, b]),
+        (shift, begin, 31) if shift > 32 - begin => {
+            ("extrwi", vec![32 - begin, shift - (32 - begin)])
+        }
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Formatter, Syntax};
+    use crate::{assemble, Resolver};
+
+    struct Symbols;
+
+    impl Resolver for Symbols {
+        fn resolve(&self, address: u32) -> Option<(&str, u32)> {
+            match address {
+                0x80003000..=0x8000303f => Some(("main", address - 0x80003000)),
+                0x8036a000..=0x8036a0ff => Some(("gTable", address - 0x8036a000)),
+                _ => None,
+            }
+        }
+    }
+
+    fn format(syntax: Syntax, assembly: &str) -> String {
+        Formatter::new(syntax).format(assemble(assembly, 0x80003000).unwrap())
+    }
+
+    #[test]
+    fn simplified_mnemonics() {
+        for (assembly, gnu, code_warrior) in [
+            ("ori r0, r0, 0", "nop", "nop"),
+            ("li r3, -16", "li r3,-16", "li r3, -0x10"),
+            ("addis r3, 0, 0x1234", "lis r3,4660", "lis r3, 0x1234"),
+            ("addi r3, r4, -8", "addi r3,r4,-8", "subi r3, r4, 8"),
+            ("addic. r3, r4, -1", "addic. r3,r4,-1", "subic. r3, r4, 1"),
+            ("mr r3, r4", "mr r3,r4", "mr r3, r4"),
+            ("nor r3, r4, r4", "not r3,r4", "not r3, r4"),
+            ("rlwinm r3, r4, 2, 0, 29", "slwi r3,r4,2", "slwi r3, r4, 2"),
+            ("rlwinm r3, r4, 30, 2, 31", "srwi r3,r4,2", "srwi r3, r4, 2"),
+            (
+                "rlwinm r3, r4, 0, 24, 31",
+                "clrlwi r3,r4,24",
+                "clrlwi r3, r4, 24",
+            ),
+            (
+                "rlwinm r3, r4, 0, 0, 27",
+                "clrrwi r3,r4,4",
+                "clrrwi r3, r4, 4",
+            ),
+            (
+                "rlwinm r3, r4, 8, 0, 7",
+                "extlwi r3,r4,8,8",
+                "extlwi r3, r4, 8, 8",
+            ),
+            (
+                "rlwinm r3, r4, 12, 24, 31",
+                "extrwi r3,r4,8,4",
+                "extrwi r3, r4, 8, 4",
+            ),
+            (
+                "rlwinm r3, r4, 16, 0, 31",
+                "rotlwi r3,r4,16",
+                "rotlwi r3, r4, 16",
+            ),
+            (
+                "rlwinm. r3, r4, 1, 3, 5",
+                "rlwinm. r3,r4,1,3,5",
+                "rlwinm. r3, r4, 1, 3, 5",
+            ),
+            (
+                "rlwnm r3, r4, r5, 0, 31",
+                "rotlw r3,r4,r5",
+                "rotlw r3, r4, r5",
+            ),
+            (
+                "crxor cr1*4+eq, cr1*4+eq, cr1*4+eq",
+                "crclr 4*cr1+eq",
+                "crclr 6",
+            ),
+            ("creqv lt, lt, lt", "crset lt", "crset 0"),
+            ("crnor gt, eq, eq", "crnot gt,eq", "crnot 1, 2"),
+            ("cror gt, eq, eq", "crmove gt,eq", "crmove 1, 2"),
+            ("mtcrf 0xff, r3", "mtcr r3", "mtcr r3"),
+            ("mflr r0", "mflr r0", "mflr r0"),
+            ("mtctr r12", "mtctr r12", "mtctr r12"),
+            ("tw 0x1f, r0, r0", "trap", "trap"),
+            ("cmpwi r3, 0", "cmpwi r3,0", "cmpwi r3, 0"),
+            ("cmplw cr7, r3, r4", "cmplw cr7,r3,r4", "cmplw cr7, r3, r4"),
+            (
+                "lwz r0, -0x10(r31)",
+                "lwz r0,-16(r31)",
+                "lwz r0, -0x10(r31)",
+            ),
+            ("stw r0, (r1)", "stw r0,0(r1)", "stw r0, 0(r1)"),
+            ("blr", "blr", "blr"),
+            ("bnelr cr7", "bnelr cr7", "bnelr cr7"),
+            ("beq+ 0x80003100", "beq+ 0x80003100", "beq+ 0x80003100"),
+            (
+                "bdnzeq cr5, 0x80003100",
+                "bdnzt 4*cr5+eq,0x80003100",
+                "bdnzt 22, 0x80003100",
+            ),
+            ("bclr 31, 0", "bclr 31,0", "bclr 31, 0"),
+            (
+                "bc 7, 2, 0x80003100",
+                "bc 7,2,0x80003100",
+                "bc 7, 2, 0x80003100",
+            ),
+        ] {
+            assert_eq!(format(Syntax::Gnu, assembly), gnu);
+            assert_eq!(format(Syntax::CodeWarrior, assembly), code_warrior);
+        }
+    }
+
+    #[test]
+    fn raw_forms() {
+        for (assembly, raw) in [
+            ("li r3, 1", "addi r3, 0, 1"),
+            ("mr. r3, r4", "or. r3, r4, r4"),
+            ("cmpwi cr7, r3, -1", "cmpi cr7, 0, r3, -1"),
+            ("mflr r0", "mfspr r0, 8"),
+            ("mftbu r3", "mftb r3, 269"),
+            ("rlwinm r3, r4, 2, 0, 29", "rlwinm r3, r4, 2, 0, 29"),
+            ("crxor cr1*4+eq, cr1*4+eq, cr1*4+eq", "crxor 6, 6, 6"),
+            ("lwzx r3, 0, r4", "lwzx r3, 0, r4"),
+            ("blr", "bclr 20, 0"),
+            ("bnectrl cr1", "bcctrl 4, 6"),
+            ("beq 0x80002000", "bc 12, 2, 0x80002000"),
+            ("beq- 0x80002000", "bc 13, 2, 0x80002000"),
+            ("beq+ 0x80003100", "bc 13, 2, 0x80003100"),
+        ] {
+            assert_eq!(format(Syntax::Raw, assembly), raw);
+        }
+    }
+
+    #[test]
+    fn options() {
+        let instruction = assemble("cmpwi r3, 100", 0).unwrap();
+        let formatter = Formatter::new(Syntax::CodeWarrior);
+        assert_eq!(formatter.format(instruction), "cmpwi r3, 0x64");
+        assert_eq!(
+            formatter.hex_immediates(false).format(instruction),
+            "cmpwi r3, 100"
+        );
+        assert_eq!(
+            formatter.elide_cr0(false).format(instruction),
+            "cmpwi cr0, r3, 0x64"
+        );
+    }
+
+    #[test]
+    fn resolved_targets() {
+        let formatter = Formatter::new(Syntax::CodeWarrior).resolver(&Symbols);
+        for (assembly, expected) in [
+            ("bl 0x80003000", "bl main"),
+            ("b 0x80003010", "b main+0x10"),
+            ("bne 0x80003020", "bne main+0x20"),
+            ("bdnz 0x80003004", "bdnz main+0x4"),
+            ("b 0x80003040", "b 0x80003040"),
+        ] {
+            let instruction = assemble(assembly, 0x80003000).unwrap();
+            assert_eq!(formatter.format(instruction), expected, "{}", assembly);
+        }
+    }
+
+    #[test]
+    fn resolved_addresses() {
+        let formatter = Formatter::new(Syntax::Gnu).resolver(&Symbols);
+        for (assembly, address, expected) in [
+            ("addis r3, 0, -0x7fc9", 0x8036a010, "lis r3,gTable+0x10@ha"),
+            ("addis r3, 0, -0x7fca", 0x8036a010, "lis r3,gTable+0x10@h"),
+            ("addis r3, r4, -0x7fc9", 0x8036a000, "addis r3,r4,gTable@ha"),
+            (
+                "addi r3, r3, -0x5ff0",
+                0x8036a010,
+                "addi r3,r3,gTable+0x10@l",
+            ),
+            ("ori r3, r3, 0xa010", 0x8036a010, "ori r3,r3,gTable+0x10@l"),
+            ("lwz r0, -0x6000(r3)", 0x8036a000, "lwz r0,gTable@l(r3)"),
+            (
+                "stfs f1, -0x5ffc(r3)",
+                0x8036a004,
+                "stfs f1,gTable+0x4@l(r3)",
+            ),
+            ("addi r3, r3, 8", 0x8036a010, "addi r3,r3,8"),
+            ("addis r3, 0, -0x7fc9", 0x80400000, "lis r3,-32713"),
+            ("mr r3, r4", 0x8036a000, "mr r3,r4"),
+        ] {
+            let instruction = assemble(assembly, 0).unwrap();
+            assert_eq!(
+                formatter.format_address(instruction, address),
+                expected,
+                "{}",
+                assembly
+            );
+        }
+    }
+}
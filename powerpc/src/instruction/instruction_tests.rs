@@ -1,454 +1,5083 @@
-use crate::EncodedInstruction;
+#![allow(clippy::unusual_byte_groupings)]
+
+use proptest::prelude::*;
+
+use crate::{assemble, EncodedInstruction, Formatter, Syntax};
 
 const TABLE: &[(u32, &str)] = &[
     //
-    // === CMPLI (CMPLWI) ===
-    // Opcode 10
+    // === TWI ===
+    // Opcode 3
+    //
+    // - all zeros
+    (0b000011_00000_00000_0000000000000000, "twi 0, r0, 0"),
+    // - TO operand
+    (0b000011_11111_00000_0000000000000000, "twi 0x1f, r0, 0"),
+    // - register A
+    (0b000011_00000_11111_0000000000000000, "twi 0, r31, 0"),
+    // - signed immediate
+    (0b000011_00000_00000_1111111111111111, "twi 0, r0, -1"),
+    // - typical use
+    (0b000011_00100_00011_0000000000000000, "twi 4, r3, 0"),
+    //
+    // === PS_CMPU0 ===
+    // Opcode 4
+    // Extended opcode 0
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_0000000000_0,
+        "ps_cmpu0 cr0, f0, f0",
+    ),
+    // - reserved bit 31
+    (0b000100_00000_00000_00000_0000000000_1, "FAIL"),
+    // - reserved bits 9..=10
+    (0b000100_00001_00000_00000_0000000000_0, "FAIL"),
+    // - register B
+    (
+        0b000100_00000_00000_11111_0000000000_0,
+        "ps_cmpu0 cr0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_0000000000_0,
+        "ps_cmpu0 cr0, f31, f0",
+    ),
+    // - condition register field
+    (
+        0b000100_11100_00000_00000_0000000000_0,
+        "ps_cmpu0 cr7, f0, f0",
+    ),
+    //
+    // === PSQ_LX ===
+    // Opcode 4
+    // Extended opcode 6
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_0_000_000110_0,
+        "psq_lx f0, 0, r0, 0, qr0",
+    ),
+    // - reserved bit 31
+    (0b000100_00000_00000_00000_0_000_000110_1, "FAIL"),
+    // - register B
+    (
+        0b000100_00000_00000_11111_0_000_000110_0,
+        "psq_lx f0, 0, r31, 0, qr0",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_0_000_000110_0,
+        "psq_lx f0, r31, r0, 0, qr0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_0_000_000110_0,
+        "psq_lx f31, 0, r0, 0, qr0",
+    ),
+    // - W field
+    (
+        0b000100_00000_00000_00000_1_000_000110_0,
+        "psq_lx f0, 0, r0, 1, qr0",
+    ),
+    // - I field
+    (
+        0b000100_00000_00000_00000_0_111_000110_0,
+        "psq_lx f0, 0, r0, 0, qr7",
+    ),
+    //
+    // === PSQ_STX ===
+    // Opcode 4
+    // Extended opcode 7
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_0_000_000111_0,
+        "psq_stx f0, 0, r0, 0, qr0",
+    ),
+    // - reserved bit 31
+    (0b000100_00000_00000_00000_0_000_000111_1, "FAIL"),
+    // - register B
+    (
+        0b000100_00000_00000_11111_0_000_000111_0,
+        "psq_stx f0, 0, r31, 0, qr0",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_0_000_000111_0,
+        "psq_stx f0, r31, r0, 0, qr0",
+    ),
+    // - register S
+    (
+        0b000100_11111_00000_00000_0_000_000111_0,
+        "psq_stx f31, 0, r0, 0, qr0",
+    ),
+    // - W field
+    (
+        0b000100_00000_00000_00000_1_000_000111_0,
+        "psq_stx f0, 0, r0, 1, qr0",
+    ),
+    // - I field
+    (
+        0b000100_00000_00000_00000_0_111_000111_0,
+        "psq_stx f0, 0, r0, 0, qr7",
+    ),
+    //
+    // === PS_SUM0 ===
+    // Opcode 4
+    // Extended opcode 10
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_01010_0,
+        "ps_sum0 f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_01010_1,
+        "ps_sum0. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_01010_0,
+        "ps_sum0 f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_01010_0,
+        "ps_sum0 f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_01010_0,
+        "ps_sum0 f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_01010_0,
+        "ps_sum0 f31, f0, f0, f0",
+    ),
+    //
+    // === PS_SUM1 ===
+    // Opcode 4
+    // Extended opcode 11
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_01011_0,
+        "ps_sum1 f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_01011_1,
+        "ps_sum1. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_01011_0,
+        "ps_sum1 f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_01011_0,
+        "ps_sum1 f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_01011_0,
+        "ps_sum1 f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_01011_0,
+        "ps_sum1 f31, f0, f0, f0",
+    ),
+    //
+    // === PS_MULS0 ===
+    // Opcode 4
+    // Extended opcode 12
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_01100_0,
+        "ps_muls0 f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_01100_1,
+        "ps_muls0. f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_01100_0,
+        "ps_muls0 f0, f0, f31",
+    ),
+    // - reserved register B
+    (0b000100_00000_00000_00001_00000_01100_0, "FAIL"),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_01100_0,
+        "ps_muls0 f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_01100_0,
+        "ps_muls0 f31, f0, f0",
+    ),
+    //
+    // === PS_MULS1 ===
+    // Opcode 4
+    // Extended opcode 13
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_01101_0,
+        "ps_muls1 f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_01101_1,
+        "ps_muls1. f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_01101_0,
+        "ps_muls1 f0, f0, f31",
+    ),
+    // - reserved register B
+    (0b000100_00000_00000_00001_00000_01101_0, "FAIL"),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_01101_0,
+        "ps_muls1 f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_01101_0,
+        "ps_muls1 f31, f0, f0",
+    ),
+    //
+    // === PS_MADDS0 ===
+    // Opcode 4
+    // Extended opcode 14
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_01110_0,
+        "ps_madds0 f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_01110_1,
+        "ps_madds0. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_01110_0,
+        "ps_madds0 f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_01110_0,
+        "ps_madds0 f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_01110_0,
+        "ps_madds0 f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_01110_0,
+        "ps_madds0 f31, f0, f0, f0",
+    ),
+    //
+    // === PS_MADDS1 ===
+    // Opcode 4
+    // Extended opcode 15
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_01111_0,
+        "ps_madds1 f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_01111_1,
+        "ps_madds1. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_01111_0,
+        "ps_madds1 f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_01111_0,
+        "ps_madds1 f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_01111_0,
+        "ps_madds1 f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_01111_0,
+        "ps_madds1 f31, f0, f0, f0",
+    ),
+    //
+    // === PS_DIV ===
+    // Opcode 4
+    // Extended opcode 18
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_10010_0,
+        "ps_div f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_10010_1,
+        "ps_div. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_10010_0,
+        "ps_div f0, f0, f31",
+    ),
+    // - reserved register C
+    (0b000100_00000_00000_00000_00001_10010_0, "FAIL"),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_10010_0,
+        "ps_div f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_10010_0,
+        "ps_div f31, f0, f0",
+    ),
+    //
+    // === PS_SUB ===
+    // Opcode 4
+    // Extended opcode 20
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_10100_0,
+        "ps_sub f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_10100_1,
+        "ps_sub. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_10100_0,
+        "ps_sub f0, f0, f31",
+    ),
+    // - reserved register C
+    (0b000100_00000_00000_00000_00001_10100_0, "FAIL"),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_10100_0,
+        "ps_sub f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_10100_0,
+        "ps_sub f31, f0, f0",
+    ),
+    //
+    // === PS_ADD ===
+    // Opcode 4
+    // Extended opcode 21
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_10101_0,
+        "ps_add f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_10101_1,
+        "ps_add. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_10101_0,
+        "ps_add f0, f0, f31",
+    ),
+    // - reserved register C
+    (0b000100_00000_00000_00000_00001_10101_0, "FAIL"),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_10101_0,
+        "ps_add f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_10101_0,
+        "ps_add f31, f0, f0",
+    ),
+    //
+    // === PS_SEL ===
+    // Opcode 4
+    // Extended opcode 23
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_10111_0,
+        "ps_sel f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_10111_1,
+        "ps_sel. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_10111_0,
+        "ps_sel f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_10111_0,
+        "ps_sel f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_10111_0,
+        "ps_sel f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_10111_0,
+        "ps_sel f31, f0, f0, f0",
+    ),
+    //
+    // === PS_RES ===
+    // Opcode 4
+    // Extended opcode 24
+    //
+    // - all zeros
+    (0b000100_00000_00000_00000_00000_11000_0, "ps_res f0, f0"),
+    // - record bit
+    (0b000100_00000_00000_00000_00000_11000_1, "ps_res. f0, f0"),
+    // - register B
+    (0b000100_00000_00000_11111_00000_11000_0, "ps_res f0, f31"),
+    // - reserved register C
+    (0b000100_00000_00000_00000_00001_11000_0, "FAIL"),
+    // - reserved register A
+    (0b000100_00000_00001_00000_00000_11000_0, "FAIL"),
+    // - register D
+    (0b000100_11111_00000_00000_00000_11000_0, "ps_res f31, f0"),
+    //
+    // === PS_MUL ===
+    // Opcode 4
+    // Extended opcode 25
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_11001_0,
+        "ps_mul f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_11001_1,
+        "ps_mul. f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_11001_0,
+        "ps_mul f0, f0, f31",
+    ),
+    // - reserved register B
+    (0b000100_00000_00000_00001_00000_11001_0, "FAIL"),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_11001_0,
+        "ps_mul f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_11001_0,
+        "ps_mul f31, f0, f0",
+    ),
+    //
+    // === PS_RSQRTE ===
+    // Opcode 4
+    // Extended opcode 26
+    //
+    // - all zeros
+    (0b000100_00000_00000_00000_00000_11010_0, "ps_rsqrte f0, f0"),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_11010_1,
+        "ps_rsqrte. f0, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_11010_0,
+        "ps_rsqrte f0, f31",
+    ),
+    // - reserved register C
+    (0b000100_00000_00000_00000_00001_11010_0, "FAIL"),
+    // - reserved register A
+    (0b000100_00000_00001_00000_00000_11010_0, "FAIL"),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_11010_0,
+        "ps_rsqrte f31, f0",
+    ),
+    //
+    // === PS_MSUB ===
+    // Opcode 4
+    // Extended opcode 28
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_11100_0,
+        "ps_msub f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_11100_1,
+        "ps_msub. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_11100_0,
+        "ps_msub f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_11100_0,
+        "ps_msub f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_11100_0,
+        "ps_msub f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_11100_0,
+        "ps_msub f31, f0, f0, f0",
+    ),
+    //
+    // === PS_MADD ===
+    // Opcode 4
+    // Extended opcode 29
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_11101_0,
+        "ps_madd f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_11101_1,
+        "ps_madd. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_11101_0,
+        "ps_madd f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_11101_0,
+        "ps_madd f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_11101_0,
+        "ps_madd f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_11101_0,
+        "ps_madd f31, f0, f0, f0",
+    ),
+    //
+    // === PS_NMSUB ===
+    // Opcode 4
+    // Extended opcode 30
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_11110_0,
+        "ps_nmsub f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_11110_1,
+        "ps_nmsub. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_11110_0,
+        "ps_nmsub f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_11110_0,
+        "ps_nmsub f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_11110_0,
+        "ps_nmsub f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_11110_0,
+        "ps_nmsub f31, f0, f0, f0",
+    ),
+    //
+    // === PS_NMADD ===
+    // Opcode 4
+    // Extended opcode 31
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_00000_11111_0,
+        "ps_nmadd f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_00000_11111_1,
+        "ps_nmadd. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b000100_00000_00000_00000_11111_11111_0,
+        "ps_nmadd f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_00000_11111_0,
+        "ps_nmadd f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_00000_11111_0,
+        "ps_nmadd f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_00000_11111_0,
+        "ps_nmadd f31, f0, f0, f0",
+    ),
+    //
+    // === PS_CMPO0 ===
+    // Opcode 4
+    // Extended opcode 32
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_0000100000_0,
+        "ps_cmpo0 cr0, f0, f0",
+    ),
+    // - reserved bit 31
+    (0b000100_00000_00000_00000_0000100000_1, "FAIL"),
+    // - reserved bits 9..=10
+    (0b000100_00001_00000_00000_0000100000_0, "FAIL"),
+    // - register B
+    (
+        0b000100_00000_00000_11111_0000100000_0,
+        "ps_cmpo0 cr0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_0000100000_0,
+        "ps_cmpo0 cr0, f31, f0",
+    ),
+    // - condition register field
+    (
+        0b000100_11100_00000_00000_0000100000_0,
+        "ps_cmpo0 cr7, f0, f0",
+    ),
+    //
+    // === PSQ_LUX ===
+    // Opcode 4
+    // Extended opcode 38
+    //
+    // - all zeros
+    (
+        0b000100_00000_00001_00000_0_000_100110_0,
+        "psq_lux f0, r1, r0, 0, qr0",
+    ),
+    // - reserved bit 31
+    (0b000100_00000_00001_00000_0_000_100110_1, "FAIL"),
+    // - register B
+    (
+        0b000100_00000_00001_11111_0_000_100110_0,
+        "psq_lux f0, r1, r31, 0, qr0",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_0_000_100110_0,
+        "psq_lux f0, r31, r0, 0, qr0",
+    ),
+    // - register A, illegal value
+    (0b000100_00000_00000_00000_0_000_100110_0, "FAIL"),
+    // - register D
+    (
+        0b000100_11111_00001_00000_0_000_100110_0,
+        "psq_lux f31, r1, r0, 0, qr0",
+    ),
+    // - W field
+    (
+        0b000100_00000_00001_00000_1_000_100110_0,
+        "psq_lux f0, r1, r0, 1, qr0",
+    ),
+    // - I field
+    (
+        0b000100_00000_00001_00000_0_111_100110_0,
+        "psq_lux f0, r1, r0, 0, qr7",
+    ),
+    //
+    // === PSQ_STUX ===
+    // Opcode 4
+    // Extended opcode 39
+    //
+    // - all zeros
+    (
+        0b000100_00000_00001_00000_0_000_100111_0,
+        "psq_stux f0, r1, r0, 0, qr0",
+    ),
+    // - reserved bit 31
+    (0b000100_00000_00001_00000_0_000_100111_1, "FAIL"),
+    // - register B
+    (
+        0b000100_00000_00001_11111_0_000_100111_0,
+        "psq_stux f0, r1, r31, 0, qr0",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_0_000_100111_0,
+        "psq_stux f0, r31, r0, 0, qr0",
+    ),
+    // - register A, illegal value
+    (0b000100_00000_00000_00000_0_000_100111_0, "FAIL"),
+    // - register S
+    (
+        0b000100_11111_00001_00000_0_000_100111_0,
+        "psq_stux f31, r1, r0, 0, qr0",
+    ),
+    // - W field
+    (
+        0b000100_00000_00001_00000_1_000_100111_0,
+        "psq_stux f0, r1, r0, 1, qr0",
+    ),
+    // - I field
+    (
+        0b000100_00000_00001_00000_0_111_100111_0,
+        "psq_stux f0, r1, r0, 0, qr7",
+    ),
+    //
+    // === PS_NEG ===
+    // Opcode 4
+    // Extended opcode 40
+    //
+    // - all zeros
+    (0b000100_00000_00000_00000_0000101000_0, "ps_neg f0, f0"),
+    // - record bit
+    (0b000100_00000_00000_00000_0000101000_1, "ps_neg. f0, f0"),
+    // - register B
+    (0b000100_00000_00000_11111_0000101000_0, "ps_neg f0, f31"),
+    // - reserved register A
+    (0b000100_00000_00001_00000_0000101000_0, "FAIL"),
+    // - register D
+    (0b000100_11111_00000_00000_0000101000_0, "ps_neg f31, f0"),
+    //
+    // === PS_CMPU1 ===
+    // Opcode 4
+    // Extended opcode 64
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_0001000000_0,
+        "ps_cmpu1 cr0, f0, f0",
+    ),
+    // - reserved bit 31
+    (0b000100_00000_00000_00000_0001000000_1, "FAIL"),
+    // - reserved bits 9..=10
+    (0b000100_00001_00000_00000_0001000000_0, "FAIL"),
+    // - register B
+    (
+        0b000100_00000_00000_11111_0001000000_0,
+        "ps_cmpu1 cr0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_0001000000_0,
+        "ps_cmpu1 cr0, f31, f0",
+    ),
+    // - condition register field
+    (
+        0b000100_11100_00000_00000_0001000000_0,
+        "ps_cmpu1 cr7, f0, f0",
+    ),
+    //
+    // === PS_MR ===
+    // Opcode 4
+    // Extended opcode 72
+    //
+    // - all zeros
+    (0b000100_00000_00000_00000_0001001000_0, "ps_mr f0, f0"),
+    // - record bit
+    (0b000100_00000_00000_00000_0001001000_1, "ps_mr. f0, f0"),
+    // - register B
+    (0b000100_00000_00000_11111_0001001000_0, "ps_mr f0, f31"),
+    // - reserved register A
+    (0b000100_00000_00001_00000_0001001000_0, "FAIL"),
+    // - register D
+    (0b000100_11111_00000_00000_0001001000_0, "ps_mr f31, f0"),
+    //
+    // === PS_CMPO1 ===
+    // Opcode 4
+    // Extended opcode 96
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_0001100000_0,
+        "ps_cmpo1 cr0, f0, f0",
+    ),
+    // - reserved bit 31
+    (0b000100_00000_00000_00000_0001100000_1, "FAIL"),
+    // - reserved bits 9..=10
+    (0b000100_00001_00000_00000_0001100000_0, "FAIL"),
+    // - register B
+    (
+        0b000100_00000_00000_11111_0001100000_0,
+        "ps_cmpo1 cr0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_0001100000_0,
+        "ps_cmpo1 cr0, f31, f0",
+    ),
+    // - condition register field
+    (
+        0b000100_11100_00000_00000_0001100000_0,
+        "ps_cmpo1 cr7, f0, f0",
+    ),
+    //
+    // === PS_NABS ===
+    // Opcode 4
+    // Extended opcode 136
+    //
+    // - all zeros
+    (0b000100_00000_00000_00000_0010001000_0, "ps_nabs f0, f0"),
+    // - record bit
+    (0b000100_00000_00000_00000_0010001000_1, "ps_nabs. f0, f0"),
+    // - register B
+    (0b000100_00000_00000_11111_0010001000_0, "ps_nabs f0, f31"),
+    // - reserved register A
+    (0b000100_00000_00001_00000_0010001000_0, "FAIL"),
+    // - register D
+    (0b000100_11111_00000_00000_0010001000_0, "ps_nabs f31, f0"),
+    //
+    // === PS_ABS ===
+    // Opcode 4
+    // Extended opcode 264
+    //
+    // - all zeros
+    (0b000100_00000_00000_00000_0100001000_0, "ps_abs f0, f0"),
+    // - record bit
+    (0b000100_00000_00000_00000_0100001000_1, "ps_abs. f0, f0"),
+    // - register B
+    (0b000100_00000_00000_11111_0100001000_0, "ps_abs f0, f31"),
+    // - reserved register A
+    (0b000100_00000_00001_00000_0100001000_0, "FAIL"),
+    // - register D
+    (0b000100_11111_00000_00000_0100001000_0, "ps_abs f31, f0"),
+    //
+    // === PS_MERGE00 ===
+    // Opcode 4
+    // Extended opcode 528
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_1000010000_0,
+        "ps_merge00 f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_1000010000_1,
+        "ps_merge00. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_1000010000_0,
+        "ps_merge00 f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_1000010000_0,
+        "ps_merge00 f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_1000010000_0,
+        "ps_merge00 f31, f0, f0",
+    ),
+    //
+    // === PS_MERGE01 ===
+    // Opcode 4
+    // Extended opcode 560
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_1000110000_0,
+        "ps_merge01 f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_1000110000_1,
+        "ps_merge01. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_1000110000_0,
+        "ps_merge01 f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_1000110000_0,
+        "ps_merge01 f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_1000110000_0,
+        "ps_merge01 f31, f0, f0",
+    ),
+    //
+    // === PS_MERGE10 ===
+    // Opcode 4
+    // Extended opcode 592
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_1001010000_0,
+        "ps_merge10 f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_1001010000_1,
+        "ps_merge10. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_1001010000_0,
+        "ps_merge10 f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_1001010000_0,
+        "ps_merge10 f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_1001010000_0,
+        "ps_merge10 f31, f0, f0",
+    ),
+    //
+    // === PS_MERGE11 ===
+    // Opcode 4
+    // Extended opcode 624
+    //
+    // - all zeros
+    (
+        0b000100_00000_00000_00000_1001110000_0,
+        "ps_merge11 f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b000100_00000_00000_00000_1001110000_1,
+        "ps_merge11. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b000100_00000_00000_11111_1001110000_0,
+        "ps_merge11 f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b000100_00000_11111_00000_1001110000_0,
+        "ps_merge11 f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b000100_11111_00000_00000_1001110000_0,
+        "ps_merge11 f31, f0, f0",
+    ),
+    //
+    // === DCBZ_L ===
+    // Opcode 4
+    // Extended opcode 1014
+    //
+    // - all zeros
+    (0b000100_00000_00000_00000_1111110110_0, "dcbz_l 0, r0"),
+    // - reserved bit 31
+    (0b000100_00000_00000_00000_1111110110_1, "FAIL"),
+    // - reserved bits 6..=10
+    (0b000100_00001_00000_00000_1111110110_0, "FAIL"),
+    // - register B
+    (0b000100_00000_00000_11111_1111110110_0, "dcbz_l 0, r31"),
+    // - register A
+    (0b000100_00000_11111_00000_1111110110_0, "dcbz_l r31, r0"),
+    //
+    // === MULLI ===
+    // Opcode 7
+    //
+    // - all zeros
+    (0b000111_00000_00000_0000000000000000, "mulli r0, r0, 0"),
+    // - signed immediate
+    (0b000111_00000_00000_1111111111111111, "mulli r0, r0, -1"),
+    // - register A
+    (0b000111_00000_11111_0000000000000000, "mulli r0, r31, 0"),
+    // - register D
+    (0b000111_11111_00000_0000000000000000, "mulli r31, r0, 0"),
+    // - all ones
+    (0b000111_11111_11111_1111111111111111, "mulli r31, r31, -1"),
+    //
+    // === SUBFIC ===
+    // Opcode 8
+    //
+    // - all zeros
+    (0b001000_00000_00000_0000000000000000, "subfic r0, r0, 0"),
+    // - signed immediate
+    (0b001000_00000_00000_1111111111111111, "subfic r0, r0, -1"),
+    // - register A
+    (0b001000_00000_11111_0000000000000000, "subfic r0, r31, 0"),
+    // - register D
+    (0b001000_11111_00000_0000000000000000, "subfic r31, r0, 0"),
+    // - all ones
+    (0b001000_11111_11111_1111111111111111, "subfic r31, r31, -1"),
+    //
+    // === CMPLI (CMPLWI) ===
+    // Opcode 10
+    //
+    // - all zeros
+    (0b001010_000_0_0_00000_0000000000000000, "cmplwi r0, 0"),
+    // - unsigned immediate
+    (0b001010_000_0_0_00000_1111111111111111, "cmplwi r0, 0xffff"),
+    // - register A
+    (0b001010_000_0_0_11111_0000000000000000, "cmplwi r31, 0"),
+    // - L flag
+    (0b001010_000_0_1_00000_0000000000000000, "FAIL"),
+    // - reserved bit 9
+    (0b001010_000_1_0_00000_0000000000000000, "FAIL"),
+    // - condition register field D
+    (0b001010_111_0_0_00000_0000000000000000, "cmplwi cr7, r0, 0"),
+    // - all ones
+    (
+        0b001010_111_0_0_11111_1111111111111111,
+        "cmplwi cr7, r31, 0xffff",
+    ),
+    //
+    // === CMPI (CMPWI) ===
+    // Opcode 11
+    //
+    // - all zeros
+    (0b001011_000_0_0_00000_0000000000000000, "cmpwi r0, 0"),
+    // - signed immediate
+    (0b001011_000_0_0_00000_1111111111111111, "cmpwi r0, -1"),
+    // - register A
+    (0b001011_000_0_0_11111_0000000000000000, "cmpwi r31, 0"),
+    // - L flag
+    (0b001011_000_0_1_00000_0000000000000000, "FAIL"),
+    // - reserved bit 9
+    (0b001011_000_1_0_00000_0000000000000000, "FAIL"),
+    // - condition register field D
+    (0b001011_111_0_0_00000_0000000000000000, "cmpwi cr7, r0, 0"),
+    // - all ones
+    (
+        0b001011_111_0_0_11111_1111111111111111,
+        "cmpwi cr7, r31, -1",
+    ),
+    //
+    // === ADDIC ===
+    // Opcode 12
+    //
+    // - all zeros
+    (0b001100_00000_00000_0000000000000000, "addic r0, r0, 0"),
+    // - signed immediate
+    (0b001100_00000_00000_1111111111111111, "addic r0, r0, -1"),
+    // - register A
+    (0b001100_00000_11111_0000000000000000, "addic r0, r31, 0"),
+    // - register D
+    (0b001100_11111_00000_0000000000000000, "addic r31, r0, 0"),
+    // - all ones
+    (0b001100_11111_11111_1111111111111111, "addic r31, r31, -1"),
+    //
+    // === ADDIC. ===
+    // Opcode 13
+    //
+    // - all zeros
+    (0b001101_00000_00000_0000000000000000, "addic. r0, r0, 0"),
+    // - signed immediate
+    (0b001101_00000_00000_1111111111111111, "addic. r0, r0, -1"),
+    // - register A
+    (0b001101_00000_11111_0000000000000000, "addic. r0, r31, 0"),
+    // - register D
+    (0b001101_11111_00000_0000000000000000, "addic. r31, r0, 0"),
+    // - all ones
+    (0b001101_11111_11111_1111111111111111, "addic. r31, r31, -1"),
+    //
+    // === ADDI (LI) ===
+    // Opcode 14
+    //
+    // - all zeros
+    (0b001110_00000_00000_0000000000000000, "li r0, 0"),
+    // - signed immediate
+    (0b001110_00000_00000_1111111111111111, "li r0, -1"),
+    // - register A
+    (0b001110_00000_11111_0000000000000000, "addi r0, r31, 0"),
+    // - register D
+    (0b001110_11111_00000_0000000000000000, "li r31, 0"),
+    // - all ones
+    (0b001110_11111_11111_1111111111111111, "addi r31, r31, -1"),
+    //
+    // === ADDIS ===
+    // Opcode 15
+    //
+    // - all zeros
+    (0b001111_00000_00000_0000000000000000, "addis r0, 0, 0"),
+    // - signed immediate
+    (0b001111_00000_00000_1111111111111111, "addis r0, 0, -1"),
+    // - register A
+    (0b001111_00000_11111_0000000000000000, "addis r0, r31, 0"),
+    // - register D
+    (0b001111_11111_00000_0000000000000000, "addis r31, 0, 0"),
+    // - all ones
+    (0b001111_11111_11111_1111111111111111, "addis r31, r31, -1"),
+    //
+    // === BCx ===
+    // Opcode 16
+    //
+    // - all zeros
+    (0b010000_00000_00000_00000000000000_0_0, "bdnznl 0x08000000"),
+    // - link flag
+    (
+        0b010000_00000_00000_00000000000000_0_1,
+        "bdnznll 0x08000000",
+    ),
+    // - absolute flag
+    (
+        0b010000_00000_00000_00000000000000_1_0,
+        "bdnznla 0x00000000",
+    ),
+    // - target address
+    (0b010000_00000_00000_11111111111111_0_0, "bdnznl 0x07fffffc"),
+    // - BI operand, condition
+    (0b010000_00000_00011_00000000000000_0_0, "bdnzns 0x08000000"),
+    // - BI operand, condition register field
+    (
+        0b010000_00000_11100_00000000000000_0_0,
+        "bdnznl cr7, 0x08000000",
+    ),
+    // - BO operand
+    (
+        0b010000_11111_00000_00000000000000_0_0,
+        "bc 31, 0, 0x08000000",
+    ),
+    // - all ones
+    (
+        0b010000_11111_11111_11111111111111_1_1,
+        "bcla 31, 31, 0xfffffffc",
+    ),
+    // - BO operand, branch always
+    (
+        0b010000_10100_00000_00000000000000_0_0,
+        "bc 20, 0, 0x08000000",
+    ),
+    (
+        0b010000_10100_00000_11111111111111_1_1,
+        "bcla 20, 0, 0xfffffffc",
+    ),
+    // - BO operand, ignored bits
+    (
+        0b010000_00110_00000_00000000000000_0_0,
+        "bc 6, 0, 0x08000000",
+    ),
+    (
+        0b010000_11000_00000_00000000000000_0_0,
+        "bc 24, 0, 0x08000000",
+    ),
+    (
+        0b010000_10101_00000_00000000000000_0_0,
+        "bc 21, 0, 0x08000000",
+    ),
+    (
+        0b010000_10110_00000_00000000000000_0_0,
+        "bc 22, 0, 0x08000000",
+    ),
+    (
+        0b010000_11100_00000_00000000000000_0_0,
+        "bc 28, 0, 0x08000000",
+    ),
+    (
+        0b010000_00111_00010_00010000000000_0_0,
+        "bc 7, 2, 0x08001000",
+    ),
+    // - BI operand, branch always
+    (
+        0b010000_10100_00001_00000000000000_0_0,
+        "bc 20, 1, 0x08000000",
+    ),
+    // - prediction hint, forward branch
+    (0b010000_01101_00010_00010000000000_0_0, "beq+ 0x08001000"),
+    (0b010000_10001_00000_00010000000000_0_0, "bdnz+ 0x08001000"),
+    // - prediction hint, backward branch
+    (0b010000_01101_00010_11111111111100_0_0, "beq- 0x07fffff0"),
+    (0b010000_10011_00000_11111111111100_0_0, "bdz- 0x07fffff0"),
+    // - prediction hint, absolute target
+    (0b010000_00101_00010_00010000000000_1_1, "bnela+ 0x00001000"),
+    // - all eight simple conditional branches
+    (0b010000_01100_00000_00010000000000_0_0, "blt 0x08001000"),
+    (0b010000_01100_00001_00010000000000_0_0, "bgt 0x08001000"),
+    (0b010000_01100_00010_00010000000000_0_0, "beq 0x08001000"),
+    (0b010000_01100_00011_00010000000000_0_0, "bso 0x08001000"),
+    (0b010000_00100_00000_00010000000000_0_0, "bnl 0x08001000"),
+    (0b010000_00100_00001_00010000000000_0_0, "bng 0x08001000"),
+    (0b010000_00100_00010_00010000000000_0_0, "bne 0x08001000"),
+    (0b010000_00100_00011_00010000000000_0_0, "bns 0x08001000"),
+    // - use every feature
+    (
+        0b010000_01000_10110_00010010001101_1_1,
+        "bdnzeqla cr5, 0x00001234",
+    ),
+    //
+    // === SC ===
+    // Opcode 17
+    //
+    // - canonical form
+    (0b010001_00000_00000_00000000000000_1_0, "sc"),
+    // - bit 30 must be set
+    (0b010001_00000_00000_00000000000000_0_0, "FAIL"),
+    // - reserved bit 31
+    (0b010001_00000_00000_00000000000000_1_1, "FAIL"),
+    // - reserved bits 6..=29
+    (0b010001_00001_00000_00000000000000_1_0, "FAIL"),
+    (0b010001_00000_00000_00000000000001_1_0, "FAIL"),
+    //
+    // === Bx ===
+    // Opcode 18
+    //
+    // - all zeros
+    (0b010010_000000000000000000000000_0_0, "b 0x08000000"),
+    // - link flag
+    (0b010010_000000000000000000000000_0_1, "bl 0x08000000"),
+    // - absolute flag
+    (0b010010_000000000000000000000000_1_0, "ba 0x00000000"),
+    // - target address
+    (0b010010_111111111111111111111111_0_0, "b 0x07fffffc"),
+    // - all ones
+    (0b010010_111111111111111111111111_1_1, "bla 0xfffffffc"),
+    //
+    // === MCRF ===
+    // Opcode 19
+    // Extended opcode 0
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0000000000_0, "mcrf cr0, cr0"),
+    // - destination field
+    (0b010011_11100_00000_00000_0000000000_0, "mcrf cr7, cr0"),
+    // - source field
+    (0b010011_00000_11100_00000_0000000000_0, "mcrf cr0, cr7"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0000000000_1, "FAIL"),
+    // - reserved bits 9..=10
+    (0b010011_00001_00000_00000_0000000000_0, "FAIL"),
+    // - reserved bits 14..=15
+    (0b010011_00000_00001_00000_0000000000_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b010011_00000_00000_00001_0000000000_0, "FAIL"),
+    //
+    // === BCLRx ===
+    // Opcode 19
+    // Extended opcode 16
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0000010000_0, "bdnznllr"),
+    // - link flag
+    (0b010011_00000_00000_00000_0000010000_1, "bdnznllrl"),
+    // reserved bits 16..=20
+    (0b010011_00000_00000_00001_0000010000_1, "FAIL"),
+    (0b010011_00000_00000_00010_0000010000_1, "FAIL"),
+    (0b010011_00000_00000_00100_0000010000_1, "FAIL"),
+    (0b010011_00000_00000_01000_0000010000_1, "FAIL"),
+    (0b010011_00000_00000_10000_0000010000_1, "FAIL"),
+    // - BI operand, condition
+    (0b010011_00000_00011_00000_0000010000_0, "bdnznslr"),
+    // - BI operand, condition register field
+    (0b010011_00000_11100_00000_0000010000_0, "bdnznllr cr7"),
+    // - BO operand
+    (0b010011_11111_00000_00000_0000010000_0, "bclr 31, 0"),
+    // - all ones
+    (0b010011_11111_11111_00000_0000010000_1, "bclrl 31, 31"),
+    // - BO operand, branch always
+    (0b010011_10100_00000_00000_0000010000_0, "blr"),
+    (0b010011_10100_00000_00000_0000010000_1, "blrl"),
+    // - BO operand, ignored bits
+    (0b010011_10101_00000_00000_0000010000_0, "bclr 21, 0"),
+    (0b010011_00110_00000_00000_0000010000_0, "bclr 6, 0"),
+    // - BI operand, branch always
+    (0b010011_10100_11111_00000_0000010000_0, "bclr 20, 31"),
+    // - prediction hint
+    (0b010011_01101_00010_00000_0000010000_0, "beqlr+"),
+    (0b010011_00001_11100_00000_0000010000_1, "bdnznllrl+ cr7"),
+    // - all eight simple conditional branches
+    (0b010011_01100_00000_00000_0000010000_0, "bltlr"),
+    (0b010011_01100_00001_00000_0000010000_0, "bgtlr"),
+    (0b010011_01100_00010_00000_0000010000_0, "beqlr"),
+    (0b010011_01100_00011_00000_0000010000_0, "bsolr"),
+    (0b010011_00100_00000_00000_0000010000_0, "bnllr"),
+    (0b010011_00100_00001_00000_0000010000_0, "bnglr"),
+    (0b010011_00100_00010_00000_0000010000_0, "bnelr"),
+    (0b010011_00100_00011_00000_0000010000_0, "bnslr"),
+    // - use every feature
+    (0b010011_01000_10110_00000_0000010000_1, "bdnzeqlrl cr5"),
+    //
+    // === CRNOR ===
+    // Opcode 19
+    // Extended opcode 33
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0000100001_0, "crnor lt, lt, lt"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0000100001_1, "FAIL"),
+    // - crbB operand
+    (
+        0b010011_00000_00000_11111_0000100001_0,
+        "crnor lt, lt, cr7*4+so",
+    ),
+    // - crbA operand
+    (
+        0b010011_00000_11111_00000_0000100001_0,
+        "crnor lt, cr7*4+so, lt",
+    ),
+    // - crbD operand
+    (
+        0b010011_11111_00000_00000_0000100001_0,
+        "crnor cr7*4+so, lt, lt",
+    ),
+    // - all ones
+    (
+        0b010011_11111_11111_11111_0000100001_0,
+        "crnor cr7*4+so, cr7*4+so, cr7*4+so",
+    ),
+    //
+    // === RFI ===
+    // Opcode 19
+    // Extended opcode 50
+    //
+    // - canonical form
+    (0b010011_00000_00000_00000_0000110010_0, "rfi"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0000110010_1, "FAIL"),
+    // - reserved bits 6..=20
+    (0b010011_00001_00000_00000_0000110010_0, "FAIL"),
+    (0b010011_00000_00001_00000_0000110010_0, "FAIL"),
+    (0b010011_00000_00000_00001_0000110010_0, "FAIL"),
+    //
+    // === CRANDC ===
+    // Opcode 19
+    // Extended opcode 129
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0010000001_0, "crandc lt, lt, lt"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0010000001_1, "FAIL"),
+    // - crbB operand
+    (
+        0b010011_00000_00000_11111_0010000001_0,
+        "crandc lt, lt, cr7*4+so",
+    ),
+    // - crbA operand
+    (
+        0b010011_00000_11111_00000_0010000001_0,
+        "crandc lt, cr7*4+so, lt",
+    ),
+    // - crbD operand
+    (
+        0b010011_11111_00000_00000_0010000001_0,
+        "crandc cr7*4+so, lt, lt",
+    ),
+    // - all ones
+    (
+        0b010011_11111_11111_11111_0010000001_0,
+        "crandc cr7*4+so, cr7*4+so, cr7*4+so",
+    ),
+    //
+    // === ISYNC ===
+    // Opcode 19
+    // Extended opcode 150
+    //
+    // - canonical form
+    (0b010011_00000_00000_00000_0010010110_0, "isync"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0010010110_1, "FAIL"),
+    // - reserved bits 6..=20
+    (0b010011_00001_00000_00000_0010010110_0, "FAIL"),
+    (0b010011_00000_00001_00000_0010010110_0, "FAIL"),
+    (0b010011_00000_00000_00001_0010010110_0, "FAIL"),
+    //
+    // === CRXOR ===
+    // Opcode 19
+    // Extended opcode 193
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0011000001_0, "crxor lt, lt, lt"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0011000001_1, "FAIL"),
+    // - crbB operand
+    (
+        0b010011_00000_00000_11111_0011000001_0,
+        "crxor lt, lt, cr7*4+so",
+    ),
+    // - crbA operand
+    (
+        0b010011_00000_11111_00000_0011000001_0,
+        "crxor lt, cr7*4+so, lt",
+    ),
+    // - crbD operand
+    (
+        0b010011_11111_00000_00000_0011000001_0,
+        "crxor cr7*4+so, lt, lt",
+    ),
+    // - all ones
+    (
+        0b010011_11111_11111_11111_0011000001_0,
+        "crxor cr7*4+so, cr7*4+so, cr7*4+so",
+    ),
+    //
+    // === CRNAND ===
+    // Opcode 19
+    // Extended opcode 225
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0011100001_0, "crnand lt, lt, lt"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0011100001_1, "FAIL"),
+    // - crbB operand
+    (
+        0b010011_00000_00000_11111_0011100001_0,
+        "crnand lt, lt, cr7*4+so",
+    ),
+    // - crbA operand
+    (
+        0b010011_00000_11111_00000_0011100001_0,
+        "crnand lt, cr7*4+so, lt",
+    ),
+    // - crbD operand
+    (
+        0b010011_11111_00000_00000_0011100001_0,
+        "crnand cr7*4+so, lt, lt",
+    ),
+    // - all ones
+    (
+        0b010011_11111_11111_11111_0011100001_0,
+        "crnand cr7*4+so, cr7*4+so, cr7*4+so",
+    ),
+    //
+    // === CRAND ===
+    // Opcode 19
+    // Extended opcode 257
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0100000001_0, "crand lt, lt, lt"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0100000001_1, "FAIL"),
+    // - crbB operand
+    (
+        0b010011_00000_00000_11111_0100000001_0,
+        "crand lt, lt, cr7*4+so",
+    ),
+    // - crbA operand
+    (
+        0b010011_00000_11111_00000_0100000001_0,
+        "crand lt, cr7*4+so, lt",
+    ),
+    // - crbD operand
+    (
+        0b010011_11111_00000_00000_0100000001_0,
+        "crand cr7*4+so, lt, lt",
+    ),
+    // - all ones
+    (
+        0b010011_11111_11111_11111_0100000001_0,
+        "crand cr7*4+so, cr7*4+so, cr7*4+so",
+    ),
+    //
+    // === CREQV ===
+    // Opcode 19
+    // Extended opcode 289
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0100100001_0, "creqv lt, lt, lt"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0100100001_1, "FAIL"),
+    // - crbB operand
+    (
+        0b010011_00000_00000_11111_0100100001_0,
+        "creqv lt, lt, cr7*4+so",
+    ),
+    // - crbA operand
+    (
+        0b010011_00000_11111_00000_0100100001_0,
+        "creqv lt, cr7*4+so, lt",
+    ),
+    // - crbD operand
+    (
+        0b010011_11111_00000_00000_0100100001_0,
+        "creqv cr7*4+so, lt, lt",
+    ),
+    // - all ones
+    (
+        0b010011_11111_11111_11111_0100100001_0,
+        "creqv cr7*4+so, cr7*4+so, cr7*4+so",
+    ),
+    //
+    // === CRORC ===
+    // Opcode 19
+    // Extended opcode 417
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0110100001_0, "crorc lt, lt, lt"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0110100001_1, "FAIL"),
+    // - crbB operand
+    (
+        0b010011_00000_00000_11111_0110100001_0,
+        "crorc lt, lt, cr7*4+so",
+    ),
+    // - crbA operand
+    (
+        0b010011_00000_11111_00000_0110100001_0,
+        "crorc lt, cr7*4+so, lt",
+    ),
+    // - crbD operand
+    (
+        0b010011_11111_00000_00000_0110100001_0,
+        "crorc cr7*4+so, lt, lt",
+    ),
+    // - all ones
+    (
+        0b010011_11111_11111_11111_0110100001_0,
+        "crorc cr7*4+so, cr7*4+so, cr7*4+so",
+    ),
+    //
+    // === CROR ===
+    // Opcode 19
+    // Extended opcode 449
+    //
+    // - all zeros
+    (0b010011_00000_00000_00000_0111000001_0, "cror lt, lt, lt"),
+    // - reserved bit 31
+    (0b010011_00000_00000_00000_0111000001_1, "FAIL"),
+    // - crbB operand
+    (
+        0b010011_00000_00000_11111_0111000001_0,
+        "cror lt, lt, cr7*4+so",
+    ),
+    // - crbA operand
+    (
+        0b010011_00000_11111_00000_0111000001_0,
+        "cror lt, cr7*4+so, lt",
+    ),
+    // - crbD operand
+    (
+        0b010011_11111_00000_00000_0111000001_0,
+        "cror cr7*4+so, lt, lt",
+    ),
+    // - all ones
+    (
+        0b010011_11111_11111_11111_0111000001_0,
+        "cror cr7*4+so, cr7*4+so, cr7*4+so",
+    ),
+    //
+    // === BCCTRx ===
+    // Opcode 19
+    // Extended opcode 528
+    //
+    // - BO operand, branch always
+    (0b010011_10100_00000_00000_1000010000_0, "bctr"),
+    // - link flag
+    (0b010011_10100_00000_00000_1000010000_1, "bctrl"),
+    // reserved bits 16..=20
+    (0b010011_10100_00000_00001_1000010000_0, "FAIL"),
+    (0b010011_10100_00000_00010_1000010000_0, "FAIL"),
+    (0b010011_10100_00000_00100_1000010000_0, "FAIL"),
+    (0b010011_10100_00000_01000_1000010000_0, "FAIL"),
+    (0b010011_10100_00000_10000_1000010000_0, "FAIL"),
+    // - BO operand, decrement CTR
+    (0b010011_00000_00000_00000_1000010000_0, "FAIL"),
+    // - BO operand, decrement CTR and branch if zero
+    (0b010011_00010_00000_00000_1000010000_0, "FAIL"),
+    // - BO operand, ignored bits
+    (0b010011_10101_00000_00000_1000010000_0, "bcctr 21, 0"),
+    (0b010011_00110_00000_00000_1000010000_0, "bcctr 6, 0"),
+    // - BI operand, branch always
+    (0b010011_10100_00001_00000_1000010000_0, "bcctr 20, 1"),
+    // - prediction hint
+    (0b010011_00101_00010_00000_1000010000_0, "bnectr+"),
+    // - BI operand, condition
+    (0b010011_00100_00011_00000_1000010000_0, "bnsctr"),
+    // - BI operand, condition register field
+    (0b010011_00100_11100_00000_1000010000_0, "bnlctr cr7"),
+    // - all eight simple conditional branches
+    (0b010011_01100_00000_00000_1000010000_0, "bltctr"),
+    (0b010011_01100_00001_00000_1000010000_0, "bgtctr"),
+    (0b010011_01100_00010_00000_1000010000_0, "beqctr"),
+    (0b010011_01100_00011_00000_1000010000_0, "bsoctr"),
+    (0b010011_00100_00000_00000_1000010000_0, "bnlctr"),
+    (0b010011_00100_00001_00000_1000010000_0, "bngctr"),
+    (0b010011_00100_00010_00000_1000010000_0, "bnectr"),
+    (0b010011_00100_00011_00000_1000010000_0, "bnsctr"),
+    // - use every feature
+    (0b010011_01100_10110_00000_1000010000_1, "beqctrl cr5"),
+    //
+    // === RLWIMI ===
+    // Opcode 20
+    //
+    // - all zeros
+    (
+        0b010100_00000_00000_00000_00000_00000_0,
+        "rlwimi r0, r0, 0, 0, 0",
+    ),
+    // - record bit
+    (
+        0b010100_00000_00000_00000_00000_00000_1,
+        "rlwimi. r0, r0, 0, 0, 0",
+    ),
+    // - ME operand
+    (
+        0b010100_00000_00000_00000_00000_11111_0,
+        "rlwimi r0, r0, 0, 0, 31",
+    ),
+    // - MB operand
+    (
+        0b010100_00000_00000_00000_11111_00000_0,
+        "rlwimi r0, r0, 0, 31, 0",
+    ),
+    // - SH operand
+    (
+        0b010100_00000_00000_11111_00000_00000_0,
+        "rlwimi r0, r0, 31, 0, 0",
+    ),
+    // - A register
+    (
+        0b010100_00000_11111_00000_00000_00000_0,
+        "rlwimi r31, r0, 0, 0, 0",
+    ),
+    // - S register
+    (
+        0b010100_11111_00000_00000_00000_00000_0,
+        "rlwimi r0, r31, 0, 0, 0",
+    ),
+    // - all ones
+    (
+        0b010100_11111_11111_11111_11111_11111_1,
+        "rlwimi. r31, r31, 31, 31, 31",
+    ),
+    //
+    // === RLWINM ===
+    // Opcode 21
+    //
+    // - all zeros
+    (
+        0b010101_00000_00000_00000_00000_00000_0,
+        "rlwinm r0, r0, 0, 0, 0",
+    ),
+    // - record bit
+    (
+        0b010101_00000_00000_00000_00000_00000_1,
+        "rlwinm. r0, r0, 0, 0, 0",
+    ),
+    // - ME operand
+    (
+        0b010101_00000_00000_00000_00000_11111_0,
+        "rlwinm r0, r0, 0, 0, 31",
+    ),
+    // - MB operand
+    (
+        0b010101_00000_00000_00000_11111_00000_0,
+        "rlwinm r0, r0, 0, 31, 0",
+    ),
+    // - SH operand
+    (
+        0b010101_00000_00000_11111_00000_00000_0,
+        "rlwinm r0, r0, 31, 0, 0",
+    ),
+    // - A register
+    (
+        0b010101_00000_11111_00000_00000_00000_0,
+        "rlwinm r31, r0, 0, 0, 0",
+    ),
+    // - S register
+    (
+        0b010101_11111_00000_00000_00000_00000_0,
+        "rlwinm r0, r31, 0, 0, 0",
+    ),
+    // - all ones
+    (
+        0b010101_11111_11111_11111_11111_11111_1,
+        "rlwinm. r31, r31, 31, 31, 31",
+    ),
+    //
+    // === RLWNM ===
+    // Opcode 23
+    //
+    // - all zeros
+    (
+        0b010111_00000_00000_00000_00000_00000_0,
+        "rlwnm r0, r0, r0, 0, 0",
+    ),
+    // - record bit
+    (
+        0b010111_00000_00000_00000_00000_00000_1,
+        "rlwnm. r0, r0, r0, 0, 0",
+    ),
+    // - ME operand
+    (
+        0b010111_00000_00000_00000_00000_11111_0,
+        "rlwnm r0, r0, r0, 0, 31",
+    ),
+    // - MB operand
+    (
+        0b010111_00000_00000_00000_11111_00000_0,
+        "rlwnm r0, r0, r0, 31, 0",
+    ),
+    // - B register
+    (
+        0b010111_00000_00000_11111_00000_00000_0,
+        "rlwnm r0, r0, r31, 0, 0",
+    ),
+    // - A register
+    (
+        0b010111_00000_11111_00000_00000_00000_0,
+        "rlwnm r31, r0, r0, 0, 0",
+    ),
+    // - S register
+    (
+        0b010111_11111_00000_00000_00000_00000_0,
+        "rlwnm r0, r31, r0, 0, 0",
+    ),
+    // - all ones
+    (
+        0b010111_11111_11111_11111_11111_11111_1,
+        "rlwnm. r31, r31, r31, 31, 31",
+    ),
+    //
+    // === ORI ===
+    // Opcode 24
+    //
+    // - all zeros
+    (0b011000_00000_00000_0000000000000000, "ori r0, r0, 0"),
+    // - unsigned immediate
+    (0b011000_00000_00000_1111111111111111, "ori r0, r0, 0xffff"),
+    // - register A
+    (0b011000_00000_11111_0000000000000000, "ori r31, r0, 0"),
+    // - register S
+    (0b011000_11111_00000_0000000000000000, "ori r0, r31, 0"),
+    // - all ones
+    (
+        0b011000_11111_11111_1111111111111111,
+        "ori r31, r31, 0xffff",
+    ),
+    //
+    // === ORIS ===
+    // Opcode 25
+    //
+    // - all zeros
+    (0b011001_00000_00000_0000000000000000, "oris r0, r0, 0"),
+    // - unsigned immediate
+    (0b011001_00000_00000_1111111111111111, "oris r0, r0, 0xffff"),
+    // - register A
+    (0b011001_00000_11111_0000000000000000, "oris r31, r0, 0"),
+    // - register S
+    (0b011001_11111_00000_0000000000000000, "oris r0, r31, 0"),
+    // - all ones
+    (
+        0b011001_11111_11111_1111111111111111,
+        "oris r31, r31, 0xffff",
+    ),
+    //
+    // === XORI ===
+    // Opcode 26
+    //
+    // - all zeros
+    (0b011010_00000_00000_0000000000000000, "xori r0, r0, 0"),
+    // - unsigned immediate
+    (0b011010_00000_00000_1111111111111111, "xori r0, r0, 0xffff"),
+    // - register A
+    (0b011010_00000_11111_0000000000000000, "xori r31, r0, 0"),
+    // - register S
+    (0b011010_11111_00000_0000000000000000, "xori r0, r31, 0"),
+    // - all ones
+    (
+        0b011010_11111_11111_1111111111111111,
+        "xori r31, r31, 0xffff",
+    ),
+    //
+    // === XORIS ===
+    // Opcode 27
+    //
+    // - all zeros
+    (0b011011_00000_00000_0000000000000000, "xoris r0, r0, 0"),
+    // - unsigned immediate
+    (
+        0b011011_00000_00000_1111111111111111,
+        "xoris r0, r0, 0xffff",
+    ),
+    // - register A
+    (0b011011_00000_11111_0000000000000000, "xoris r31, r0, 0"),
+    // - register S
+    (0b011011_11111_00000_0000000000000000, "xoris r0, r31, 0"),
+    // - all ones
+    (
+        0b011011_11111_11111_1111111111111111,
+        "xoris r31, r31, 0xffff",
+    ),
+    //
+    // === ANDI. ===
+    // Opcode 28
+    //
+    // - all zeros
+    (0b011100_00000_00000_0000000000000000, "andi. r0, r0, 0"),
+    // - unsigned immediate
+    (
+        0b011100_00000_00000_1111111111111111,
+        "andi. r0, r0, 0xffff",
+    ),
+    // - register A
+    (0b011100_00000_11111_0000000000000000, "andi. r31, r0, 0"),
+    // - register S
+    (0b011100_11111_00000_0000000000000000, "andi. r0, r31, 0"),
+    // - all ones
+    (
+        0b011100_11111_11111_1111111111111111,
+        "andi. r31, r31, 0xffff",
+    ),
+    //
+    // === ANDIS. ===
+    // Opcode 29
+    //
+    // - all zeros
+    (0b011101_00000_00000_0000000000000000, "andis. r0, r0, 0"),
+    // - unsigned immediate
+    (
+        0b011101_00000_00000_1111111111111111,
+        "andis. r0, r0, 0xffff",
+    ),
+    // - register A
+    (0b011101_00000_11111_0000000000000000, "andis. r31, r0, 0"),
+    // - register S
+    (0b011101_11111_00000_0000000000000000, "andis. r0, r31, 0"),
+    // - all ones
+    (
+        0b011101_11111_11111_1111111111111111,
+        "andis. r31, r31, 0xffff",
+    ),
+    //
+    // === CMP (CMPW) ===
+    // Opcode 31
+    // Extended opcode 0
+    //
+    // - all zeros
+    (0b011111_000_0_0_00000_00000_0000000000_0, "cmpw r0, r0"),
+    // - reserved bit 31
+    (0b011111_000_0_0_00000_00000_0000000000_1, "FAIL"),
+    // - register B
+    (0b011111_000_0_0_00000_11111_0000000000_0, "cmpw r0, r31"),
+    // - register A
+    (0b011111_000_0_0_11111_00000_0000000000_0, "cmpw r31, r0"),
+    // - L flag
+    (0b011111_000_0_1_00000_00000_0000000000_0, "FAIL"),
+    // - reserved bit 9
+    (0b011111_000_1_0_00000_00000_0000000000_0, "FAIL"),
+    // - condition register field D
+    (
+        0b011111_111_0_0_00000_00000_0000000000_0,
+        "cmpw cr7, r0, r0",
+    ),
+    // - all ones
+    (
+        0b011111_111_0_0_11111_11111_0000000000_0,
+        "cmpw cr7, r31, r31",
+    ),
+    //
+    // === TW ===
+    // Opcode 31
+    // Extended opcode 4
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0000000100_0, "tw 0, r0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0000000100_1, "FAIL"),
+    // - TO operand
+    (0b011111_11111_00000_00000_0000000100_0, "tw 0x1f, r0, r0"),
+    // - register A
+    (0b011111_00000_11111_00000_0000000100_0, "tw 0, r31, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0000000100_0, "tw 0, r0, r31"),
+    //
+    // === SUBFC ===
+    // Opcode 31
+    // Extended opcode 8
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_000001000_0, "subfc r0, r0, r0"),
+    // - record bit
+    (
+        0b011111_00000_00000_00000_0_000001000_1,
+        "subfc. r0, r0, r0",
+    ),
+    // - OE operand
+    (
+        0b011111_00000_00000_00000_1_000001000_0,
+        "subfco r0, r0, r0",
+    ),
+    // - register B
+    (
+        0b011111_00000_00000_11111_0_000001000_0,
+        "subfc r0, r0, r31",
+    ),
+    // - register A
+    (
+        0b011111_00000_11111_00000_0_000001000_0,
+        "subfc r0, r31, r0",
+    ),
+    // - register D
+    (
+        0b011111_11111_00000_00000_0_000001000_0,
+        "subfc r31, r0, r0",
+    ),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1_000001000_1,
+        "subfco. r31, r31, r31",
+    ),
+    //
+    // === ADDC ===
+    // Opcode 31
+    // Extended opcode 10
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_000001010_0, "addc r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_000001010_1, "addc. r0, r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_000001010_0, "addco r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0_000001010_0, "addc r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0_000001010_0, "addc r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0_000001010_0, "addc r31, r0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1_000001010_1,
+        "addco. r31, r31, r31",
+    ),
+    //
+    // === MULHWU ===
+    // Opcode 31
+    // Extended opcode 11
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0000001011_0, "mulhwu r0, r0, r0"),
+    // - record bit
+    (
+        0b011111_00000_00000_00000_0000001011_1,
+        "mulhwu. r0, r0, r0",
+    ),
+    // - reserved bit 21
+    (0b011111_00000_00000_00000_1_000001011_0, "FAIL"),
+    // - register B
+    (
+        0b011111_00000_00000_11111_0000001011_0,
+        "mulhwu r0, r0, r31",
+    ),
+    // - register A
+    (
+        0b011111_00000_11111_00000_0000001011_0,
+        "mulhwu r0, r31, r0",
+    ),
+    // - register D
+    (
+        0b011111_11111_00000_00000_0000001011_0,
+        "mulhwu r31, r0, r0",
+    ),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0000001011_1,
+        "mulhwu. r31, r31, r31",
+    ),
+    //
+    // === MFCR ===
+    // Opcode 31
+    // Extended opcode 19
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0000010011_0, "mfcr r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0000010011_0, "mfcr r31"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0000010011_1, "FAIL"),
+    // - reserved bits 11..=15
+    (0b011111_00000_00001_00000_0000010011_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b011111_00000_00000_00001_0000010011_0, "FAIL"),
+    //
+    // === LWZX ===
+    // Opcode 31
+    // Extended opcode 23
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0000010111_0, "lwzx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0000010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0000010111_0, "lwzx r0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0000010111_0, "lwzx r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0000010111_0, "lwzx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0000010111_0,
+        "lwzx r31, r31, r31",
+    ),
+    //
+    // === SLW ===
+    // Opcode 31
+    // Extended opcode 24
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0000011000_0, "slw r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0000011000_1, "slw. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0000011000_0, "slw r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0000011000_0, "slw r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0000011000_0, "slw r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0000011000_1,
+        "slw. r31, r31, r31",
+    ),
+    //
+    // === CNTLZW ===
+    // Opcode 31
+    // Extended opcode 26
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0000011010_0, "cntlzw r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0000011010_1, "cntlzw. r0, r0"),
+    // - reserved bits 16..20
+    (0b011111_00000_00000_00001_0000011010_0, "FAIL"),
+    (0b011111_00000_00000_00010_0000011010_0, "FAIL"),
+    (0b011111_00000_00000_00100_0000011010_0, "FAIL"),
+    (0b011111_00000_00000_01000_0000011010_0, "FAIL"),
+    (0b011111_00000_00000_10000_0000011010_0, "FAIL"),
+    // - register A
+    (0b011111_00000_11111_00000_0000011010_0, "cntlzw r31, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0000011010_0, "cntlzw r0, r31"),
+    // - all ones
+    (0b011111_11111_11111_00000_0000011010_1, "cntlzw. r31, r31"),
+    //
+    // === AND ===
+    // Opcode 31
+    // Extended opcode 28
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0000011100_0, "and r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0000011100_1, "and. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0000011100_0, "and r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0000011100_0, "and r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0000011100_0, "and r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0000011100_1,
+        "and. r31, r31, r31",
+    ),
+    //
+    // === CMPL (CMPLW) ===
+    // Opcode 31
+    // Extended opcode 32
+    //
+    // - all zeros
+    (0b011111_000_0_0_00000_00000_0000100000_0, "cmplw r0, r0"),
+    // - reserved bit 31
+    (0b011111_000_0_0_00000_00000_0000100000_1, "FAIL"),
+    // - register B
+    (0b011111_000_0_0_00000_11111_0000100000_0, "cmplw r0, r31"),
+    // - register A
+    (0b011111_000_0_0_11111_00000_0000100000_0, "cmplw r31, r0"),
+    // - L flag
+    (0b011111_000_0_1_00000_00000_0000100000_0, "FAIL"),
+    // - reserved bit 9
+    (0b011111_000_1_0_00000_00000_0000100000_0, "FAIL"),
+    // - condition register field D
+    (
+        0b011111_111_0_0_00000_00000_0000100000_0,
+        "cmplw cr7, r0, r0",
+    ),
+    // - all ones
+    (
+        0b011111_111_0_0_11111_11111_0000100000_0,
+        "cmplw cr7, r31, r31",
+    ),
+    //
+    // === SUBF ===
+    // Opcode 31
+    // Extended opcode 40
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_000101000_0, "subf r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_000101000_1, "subf. r0, r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_000101000_0, "subfo r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0_000101000_0, "subf r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0_000101000_0, "subf r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0_000101000_0, "subf r31, r0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1_000101000_1,
+        "subfo. r31, r31, r31",
+    ),
+    //
+    // === DCBST ===
+    // Opcode 31
+    // Extended opcode 54
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0000110110_0, "dcbst 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0000110110_1, "FAIL"),
+    // - reserved bits 6..10
+    (0b011111_00001_00000_00000_0000110110_0, "FAIL"),
+    (0b011111_00010_00000_00000_0000110110_0, "FAIL"),
+    (0b011111_00100_00000_00000_0000110110_0, "FAIL"),
+    (0b011111_01000_00000_00000_0000110110_0, "FAIL"),
+    (0b011111_10000_00000_00000_0000110110_0, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0000110110_0, "dcbst 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0000110110_0, "dcbst r31, r0"),
+    // - all ones
+    (0b011111_00000_11111_11111_0000110110_0, "dcbst r31, r31"),
+    //
+    // === LWZUX ===
+    // Opcode 31
+    // Extended opcode 55
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_0000110111_0, "lwzux r0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_0000110111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00001_11111_0000110111_0, "lwzux r0, r1, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0000110111_0, "lwzux r0, r31, r0"),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_0000110111_0, "FAIL"),
+    // - register A equal to register D
+    (0b011111_00101_00101_00000_0000110111_0, "FAIL"),
+    // - register D
+    (0b011111_11111_00001_00000_0000110111_0, "lwzux r31, r1, r0"),
+    // - use every feature
+    (
+        0b011111_11111_11110_11111_0000110111_0,
+        "lwzux r31, r30, r31",
+    ),
+    //
+    // === ANDC ===
+    // Opcode 31
+    // Extended opcode 60
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0000111100_0, "andc r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0000111100_1, "andc. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0000111100_0, "andc r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0000111100_0, "andc r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0000111100_0, "andc r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0000111100_1,
+        "andc. r31, r31, r31",
+    ),
+    //
+    // === MULHW ===
+    // Opcode 31
+    // Extended opcode 75
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0001001011_0, "mulhw r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0001001011_1, "mulhw. r0, r0, r0"),
+    // - reserved bit 21
+    (0b011111_00000_00000_00000_1_001001011_0, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0001001011_0, "mulhw r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0001001011_0, "mulhw r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0001001011_0, "mulhw r31, r0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0001001011_1,
+        "mulhw. r31, r31, r31",
+    ),
+    //
+    // === MFMSR ===
+    // Opcode 31
+    // Extended opcode 83
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0001010011_0, "mfmsr r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0001010011_0, "mfmsr r31"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0001010011_1, "FAIL"),
+    // - reserved bits 11..=15
+    (0b011111_00000_00001_00000_0001010011_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b011111_00000_00000_00001_0001010011_0, "FAIL"),
+    //
+    // === DCBF ===
+    // Opcode 31
+    // Extended opcode 86
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0001010110_0, "dcbf 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0001010110_1, "FAIL"),
+    // - reserved bits 6..10
+    (0b011111_00001_00000_00000_0001010110_0, "FAIL"),
+    (0b011111_00010_00000_00000_0001010110_0, "FAIL"),
+    (0b011111_00100_00000_00000_0001010110_0, "FAIL"),
+    (0b011111_01000_00000_00000_0001010110_0, "FAIL"),
+    (0b011111_10000_00000_00000_0001010110_0, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0001010110_0, "dcbf 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0001010110_0, "dcbf r31, r0"),
+    // - all ones
+    (0b011111_00000_11111_11111_0001010110_0, "dcbf r31, r31"),
+    //
+    // === LBZX ===
+    // Opcode 31
+    // Extended opcode 87
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0001010111_0, "lbzx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0001010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0001010111_0, "lbzx r0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0001010111_0, "lbzx r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0001010111_0, "lbzx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0001010111_0,
+        "lbzx r31, r31, r31",
+    ),
+    //
+    // === NEG ===
+    // Opcode 31
+    // Extended opcode 104
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_001101000_0, "neg r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_001101000_1, "neg. r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_001101000_0, "nego r0, r0"),
+    // - reserved bits 16..20
+    (0b011111_00000_00000_00001_0_001101000_0, "FAIL"),
+    (0b011111_00000_00000_00010_0_001101000_0, "FAIL"),
+    (0b011111_00000_00000_00100_0_001101000_0, "FAIL"),
+    (0b011111_00000_00000_01000_0_001101000_0, "FAIL"),
+    (0b011111_00000_00000_10000_0_001101000_0, "FAIL"),
+    // - register A
+    (0b011111_00000_11111_00000_0_001101000_0, "neg r0, r31"),
+    // - register D
+    (0b011111_11111_00000_00000_0_001101000_0, "neg r31, r0"),
+    // - all ones
+    (0b011111_11111_11111_00000_1_001101000_1, "nego. r31, r31"),
+    //
+    // === LBZUX ===
+    // Opcode 31
+    // Extended opcode 119
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_0001110111_0, "lbzux r0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_0001110111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00001_11111_0001110111_0, "lbzux r0, r1, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0001110111_0, "lbzux r0, r31, r0"),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_0001110111_0, "FAIL"),
+    // - register A equal to register D
+    (0b011111_00101_00101_00000_0001110111_0, "FAIL"),
+    // - register D
+    (0b011111_11111_00001_00000_0001110111_0, "lbzux r31, r1, r0"),
+    // - use every feature
+    (
+        0b011111_11111_11110_11111_0001110111_0,
+        "lbzux r31, r30, r31",
+    ),
+    //
+    // === NOR ===
+    // Opcode 31
+    // Extended opcode 124
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0001111100_0, "nor r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0001111100_1, "nor. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0001111100_0, "nor r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0001111100_0, "nor r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0001111100_0, "nor r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0001111100_1,
+        "nor. r31, r31, r31",
+    ),
+    //
+    // === SUBFE ===
+    // Opcode 31
+    // Extended opcode 136
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_010001000_0, "subfe r0, r0, r0"),
+    // - record bit
+    (
+        0b011111_00000_00000_00000_0_010001000_1,
+        "subfe. r0, r0, r0",
+    ),
+    // - OE operand
+    (
+        0b011111_00000_00000_00000_1_010001000_0,
+        "subfeo r0, r0, r0",
+    ),
+    // - register B
+    (
+        0b011111_00000_00000_11111_0_010001000_0,
+        "subfe r0, r0, r31",
+    ),
+    // - register A
+    (
+        0b011111_00000_11111_00000_0_010001000_0,
+        "subfe r0, r31, r0",
+    ),
+    // - register D
+    (
+        0b011111_11111_00000_00000_0_010001000_0,
+        "subfe r31, r0, r0",
+    ),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1_010001000_1,
+        "subfeo. r31, r31, r31",
+    ),
+    //
+    // === ADDE ===
+    // Opcode 31
+    // Extended opcode 138
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_010001010_0, "adde r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_010001010_1, "adde. r0, r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_010001010_0, "addeo r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0_010001010_0, "adde r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0_010001010_0, "adde r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0_010001010_0, "adde r31, r0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1_010001010_1,
+        "addeo. r31, r31, r31",
+    ),
+    //
+    // === MTCRF ===
+    // Opcode 31
+    // Extended opcode 144
+    //
+    // - all zeros
+    (0b011111_00000_0_00000000_0_0010010000_0, "mtcrf 0, r0"),
+    // - field mask
+    (0b011111_00000_0_11111111_0_0010010000_0, "mtcrf 0xff, r0"),
+    // - field mask, CR0 only
+    (0b011111_00000_0_10000000_0_0010010000_0, "mtcrf 0x80, r0"),
+    // - register S
+    (0b011111_11111_0_00000000_0_0010010000_0, "mtcrf 0, r31"),
+    // - reserved bit 31
+    (0b011111_00000_0_00000000_0_0010010000_1, "FAIL"),
+    // - reserved bit 11
+    (0b011111_00000_1_00000000_0_0010010000_0, "FAIL"),
+    // - reserved bit 20
+    (0b011111_00000_0_00000000_1_0010010000_0, "FAIL"),
+    //
+    // === MTMSR ===
+    // Opcode 31
+    // Extended opcode 146
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0010010010_0, "mtmsr r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0010010010_0, "mtmsr r31"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0010010010_1, "FAIL"),
+    // - reserved bits 11..=15
+    (0b011111_00000_00001_00000_0010010010_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b011111_00000_00000_00001_0010010010_0, "FAIL"),
+    //
+    // === STWX ===
+    // Opcode 31
+    // Extended opcode 151
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0010010111_0, "stwx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0010010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0010010111_0, "stwx r0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0010010111_0, "stwx r0, r31, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0010010111_0, "stwx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0010010111_0,
+        "stwx r31, r31, r31",
+    ),
+    //
+    // === STWUX ===
+    // Opcode 31
+    // Extended opcode 183
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_0010110111_0, "stwux r0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_0010110111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00001_11111_0010110111_0, "stwux r0, r1, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0010110111_0, "stwux r0, r31, r0"),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_0010110111_0, "FAIL"),
+    // - register S
+    (0b011111_11111_00001_00000_0010110111_0, "stwux r31, r1, r0"),
+    // - use every feature
+    (
+        0b011111_11111_11110_11111_0010110111_0,
+        "stwux r31, r30, r31",
+    ),
+    //
+    // === SUBFZE ===
+    // Opcode 31
+    // Extended opcode 200
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_011001000_0, "subfze r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_011001000_1, "subfze. r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_011001000_0, "subfzeo r0, r0"),
+    // - reserved bits 16..20
+    (0b011111_00000_00000_00001_0_011001000_0, "FAIL"),
+    (0b011111_00000_00000_00010_0_011001000_0, "FAIL"),
+    (0b011111_00000_00000_00100_0_011001000_0, "FAIL"),
+    (0b011111_00000_00000_01000_0_011001000_0, "FAIL"),
+    (0b011111_00000_00000_10000_0_011001000_0, "FAIL"),
+    // - register A
+    (0b011111_00000_11111_00000_0_011001000_0, "subfze r0, r31"),
+    // - register D
+    (0b011111_11111_00000_00000_0_011001000_0, "subfze r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_00000_1_011001000_1,
+        "subfzeo. r31, r31",
+    ),
+    //
+    // === ADDZE ===
+    // Opcode 31
+    // Extended opcode 202
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_011001010_0, "addze r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_011001010_1, "addze. r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_011001010_0, "addzeo r0, r0"),
+    // - reserved bits 16..20
+    (0b011111_00000_00000_00001_0_011001010_0, "FAIL"),
+    (0b011111_00000_00000_00010_0_011001010_0, "FAIL"),
+    (0b011111_00000_00000_00100_0_011001010_0, "FAIL"),
+    (0b011111_00000_00000_01000_0_011001010_0, "FAIL"),
+    (0b011111_00000_00000_10000_0_011001010_0, "FAIL"),
+    // - register A
+    (0b011111_00000_11111_00000_0_011001010_0, "addze r0, r31"),
+    // - register D
+    (0b011111_11111_00000_00000_0_011001010_0, "addze r31, r0"),
+    // - all ones
+    (0b011111_11111_11111_00000_1_011001010_1, "addzeo. r31, r31"),
+    //
+    // === MTSR ===
+    // Opcode 31
+    // Extended opcode 210
+    //
+    // - all zeros
+    (0b011111_00000_0_0000_00000_0011010010_0, "mtsr sr0, r0"),
+    // - register S
+    (0b011111_11111_0_0000_00000_0011010010_0, "mtsr sr0, r31"),
+    // - segment register
+    (0b011111_00000_0_1111_00000_0011010010_0, "mtsr sr15, r0"),
+    // - reserved bit 31
+    (0b011111_00000_0_0000_00000_0011010010_1, "FAIL"),
+    // - reserved bit 11
+    (0b011111_00000_1_0000_00000_0011010010_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b011111_00000_0_0000_00001_0011010010_0, "FAIL"),
+    //
+    // === STBX ===
+    // Opcode 31
+    // Extended opcode 215
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0011010111_0, "stbx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0011010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0011010111_0, "stbx r0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0011010111_0, "stbx r0, r31, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0011010111_0, "stbx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0011010111_0,
+        "stbx r31, r31, r31",
+    ),
+    //
+    // === SUBFME ===
+    // Opcode 31
+    // Extended opcode 232
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_011101000_0, "subfme r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_011101000_1, "subfme. r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_011101000_0, "subfmeo r0, r0"),
+    // - reserved bits 16..20
+    (0b011111_00000_00000_00001_0_011101000_0, "FAIL"),
+    (0b011111_00000_00000_00010_0_011101000_0, "FAIL"),
+    (0b011111_00000_00000_00100_0_011101000_0, "FAIL"),
+    (0b011111_00000_00000_01000_0_011101000_0, "FAIL"),
+    (0b011111_00000_00000_10000_0_011101000_0, "FAIL"),
+    // - register A
+    (0b011111_00000_11111_00000_0_011101000_0, "subfme r0, r31"),
+    // - register D
+    (0b011111_11111_00000_00000_0_011101000_0, "subfme r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_00000_1_011101000_1,
+        "subfmeo. r31, r31",
+    ),
+    //
+    // === ADDME ===
+    // Opcode 31
+    // Extended opcode 234
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_011101010_0, "addme r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_011101010_1, "addme. r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_011101010_0, "addmeo r0, r0"),
+    // - reserved bits 16..20
+    (0b011111_00000_00000_00001_0_011101010_0, "FAIL"),
+    (0b011111_00000_00000_00010_0_011101010_0, "FAIL"),
+    (0b011111_00000_00000_00100_0_011101010_0, "FAIL"),
+    (0b011111_00000_00000_01000_0_011101010_0, "FAIL"),
+    (0b011111_00000_00000_10000_0_011101010_0, "FAIL"),
+    // - register A
+    (0b011111_00000_11111_00000_0_011101010_0, "addme r0, r31"),
+    // - register D
+    (0b011111_11111_00000_00000_0_011101010_0, "addme r31, r0"),
+    // - all ones
+    (0b011111_11111_11111_00000_1_011101010_1, "addmeo. r31, r31"),
+    //
+    // === MULLW ===
+    // Opcode 31
+    // Extended opcode 235
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_011101011_0, "mullw r0, r0, r0"),
+    // - record bit
+    (
+        0b011111_00000_00000_00000_0_011101011_1,
+        "mullw. r0, r0, r0",
+    ),
+    // - OE operand
+    (
+        0b011111_00000_00000_00000_1_011101011_0,
+        "mullwo r0, r0, r0",
+    ),
+    // - register B
+    (
+        0b011111_00000_00000_11111_0_011101011_0,
+        "mullw r0, r0, r31",
+    ),
+    // - register A
+    (
+        0b011111_00000_11111_00000_0_011101011_0,
+        "mullw r0, r31, r0",
+    ),
+    // - register D
+    (
+        0b011111_11111_00000_00000_0_011101011_0,
+        "mullw r31, r0, r0",
+    ),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1_011101011_1,
+        "mullwo. r31, r31, r31",
+    ),
+    //
+    // === MTSRIN ===
+    // Opcode 31
+    // Extended opcode 242
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0011110010_0, "mtsrin r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0011110010_0, "mtsrin r31, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0011110010_0, "mtsrin r0, r31"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0011110010_1, "FAIL"),
+    // - reserved bits 11..=15
+    (0b011111_00000_00001_00000_0011110010_0, "FAIL"),
+    //
+    // === DCBTST ===
+    // Opcode 31
+    // Extended opcode 246
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0011110110_0, "dcbtst 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0011110110_1, "FAIL"),
+    // - reserved bits 6..10
+    (0b011111_00001_00000_00000_0011110110_0, "FAIL"),
+    (0b011111_00010_00000_00000_0011110110_0, "FAIL"),
+    (0b011111_00100_00000_00000_0011110110_0, "FAIL"),
+    (0b011111_01000_00000_00000_0011110110_0, "FAIL"),
+    (0b011111_10000_00000_00000_0011110110_0, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0011110110_0, "dcbtst 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0011110110_0, "dcbtst r31, r0"),
+    // - all ones
+    (0b011111_00000_11111_11111_0011110110_0, "dcbtst r31, r31"),
+    //
+    // === STBUX ===
+    // Opcode 31
+    // Extended opcode 247
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_0011110111_0, "stbux r0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_0011110111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00001_11111_0011110111_0, "stbux r0, r1, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0011110111_0, "stbux r0, r31, r0"),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_0011110111_0, "FAIL"),
+    // - register S
+    (0b011111_11111_00001_00000_0011110111_0, "stbux r31, r1, r0"),
+    // - use every feature
+    (
+        0b011111_11111_11110_11111_0011110111_0,
+        "stbux r31, r30, r31",
+    ),
+    //
+    // === ADD ===
+    // Opcode 31
+    // Extended opcode 266
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_100001010_0, "add r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_100001010_1, "add. r0, r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_100001010_0, "addo r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0_100001010_0, "add r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0_100001010_0, "add r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0_100001010_0, "add r31, r0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1_100001010_1,
+        "addo. r31, r31, r31",
+    ),
+    //
+    // === DCBT ===
+    // Opcode 31
+    // Extended opcode 278
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0100010110_0, "dcbt 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0100010110_1, "FAIL"),
+    // - reserved bits 6..10
+    (0b011111_00001_00000_00000_0100010110_0, "FAIL"),
+    (0b011111_00010_00000_00000_0100010110_0, "FAIL"),
+    (0b011111_00100_00000_00000_0100010110_0, "FAIL"),
+    (0b011111_01000_00000_00000_0100010110_0, "FAIL"),
+    (0b011111_10000_00000_00000_0100010110_0, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0100010110_0, "dcbt 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0100010110_0, "dcbt r31, r0"),
+    // - all ones
+    (0b011111_00000_11111_11111_0100010110_0, "dcbt r31, r31"),
+    //
+    // === LHZX ===
+    // Opcode 31
+    // Extended opcode 279
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0100010111_0, "lhzx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0100010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0100010111_0, "lhzx r0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0100010111_0, "lhzx r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0100010111_0, "lhzx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0100010111_0,
+        "lhzx r31, r31, r31",
+    ),
+    //
+    // === EQV ===
+    // Opcode 31
+    // Extended opcode 284
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0100011100_0, "eqv r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0100011100_1, "eqv. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0100011100_0, "eqv r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0100011100_0, "eqv r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0100011100_0, "eqv r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0100011100_1,
+        "eqv. r31, r31, r31",
+    ),
+    //
+    // === LHZUX ===
+    // Opcode 31
+    // Extended opcode 311
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_0100110111_0, "lhzux r0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_0100110111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00001_11111_0100110111_0, "lhzux r0, r1, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0100110111_0, "lhzux r0, r31, r0"),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_0100110111_0, "FAIL"),
+    // - register A equal to register D
+    (0b011111_00101_00101_00000_0100110111_0, "FAIL"),
+    // - register D
+    (0b011111_11111_00001_00000_0100110111_0, "lhzux r31, r1, r0"),
+    // - use every feature
+    (
+        0b011111_11111_11110_11111_0100110111_0,
+        "lhzux r31, r30, r31",
+    ),
+    //
+    // === XOR ===
+    // Opcode 31
+    // Extended opcode 316
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0100111100_0, "xor r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0100111100_1, "xor. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0100111100_0, "xor r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0100111100_0, "xor r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0100111100_0, "xor r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0100111100_1,
+        "xor. r31, r31, r31",
+    ),
+    //
+    // === MFSPR ===
+    // Opcode 31
+    // Extended opcode 339
+    //
+    // - all variants with r0
+    (0b011111_00000_00001_00000_0101010011_0, "mfxer r0"),
+    (0b011111_00000_01000_00000_0101010011_0, "mflr r0"),
+    (0b011111_00000_01001_00000_0101010011_0, "mfctr r0"),
+    (0b011111_00000_10010_00000_0101010011_0, "mfdsisr r0"),
+    (0b011111_00000_10011_00000_0101010011_0, "mfdar r0"),
+    (0b011111_00000_10110_00000_0101010011_0, "mfdec r0"),
+    (0b011111_00000_11001_00000_0101010011_0, "mfsdr1 r0"),
+    (0b011111_00000_11010_00000_0101010011_0, "mfsrr0 r0"),
+    (0b011111_00000_11011_00000_0101010011_0, "mfsrr1 r0"),
+    (0b011111_00000_10000_01000_0101010011_0, "mfsprg0 r0"),
+    (0b011111_00000_10001_01000_0101010011_0, "mfsprg1 r0"),
+    (0b011111_00000_10010_01000_0101010011_0, "mfsprg2 r0"),
+    (0b011111_00000_10011_01000_0101010011_0, "mfsprg3 r0"),
+    (0b011111_00000_11010_01000_0101010011_0, "mfear r0"),
+    (0b011111_00000_11111_01000_0101010011_0, "mfpvr r0"),
+    (0b011111_00000_10000_10000_0101010011_0, "mfibat0u r0"),
+    (0b011111_00000_10001_10000_0101010011_0, "mfibat0l r0"),
+    (0b011111_00000_10010_10000_0101010011_0, "mfibat1u r0"),
+    (0b011111_00000_10011_10000_0101010011_0, "mfibat1l r0"),
+    (0b011111_00000_10100_10000_0101010011_0, "mfibat2u r0"),
+    (0b011111_00000_10101_10000_0101010011_0, "mfibat2l r0"),
+    (0b011111_00000_10110_10000_0101010011_0, "mfibat3u r0"),
+    (0b011111_00000_10111_10000_0101010011_0, "mfibat3l r0"),
+    (0b011111_00000_11000_10000_0101010011_0, "mfdbat0u r0"),
+    (0b011111_00000_11001_10000_0101010011_0, "mfdbat0l r0"),
+    (0b011111_00000_11010_10000_0101010011_0, "mfdbat1u r0"),
+    (0b011111_00000_11011_10000_0101010011_0, "mfdbat1l r0"),
+    (0b011111_00000_11100_10000_0101010011_0, "mfdbat2u r0"),
+    (0b011111_00000_11101_10000_0101010011_0, "mfdbat2l r0"),
+    (0b011111_00000_11110_10000_0101010011_0, "mfdbat3u r0"),
+    (0b011111_00000_11111_10000_0101010011_0, "mfdbat3l r0"),
+    (0b011111_00000_10000_11100_0101010011_0, "mfgqr0 r0"),
+    (0b011111_00000_10001_11100_0101010011_0, "mfgqr1 r0"),
+    (0b011111_00000_10010_11100_0101010011_0, "mfgqr2 r0"),
+    (0b011111_00000_10011_11100_0101010011_0, "mfgqr3 r0"),
+    (0b011111_00000_10100_11100_0101010011_0, "mfgqr4 r0"),
+    (0b011111_00000_10101_11100_0101010011_0, "mfgqr5 r0"),
+    (0b011111_00000_10110_11100_0101010011_0, "mfgqr6 r0"),
+    (0b011111_00000_10111_11100_0101010011_0, "mfgqr7 r0"),
+    (0b011111_00000_11000_11100_0101010011_0, "mfhid2 r0"),
+    (0b011111_00000_11001_11100_0101010011_0, "mfwpar r0"),
+    (0b011111_00000_11010_11100_0101010011_0, "mfdmau r0"),
+    (0b011111_00000_11011_11100_0101010011_0, "mfdmal r0"),
+    (0b011111_00000_01000_11101_0101010011_0, "mfummcr0 r0"),
+    (0b011111_00000_01001_11101_0101010011_0, "mfupmc1 r0"),
+    (0b011111_00000_01010_11101_0101010011_0, "mfupmc2 r0"),
+    (0b011111_00000_01011_11101_0101010011_0, "mfusia r0"),
+    (0b011111_00000_01100_11101_0101010011_0, "mfummcr1 r0"),
+    (0b011111_00000_01101_11101_0101010011_0, "mfupmc3 r0"),
+    (0b011111_00000_01110_11101_0101010011_0, "mfupmc4 r0"),
+    (0b011111_00000_01111_11101_0101010011_0, "mfusda r0"),
+    (0b011111_00000_11000_11101_0101010011_0, "mfmmcr0 r0"),
+    (0b011111_00000_11001_11101_0101010011_0, "mfpmc1 r0"),
+    (0b011111_00000_11010_11101_0101010011_0, "mfpmc2 r0"),
+    (0b011111_00000_11011_11101_0101010011_0, "mfsia r0"),
+    (0b011111_00000_11100_11101_0101010011_0, "mfmmcr1 r0"),
+    (0b011111_00000_11101_11101_0101010011_0, "mfpmc3 r0"),
+    (0b011111_00000_11110_11101_0101010011_0, "mfpmc4 r0"),
+    (0b011111_00000_11111_11101_0101010011_0, "mfsda r0"),
+    (0b011111_00000_10000_11111_0101010011_0, "mfhid0 r0"),
+    (0b011111_00000_10001_11111_0101010011_0, "mfhid1 r0"),
+    (0b011111_00000_10010_11111_0101010011_0, "mfiabr r0"),
+    (0b011111_00000_10101_11111_0101010011_0, "mfdabr r0"),
+    (0b011111_00000_11001_11111_0101010011_0, "mfl2cr r0"),
+    (0b011111_00000_11011_11111_0101010011_0, "mfictc r0"),
+    (0b011111_00000_11100_11111_0101010011_0, "mfthrm1 r0"),
+    (0b011111_00000_11101_11111_0101010011_0, "mfthrm2 r0"),
+    (0b011111_00000_11110_11111_0101010011_0, "mfthrm3 r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_0101010011_1, "FAIL"),
+    // - register D
+    (0b011111_11111_00001_00000_0101010011_0, "mfxer r31"),
+    // - spr operand, illegal value
+    (0b011111_00000_00000_00000_0101010011_0, "FAIL"),
+    (0b011111_00000_00001_00001_0101010011_0, "FAIL"),
+    // - spr operand, time base, read with mftb instead
+    (0b011111_00000_11100_01000_0101010011_0, "FAIL"),
+    //
+    // === LHAX ===
+    // Opcode 31
+    // Extended opcode 343
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0101010111_0, "lhax r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0101010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0101010111_0, "lhax r0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0101010111_0, "lhax r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0101010111_0, "lhax r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0101010111_0,
+        "lhax r31, r31, r31",
+    ),
+    //
+    // === MFTB ===
+    // Opcode 31
+    // Extended opcode 371
+    //
+    // - lower half
+    (0b011111_00000_01100_01000_0101110011_0, "mftb r0"),
+    // - upper half
+    (0b011111_00000_01101_01000_0101110011_0, "mftbu r0"),
+    // - register D
+    (0b011111_11111_01100_01000_0101110011_0, "mftb r31"),
+    // - reserved bit 31
+    (0b011111_00000_01100_01000_0101110011_1, "FAIL"),
+    // - tbr operand, illegal value
+    (0b011111_00000_00000_00000_0101110011_0, "FAIL"),
+    (0b011111_00000_11100_01000_0101110011_0, "FAIL"),
+    //
+    // === LHAUX ===
+    // Opcode 31
+    // Extended opcode 375
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_0101110111_0, "lhaux r0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_0101110111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00001_11111_0101110111_0, "lhaux r0, r1, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0101110111_0, "lhaux r0, r31, r0"),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_0101110111_0, "FAIL"),
+    // - register A equal to register D
+    (0b011111_00101_00101_00000_0101110111_0, "FAIL"),
+    // - register D
+    (0b011111_11111_00001_00000_0101110111_0, "lhaux r31, r1, r0"),
+    // - use every feature
+    (
+        0b011111_11111_11110_11111_0101110111_0,
+        "lhaux r31, r30, r31",
+    ),
+    //
+    // === STHX ===
+    // Opcode 31
+    // Extended opcode 407
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0110010111_0, "sthx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0110010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0110010111_0, "sthx r0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0110010111_0, "sthx r0, r31, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0110010111_0, "sthx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0110010111_0,
+        "sthx r31, r31, r31",
+    ),
+    //
+    // === ORC ===
+    // Opcode 31
+    // Extended opcode 412
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0110011100_0, "orc r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0110011100_1, "orc. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0110011100_0, "orc r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0110011100_0, "orc r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0110011100_0, "orc r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0110011100_1,
+        "orc. r31, r31, r31",
+    ),
+    //
+    // === STHUX ===
+    // Opcode 31
+    // Extended opcode 439
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_0110110111_0, "sthux r0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_0110110111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00001_11111_0110110111_0, "sthux r0, r1, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0110110111_0, "sthux r0, r31, r0"),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_0110110111_0, "FAIL"),
+    // - register S
+    (0b011111_11111_00001_00000_0110110111_0, "sthux r31, r1, r0"),
+    // - use every feature
+    (
+        0b011111_11111_11110_11111_0110110111_0,
+        "sthux r31, r30, r31",
+    ),
+    //
+    // === OR (MR) ===
+    // Opcode 31
+    // Extended opcode 444
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0110111100_0, "mr r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0110111100_1, "mr. r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0110111100_0, "or r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0110111100_0, "mr r31, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0110111100_0, "or r0, r31, r0"),
+    // - use every feature, S = B
+    (0b011111_00010_00001_00010_0110111100_1, "mr. r1, r2"),
+    // - use every feature, S != B
+    (0b011111_00010_00001_00011_0110111100_1, "or. r1, r2, r3"),
+    //
+    // === DIVWU ===
+    // Opcode 31
+    // Extended opcode 459
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_111001011_0, "divwu r0, r0, r0"),
+    // - record bit
+    (
+        0b011111_00000_00000_00000_0_111001011_1,
+        "divwu. r0, r0, r0",
+    ),
+    // - OE operand
+    (
+        0b011111_00000_00000_00000_1_111001011_0,
+        "divwuo r0, r0, r0",
+    ),
+    // - register B
+    (
+        0b011111_00000_00000_11111_0_111001011_0,
+        "divwu r0, r0, r31",
+    ),
+    // - register A
+    (
+        0b011111_00000_11111_00000_0_111001011_0,
+        "divwu r0, r31, r0",
+    ),
+    // - register D
+    (
+        0b011111_11111_00000_00000_0_111001011_0,
+        "divwu r31, r0, r0",
+    ),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1_111001011_1,
+        "divwuo. r31, r31, r31",
+    ),
+    //
+    // === MTSPR ===
+    // Opcode 31
+    // Extended opcode 467
+    //
+    // - all variants with r0
+    (0b011111_00000_00001_00000_0111010011_0, "mtxer r0"),
+    (0b011111_00000_01000_00000_0111010011_0, "mtlr r0"),
+    (0b011111_00000_01001_00000_0111010011_0, "mtctr r0"),
+    (0b011111_00000_10010_00000_0111010011_0, "mtdsisr r0"),
+    (0b011111_00000_10011_00000_0111010011_0, "mtdar r0"),
+    (0b011111_00000_10110_00000_0111010011_0, "mtdec r0"),
+    (0b011111_00000_11001_00000_0111010011_0, "mtsdr1 r0"),
+    (0b011111_00000_11010_00000_0111010011_0, "mtsrr0 r0"),
+    (0b011111_00000_11011_00000_0111010011_0, "mtsrr1 r0"),
+    (0b011111_00000_10000_01000_0111010011_0, "mtsprg0 r0"),
+    (0b011111_00000_10001_01000_0111010011_0, "mtsprg1 r0"),
+    (0b011111_00000_10010_01000_0111010011_0, "mtsprg2 r0"),
+    (0b011111_00000_10011_01000_0111010011_0, "mtsprg3 r0"),
+    (0b011111_00000_11010_01000_0111010011_0, "mtear r0"),
+    (0b011111_00000_11100_01000_0111010011_0, "mttbl r0"),
+    (0b011111_00000_11101_01000_0111010011_0, "mttbu r0"),
+    (0b011111_00000_10000_10000_0111010011_0, "mtibat0u r0"),
+    (0b011111_00000_10001_10000_0111010011_0, "mtibat0l r0"),
+    (0b011111_00000_10010_10000_0111010011_0, "mtibat1u r0"),
+    (0b011111_00000_10011_10000_0111010011_0, "mtibat1l r0"),
+    (0b011111_00000_10100_10000_0111010011_0, "mtibat2u r0"),
+    (0b011111_00000_10101_10000_0111010011_0, "mtibat2l r0"),
+    (0b011111_00000_10110_10000_0111010011_0, "mtibat3u r0"),
+    (0b011111_00000_10111_10000_0111010011_0, "mtibat3l r0"),
+    (0b011111_00000_11000_10000_0111010011_0, "mtdbat0u r0"),
+    (0b011111_00000_11001_10000_0111010011_0, "mtdbat0l r0"),
+    (0b011111_00000_11010_10000_0111010011_0, "mtdbat1u r0"),
+    (0b011111_00000_11011_10000_0111010011_0, "mtdbat1l r0"),
+    (0b011111_00000_11100_10000_0111010011_0, "mtdbat2u r0"),
+    (0b011111_00000_11101_10000_0111010011_0, "mtdbat2l r0"),
+    (0b011111_00000_11110_10000_0111010011_0, "mtdbat3u r0"),
+    (0b011111_00000_11111_10000_0111010011_0, "mtdbat3l r0"),
+    (0b011111_00000_10000_11100_0111010011_0, "mtgqr0 r0"),
+    (0b011111_00000_10001_11100_0111010011_0, "mtgqr1 r0"),
+    (0b011111_00000_10010_11100_0111010011_0, "mtgqr2 r0"),
+    (0b011111_00000_10011_11100_0111010011_0, "mtgqr3 r0"),
+    (0b011111_00000_10100_11100_0111010011_0, "mtgqr4 r0"),
+    (0b011111_00000_10101_11100_0111010011_0, "mtgqr5 r0"),
+    (0b011111_00000_10110_11100_0111010011_0, "mtgqr6 r0"),
+    (0b011111_00000_10111_11100_0111010011_0, "mtgqr7 r0"),
+    (0b011111_00000_11000_11100_0111010011_0, "mthid2 r0"),
+    (0b011111_00000_11001_11100_0111010011_0, "mtwpar r0"),
+    (0b011111_00000_11010_11100_0111010011_0, "mtdmau r0"),
+    (0b011111_00000_11011_11100_0111010011_0, "mtdmal r0"),
+    (0b011111_00000_11000_11101_0111010011_0, "mtmmcr0 r0"),
+    (0b011111_00000_11001_11101_0111010011_0, "mtpmc1 r0"),
+    (0b011111_00000_11010_11101_0111010011_0, "mtpmc2 r0"),
+    (0b011111_00000_11011_11101_0111010011_0, "mtsia r0"),
+    (0b011111_00000_11100_11101_0111010011_0, "mtmmcr1 r0"),
+    (0b011111_00000_11101_11101_0111010011_0, "mtpmc3 r0"),
+    (0b011111_00000_11110_11101_0111010011_0, "mtpmc4 r0"),
+    (0b011111_00000_11111_11101_0111010011_0, "mtsda r0"),
+    (0b011111_00000_10000_11111_0111010011_0, "mthid0 r0"),
+    (0b011111_00000_10001_11111_0111010011_0, "mthid1 r0"),
+    (0b011111_00000_10010_11111_0111010011_0, "mtiabr r0"),
+    (0b011111_00000_10101_11111_0111010011_0, "mtdabr r0"),
+    (0b011111_00000_11001_11111_0111010011_0, "mtl2cr r0"),
+    (0b011111_00000_11011_11111_0111010011_0, "mtictc r0"),
+    (0b011111_00000_11100_11111_0111010011_0, "mtthrm1 r0"),
+    (0b011111_00000_11101_11111_0111010011_0, "mtthrm2 r0"),
+    (0b011111_00000_11110_11111_0111010011_0, "mtthrm3 r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_0111010011_1, "FAIL"),
+    // - register S
+    (0b011111_11111_00001_00000_0111010011_0, "mtxer r31"),
+    // - spr operand, illegal value
+    (0b011111_00000_00000_00000_0111010011_0, "FAIL"),
+    (0b011111_00000_00001_00001_0111010011_0, "FAIL"),
+    // - spr operand, read-only register
+    (0b011111_00000_11111_01000_0111010011_0, "FAIL"),
+    //
+    // === DCBI ===
+    // Opcode 31
+    // Extended opcode 470
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0111010110_0, "dcbi 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_0111010110_1, "FAIL"),
+    // - reserved bits 6..10
+    (0b011111_00001_00000_00000_0111010110_0, "FAIL"),
+    (0b011111_00010_00000_00000_0111010110_0, "FAIL"),
+    (0b011111_00100_00000_00000_0111010110_0, "FAIL"),
+    (0b011111_01000_00000_00000_0111010110_0, "FAIL"),
+    (0b011111_10000_00000_00000_0111010110_0, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_0111010110_0, "dcbi 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0111010110_0, "dcbi r31, r0"),
+    // - all ones
+    (0b011111_00000_11111_11111_0111010110_0, "dcbi r31, r31"),
+    //
+    // === NAND ===
+    // Opcode 31
+    // Extended opcode 476
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0111011100_0, "nand r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0111011100_1, "nand. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0111011100_0, "nand r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0111011100_0, "nand r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_0111011100_0, "nand r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_0111011100_1,
+        "nand. r31, r31, r31",
+    ),
+    //
+    // === DIVW ===
+    // Opcode 31
+    // Extended opcode 491
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_0_111101011_0, "divw r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_0_111101011_1, "divw. r0, r0, r0"),
+    // - OE operand
+    (0b011111_00000_00000_00000_1_111101011_0, "divwo r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_0_111101011_0, "divw r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_0_111101011_0, "divw r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_0_111101011_0, "divw r31, r0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1_111101011_1,
+        "divwo. r31, r31, r31",
+    ),
+    //
+    // === MCRXR ===
+    // Opcode 31
+    // Extended opcode 512
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1000000000_0, "mcrxr cr0"),
+    // - destination field
+    (0b011111_11100_00000_00000_1000000000_0, "mcrxr cr7"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1000000000_1, "FAIL"),
+    // - reserved bits 9..=10
+    (0b011111_00001_00000_00000_1000000000_0, "FAIL"),
+    // - reserved bits 11..=15
+    (0b011111_00000_00001_00000_1000000000_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b011111_00000_00000_00001_1000000000_0, "FAIL"),
+    //
+    // === LWBRX ===
+    // Opcode 31
+    // Extended opcode 534
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1000010110_0, "lwbrx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1000010110_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1000010110_0, "lwbrx r0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1000010110_0, "lwbrx r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_1000010110_0, "lwbrx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1000010110_0,
+        "lwbrx r31, r31, r31",
+    ),
+    //
+    // === LFSX ===
+    // Opcode 31
+    // Extended opcode 535
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1000010111_0, "lfsx f0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1000010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1000010111_0, "lfsx f0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1000010111_0, "lfsx f0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_1000010111_0, "lfsx f31, 0, r0"),
+    //
+    // === SRW ===
+    // Opcode 31
+    // Extended opcode 536
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1000011000_0, "srw r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_1000011000_1, "srw. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_1000011000_0, "srw r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1000011000_0, "srw r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_1000011000_0, "srw r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1000011000_1,
+        "srw. r31, r31, r31",
+    ),
+    //
+    // === LFSUX ===
+    // Opcode 31
+    // Extended opcode 567
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_1000110111_0, "lfsux f0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_1000110111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00001_11111_1000110111_0, "lfsux f0, r1, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1000110111_0, "lfsux f0, r31, r0"),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_1000110111_0, "FAIL"),
+    // - register D
+    (0b011111_11111_00001_00000_1000110111_0, "lfsux f31, r1, r0"),
+    //
+    // === MFSR ===
+    // Opcode 31
+    // Extended opcode 595
+    //
+    // - all zeros
+    (0b011111_00000_0_0000_00000_1001010011_0, "mfsr r0, sr0"),
+    // - register D
+    (0b011111_11111_0_0000_00000_1001010011_0, "mfsr r31, sr0"),
+    // - segment register
+    (0b011111_00000_0_1111_00000_1001010011_0, "mfsr r0, sr15"),
+    // - reserved bit 31
+    (0b011111_00000_0_0000_00000_1001010011_1, "FAIL"),
+    // - reserved bit 11
+    (0b011111_00000_1_0000_00000_1001010011_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b011111_00000_0_0000_00001_1001010011_0, "FAIL"),
+    //
+    // === SYNC ===
+    // Opcode 31
+    // Extended opcode 598
+    //
+    // - canonical form
+    (0b011111_00000_00000_00000_1001010110_0, "sync"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1001010110_1, "FAIL"),
+    // - reserved bits 6..=20
+    (0b011111_00001_00000_00000_1001010110_0, "FAIL"),
+    (0b011111_00000_00001_00000_1001010110_0, "FAIL"),
+    (0b011111_00000_00000_00001_1001010110_0, "FAIL"),
+    //
+    // === LFDX ===
+    // Opcode 31
+    // Extended opcode 599
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1001010111_0, "lfdx f0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1001010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1001010111_0, "lfdx f0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1001010111_0, "lfdx f0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_1001010111_0, "lfdx f31, 0, r0"),
+    //
+    // === LFDUX ===
+    // Opcode 31
+    // Extended opcode 631
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_1001110111_0, "lfdux f0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_1001110111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00001_11111_1001110111_0, "lfdux f0, r1, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1001110111_0, "lfdux f0, r31, r0"),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_1001110111_0, "FAIL"),
+    // - register D
+    (0b011111_11111_00001_00000_1001110111_0, "lfdux f31, r1, r0"),
+    //
+    // === MFSRIN ===
+    // Opcode 31
+    // Extended opcode 659
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1010010011_0, "mfsrin r0, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_1010010011_0, "mfsrin r31, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_1010010011_0, "mfsrin r0, r31"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1010010011_1, "FAIL"),
+    // - reserved bits 11..=15
+    (0b011111_00000_00001_00000_1010010011_0, "FAIL"),
+    //
+    // === STWBRX ===
+    // Opcode 31
+    // Extended opcode 662
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1010010110_0, "stwbrx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1010010110_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1010010110_0, "stwbrx r0, 0, r31"),
+    // - register A
+    (
+        0b011111_00000_11111_00000_1010010110_0,
+        "stwbrx r0, r31, r0",
+    ),
+    // - register S
+    (0b011111_11111_00000_00000_1010010110_0, "stwbrx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1010010110_0,
+        "stwbrx r31, r31, r31",
+    ),
+    //
+    // === STFSX ===
+    // Opcode 31
+    // Extended opcode 663
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1010010111_0, "stfsx f0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1010010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1010010111_0, "stfsx f0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1010010111_0, "stfsx f0, r31, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_1010010111_0, "stfsx f31, 0, r0"),
+    //
+    // === STFSUX ===
+    // Opcode 31
+    // Extended opcode 695
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_1010110111_0, "stfsux f0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_1010110111_1, "FAIL"),
+    // - register B
+    (
+        0b011111_00000_00001_11111_1010110111_0,
+        "stfsux f0, r1, r31",
+    ),
+    // - register A
+    (
+        0b011111_00000_11111_00000_1010110111_0,
+        "stfsux f0, r31, r0",
+    ),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_1010110111_0, "FAIL"),
+    // - register S
+    (
+        0b011111_11111_00001_00000_1010110111_0,
+        "stfsux f31, r1, r0",
+    ),
+    //
+    // === STFDX ===
+    // Opcode 31
+    // Extended opcode 727
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1011010111_0, "stfdx f0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1011010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1011010111_0, "stfdx f0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1011010111_0, "stfdx f0, r31, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_1011010111_0, "stfdx f31, 0, r0"),
+    //
+    // === STFDUX ===
+    // Opcode 31
+    // Extended opcode 759
+    //
+    // - all zeros
+    (0b011111_00000_00001_00000_1011110111_0, "stfdux f0, r1, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00001_00000_1011110111_1, "FAIL"),
+    // - register B
+    (
+        0b011111_00000_00001_11111_1011110111_0,
+        "stfdux f0, r1, r31",
+    ),
+    // - register A
+    (
+        0b011111_00000_11111_00000_1011110111_0,
+        "stfdux f0, r31, r0",
+    ),
+    // - register A, illegal value
+    (0b011111_00000_00000_00000_1011110111_0, "FAIL"),
+    // - register S
+    (
+        0b011111_11111_00001_00000_1011110111_0,
+        "stfdux f31, r1, r0",
+    ),
+    //
+    // === LHBRX ===
+    // Opcode 31
+    // Extended opcode 790
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1100010110_0, "lhbrx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1100010110_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1100010110_0, "lhbrx r0, 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1100010110_0, "lhbrx r0, r31, r0"),
+    // - register D
+    (0b011111_11111_00000_00000_1100010110_0, "lhbrx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1100010110_0,
+        "lhbrx r31, r31, r31",
+    ),
+    //
+    // === SRAW ===
+    // Opcode 31
+    // Extended opcode 792
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1100011000_0, "sraw r0, r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_1100011000_1, "sraw. r0, r0, r0"),
+    // - register B
+    (0b011111_00000_00000_11111_1100011000_0, "sraw r0, r0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1100011000_0, "sraw r31, r0, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_1100011000_0, "sraw r0, r31, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1100011000_1,
+        "sraw. r31, r31, r31",
+    ),
+    //
+    // === SRAWI ===
+    // Opcode 31
+    // Extended opcode 824
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1100111000_0, "srawi r0, r0, 0"),
+    // - record bit
+    (0b011111_00000_00000_00000_1100111000_1, "srawi. r0, r0, 0"),
+    // - shift amount
+    (0b011111_00000_00000_11111_1100111000_0, "srawi r0, r0, 31"),
+    // - register A
+    (0b011111_00000_11111_00000_1100111000_0, "srawi r31, r0, 0"),
+    // - register S
+    (0b011111_11111_00000_00000_1100111000_0, "srawi r0, r31, 0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1100111000_1,
+        "srawi. r31, r31, 31",
+    ),
+    //
+    // === EIEIO ===
+    // Opcode 31
+    // Extended opcode 854
+    //
+    // - canonical form
+    (0b011111_00000_00000_00000_1101010110_0, "eieio"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1101010110_1, "FAIL"),
+    // - reserved bits 6..=20
+    (0b011111_00001_00000_00000_1101010110_0, "FAIL"),
+    (0b011111_00000_00001_00000_1101010110_0, "FAIL"),
+    (0b011111_00000_00000_00001_1101010110_0, "FAIL"),
+    //
+    // === STHBRX ===
+    // Opcode 31
+    // Extended opcode 918
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1110010110_0, "sthbrx r0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1110010110_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1110010110_0, "sthbrx r0, 0, r31"),
+    // - register A
+    (
+        0b011111_00000_11111_00000_1110010110_0,
+        "sthbrx r0, r31, r0",
+    ),
+    // - register S
+    (0b011111_11111_00000_00000_1110010110_0, "sthbrx r31, 0, r0"),
+    // - all ones
+    (
+        0b011111_11111_11111_11111_1110010110_0,
+        "sthbrx r31, r31, r31",
+    ),
+    //
+    // === EXTSH ===
+    // Opcode 31
+    // Extended opcode 922
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1110011010_0, "extsh r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_1110011010_1, "extsh. r0, r0"),
+    // - reserved bits 16..20
+    (0b011111_00000_00000_00001_1110011010_0, "FAIL"),
+    (0b011111_00000_00000_00010_1110011010_0, "FAIL"),
+    (0b011111_00000_00000_00100_1110011010_0, "FAIL"),
+    (0b011111_00000_00000_01000_1110011010_0, "FAIL"),
+    (0b011111_00000_00000_10000_1110011010_0, "FAIL"),
+    // - register A
+    (0b011111_00000_11111_00000_1110011010_0, "extsh r31, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_1110011010_0, "extsh r0, r31"),
+    // - all ones
+    (0b011111_11111_11111_00000_1110011010_1, "extsh. r31, r31"),
+    //
+    // === EXTSB ===
+    // Opcode 31
+    // Extended opcode 954
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1110111010_0, "extsb r0, r0"),
+    // - record bit
+    (0b011111_00000_00000_00000_1110111010_1, "extsb. r0, r0"),
+    // - reserved bits 16..20
+    (0b011111_00000_00000_00001_1110111010_0, "FAIL"),
+    (0b011111_00000_00000_00010_1110111010_0, "FAIL"),
+    (0b011111_00000_00000_00100_1110111010_0, "FAIL"),
+    (0b011111_00000_00000_01000_1110111010_0, "FAIL"),
+    (0b011111_00000_00000_10000_1110111010_0, "FAIL"),
+    // - register A
+    (0b011111_00000_11111_00000_1110111010_0, "extsb r31, r0"),
+    // - register S
+    (0b011111_11111_00000_00000_1110111010_0, "extsb r0, r31"),
+    // - all ones
+    (0b011111_11111_11111_00000_1110111010_1, "extsb. r31, r31"),
+    //
+    // === ICBI ===
+    // Opcode 31
+    // Extended opcode 982
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1111010110_0, "icbi 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1111010110_1, "FAIL"),
+    // - reserved bits 6..10
+    (0b011111_00001_00000_00000_1111010110_0, "FAIL"),
+    (0b011111_00010_00000_00000_1111010110_0, "FAIL"),
+    (0b011111_00100_00000_00000_1111010110_0, "FAIL"),
+    (0b011111_01000_00000_00000_1111010110_0, "FAIL"),
+    (0b011111_10000_00000_00000_1111010110_0, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1111010110_0, "icbi 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1111010110_0, "icbi r31, r0"),
+    // - all ones
+    (0b011111_00000_11111_11111_1111010110_0, "icbi r31, r31"),
+    //
+    // === STFIWX ===
+    // Opcode 31
+    // Extended opcode 983
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1111010111_0, "stfiwx f0, 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1111010111_1, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1111010111_0, "stfiwx f0, 0, r31"),
+    // - register A
+    (
+        0b011111_00000_11111_00000_1111010111_0,
+        "stfiwx f0, r31, r0",
+    ),
+    // - register S
+    (0b011111_11111_00000_00000_1111010111_0, "stfiwx f31, 0, r0"),
+    //
+    // === DCBZ ===
+    // Opcode 31
+    // Extended opcode 1014
+    //
+    // - all zeros
+    (0b011111_00000_00000_00000_1111110110_0, "dcbz 0, r0"),
+    // - reserved bit 31
+    (0b011111_00000_00000_00000_1111110110_1, "FAIL"),
+    // - reserved bits 6..10
+    (0b011111_00001_00000_00000_1111110110_0, "FAIL"),
+    (0b011111_00010_00000_00000_1111110110_0, "FAIL"),
+    (0b011111_00100_00000_00000_1111110110_0, "FAIL"),
+    (0b011111_01000_00000_00000_1111110110_0, "FAIL"),
+    (0b011111_10000_00000_00000_1111110110_0, "FAIL"),
+    // - register B
+    (0b011111_00000_00000_11111_1111110110_0, "dcbz 0, r31"),
+    // - register A
+    (0b011111_00000_11111_00000_1111110110_0, "dcbz r31, r0"),
+    // - all ones
+    (0b011111_00000_11111_11111_1111110110_0, "dcbz r31, r31"),
+    //
+    // === LWZ ===
+    // Opcode 32
+    //
+    // - all zeros
+    (0b100000_00000_00000_0000000000000000, "lwz r0, (0)"),
+    // - signed immediate
+    (0b100000_00000_00000_1111111111111111, "lwz r0, -1(0)"),
+    // - register A
+    (0b100000_00000_11111_0000000000000000, "lwz r0, (r31)"),
+    // - register D
+    (0b100000_11111_00000_0000000000000000, "lwz r31, (0)"),
+    // - all ones
+    (0b100000_11111_11111_1111111111111111, "lwz r31, -1(r31)"),
+    //
+    // === LWZU ===
+    // Opcode 33
+    //
+    // - all zeros
+    (0b100001_00000_00001_0000000000000000, "lwzu r0, (r1)"),
+    // - signed immediate
+    (0b100001_00000_00001_1111111111111111, "lwzu r0, -1(r1)"),
+    // - register A
+    (0b100001_00000_11111_0000000000000000, "lwzu r0, (r31)"),
+    // - register A, illegal value
+    (0b100001_00000_00000_0000000000000000, "FAIL"),
+    // - register A equal to register D
+    (0b100001_00101_00101_0000000000000000, "FAIL"),
+    // - register D
+    (0b100001_11111_00001_0000000000000000, "lwzu r31, (r1)"),
+    // - use every feature
+    (0b100001_11111_11110_1111111111111111, "lwzu r31, -1(r30)"),
+    //
+    // === LBZ ===
+    // Opcode 34
+    //
+    // - all zeros
+    (0b100010_00000_00000_0000000000000000, "lbz r0, (0)"),
+    // - signed immediate
+    (0b100010_00000_00000_1111111111111111, "lbz r0, -1(0)"),
+    // - register A
+    (0b100010_00000_11111_0000000000000000, "lbz r0, (r31)"),
+    // - register D
+    (0b100010_11111_00000_0000000000000000, "lbz r31, (0)"),
+    // - all ones
+    (0b100010_11111_11111_1111111111111111, "lbz r31, -1(r31)"),
+    //
+    // === LBZU ===
+    // Opcode 35
+    //
+    // - all zeros
+    (0b100011_00000_00001_0000000000000000, "lbzu r0, (r1)"),
+    // - signed immediate
+    (0b100011_00000_00001_1111111111111111, "lbzu r0, -1(r1)"),
+    // - register A
+    (0b100011_00000_11111_0000000000000000, "lbzu r0, (r31)"),
+    // - register A, illegal value
+    (0b100011_00000_00000_0000000000000000, "FAIL"),
+    // - register A equal to register D
+    (0b100011_00101_00101_0000000000000000, "FAIL"),
+    // - register D
+    (0b100011_11111_00001_0000000000000000, "lbzu r31, (r1)"),
+    // - use every feature
+    (0b100011_11111_11110_1111111111111111, "lbzu r31, -1(r30)"),
+    //
+    // === STW ===
+    // Opcode 36
+    //
+    // - all zeros
+    (0b100100_00000_00000_0000000000000000, "stw r0, (0)"),
+    // - signed immediate
+    (0b100100_00000_00000_1111111111111111, "stw r0, -1(0)"),
+    // - register A
+    (0b100100_00000_11111_0000000000000000, "stw r0, (r31)"),
+    // - register S
+    (0b100100_11111_00000_0000000000000000, "stw r31, (0)"),
+    // - all ones
+    (0b100100_11111_11111_1111111111111111, "stw r31, -1(r31)"),
+    //
+    // === STWU ===
+    // Opcode 37
+    //
+    // - all zeros
+    (0b100101_00000_00001_0000000000000000, "stwu r0, (r1)"),
+    // - signed immediate
+    (0b100101_00000_00001_1111111111111111, "stwu r0, -1(r1)"),
+    // - register A
+    (0b100101_00000_11111_0000000000000000, "stwu r0, (r31)"),
+    // - register A, illegal value
+    (0b100101_00000_00000_0000000000000000, "FAIL"),
+    // - register S
+    (0b100101_11111_00001_0000000000000000, "stwu r31, (r1)"),
+    // - all ones
+    (0b100101_11111_11111_1111111111111111, "stwu r31, -1(r31)"),
+    //
+    // === STB ===
+    // Opcode 38
+    //
+    // - all zeros
+    (0b100110_00000_00000_0000000000000000, "stb r0, (0)"),
+    // - signed immediate
+    (0b100110_00000_00000_1111111111111111, "stb r0, -1(0)"),
+    // - register A
+    (0b100110_00000_11111_0000000000000000, "stb r0, (r31)"),
+    // - register S
+    (0b100110_11111_00000_0000000000000000, "stb r31, (0)"),
+    // - all ones
+    (0b100110_11111_11111_1111111111111111, "stb r31, -1(r31)"),
+    //
+    // === STBU ===
+    // Opcode 39
+    //
+    // - all zeros
+    (0b100111_00000_00001_0000000000000000, "stbu r0, (r1)"),
+    // - signed immediate
+    (0b100111_00000_00001_1111111111111111, "stbu r0, -1(r1)"),
+    // - register A
+    (0b100111_00000_11111_0000000000000000, "stbu r0, (r31)"),
+    // - register A, illegal value
+    (0b100111_00000_00000_0000000000000000, "FAIL"),
+    // - register S
+    (0b100111_11111_00001_0000000000000000, "stbu r31, (r1)"),
+    // - use every feature
+    (0b100111_11111_11110_1111111111111111, "stbu r31, -1(r30)"),
+    //
+    // === LHZ ===
+    // Opcode 40
+    //
+    // - all zeros
+    (0b101000_00000_00000_0000000000000000, "lhz r0, (0)"),
+    // - signed immediate
+    (0b101000_00000_00000_1111111111111111, "lhz r0, -1(0)"),
+    // - register A
+    (0b101000_00000_11111_0000000000000000, "lhz r0, (r31)"),
+    // - register D
+    (0b101000_11111_00000_0000000000000000, "lhz r31, (0)"),
+    // - all ones
+    (0b101000_11111_11111_1111111111111111, "lhz r31, -1(r31)"),
+    //
+    // === LHZU ===
+    // Opcode 41
+    //
+    // - all zeros
+    (0b101001_00000_00001_0000000000000000, "lhzu r0, (r1)"),
+    // - signed immediate
+    (0b101001_00000_00001_1111111111111111, "lhzu r0, -1(r1)"),
+    // - register A
+    (0b101001_00000_11111_0000000000000000, "lhzu r0, (r31)"),
+    // - register A, illegal value
+    (0b101001_00000_00000_0000000000000000, "FAIL"),
+    // - register A equal to register D
+    (0b101001_00101_00101_0000000000000000, "FAIL"),
+    // - register D
+    (0b101001_11111_00001_0000000000000000, "lhzu r31, (r1)"),
+    // - use every feature
+    (0b101001_11111_11110_1111111111111111, "lhzu r31, -1(r30)"),
+    //
+    // === LHA ===
+    // Opcode 42
+    //
+    // - all zeros
+    (0b101010_00000_00000_0000000000000000, "lha r0, (0)"),
+    // - signed immediate
+    (0b101010_00000_00000_1111111111111111, "lha r0, -1(0)"),
+    // - register A
+    (0b101010_00000_11111_0000000000000000, "lha r0, (r31)"),
+    // - register D
+    (0b101010_11111_00000_0000000000000000, "lha r31, (0)"),
+    // - all ones
+    (0b101010_11111_11111_1111111111111111, "lha r31, -1(r31)"),
+    //
+    // === LHAU ===
+    // Opcode 43
+    //
+    // - all zeros
+    (0b101011_00000_00001_0000000000000000, "lhau r0, (r1)"),
+    // - signed immediate
+    (0b101011_00000_00001_1111111111111111, "lhau r0, -1(r1)"),
+    // - register A
+    (0b101011_00000_11111_0000000000000000, "lhau r0, (r31)"),
+    // - register A, illegal value
+    (0b101011_00000_00000_0000000000000000, "FAIL"),
+    // - register A equal to register D
+    (0b101011_00101_00101_0000000000000000, "FAIL"),
+    // - register D
+    (0b101011_11111_00001_0000000000000000, "lhau r31, (r1)"),
+    // - use every feature
+    (0b101011_11111_11110_1111111111111111, "lhau r31, -1(r30)"),
+    //
+    // === STH ===
+    // Opcode 44
+    //
+    // - all zeros
+    (0b101100_00000_00000_0000000000000000, "sth r0, (0)"),
+    // - signed immediate
+    (0b101100_00000_00000_1111111111111111, "sth r0, -1(0)"),
+    // - register A
+    (0b101100_00000_11111_0000000000000000, "sth r0, (r31)"),
+    // - register S
+    (0b101100_11111_00000_0000000000000000, "sth r31, (0)"),
+    // - all ones
+    (0b101100_11111_11111_1111111111111111, "sth r31, -1(r31)"),
+    //
+    // === STHU ===
+    // Opcode 45
+    //
+    // - all zeros
+    (0b101101_00000_00001_0000000000000000, "sthu r0, (r1)"),
+    // - signed immediate
+    (0b101101_00000_00001_1111111111111111, "sthu r0, -1(r1)"),
+    // - register A
+    (0b101101_00000_11111_0000000000000000, "sthu r0, (r31)"),
+    // - register A, illegal value
+    (0b101101_00000_00000_0000000000000000, "FAIL"),
+    // - register S
+    (0b101101_11111_00001_0000000000000000, "sthu r31, (r1)"),
+    // - use every feature
+    (0b101101_11111_11110_1111111111111111, "sthu r31, -1(r30)"),
+    //
+    // === LMW ===
+    // Opcode 46
+    //
+    // - register D
+    (0b101110_11111_00000_0000000000000000, "lmw r31, (0)"),
+    // - signed immediate
+    (0b101110_11111_00000_1111111111111111, "lmw r31, -1(0)"),
+    // - register A
+    (0b101110_11111_11110_0000000000000000, "lmw r31, (r30)"),
+    // - register A in the range of registers to be loaded
+    (0b101110_00000_00000_0000000000000000, "FAIL"),
+    (0b101110_11110_11110_0000000000000000, "FAIL"),
+    (0b101110_11110_11111_0000000000000000, "FAIL"),
+    // - typical use
+    (0b101110_01110_00001_0000000000001000, "lmw r14, 8(r1)"),
+    //
+    // === STMW ===
+    // Opcode 47
+    //
+    // - all zeros
+    (0b101111_00000_00000_0000000000000000, "stmw r0, (0)"),
+    // - signed immediate
+    (0b101111_00000_00000_1111111111111111, "stmw r0, -1(0)"),
+    // - register A
+    (0b101111_00000_11111_0000000000000000, "stmw r0, (r31)"),
+    // - register S
+    (0b101111_11111_00000_0000000000000000, "stmw r31, (0)"),
+    // - all ones
+    (0b101111_11111_11111_1111111111111111, "stmw r31, -1(r31)"),
+    //
+    // === LFS ===
+    // Opcode 48
+    //
+    // - all zeros
+    (0b110000_00000_00000_0000000000000000, "lfs f0, (0)"),
+    // - signed immediate
+    (0b110000_00000_00000_1111111111111111, "lfs f0, -1(0)"),
+    // - register A
+    (0b110000_00000_11111_0000000000000000, "lfs f0, (r31)"),
+    // - register D
+    (0b110000_11111_00000_0000000000000000, "lfs f31, (0)"),
+    // - all ones
+    (0b110000_11111_11111_1111111111111111, "lfs f31, -1(r31)"),
+    //
+    // === LFSU ===
+    // Opcode 49
+    //
+    // - all zeros
+    (0b110001_00000_00001_0000000000000000, "lfsu f0, (r1)"),
+    // - signed immediate
+    (0b110001_00000_00001_1111111111111111, "lfsu f0, -1(r1)"),
+    // - register A
+    (0b110001_00000_11111_0000000000000000, "lfsu f0, (r31)"),
+    // - register A, illegal value
+    (0b110001_00000_00000_0000000000000000, "FAIL"),
+    // - register A may equal register D
+    (0b110001_00101_00101_0000000000000000, "lfsu f5, (r5)"),
+    // - register D
+    (0b110001_11111_00001_0000000000000000, "lfsu f31, (r1)"),
+    // - all ones
+    (0b110001_11111_11111_1111111111111111, "lfsu f31, -1(r31)"),
+    //
+    // === LFD ===
+    // Opcode 50
+    //
+    // - all zeros
+    (0b110010_00000_00000_0000000000000000, "lfd f0, (0)"),
+    // - signed immediate
+    (0b110010_00000_00000_1111111111111111, "lfd f0, -1(0)"),
+    // - register A
+    (0b110010_00000_11111_0000000000000000, "lfd f0, (r31)"),
+    // - register D
+    (0b110010_11111_00000_0000000000000000, "lfd f31, (0)"),
+    // - all ones
+    (0b110010_11111_11111_1111111111111111, "lfd f31, -1(r31)"),
+    //
+    // === LFDU ===
+    // Opcode 51
+    //
+    // - all zeros
+    (0b110011_00000_00001_0000000000000000, "lfdu f0, (r1)"),
+    // - signed immediate
+    (0b110011_00000_00001_1111111111111111, "lfdu f0, -1(r1)"),
+    // - register A
+    (0b110011_00000_11111_0000000000000000, "lfdu f0, (r31)"),
+    // - register A, illegal value
+    (0b110011_00000_00000_0000000000000000, "FAIL"),
+    // - register A may equal register D
+    (0b110011_00101_00101_0000000000000000, "lfdu f5, (r5)"),
+    // - register D
+    (0b110011_11111_00001_0000000000000000, "lfdu f31, (r1)"),
+    // - all ones
+    (0b110011_11111_11111_1111111111111111, "lfdu f31, -1(r31)"),
+    //
+    // === STFS ===
+    // Opcode 52
+    //
+    // - all zeros
+    (0b110100_00000_00000_0000000000000000, "stfs f0, (0)"),
+    // - signed immediate
+    (0b110100_00000_00000_1111111111111111, "stfs f0, -1(0)"),
+    // - register A
+    (0b110100_00000_11111_0000000000000000, "stfs f0, (r31)"),
+    // - register S
+    (0b110100_11111_00000_0000000000000000, "stfs f31, (0)"),
+    // - all ones
+    (0b110100_11111_11111_1111111111111111, "stfs f31, -1(r31)"),
+    //
+    // === STFSU ===
+    // Opcode 53
+    //
+    // - all zeros
+    (0b110101_00000_00001_0000000000000000, "stfsu f0, (r1)"),
+    // - signed immediate
+    (0b110101_00000_00001_1111111111111111, "stfsu f0, -1(r1)"),
+    // - register A
+    (0b110101_00000_11111_0000000000000000, "stfsu f0, (r31)"),
+    // - register A, illegal value
+    (0b110101_00000_00000_0000000000000000, "FAIL"),
+    // - register A may equal register S
+    (0b110101_00101_00101_0000000000000000, "stfsu f5, (r5)"),
+    // - register S
+    (0b110101_11111_00001_0000000000000000, "stfsu f31, (r1)"),
+    // - all ones
+    (0b110101_11111_11111_1111111111111111, "stfsu f31, -1(r31)"),
+    //
+    // === STFD ===
+    // Opcode 54
+    //
+    // - all zeros
+    (0b110110_00000_00000_0000000000000000, "stfd f0, (0)"),
+    // - signed immediate
+    (0b110110_00000_00000_1111111111111111, "stfd f0, -1(0)"),
+    // - register A
+    (0b110110_00000_11111_0000000000000000, "stfd f0, (r31)"),
+    // - register S
+    (0b110110_11111_00000_0000000000000000, "stfd f31, (0)"),
+    // - all ones
+    (0b110110_11111_11111_1111111111111111, "stfd f31, -1(r31)"),
+    //
+    // === STFDU ===
+    // Opcode 55
+    //
+    // - all zeros
+    (0b110111_00000_00001_0000000000000000, "stfdu f0, (r1)"),
+    // - signed immediate
+    (0b110111_00000_00001_1111111111111111, "stfdu f0, -1(r1)"),
+    // - register A
+    (0b110111_00000_11111_0000000000000000, "stfdu f0, (r31)"),
+    // - register A, illegal value
+    (0b110111_00000_00000_0000000000000000, "FAIL"),
+    // - register A may equal register S
+    (0b110111_00101_00101_0000000000000000, "stfdu f5, (r5)"),
+    // - register S
+    (0b110111_11111_00001_0000000000000000, "stfdu f31, (r1)"),
+    // - all ones
+    (0b110111_11111_11111_1111111111111111, "stfdu f31, -1(r31)"),
+    //
+    // === PSQ_L ===
+    // Opcode 56
+    //
+    // - all zeros
+    (
+        0b111000_00000_00000_0_000_000000000000,
+        "psq_l f0, (0), 0, qr0",
+    ),
+    // - signed immediate
+    (
+        0b111000_00000_00000_0_000_111111111111,
+        "psq_l f0, -1(0), 0, qr0",
+    ),
+    // - largest immediate
+    (
+        0b111000_00000_00000_0_000_011111111111,
+        "psq_l f0, 0x7ff(0), 0, qr0",
+    ),
+    // - smallest immediate
+    (
+        0b111000_00000_00000_0_000_100000000000,
+        "psq_l f0, -0x800(0), 0, qr0",
+    ),
+    // - register A
+    (
+        0b111000_00000_11111_0_000_000000000000,
+        "psq_l f0, (r31), 0, qr0",
+    ),
+    // - register D
+    (
+        0b111000_11111_00000_0_000_000000000000,
+        "psq_l f31, (0), 0, qr0",
+    ),
+    // - W field
+    (
+        0b111000_00000_00000_1_000_000000000000,
+        "psq_l f0, (0), 1, qr0",
+    ),
+    // - I field
+    (
+        0b111000_00000_00000_0_111_000000000000,
+        "psq_l f0, (0), 0, qr7",
+    ),
+    //
+    // === PSQ_LU ===
+    // Opcode 57
+    //
+    // - all zeros
+    (
+        0b111001_00000_00001_0_000_000000000000,
+        "psq_lu f0, (r1), 0, qr0",
+    ),
+    // - signed immediate
+    (
+        0b111001_00000_00001_0_000_111111111111,
+        "psq_lu f0, -1(r1), 0, qr0",
+    ),
+    // - largest immediate
+    (
+        0b111001_00000_00001_0_000_011111111111,
+        "psq_lu f0, 0x7ff(r1), 0, qr0",
+    ),
+    // - smallest immediate
+    (
+        0b111001_00000_00001_0_000_100000000000,
+        "psq_lu f0, -0x800(r1), 0, qr0",
+    ),
+    // - register A
+    (
+        0b111001_00000_11111_0_000_000000000000,
+        "psq_lu f0, (r31), 0, qr0",
+    ),
+    // - register A, illegal value
+    (0b111001_00000_00000_0_000_000000000000, "FAIL"),
+    // - register D
+    (
+        0b111001_11111_00001_0_000_000000000000,
+        "psq_lu f31, (r1), 0, qr0",
+    ),
+    // - W field
+    (
+        0b111001_00000_00001_1_000_000000000000,
+        "psq_lu f0, (r1), 1, qr0",
+    ),
+    // - I field
+    (
+        0b111001_00000_00001_0_111_000000000000,
+        "psq_lu f0, (r1), 0, qr7",
+    ),
+    //
+    // === FDIVS ===
+    // Opcode 59
+    // Extended opcode 18
+    //
+    // - all zeros
+    (0b111011_00000_00000_00000_00000_10010_0, "fdivs f0, f0, f0"),
+    // - record bit
+    (
+        0b111011_00000_00000_00000_00000_10010_1,
+        "fdivs. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b111011_00000_00000_11111_00000_10010_0,
+        "fdivs f0, f0, f31",
+    ),
+    // - reserved register C
+    (0b111011_00000_00000_00000_00001_10010_0, "FAIL"),
+    // - register A
+    (
+        0b111011_00000_11111_00000_00000_10010_0,
+        "fdivs f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b111011_11111_00000_00000_00000_10010_0,
+        "fdivs f31, f0, f0",
+    ),
+    //
+    // === FSUBS ===
+    // Opcode 59
+    // Extended opcode 20
+    //
+    // - all zeros
+    (0b111011_00000_00000_00000_00000_10100_0, "fsubs f0, f0, f0"),
+    // - record bit
+    (
+        0b111011_00000_00000_00000_00000_10100_1,
+        "fsubs. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b111011_00000_00000_11111_00000_10100_0,
+        "fsubs f0, f0, f31",
+    ),
+    // - reserved register C
+    (0b111011_00000_00000_00000_00001_10100_0, "FAIL"),
+    // - register A
+    (
+        0b111011_00000_11111_00000_00000_10100_0,
+        "fsubs f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b111011_11111_00000_00000_00000_10100_0,
+        "fsubs f31, f0, f0",
+    ),
+    //
+    // === FADDS ===
+    // Opcode 59
+    // Extended opcode 21
+    //
+    // - all zeros
+    (0b111011_00000_00000_00000_00000_10101_0, "fadds f0, f0, f0"),
+    // - record bit
+    (
+        0b111011_00000_00000_00000_00000_10101_1,
+        "fadds. f0, f0, f0",
+    ),
+    // - register B
+    (
+        0b111011_00000_00000_11111_00000_10101_0,
+        "fadds f0, f0, f31",
+    ),
+    // - reserved register C
+    (0b111011_00000_00000_00000_00001_10101_0, "FAIL"),
+    // - register A
+    (
+        0b111011_00000_11111_00000_00000_10101_0,
+        "fadds f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b111011_11111_00000_00000_00000_10101_0,
+        "fadds f31, f0, f0",
+    ),
+    //
+    // === FRES ===
+    // Opcode 59
+    // Extended opcode 24
+    //
+    // - all zeros
+    (0b111011_00000_00000_00000_00000_11000_0, "fres f0, f0"),
+    // - record bit
+    (0b111011_00000_00000_00000_00000_11000_1, "fres. f0, f0"),
+    // - register B
+    (0b111011_00000_00000_11111_00000_11000_0, "fres f0, f31"),
+    // - reserved register C
+    (0b111011_00000_00000_00000_00001_11000_0, "FAIL"),
+    // - reserved register A
+    (0b111011_00000_00001_00000_00000_11000_0, "FAIL"),
+    // - register D
+    (0b111011_11111_00000_00000_00000_11000_0, "fres f31, f0"),
+    //
+    // === FMULS ===
+    // Opcode 59
+    // Extended opcode 25
+    //
+    // - all zeros
+    (0b111011_00000_00000_00000_00000_11001_0, "fmuls f0, f0, f0"),
+    // - record bit
+    (
+        0b111011_00000_00000_00000_00000_11001_1,
+        "fmuls. f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b111011_00000_00000_00000_11111_11001_0,
+        "fmuls f0, f0, f31",
+    ),
+    // - reserved register B
+    (0b111011_00000_00000_00001_00000_11001_0, "FAIL"),
+    // - register A
+    (
+        0b111011_00000_11111_00000_00000_11001_0,
+        "fmuls f0, f31, f0",
+    ),
+    // - register D
+    (
+        0b111011_11111_00000_00000_00000_11001_0,
+        "fmuls f31, f0, f0",
+    ),
+    //
+    // === FMSUBS ===
+    // Opcode 59
+    // Extended opcode 28
+    //
+    // - all zeros
+    (
+        0b111011_00000_00000_00000_00000_11100_0,
+        "fmsubs f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b111011_00000_00000_00000_00000_11100_1,
+        "fmsubs. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b111011_00000_00000_00000_11111_11100_0,
+        "fmsubs f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b111011_00000_00000_11111_00000_11100_0,
+        "fmsubs f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b111011_00000_11111_00000_00000_11100_0,
+        "fmsubs f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b111011_11111_00000_00000_00000_11100_0,
+        "fmsubs f31, f0, f0, f0",
+    ),
+    //
+    // === FMADDS ===
+    // Opcode 59
+    // Extended opcode 29
+    //
+    // - all zeros
+    (
+        0b111011_00000_00000_00000_00000_11101_0,
+        "fmadds f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b111011_00000_00000_00000_00000_11101_1,
+        "fmadds. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b111011_00000_00000_00000_11111_11101_0,
+        "fmadds f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b111011_00000_00000_11111_00000_11101_0,
+        "fmadds f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b111011_00000_11111_00000_00000_11101_0,
+        "fmadds f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b111011_11111_00000_00000_00000_11101_0,
+        "fmadds f31, f0, f0, f0",
+    ),
+    //
+    // === FNMSUBS ===
+    // Opcode 59
+    // Extended opcode 30
+    //
+    // - all zeros
+    (
+        0b111011_00000_00000_00000_00000_11110_0,
+        "fnmsubs f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b111011_00000_00000_00000_00000_11110_1,
+        "fnmsubs. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b111011_00000_00000_00000_11111_11110_0,
+        "fnmsubs f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b111011_00000_00000_11111_00000_11110_0,
+        "fnmsubs f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b111011_00000_11111_00000_00000_11110_0,
+        "fnmsubs f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b111011_11111_00000_00000_00000_11110_0,
+        "fnmsubs f31, f0, f0, f0",
+    ),
+    //
+    // === FNMADDS ===
+    // Opcode 59
+    // Extended opcode 31
+    //
+    // - all zeros
+    (
+        0b111011_00000_00000_00000_00000_11111_0,
+        "fnmadds f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b111011_00000_00000_00000_00000_11111_1,
+        "fnmadds. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b111011_00000_00000_00000_11111_11111_0,
+        "fnmadds f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b111011_00000_00000_11111_00000_11111_0,
+        "fnmadds f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b111011_00000_11111_00000_00000_11111_0,
+        "fnmadds f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b111011_11111_00000_00000_00000_11111_0,
+        "fnmadds f31, f0, f0, f0",
+    ),
+    //
+    // === PSQ_ST ===
+    // Opcode 60
     //
     // - all zeros
-    (0b001010_000_0_0_00000_0000000000000000, "cmplwi r0, 0"),
-    // - unsigned immediate
-    (0b001010_000_0_0_00000_1111111111111111, "cmplwi r0, 0xffff"),
+    (
+        0b111100_00000_00000_0_000_000000000000,
+        "psq_st f0, (0), 0, qr0",
+    ),
+    // - signed immediate
+    (
+        0b111100_00000_00000_0_000_111111111111,
+        "psq_st f0, -1(0), 0, qr0",
+    ),
+    // - largest immediate
+    (
+        0b111100_00000_00000_0_000_011111111111,
+        "psq_st f0, 0x7ff(0), 0, qr0",
+    ),
+    // - smallest immediate
+    (
+        0b111100_00000_00000_0_000_100000000000,
+        "psq_st f0, -0x800(0), 0, qr0",
+    ),
     // - register A
-    (0b001010_000_0_0_11111_0000000000000000, "cmplwi r31, 0"),
-    // - L flag
-    (0b001010_000_0_1_00000_0000000000000000, "FAIL"),
-    // - reserved bit 9
-    (0b001010_000_1_0_00000_0000000000000000, "FAIL"),
-    // - condition register field D
-    (0b001010_111_0_0_00000_0000000000000000, "cmplwi cr7, r0, 0"),
-    // - all ones
     (
-        0b001010_111_0_0_11111_1111111111111111,
-        "cmplwi cr7, r31, 0xffff",
+        0b111100_00000_11111_0_000_000000000000,
+        "psq_st f0, (r31), 0, qr0",
+    ),
+    // - register S
+    (
+        0b111100_11111_00000_0_000_000000000000,
+        "psq_st f31, (0), 0, qr0",
+    ),
+    // - W field
+    (
+        0b111100_00000_00000_1_000_000000000000,
+        "psq_st f0, (0), 1, qr0",
+    ),
+    // - I field
+    (
+        0b111100_00000_00000_0_111_000000000000,
+        "psq_st f0, (0), 0, qr7",
     ),
     //
-    // === CMPI (CMPWI) ===
-    // Opcode 11
+    // === PSQ_STU ===
+    // Opcode 61
     //
     // - all zeros
-    (0b001011_000_0_0_00000_0000000000000000, "cmpwi r0, 0"),
+    (
+        0b111101_00000_00001_0_000_000000000000,
+        "psq_stu f0, (r1), 0, qr0",
+    ),
     // - signed immediate
-    (0b001011_000_0_0_00000_1111111111111111, "cmpwi r0, -1"),
+    (
+        0b111101_00000_00001_0_000_111111111111,
+        "psq_stu f0, -1(r1), 0, qr0",
+    ),
+    // - largest immediate
+    (
+        0b111101_00000_00001_0_000_011111111111,
+        "psq_stu f0, 0x7ff(r1), 0, qr0",
+    ),
+    // - smallest immediate
+    (
+        0b111101_00000_00001_0_000_100000000000,
+        "psq_stu f0, -0x800(r1), 0, qr0",
+    ),
     // - register A
-    (0b001011_000_0_0_11111_0000000000000000, "cmpwi r31, 0"),
-    // - L flag
-    (0b001011_000_0_1_00000_0000000000000000, "FAIL"),
-    // - reserved bit 9
-    (0b001011_000_1_0_00000_0000000000000000, "FAIL"),
-    // - condition register field D
-    (0b001011_111_0_0_00000_0000000000000000, "cmpwi cr7, r0, 0"),
-    // - all ones
     (
-        0b001011_111_0_0_11111_1111111111111111,
-        "cmpwi cr7, r31, -1",
+        0b111101_00000_11111_0_000_000000000000,
+        "psq_stu f0, (r31), 0, qr0",
+    ),
+    // - register A, illegal value
+    (0b111101_00000_00000_0_000_000000000000, "FAIL"),
+    // - register S
+    (
+        0b111101_11111_00001_0_000_000000000000,
+        "psq_stu f31, (r1), 0, qr0",
+    ),
+    // - W field
+    (
+        0b111101_00000_00001_1_000_000000000000,
+        "psq_stu f0, (r1), 1, qr0",
+    ),
+    // - I field
+    (
+        0b111101_00000_00001_0_111_000000000000,
+        "psq_stu f0, (r1), 0, qr7",
     ),
     //
-    // === ADDI (LI) ===
-    // Opcode 14
+    // === FCMPU ===
+    // Opcode 63
+    // Extended opcode 0
     //
     // - all zeros
-    (0b001110_00000_00000_0000000000000000, "li r0, 0"),
-    // - signed immediate
-    (0b001110_00000_00000_1111111111111111, "li r0, -1"),
+    (0b111111_00000_00000_00000_0000000000_0, "fcmpu cr0, f0, f0"),
+    // - reserved bit 31
+    (0b111111_00000_00000_00000_0000000000_1, "FAIL"),
+    // - reserved bits 9..=10
+    (0b111111_00001_00000_00000_0000000000_0, "FAIL"),
+    // - register B
+    (
+        0b111111_00000_00000_11111_0000000000_0,
+        "fcmpu cr0, f0, f31",
+    ),
     // - register A
-    (0b001110_00000_11111_0000000000000000, "addi r0, r31, 0"),
+    (
+        0b111111_00000_11111_00000_0000000000_0,
+        "fcmpu cr0, f31, f0",
+    ),
+    // - condition register field
+    (0b111111_11100_00000_00000_0000000000_0, "fcmpu cr7, f0, f0"),
+    //
+    // === FRSP ===
+    // Opcode 63
+    // Extended opcode 12
+    //
+    // - all zeros
+    (0b111111_00000_00000_00000_0000001100_0, "frsp f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_0000001100_1, "frsp. f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_0000001100_0, "frsp f0, f31"),
+    // - reserved register A
+    (0b111111_00000_00001_00000_0000001100_0, "FAIL"),
     // - register D
-    (0b001110_11111_00000_0000000000000000, "li r31, 0"),
-    // - all ones
-    (0b001110_11111_11111_1111111111111111, "addi r31, r31, -1"),
+    (0b111111_11111_00000_00000_0000001100_0, "frsp f31, f0"),
     //
-    // === ADDIS ===
-    // Opcode 15
+    // === FCTIW ===
+    // Opcode 63
+    // Extended opcode 14
     //
     // - all zeros
-    (0b001111_00000_00000_0000000000000000, "addis r0, 0, 0"),
-    // - signed immediate
-    (0b001111_00000_00000_1111111111111111, "addis r0, 0, -1"),
+    (0b111111_00000_00000_00000_0000001110_0, "fctiw f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_0000001110_1, "fctiw. f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_0000001110_0, "fctiw f0, f31"),
+    // - reserved register A
+    (0b111111_00000_00001_00000_0000001110_0, "FAIL"),
+    // - register D
+    (0b111111_11111_00000_00000_0000001110_0, "fctiw f31, f0"),
+    //
+    // === FCTIWZ ===
+    // Opcode 63
+    // Extended opcode 15
+    //
+    // - all zeros
+    (0b111111_00000_00000_00000_0000001111_0, "fctiwz f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_0000001111_1, "fctiwz. f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_0000001111_0, "fctiwz f0, f31"),
+    // - reserved register A
+    (0b111111_00000_00001_00000_0000001111_0, "FAIL"),
+    // - register D
+    (0b111111_11111_00000_00000_0000001111_0, "fctiwz f31, f0"),
+    //
+    // === FDIV ===
+    // Opcode 63
+    // Extended opcode 18
+    //
+    // - all zeros
+    (0b111111_00000_00000_00000_00000_10010_0, "fdiv f0, f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_00000_10010_1, "fdiv. f0, f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_00000_10010_0, "fdiv f0, f0, f31"),
+    // - reserved register C
+    (0b111111_00000_00000_00000_00001_10010_0, "FAIL"),
     // - register A
-    (0b001111_00000_11111_0000000000000000, "addis r0, r31, 0"),
+    (0b111111_00000_11111_00000_00000_10010_0, "fdiv f0, f31, f0"),
     // - register D
-    (0b001111_11111_00000_0000000000000000, "addis r31, 0, 0"),
-    // - all ones
-    (0b001111_11111_11111_1111111111111111, "addis r31, r31, -1"),
+    (0b111111_11111_00000_00000_00000_10010_0, "fdiv f31, f0, f0"),
     //
-    // === BCx ===
-    // Opcode 16
+    // === FSUB ===
+    // Opcode 63
+    // Extended opcode 20
+    //
+    // - all zeros
+    (0b111111_00000_00000_00000_00000_10100_0, "fsub f0, f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_00000_10100_1, "fsub. f0, f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_00000_10100_0, "fsub f0, f0, f31"),
+    // - reserved register C
+    (0b111111_00000_00000_00000_00001_10100_0, "FAIL"),
+    // - register A
+    (0b111111_00000_11111_00000_00000_10100_0, "fsub f0, f31, f0"),
+    // - register D
+    (0b111111_11111_00000_00000_00000_10100_0, "fsub f31, f0, f0"),
+    //
+    // === FADD ===
+    // Opcode 63
+    // Extended opcode 21
+    //
+    // - all zeros
+    (0b111111_00000_00000_00000_00000_10101_0, "fadd f0, f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_00000_10101_1, "fadd. f0, f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_00000_10101_0, "fadd f0, f0, f31"),
+    // - reserved register C
+    (0b111111_00000_00000_00000_00001_10101_0, "FAIL"),
+    // - register A
+    (0b111111_00000_11111_00000_00000_10101_0, "fadd f0, f31, f0"),
+    // - register D
+    (0b111111_11111_00000_00000_00000_10101_0, "fadd f31, f0, f0"),
+    //
+    // === FSEL ===
+    // Opcode 63
+    // Extended opcode 23
     //
     // - all zeros
-    (0b010000_00000_00000_00000000000000_0_0, "bdnznl 0x08000000"),
-    // - link flag
     (
-        0b010000_00000_00000_00000000000000_0_1,
-        "bdnznll 0x08000000",
+        0b111111_00000_00000_00000_00000_10111_0,
+        "fsel f0, f0, f0, f0",
     ),
-    // - absolute flag
+    // - record bit
     (
-        0b010000_00000_00000_00000000000000_1_0,
-        "bdnznla 0x00000000",
+        0b111111_00000_00000_00000_00000_10111_1,
+        "fsel. f0, f0, f0, f0",
     ),
-    // - target address
-    (0b010000_00000_00000_11111111111111_0_0, "bdnznl 0x07fffffc"),
-    // - BI operand, condition
-    (0b010000_00000_00011_00000000000000_0_0, "bdnzns 0x08000000"),
-    // - BI operand, condition register field
+    // - register C
     (
-        0b010000_00000_11100_00000000000000_0_0,
-        "bdnznl cr7, 0x08000000",
+        0b111111_00000_00000_00000_11111_10111_0,
+        "fsel f0, f0, f31, f0",
     ),
-    // - BO operand
-    (0b010000_11111_00000_00000000000000_0_0, "b 0x08000000"),
-    // - all ones
-    (0b010000_11111_11111_11111111111111_1_1, "bla 0xfffffffc"),
-    // - all eight simple conditional branches
-    (0b010000_01100_00000_00010000000000_0_0, "blt 0x08001000"),
-    (0b010000_01100_00001_00010000000000_0_0, "bgt 0x08001000"),
-    (0b010000_01100_00010_00010000000000_0_0, "beq 0x08001000"),
-    (0b010000_01100_00011_00010000000000_0_0, "bso 0x08001000"),
-    (0b010000_00100_00000_00010000000000_0_0, "bnl 0x08001000"),
-    (0b010000_00100_00001_00010000000000_0_0, "bng 0x08001000"),
-    (0b010000_00100_00010_00010000000000_0_0, "bne 0x08001000"),
-    (0b010000_00100_00011_00010000000000_0_0, "bns 0x08001000"),
-    // - use every feature
+    // - register B
     (
-        0b010000_01000_10110_00010010001101_1_1,
-        "bdnzeqla cr5, 0x00001234",
+        0b111111_00000_00000_11111_00000_10111_0,
+        "fsel f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b111111_00000_11111_00000_00000_10111_0,
+        "fsel f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b111111_11111_00000_00000_00000_10111_0,
+        "fsel f31, f0, f0, f0",
     ),
     //
-    // === Bx ===
-    // Opcode 18
+    // === FMUL ===
+    // Opcode 63
+    // Extended opcode 25
     //
     // - all zeros
-    (0b010010_000000000000000000000000_0_0, "b 0x08000000"),
-    // - link flag
-    (0b010010_000000000000000000000000_0_1, "bl 0x08000000"),
-    // - absolute flag
-    (0b010010_000000000000000000000000_1_0, "ba 0x00000000"),
-    // - target address
-    (0b010010_111111111111111111111111_0_0, "b 0x07fffffc"),
-    // - all ones
-    (0b010010_111111111111111111111111_1_1, "bla 0xfffffffc"),
+    (0b111111_00000_00000_00000_00000_11001_0, "fmul f0, f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_00000_11001_1, "fmul. f0, f0, f0"),
+    // - register C
+    (0b111111_00000_00000_00000_11111_11001_0, "fmul f0, f0, f31"),
+    // - reserved register B
+    (0b111111_00000_00000_00001_00000_11001_0, "FAIL"),
+    // - register A
+    (0b111111_00000_11111_00000_00000_11001_0, "fmul f0, f31, f0"),
+    // - register D
+    (0b111111_11111_00000_00000_00000_11001_0, "fmul f31, f0, f0"),
     //
-    // === BCLRx ===
-    // Opcode 19
-    // Extended opcode 16
+    // === FRSQRTE ===
+    // Opcode 63
+    // Extended opcode 26
     //
     // - all zeros
-    (0b010011_00000_00000_00000_0000010000_0, "bdnznllr"),
-    // - link flag
-    (0b010011_00000_00000_00000_0000010000_1, "bdnznllrl"),
-    // reserved bits 16..=20
-    (0b010011_00000_00000_00001_0000010000_1, "FAIL"),
-    (0b010011_00000_00000_00010_0000010000_1, "FAIL"),
-    (0b010011_00000_00000_00100_0000010000_1, "FAIL"),
-    (0b010011_00000_00000_01000_0000010000_1, "FAIL"),
-    (0b010011_00000_00000_10000_0000010000_1, "FAIL"),
-    // - BI operand, condition
-    (0b010011_00000_00011_00000_0000010000_0, "bdnznslr"),
-    // - BI operand, condition register field
-    (0b010011_00000_11100_00000_0000010000_0, "bdnznllr cr7"),
-    // - BO operand
-    (0b010011_11111_00000_00000_0000010000_0, "blr"),
-    // - all ones
-    (0b010011_11111_11111_00000_0000010000_1, "blrl"),
-    // - all eight simple conditional branches
-    (0b010011_01100_00000_00000_0000010000_0, "bltlr"),
-    (0b010011_01100_00001_00000_0000010000_0, "bgtlr"),
-    (0b010011_01100_00010_00000_0000010000_0, "beqlr"),
-    (0b010011_01100_00011_00000_0000010000_0, "bsolr"),
-    (0b010011_00100_00000_00000_0000010000_0, "bnllr"),
-    (0b010011_00100_00001_00000_0000010000_0, "bnglr"),
-    (0b010011_00100_00010_00000_0000010000_0, "bnelr"),
-    (0b010011_00100_00011_00000_0000010000_0, "bnslr"),
-    // - use every feature
-    (0b010011_01000_10110_00000_0000010000_1, "bdnzeqlrl cr5"),
+    (0b111111_00000_00000_00000_00000_11010_0, "frsqrte f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_00000_11010_1, "frsqrte. f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_00000_11010_0, "frsqrte f0, f31"),
+    // - reserved register C
+    (0b111111_00000_00000_00000_00001_11010_0, "FAIL"),
+    // - reserved register A
+    (0b111111_00000_00001_00000_00000_11010_0, "FAIL"),
+    // - register D
+    (0b111111_11111_00000_00000_00000_11010_0, "frsqrte f31, f0"),
     //
-    // === CRXOR ===
-    // Opcode 19
-    // Extended opcode 193
+    // === FMSUB ===
+    // Opcode 63
+    // Extended opcode 28
     //
     // - all zeros
-    (0b010011_00000_00000_00000_0011000001_0, "crxor lt, lt, lt"),
-    // - reserved bit 31
-    (0b010011_00000_00000_00000_0011000001_1, "FAIL"),
-    // - crbB operand
     (
-        0b010011_00000_00000_11111_0011000001_0,
-        "crxor lt, lt, cr7*4+so",
+        0b111111_00000_00000_00000_00000_11100_0,
+        "fmsub f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b111111_00000_00000_00000_00000_11100_1,
+        "fmsub. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b111111_00000_00000_00000_11111_11100_0,
+        "fmsub f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b111111_00000_00000_11111_00000_11100_0,
+        "fmsub f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b111111_00000_11111_00000_00000_11100_0,
+        "fmsub f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b111111_11111_00000_00000_00000_11100_0,
+        "fmsub f31, f0, f0, f0",
+    ),
+    //
+    // === FMADD ===
+    // Opcode 63
+    // Extended opcode 29
+    //
+    // - all zeros
+    (
+        0b111111_00000_00000_00000_00000_11101_0,
+        "fmadd f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b111111_00000_00000_00000_00000_11101_1,
+        "fmadd. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b111111_00000_00000_00000_11111_11101_0,
+        "fmadd f0, f0, f31, f0",
+    ),
+    // - register B
+    (
+        0b111111_00000_00000_11111_00000_11101_0,
+        "fmadd f0, f0, f0, f31",
+    ),
+    // - register A
+    (
+        0b111111_00000_11111_00000_00000_11101_0,
+        "fmadd f0, f31, f0, f0",
+    ),
+    // - register D
+    (
+        0b111111_11111_00000_00000_00000_11101_0,
+        "fmadd f31, f0, f0, f0",
+    ),
+    //
+    // === FNMSUB ===
+    // Opcode 63
+    // Extended opcode 30
+    //
+    // - all zeros
+    (
+        0b111111_00000_00000_00000_00000_11110_0,
+        "fnmsub f0, f0, f0, f0",
+    ),
+    // - record bit
+    (
+        0b111111_00000_00000_00000_00000_11110_1,
+        "fnmsub. f0, f0, f0, f0",
+    ),
+    // - register C
+    (
+        0b111111_00000_00000_00000_11111_11110_0,
+        "fnmsub f0, f0, f31, f0",
     ),
-    // - crbA operand
+    // - register B
     (
-        0b010011_00000_11111_00000_0011000001_0,
-        "crxor lt, cr7*4+so, lt",
+        0b111111_00000_00000_11111_00000_11110_0,
+        "fnmsub f0, f0, f0, f31",
     ),
-    // - crbD operand
+    // - register A
     (
-        0b010011_11111_00000_00000_0011000001_0,
-        "crxor cr7*4+so, lt, lt",
+        0b111111_00000_11111_00000_00000_11110_0,
+        "fnmsub f0, f31, f0, f0",
     ),
-    // - all ones
+    // - register D
     (
-        0b010011_11111_11111_11111_0011000001_0,
-        "crxor cr7*4+so, cr7*4+so, cr7*4+so",
+        0b111111_11111_00000_00000_00000_11110_0,
+        "fnmsub f31, f0, f0, f0",
     ),
     //
-    // === RLWINM ===
-    // Opcode 21
+    // === FNMADD ===
+    // Opcode 63
+    // Extended opcode 31
     //
     // - all zeros
     (
-        0b010101_00000_00000_00000_00000_00000_0,
-        "rlwinm r0, r0, 0, 0, 0",
+        0b111111_00000_00000_00000_00000_11111_0,
+        "fnmadd f0, f0, f0, f0",
     ),
     // - record bit
     (
-        0b010101_00000_00000_00000_00000_00000_1,
-        "rlwinm. r0, r0, 0, 0, 0",
-    ),
-    // - ME operand
-    (
-        0b010101_00000_00000_00000_00000_11111_0,
-        "rlwinm r0, r0, 0, 0, 31",
-    ),
-    // - MB operand
-    (
-        0b010101_00000_00000_00000_11111_00000_0,
-        "rlwinm r0, r0, 0, 31, 0",
+        0b111111_00000_00000_00000_00000_11111_1,
+        "fnmadd. f0, f0, f0, f0",
     ),
-    // - SH operand
+    // - register C
     (
-        0b010101_00000_00000_11111_00000_00000_0,
-        "rlwinm r0, r0, 31, 0, 0",
+        0b111111_00000_00000_00000_11111_11111_0,
+        "fnmadd f0, f0, f31, f0",
     ),
-    // - A register
+    // - register B
     (
-        0b010101_00000_11111_00000_00000_00000_0,
-        "rlwinm r31, r0, 0, 0, 0",
+        0b111111_00000_00000_11111_00000_11111_0,
+        "fnmadd f0, f0, f0, f31",
     ),
-    // - S register
+    // - register A
     (
-        0b010101_11111_00000_00000_00000_00000_0,
-        "rlwinm r0, r31, 0, 0, 0",
+        0b111111_00000_11111_00000_00000_11111_0,
+        "fnmadd f0, f31, f0, f0",
     ),
-    // - all ones
+    // - register D
     (
-        0b010101_11111_11111_11111_11111_11111_1,
-        "rlwinm. r31, r31, 31, 31, 31",
+        0b111111_11111_00000_00000_00000_11111_0,
+        "fnmadd f31, f0, f0, f0",
     ),
     //
-    // === CMPL (CMPLW) ===
-    // Opcode 31
+    // === FCMPO ===
+    // Opcode 63
     // Extended opcode 32
     //
     // - all zeros
-    (0b011111_000_0_0_00000_00000_0000100000_0, "cmplw r0, r0"),
+    (0b111111_00000_00000_00000_0000100000_0, "fcmpo cr0, f0, f0"),
     // - reserved bit 31
-    (0b011111_000_0_0_00000_00000_0000100000_1, "FAIL"),
+    (0b111111_00000_00000_00000_0000100000_1, "FAIL"),
+    // - reserved bits 9..=10
+    (0b111111_00001_00000_00000_0000100000_0, "FAIL"),
     // - register B
-    (0b011111_000_0_0_00000_11111_0000100000_0, "cmplw r0, r31"),
-    // - register A
-    (0b011111_000_0_0_11111_00000_0000100000_0, "cmplw r31, r0"),
-    // - L flag
-    (0b011111_000_0_1_00000_00000_0000100000_0, "FAIL"),
-    // - reserved bit 9
-    (0b011111_000_1_0_00000_00000_0000100000_0, "FAIL"),
-    // - condition register field D
     (
-        0b011111_111_0_0_00000_00000_0000100000_0,
-        "cmplw cr7, r0, r0",
+        0b111111_00000_00000_11111_0000100000_0,
+        "fcmpo cr0, f0, f31",
     ),
-    // - all ones
+    // - register A
     (
-        0b011111_111_0_0_11111_11111_0000100000_0,
-        "cmplw cr7, r31, r31",
+        0b111111_00000_11111_00000_0000100000_0,
+        "fcmpo cr0, f31, f0",
     ),
+    // - condition register field
+    (0b111111_11100_00000_00000_0000100000_0, "fcmpo cr7, f0, f0"),
     //
-    // === ADDZE ===
-    // Opcode 31
-    // Extended opcode 202
+    // === MTFSB1 ===
+    // Opcode 63
+    // Extended opcode 38
     //
     // - all zeros
-    (0b011111_00000_00000_00000_0_011001010_0, "addze r0, r0"),
+    (0b111111_00000_00000_00000_0000100110_0, "mtfsb1 0"),
     // - record bit
-    (0b011111_00000_00000_00000_0_011001010_1, "addze. r0, r0"),
-    // - OE operand
-    (0b011111_00000_00000_00000_1_011001010_0, "addzeo r0, r0"),
-    // - reserved bits 16..20
-    (0b011111_00000_00000_00001_0_011001010_0, "FAIL"),
-    (0b011111_00000_00000_00010_0_011001010_0, "FAIL"),
-    (0b011111_00000_00000_00100_0_011001010_0, "FAIL"),
-    (0b011111_00000_00000_01000_0_011001010_0, "FAIL"),
-    (0b011111_00000_00000_10000_0_011001010_0, "FAIL"),
-    // - register A
-    (0b011111_00000_11111_00000_0_011001010_0, "addze r0, r31"),
-    // - register D
-    (0b011111_11111_00000_00000_0_011001010_0, "addze r31, r0"),
-    // - all ones
-    (0b011111_11111_11111_00000_1_011001010_1, "addzeo. r31, r31"),
-    //
-    // === MFSPR ===
-    // Opcode 31
-    // Extended opcode 339
-    //
-    // - all variants with r0
-    (0b011111_00000_00001_00000_0101010011_0, "mfxer r0"),
-    (0b011111_00000_01000_00000_0101010011_0, "mflr r0"),
-    (0b011111_00000_01001_00000_0101010011_0, "mfctr r0"),
-    (0b011111_00000_10001_11100_0101010011_0, "mfgqr1 r0"),
-    (0b011111_00000_10010_11100_0101010011_0, "mfgqr2 r0"),
-    (0b011111_00000_10011_11100_0101010011_0, "mfgqr3 r0"),
-    (0b011111_00000_10100_11100_0101010011_0, "mfgqr4 r0"),
-    (0b011111_00000_10101_11100_0101010011_0, "mfgqr5 r0"),
-    (0b011111_00000_10110_11100_0101010011_0, "mfgqr6 r0"),
-    (0b011111_00000_10111_11100_0101010011_0, "mfgqr7 r0"),
-    // - reserved bit 31
-    (0b011111_00000_00001_00000_0101010011_1, "FAIL"),
-    // - register D
-    (0b011111_11111_00001_00000_0101010011_0, "mfxer r31"),
-    // - spr operand, illegal value
-    (0b011111_00000_00000_00000_0101010011_0, "FAIL"),
-    (0b011111_00000_00001_00001_0101010011_0, "FAIL"),
+    (0b111111_00000_00000_00000_0000100110_1, "mtfsb1. 0"),
+    // - FPSCR bit
+    (0b111111_11111_00000_00000_0000100110_0, "mtfsb1 31"),
+    // - reserved bits 11..=15
+    (0b111111_00000_00001_00000_0000100110_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b111111_00000_00000_00001_0000100110_0, "FAIL"),
     //
-    // === OR (MR) ===
-    // Opcode 31
-    // Extended opcode 444
+    // === FNEG ===
+    // Opcode 63
+    // Extended opcode 40
     //
     // - all zeros
-    (0b011111_00000_00000_00000_0110111100_0, "mr r0, r0"),
+    (0b111111_00000_00000_00000_0000101000_0, "fneg f0, f0"),
     // - record bit
-    (0b011111_00000_00000_00000_0110111100_1, "mr. r0, r0"),
+    (0b111111_00000_00000_00000_0000101000_1, "fneg. f0, f0"),
     // - register B
-    (0b011111_00000_00000_11111_0110111100_0, "or r0, r0, r31"),
-    // - register A
-    (0b011111_00000_11111_00000_0110111100_0, "mr r31, r0"),
-    // - register S
-    (0b011111_11111_00000_00000_0110111100_0, "or r0, r31, r0"),
-    // - use every feature, S = B
-    (0b011111_00010_00001_00010_0110111100_1, "mr. r1, r2"),
-    // - use every feature, S != B
-    (0b011111_00010_00001_00011_0110111100_1, "or. r1, r2, r3"),
+    (0b111111_00000_00000_11111_0000101000_0, "fneg f0, f31"),
+    // - reserved register A
+    (0b111111_00000_00001_00000_0000101000_0, "FAIL"),
+    // - register D
+    (0b111111_11111_00000_00000_0000101000_0, "fneg f31, f0"),
     //
-    // === MTSPR ===
-    // Opcode 31
-    // Extended opcode 467
+    // === MCRFS ===
+    // Opcode 63
+    // Extended opcode 64
     //
-    // - all variants with r0
-    (0b011111_00000_00001_00000_0111010011_0, "mtxer r0"),
-    (0b011111_00000_01000_00000_0111010011_0, "mtlr r0"),
-    (0b011111_00000_01001_00000_0111010011_0, "mtctr r0"),
+    // - all zeros
+    (0b111111_00000_00000_00000_0001000000_0, "mcrfs cr0, cr0"),
+    // - destination field
+    (0b111111_11100_00000_00000_0001000000_0, "mcrfs cr7, cr0"),
+    // - source field
+    (0b111111_00000_11100_00000_0001000000_0, "mcrfs cr0, cr7"),
     // - reserved bit 31
-    (0b011111_00000_00001_00000_0111010011_1, "FAIL"),
-    // - register D
-    (0b011111_11111_00001_00000_0111010011_0, "mtxer r31"),
-    // - spr operand, illegal value
-    (0b011111_00000_00000_00000_0111010011_0, "FAIL"),
-    (0b011111_00000_00001_00001_0111010011_0, "FAIL"),
+    (0b111111_00000_00000_00000_0001000000_1, "FAIL"),
+    // - reserved bits 9..=10
+    (0b111111_00001_00000_00000_0001000000_0, "FAIL"),
+    // - reserved bits 14..=15
+    (0b111111_00000_00001_00000_0001000000_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b111111_00000_00000_00001_0001000000_0, "FAIL"),
     //
-    // === SRAWI ===
-    // Opcode 31
-    // Extended opcode 824
+    // === MTFSB0 ===
+    // Opcode 63
+    // Extended opcode 70
     //
     // - all zeros
-    (0b011111_00000_00000_00000_1100111000_0, "srawi r0, r0, 0"),
+    (0b111111_00000_00000_00000_0001000110_0, "mtfsb0 0"),
     // - record bit
-    (0b011111_00000_00000_00000_1100111000_1, "srawi. r0, r0, 0"),
-    // - shift amount
-    (0b011111_00000_00000_11111_1100111000_0, "srawi r0, r0, 31"),
-    // - register A
-    (0b011111_00000_11111_00000_1100111000_0, "srawi r31, r0, 0"),
-    // - register S
-    (0b011111_11111_00000_00000_1100111000_0, "srawi r0, r31, 0"),
-    // - all ones
-    (
-        0b011111_11111_11111_11111_1100111000_1,
-        "srawi. r31, r31, 31",
-    ),
+    (0b111111_00000_00000_00000_0001000110_1, "mtfsb0. 0"),
+    // - FPSCR bit
+    (0b111111_11111_00000_00000_0001000110_0, "mtfsb0 31"),
+    // - reserved bits 11..=15
+    (0b111111_00000_00001_00000_0001000110_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b111111_00000_00000_00001_0001000110_0, "FAIL"),
     //
-    // === LWZ ===
-    // Opcode 32
+    // === FMR ===
+    // Opcode 63
+    // Extended opcode 72
     //
     // - all zeros
-    (0b100000_00000_00000_0000000000000000, "lwz r0, (0)"),
-    // - signed immediate
-    (0b100000_00000_00000_1111111111111111, "lwz r0, -1(0)"),
-    // - register A
-    (0b100000_00000_11111_0000000000000000, "lwz r0, (r31)"),
+    (0b111111_00000_00000_00000_0001001000_0, "fmr f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_0001001000_1, "fmr. f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_0001001000_0, "fmr f0, f31"),
+    // - reserved register A
+    (0b111111_00000_00001_00000_0001001000_0, "FAIL"),
     // - register D
-    (0b100000_11111_00000_0000000000000000, "lwz r31, (0)"),
-    // - all ones
-    (0b100000_11111_11111_1111111111111111, "lwz r31, -1(r31)"),
+    (0b111111_11111_00000_00000_0001001000_0, "fmr f31, f0"),
     //
-    // === LBZ ===
-    // Opcode 34
+    // === MTFSFI ===
+    // Opcode 63
+    // Extended opcode 134
     //
     // - all zeros
-    (0b100010_00000_00000_0000000000000000, "lbz r0, (0)"),
-    // - signed immediate
-    (0b100010_00000_00000_1111111111111111, "lbz r0, -1(0)"),
-    // - register A
-    (0b100010_00000_11111_0000000000000000, "lbz r0, (r31)"),
-    // - register D
-    (0b100010_11111_00000_0000000000000000, "lbz r31, (0)"),
-    // - all ones
-    (0b100010_11111_11111_1111111111111111, "lbz r31, -1(r31)"),
+    (0b111111_00000_00000_00000_0010000110_0, "mtfsfi cr0, 0"),
+    // - record bit
+    (0b111111_00000_00000_00000_0010000110_1, "mtfsfi. cr0, 0"),
+    // - field
+    (0b111111_11100_00000_00000_0010000110_0, "mtfsfi cr7, 0"),
+    // - immediate
+    (0b111111_00000_00000_11110_0010000110_0, "mtfsfi cr0, 15"),
+    // - reserved bits 9..=10
+    (0b111111_00001_00000_00000_0010000110_0, "FAIL"),
+    // - reserved bits 11..=15
+    (0b111111_00000_00001_00000_0010000110_0, "FAIL"),
+    // - reserved bit 20
+    (0b111111_00000_00000_00001_0010000110_0, "FAIL"),
     //
-    // === STW ===
-    // Opcode 36
+    // === FNABS ===
+    // Opcode 63
+    // Extended opcode 136
     //
     // - all zeros
-    (0b100100_00000_00000_0000000000000000, "stw r0, (0)"),
-    // - signed immediate
-    (0b100100_00000_00000_1111111111111111, "stw r0, -1(0)"),
-    // - register A
-    (0b100100_00000_11111_0000000000000000, "stw r0, (r31)"),
-    // - register S
-    (0b100100_11111_00000_0000000000000000, "stw r31, (0)"),
-    // - all ones
-    (0b100100_11111_11111_1111111111111111, "stw r31, -1(r31)"),
+    (0b111111_00000_00000_00000_0010001000_0, "fnabs f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_0010001000_1, "fnabs. f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_0010001000_0, "fnabs f0, f31"),
+    // - reserved register A
+    (0b111111_00000_00001_00000_0010001000_0, "FAIL"),
+    // - register D
+    (0b111111_11111_00000_00000_0010001000_0, "fnabs f31, f0"),
     //
-    // === STWU ===
-    // Opcode 37
+    // === FABS ===
+    // Opcode 63
+    // Extended opcode 264
     //
     // - all zeros
-    (0b100101_00000_00001_0000000000000000, "stwu r0, (r1)"),
-    // - signed immediate
-    (0b100101_00000_00001_1111111111111111, "stwu r0, -1(r1)"),
-    // - register A
-    (0b100101_00000_11111_0000000000000000, "stwu r0, (r31)"),
-    // - register A, illegal value
-    (0b100101_00000_00000_0000000000000000, "FAIL"),
-    // - register S
-    (0b100101_11111_00001_0000000000000000, "stwu r31, (r1)"),
-    // - all ones
-    (0b100101_11111_11111_1111111111111111, "stwu r31, -1(r31)"),
+    (0b111111_00000_00000_00000_0100001000_0, "fabs f0, f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_0100001000_1, "fabs. f0, f0"),
+    // - register B
+    (0b111111_00000_00000_11111_0100001000_0, "fabs f0, f31"),
+    // - reserved register A
+    (0b111111_00000_00001_00000_0100001000_0, "FAIL"),
+    // - register D
+    (0b111111_11111_00000_00000_0100001000_0, "fabs f31, f0"),
     //
-    // === LHA ===
-    // Opcode 42
+    // === MFFS ===
+    // Opcode 63
+    // Extended opcode 583
     //
     // - all zeros
-    (0b101010_00000_00000_0000000000000000, "lha r0, (0)"),
-    // - signed immediate
-    (0b101010_00000_00000_1111111111111111, "lha r0, -1(0)"),
-    // - register A
-    (0b101010_00000_11111_0000000000000000, "lha r0, (r31)"),
+    (0b111111_00000_00000_00000_1001000111_0, "mffs f0"),
+    // - record bit
+    (0b111111_00000_00000_00000_1001000111_1, "mffs. f0"),
     // - register D
-    (0b101010_11111_00000_0000000000000000, "lha r31, (0)"),
-    // - all ones
-    (0b101010_11111_11111_1111111111111111, "lha r31, -1(r31)"),
+    (0b111111_11111_00000_00000_1001000111_0, "mffs f31"),
+    // - reserved bits 11..=15
+    (0b111111_00000_00001_00000_1001000111_0, "FAIL"),
+    // - reserved bits 16..=20
+    (0b111111_00000_00000_00001_1001000111_0, "FAIL"),
     //
-    // === STMW ===
-    // Opcode 47
+    // === MTFSF ===
+    // Opcode 63
+    // Extended opcode 711
     //
     // - all zeros
-    (0b101111_00000_00000_0000000000000000, "stmw r0, (0)"),
-    // - signed immediate
-    (0b101111_00000_00000_1111111111111111, "stmw r0, -1(0)"),
-    // - register A
-    (0b101111_00000_11111_0000000000000000, "stmw r0, (r31)"),
-    // - register S
-    (0b101111_11111_00000_0000000000000000, "stmw r31, (0)"),
-    // - all ones
-    (0b101111_11111_11111_1111111111111111, "stmw r31, -1(r31)"),
+    (0b111111_0_00000000_0_00000_1011000111_0, "mtfsf 0, f0"),
+    // - record bit
+    (0b111111_0_00000000_0_00000_1011000111_1, "mtfsf. 0, f0"),
+    // - field mask
+    (0b111111_0_11111111_0_00000_1011000111_0, "mtfsf 0xff, f0"),
+    // - register B
+    (0b111111_0_00000000_0_11111_1011000111_0, "mtfsf 0, f31"),
+    // - reserved bit 6
+    (0b111111_1_00000000_0_00000_1011000111_0, "FAIL"),
+    // - reserved bit 15
+    (0b111111_0_00000000_1_00000_1011000111_0, "FAIL"),
 ];
 
 #[test]
@@ -501,3 +5130,101 @@ fn to_assembly() {
         panic!("one or more table test cases failed");
     }
 }
+
+/// Checks that an instruction survives encoding and that its assembly survives assembling.
+fn check_round_trip(word: u32, address: u32) -> Result<(), String> {
+    let decoded = match EncodedInstruction(word).parse(address) {
+        Ok(decoded) => decoded,
+        Err(_) => return Ok(()),
+    };
+
+    match decoded.encode(address) {
+        Ok(encoded) => match encoded.parse(address) {
+            Ok(reparsed) if reparsed == decoded => {}
+            reparsed => {
+                return Err(format!(
+                    "0b{:032b} encoded as 0b{:032b}, which parses as {:?}",
+                    word, encoded.0, reparsed,
+                ))
+            }
+        },
+        Err(e) => return Err(format!("failed to encode 0b{:032b}: {}", word, e)),
+    }
+
+    let assembly = decoded.to_string();
+    match assemble(&assembly, address) {
+        Ok(assembled) if assembled == decoded => {}
+        Ok(assembled) => {
+            return Err(format!(
+                "{:?} of 0b{:032b} assembled as {:?} ({:?})",
+                assembly,
+                word,
+                assembled.to_string(),
+                assembled,
+            ))
+        }
+        Err(e) => return Err(format!("failed to assemble {:?}: {}", assembly, e)),
+    }
+
+    // The raw syntax names every field, so it assembles back to the same instruction.
+    let raw = Formatter::new(Syntax::Raw).format(decoded);
+    match assemble(&raw, address) {
+        Ok(assembled) if assembled == decoded => Ok(()),
+        assembled => Err(format!(
+            "raw syntax {:?} of 0b{:032b} assembled as {:?}",
+            raw, word, assembled,
+        )),
+    }
+}
+
+#[test]
+fn round_trip() {
+    let mut any_errors = false;
+
+    for row in TABLE {
+        if let Err(message) = check_round_trip(row.0, 0x08000000) {
+            any_errors = true;
+            eprintln!("{}", message);
+        }
+    }
+
+    if any_errors {
+        panic!("one or more table test cases failed to round-trip");
+    }
+}
+
+/// A hint that agrees with the default prediction leaves the y bit clear, as in the GNU assembler.
+#[test]
+fn default_branch_hints() {
+    let encode = |text| {
+        assemble(text, 0x08000000)
+            .unwrap()
+            .encode(0x08000000)
+            .unwrap()
+            .0
+    };
+    assert_eq!(encode("bne+ 0x07fffff0"), encode("bne 0x07fffff0"));
+    assert_eq!(encode("bne- 0x08000010"), encode("bne 0x08000010"));
+    assert_eq!(encode("bnelr-"), encode("bnelr"));
+    assert_ne!(encode("bne+ 0x08000010"), encode("bne 0x08000010"));
+    assert!(assemble("b+ 0x08000010", 0x08000000).is_err());
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10_000))]
+
+    /// Keeps the opcode and extended opcode of a table row, so every implemented instruction is
+    /// reached, and randomizes the remaining fields and the address.
+    #[test]
+    fn round_trip_random_fields(
+        row in 0..TABLE.len(),
+        fields: u32,
+        address in any::<u32>().prop_map(|address| address & !3),
+    ) {
+        let mask = 0xfc0007fe;
+        let word = TABLE[row].0 & mask | fields & !mask;
+        if let Err(message) = check_round_trip(word, address) {
+            return Err(TestCaseError::fail(message));
+        }
+    }
+}
@@ -0,0 +1,668 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{DecodedInstruction, Gpr, GprOrZero, NonZeroGpr, Register};
+
+/// How an instruction accesses memory. See `DecodedInstruction::memory_access`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MemoryAccess {
+    pub kind: AccessKind,
+    /// The size of each register's transfer. Single-precision floats are words and are converted
+    /// to or from double precision in the FPR.
+    pub width: AccessWidth,
+    /// The register loaded or stored. For `lmw` and `stmw`, the first of `count` consecutive GPRs.
+    pub data: Register,
+    pub address: EffectiveAddress,
+    /// Whether a halfword load sign-extends its result rather than zero-extending it.
+    pub sign_extend: bool,
+    /// Whether the bytes are swapped, making the access little-endian.
+    pub byte_reverse: bool,
+    /// Whether the effective address is written back to the base register.
+    pub update: bool,
+    /// The number of consecutive registers transferred, one word each for `lmw` and `stmw`.
+    pub count: u32,
+    /// For the quantized paired-single forms, the GQR whose type decides how each element is
+    /// stored. `width` is then the size of the unquantized floats, which only matches memory when
+    /// the GQR selects the float type.
+    pub gqr: Option<u8>,
+}
+
+impl MemoryAccess {
+    fn load(width: AccessWidth, data: impl Into<Register>, address: EffectiveAddress) -> Self {
+        MemoryAccess {
+            kind: AccessKind::Load,
+            width,
+            data: data.into(),
+            address,
+            sign_extend: false,
+            byte_reverse: false,
+            update: false,
+            count: 1,
+            gqr: None,
+        }
+    }
+
+    fn store(width: AccessWidth, data: impl Into<Register>, address: EffectiveAddress) -> Self {
+        MemoryAccess {
+            kind: AccessKind::Store,
+            ..MemoryAccess::load(width, data, address)
+        }
+    }
+
+    fn update(self) -> Self {
+        MemoryAccess {
+            update: true,
+            ..self
+        }
+    }
+
+    fn sign_extend(self) -> Self {
+        MemoryAccess {
+            sign_extend: true,
+            ..self
+        }
+    }
+
+    fn byte_reverse(self) -> Self {
+        MemoryAccess {
+            byte_reverse: true,
+            ..self
+        }
+    }
+
+    /// Extends the access from the data register through `r31`.
+    fn multiple(self) -> Self {
+        let first = match self.data {
+            Register::GeneralPurpose(gpr) => gpr.as_u32(),
+            _ => unreachable!(),
+        };
+        MemoryAccess {
+            count: 32 - first,
+            ..self
+        }
+    }
+
+    fn quantized(self, gqr: u8) -> Self {
+        MemoryAccess {
+            gqr: Some(gqr),
+            ..self
+        }
+    }
+
+    pub fn is_load(self) -> bool {
+        self.kind == AccessKind::Load
+    }
+
+    pub fn is_store(self) -> bool {
+        self.kind == AccessKind::Store
+    }
+
+    /// The base register that receives the effective address, for update forms.
+    pub fn update_base(self) -> Option<NonZeroGpr> {
+        if self.update {
+            self.address.base().try_unwrap_gpr()
+        } else {
+            None
+        }
+    }
+
+    /// The total number of bytes transferred, unless quantization makes it depend on a GQR.
+    pub fn size(self) -> Option<u32> {
+        match self.gqr {
+            Some(_) => None,
+            None => Some(self.width.bytes() * self.count),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AccessKind {
+    Load,
+    Store,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AccessWidth {
+    Byte,
+    Halfword,
+    Word,
+    Doubleword,
+}
+
+impl AccessWidth {
+    pub fn bytes(self) -> u32 {
+        match self {
+            AccessWidth::Byte => 1,
+            AccessWidth::Halfword => 2,
+            AccessWidth::Word => 4,
+            AccessWidth::Doubleword => 8,
+        }
+    }
+}
+
+impl Display for AccessWidth {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AccessWidth::Byte => write!(f, "b"),
+            AccessWidth::Halfword => write!(f, "h"),
+            AccessWidth::Word => write!(f, "w"),
+            AccessWidth::Doubleword => write!(f, "d"),
+        }
+    }
+}
+
+/// The two ways of forming an effective address.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EffectiveAddress {
+    /// `(base|0) + offset`, from the D-form instructions.
+    Offset { base: GprOrZero, offset: i16 },
+    /// `(base|0) + index`, from the X-form instructions.
+    Indexed { base: GprOrZero, index: Gpr },
+}
+
+impl EffectiveAddress {
+    fn offset(base: impl Into<GprOrZero>, offset: i16) -> Self {
+        EffectiveAddress::Offset {
+            base: base.into(),
+            offset,
+        }
+    }
+
+    fn indexed(base: impl Into<GprOrZero>, index: Gpr) -> Self {
+        EffectiveAddress::Indexed {
+            base: base.into(),
+            index,
+        }
+    }
+
+    pub fn base(self) -> GprOrZero {
+        match self {
+            EffectiveAddress::Offset { base, .. } | EffectiveAddress::Indexed { base, .. } => base,
+        }
+    }
+}
+
+/// The width of one quantized paired-single transfer, before quantization.
+fn paired_single_width(single: bool) -> AccessWidth {
+    if single {
+        AccessWidth::Word
+    } else {
+        AccessWidth::Doubleword
+    }
+}
+
+impl DecodedInstruction {
+    /// Describes the instruction's data access, or `None` if it doesn't load or store. Cache
+    /// block instructions are not data accesses, even though `dcbz` clears memory.
+    pub fn memory_access(self) -> Option<MemoryAccess> {
+        let access = match self {
+            DecodedInstruction::Lbz { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Byte,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Lbzu { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Byte,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Lbzux { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Byte,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Lbzx { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Byte,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::Lfd { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Doubleword,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Lfdu { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Doubleword,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Lfdux { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Doubleword,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Lfdx { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Doubleword,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::Lfs { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Lfsu { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Lfsux { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Lfsx { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::Lha { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Halfword,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .sign_extend(),
+            DecodedInstruction::Lhau { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Halfword,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update()
+            .sign_extend(),
+            DecodedInstruction::Lhaux { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Halfword,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update()
+            .sign_extend(),
+            DecodedInstruction::Lhax { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Halfword,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .sign_extend(),
+            DecodedInstruction::Lhbrx { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Halfword,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .byte_reverse(),
+            DecodedInstruction::Lhz { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Halfword,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Lhzu { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Halfword,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Lhzux { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Halfword,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Lhzx { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Halfword,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::Lmw { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .multiple(),
+            DecodedInstruction::Lwbrx { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .byte_reverse(),
+            DecodedInstruction::Lwz { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Lwzu { dst, offset, base } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Lwzux { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Lwzx { dst, base, index } => MemoryAccess::load(
+                AccessWidth::Word,
+                dst,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::PsqL {
+                dst,
+                offset,
+                base,
+                single,
+                gqr,
+            } => MemoryAccess::load(
+                paired_single_width(single),
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .quantized(gqr),
+            DecodedInstruction::PsqLu {
+                dst,
+                offset,
+                base,
+                single,
+                gqr,
+            } => MemoryAccess::load(
+                paired_single_width(single),
+                dst,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update()
+            .quantized(gqr),
+            DecodedInstruction::PsqLux {
+                dst,
+                base,
+                index,
+                single,
+                gqr,
+            } => MemoryAccess::load(
+                paired_single_width(single),
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update()
+            .quantized(gqr),
+            DecodedInstruction::PsqLx {
+                dst,
+                base,
+                index,
+                single,
+                gqr,
+            } => MemoryAccess::load(
+                paired_single_width(single),
+                dst,
+                EffectiveAddress::indexed(base, index),
+            )
+            .quantized(gqr),
+            DecodedInstruction::PsqSt {
+                src,
+                offset,
+                base,
+                single,
+                gqr,
+            } => MemoryAccess::store(
+                paired_single_width(single),
+                src,
+                EffectiveAddress::offset(base, offset),
+            )
+            .quantized(gqr),
+            DecodedInstruction::PsqStu {
+                src,
+                offset,
+                base,
+                single,
+                gqr,
+            } => MemoryAccess::store(
+                paired_single_width(single),
+                src,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update()
+            .quantized(gqr),
+            DecodedInstruction::PsqStux {
+                src,
+                base,
+                index,
+                single,
+                gqr,
+            } => MemoryAccess::store(
+                paired_single_width(single),
+                src,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update()
+            .quantized(gqr),
+            DecodedInstruction::PsqStx {
+                src,
+                base,
+                index,
+                single,
+                gqr,
+            } => MemoryAccess::store(
+                paired_single_width(single),
+                src,
+                EffectiveAddress::indexed(base, index),
+            )
+            .quantized(gqr),
+            DecodedInstruction::Stb { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Byte,
+                src,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Stbu { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Byte,
+                src,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Stbux { src, base, index } => MemoryAccess::store(
+                AccessWidth::Byte,
+                src,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Stbx { src, base, index } => MemoryAccess::store(
+                AccessWidth::Byte,
+                src,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::Stfd { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Doubleword,
+                src,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Stfdu { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Doubleword,
+                src,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Stfdux { src, base, index } => MemoryAccess::store(
+                AccessWidth::Doubleword,
+                src,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Stfdx { src, base, index } => MemoryAccess::store(
+                AccessWidth::Doubleword,
+                src,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::Stfiwx { src, base, index } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::Stfs { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Stfsu { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Stfsux { src, base, index } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Stfsx { src, base, index } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::Sth { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Halfword,
+                src,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Sthbrx { src, base, index } => MemoryAccess::store(
+                AccessWidth::Halfword,
+                src,
+                EffectiveAddress::indexed(base, index),
+            )
+            .byte_reverse(),
+            DecodedInstruction::Sthu { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Halfword,
+                src,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Sthux { src, base, index } => MemoryAccess::store(
+                AccessWidth::Halfword,
+                src,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Sthx { src, base, index } => MemoryAccess::store(
+                AccessWidth::Halfword,
+                src,
+                EffectiveAddress::indexed(base, index),
+            ),
+            DecodedInstruction::Stmw { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::offset(base, offset),
+            )
+            .multiple(),
+            DecodedInstruction::Stw { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::offset(base, offset),
+            ),
+            DecodedInstruction::Stwbrx { src, base, index } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::indexed(base, index),
+            )
+            .byte_reverse(),
+            DecodedInstruction::Stwu { src, offset, base } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::offset(base, offset),
+            )
+            .update(),
+            DecodedInstruction::Stwux { src, base, index } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::indexed(base, index),
+            )
+            .update(),
+            DecodedInstruction::Stwx { src, base, index } => MemoryAccess::store(
+                AccessWidth::Word,
+                src,
+                EffectiveAddress::indexed(base, index),
+            ),
+            _ => return None,
+        };
+        Some(access)
+    }
+}
+
+#[cfg(test)]
+mod memory_access_tests {
+    use super::{AccessKind, AccessWidth, EffectiveAddress, MemoryAccess};
+    use crate::{assemble, Fpr, Gpr, GprOrZero, Register};
+
+    fn access(assembly: &str) -> Option<MemoryAccess> {
+        assemble(assembly, 0x80003000).unwrap().memory_access()
+    }
+
+    fn gpr(x: u32) -> Gpr {
+        Gpr::new(x).unwrap()
+    }
+
+    #[test]
+    fn offset_load() {
+        let access = access("lha r3, -8(r1)").unwrap();
+        assert_eq!(access.kind, AccessKind::Load);
+        assert_eq!(access.width, AccessWidth::Halfword);
+        assert_eq!(access.data, Register::from(gpr(3)));
+        assert_eq!(
+            access.address,
+            EffectiveAddress::Offset {
+                base: GprOrZero::new(1).unwrap(),
+                offset: -8,
+            }
+        );
+        assert!(access.sign_extend);
+        assert!(!access.update);
+        assert_eq!(access.size(), Some(2));
+    }
+
+    #[test]
+    fn indexed_update_store() {
+        let access = access("stwux r1, r1, r12").unwrap();
+        assert!(access.is_store());
+        assert_eq!(
+            access.address,
+            EffectiveAddress::Indexed {
+                base: GprOrZero::new(1).unwrap(),
+                index: gpr(12),
+            }
+        );
+        assert_eq!(access.update_base().map(|base| base.as_u32()), Some(1));
+    }
+
+    #[test]
+    fn byte_reversed() {
+        let access = access("lwbrx r3, 0, r4").unwrap();
+        assert!(access.byte_reverse);
+        assert_eq!(access.address.base(), GprOrZero::Zero);
+    }
+
+    #[test]
+    fn multiple_words() {
+        let access = access("stmw r27, 0xc(r1)").unwrap();
+        assert_eq!(access.data, Register::from(gpr(27)));
+        assert_eq!(access.count, 5);
+        assert_eq!(access.size(), Some(20));
+    }
+
+    #[test]
+    fn floating_point() {
+        let double = access("lfd f31, 0x18(r1)").unwrap();
+        assert_eq!(double.data, Register::from(Fpr::new(31).unwrap()));
+        assert_eq!(double.width, AccessWidth::Doubleword);
+
+        let quantized = access("psq_st f1, 8(r3), 1, qr5").unwrap();
+        assert_eq!(quantized.gqr, Some(5));
+        assert_eq!(quantized.width, AccessWidth::Word);
+        assert_eq!(quantized.size(), None);
+    }
+
+    #[test]
+    fn not_memory_accesses() {
+        assert_eq!(access("add r3, r4, r5"), None);
+        assert_eq!(access("dcbz 0, r3"), None);
+    }
+}
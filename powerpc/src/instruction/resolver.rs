@@ -0,0 +1,7 @@
+/// Names addresses, so that a `Formatter` can print branch targets and materialized addresses as
+/// symbols.
+pub trait Resolver {
+    /// Returns the name of the symbol containing `address`, along with the offset of `address`
+    /// into it.
+    fn resolve(&self, address: u32) -> Option<(&str, u32)>;
+}
@@ -1,4 +1,5 @@
 pub mod cr_constants;
+pub mod fpr_constants;
 pub mod gpr_constants;
 
 mod branch;
@@ -7,19 +8,36 @@ mod instruction;
 mod register;
 
 pub use branch::bo::Bo;
+pub use branch::branch_hint::BranchHint;
 pub use branch::condition_behavior::ConditionBehavior;
 pub use branch::ctr_behavior::CtrBehavior;
+pub use branch::ignored_branch_bits::IgnoredBranchBits;
 pub use condition::condition::Condition;
 pub use condition::condition_bit::ConditionBit;
 pub use condition::negative_condition::NegativeCondition;
 pub use condition::positive_condition::PositiveCondition;
+pub use instruction::assembler::assemble;
+pub use instruction::assembler::AssembleError;
 pub use instruction::branch_info::BranchInfo;
 pub use instruction::decoded_instruction::DecodedInstruction;
+pub use instruction::decoded_section::DecodedSection;
 pub use instruction::encoded_instruction::EncodedInstruction;
 pub use instruction::encoded_instruction::ParseError;
+pub use instruction::encoded_instruction::ParseErrorKind;
+pub use instruction::encoder::EncodeError;
+pub use instruction::formatter::Formatter;
+pub use instruction::formatter::Syntax;
+pub use instruction::memory_access::AccessKind;
+pub use instruction::memory_access::AccessWidth;
+pub use instruction::memory_access::EffectiveAddress;
+pub use instruction::memory_access::MemoryAccess;
+pub use instruction::resolver::Resolver;
 pub use register::crf::Crf;
+pub use register::fpr::Fpr;
 pub use register::gpr::Gpr;
 pub use register::gpr_or_zero::GprOrZero;
 pub use register::non_zero_gpr::NonZeroGpr;
+pub use register::register_set::RegisterSet;
 pub use register::spr::Spr;
+pub use register::sr::Sr;
 pub use register::Register;
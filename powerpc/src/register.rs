@@ -1,12 +1,15 @@
 use std::fmt::{self, Debug, Display, Formatter};
 
-use crate::{ConditionBit, Gpr, GprOrZero, NonZeroGpr, Spr};
+use crate::{ConditionBit, Crf, Fpr, Gpr, GprOrZero, NonZeroGpr, Spr, Sr};
 
 pub mod crf;
+pub mod fpr;
 pub mod gpr;
 pub mod gpr_or_zero;
 pub mod non_zero_gpr;
+pub mod register_set;
 pub mod spr;
+pub mod sr;
 
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Register {
@@ -14,6 +17,14 @@ pub enum Register {
     GeneralPurpose(Gpr),
     SpecialPurpose(Spr),
     ConditionBit(ConditionBit),
+    /// A whole condition register field, standing for its four condition bits.
+    ConditionField(Crf),
+    FloatingPoint(Fpr),
+    /// The floating-point status and control register.
+    Fpscr,
+    /// The machine state register.
+    Msr,
+    Segment(Sr),
 }
 
 impl From<Gpr> for Register {
@@ -49,6 +60,24 @@ impl From<ConditionBit> for Register {
     }
 }
 
+impl From<Crf> for Register {
+    fn from(crf: Crf) -> Register {
+        Register::ConditionField(crf)
+    }
+}
+
+impl From<Fpr> for Register {
+    fn from(fpr: Fpr) -> Register {
+        Register::FloatingPoint(fpr)
+    }
+}
+
+impl From<Sr> for Register {
+    fn from(sr: Sr) -> Register {
+        Register::Segment(sr)
+    }
+}
+
 impl Display for Register {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
@@ -56,6 +85,11 @@ impl Display for Register {
             Register::GeneralPurpose(gpr) => write!(f, "{}", gpr),
             Register::SpecialPurpose(spr) => write!(f, "{}", spr),
             Register::ConditionBit(condition_bit) => write!(f, "{}", condition_bit),
+            Register::ConditionField(crf) => write!(f, "{}", crf),
+            Register::FloatingPoint(fpr) => write!(f, "{}", fpr),
+            Register::Fpscr => write!(f, "fpscr"),
+            Register::Msr => write!(f, "msr"),
+            Register::Segment(sr) => write!(f, "{}", sr),
         }
     }
 }
@@ -1,5 +1,7 @@
 use std::fmt::{self, Debug, Display, Formatter};
 
+use crate::{Condition, ConditionBit};
+
 // One of the eight condition register fields, `crf0..=crf7`.
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Crf(u32);
@@ -13,7 +15,9 @@ impl Crf {
         }
     }
 
-    /// SAFETY: x must be in 0..8.
+    /// # Safety
+    ///
+    /// `x` must be in `0..8`.
     pub const unsafe fn new_unchecked(x: u32) -> Crf {
         Crf(x)
     }
@@ -22,6 +26,13 @@ impl Crf {
         self.0
     }
 
+    /// The field's LT, GT, EQ and SO bits, in that order.
+    pub fn condition_bits(self) -> [ConditionBit; 4] {
+        [0, 1, 2, 3].map(|condition| {
+            ConditionBit::from_crf_and_condition(self, Condition::new(condition).unwrap())
+        })
+    }
+
     pub fn nonzero(self) -> Option<Crf> {
         if self.0 > 0 {
             Some(self)
@@ -0,0 +1,68 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// One of the floating-point registers, `f0..=f31`.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fpr(u32);
+
+impl Fpr {
+    pub fn new(x: u32) -> Option<Fpr> {
+        if x < 32 {
+            Some(Fpr(x))
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `x` must be in `0..32`.
+    pub const unsafe fn new_unchecked(x: u32) -> Fpr {
+        Fpr(x)
+    }
+
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+}
+
+impl Display for Fpr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "f{}", self.0)
+    }
+}
+
+impl Debug for Fpr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        <Fpr as Display>::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod fpr_tests {
+    use super::Fpr;
+
+    #[test]
+    fn new_in_range() {
+        assert_eq!(Fpr::new(0).unwrap().as_u32(), 0);
+        assert_eq!(Fpr::new(5).unwrap().as_u32(), 5);
+        assert_eq!(Fpr::new(31).unwrap().as_u32(), 31);
+    }
+
+    #[test]
+    fn new_out_of_range() {
+        assert!(Fpr::new(32).is_none());
+        assert!(Fpr::new(u32::MAX).is_none());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Fpr::new(0).unwrap()), "f0");
+        assert_eq!(format!("{}", Fpr::new(5).unwrap()), "f5");
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Fpr::new(0).unwrap()), "f0");
+        assert_eq!(format!("{:?}", Fpr::new(5).unwrap()), "f5");
+    }
+}
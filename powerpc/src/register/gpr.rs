@@ -13,7 +13,9 @@ impl Gpr {
         }
     }
 
-    /// SAFETY: x must be in 0..32.
+    /// # Safety
+    ///
+    /// `x` must be in `0..32`.
     pub const unsafe fn new_unchecked(x: u32) -> Gpr {
         Gpr(x)
     }
@@ -49,7 +51,7 @@ mod gpr_tests {
     #[test]
     fn new_out_of_range() {
         assert!(Gpr::new(32).is_none());
-        assert!(Gpr::new(std::u32::MAX).is_none());
+        assert!(Gpr::new(u32::MAX).is_none());
     }
 
     #[test]
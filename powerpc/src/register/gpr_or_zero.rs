@@ -21,7 +21,9 @@ impl GprOrZero {
         }
     }
 
-    /// SAFETY: x must be in 0..32.
+    /// # Safety
+    ///
+    /// `x` must be in `0..32`.
     pub unsafe fn new_unchecked(x: u32) -> GprOrZero {
         match x {
             0 => GprOrZero::Zero,
@@ -35,10 +37,7 @@ impl GprOrZero {
     }
 
     pub fn is_gpr(self) -> bool {
-        match self {
-            GprOrZero::Gpr(_) => true,
-            _ => false,
-        }
+        matches!(self, GprOrZero::Gpr(_))
     }
 
     pub fn try_unwrap_gpr(self) -> Option<NonZeroGpr> {
@@ -98,7 +97,7 @@ mod gpr_or_zero_tests {
     #[test]
     fn new_out_of_range() {
         assert!(GprOrZero::new(32).is_none());
-        assert!(GprOrZero::new(std::u32::MAX).is_none());
+        assert!(GprOrZero::new(u32::MAX).is_none());
     }
 
     #[test]
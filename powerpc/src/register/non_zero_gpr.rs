@@ -16,7 +16,9 @@ impl NonZeroGpr {
         }
     }
 
-    /// SAFETY: x must be in 1..32.
+    /// # Safety
+    ///
+    /// `x` must be in `1..32`.
     pub unsafe fn new_unchecked(x: u32) -> NonZeroGpr {
         NonZeroGpr(NonZeroU32::new_unchecked(x))
     }
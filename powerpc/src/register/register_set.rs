@@ -0,0 +1,231 @@
+use std::fmt::{self, Debug, Formatter};
+use std::iter::FromIterator;
+use std::ops::Range;
+
+use crate::{ConditionBit, Fpr, Gpr, Register, Spr, Sr};
+
+/// A set of registers, stored as one bit per register.
+///
+/// `Register::Zero` stands for the literal zero of `(rA|0)` operands rather than a register, so it
+/// is never a member. A `Register::ConditionField` is shorthand for its four condition bits, which
+/// are what the set stores and iterates over.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct RegisterSet([u64; 4]);
+
+// Bit layout: the GPRs, FPRs and condition bits take 32 bits each, followed by the FPSCR, the MSR
+// and the segment registers. SPRs start at the second half, indexed by their position in
+// `Spr::ALL`.
+const GPRS: usize = 0;
+const FPRS: usize = 32;
+const CONDITION_BITS: usize = 64;
+const FPSCR: usize = 96;
+const MSR: usize = 97;
+const SEGMENTS: usize = 98;
+const SPRS: usize = 128;
+
+const _: () = assert!(SPRS + Spr::ALL.len() <= 256);
+
+impl RegisterSet {
+    pub const fn new() -> RegisterSet {
+        RegisterSet([0; 4])
+    }
+
+    /// Adds a register, returning whether it was newly added. A condition field counts as newly
+    /// added if any of its bits was missing.
+    pub fn insert(&mut self, register: impl Into<Register>) -> bool {
+        let mut inserted = false;
+        for i in indices(register.into()) {
+            inserted |= !self.get(i);
+            self.0[i / 64] |= 1 << (i % 64);
+        }
+        inserted
+    }
+
+    /// Removes a register, returning whether it was present. A condition field counts as present
+    /// if any of its bits was.
+    pub fn remove(&mut self, register: impl Into<Register>) -> bool {
+        let mut removed = false;
+        for i in indices(register.into()) {
+            removed |= self.get(i);
+            self.0[i / 64] &= !(1 << (i % 64));
+        }
+        removed
+    }
+
+    /// Whether the register is a member. A condition field is a member only if all of its bits
+    /// are.
+    pub fn contains(self, register: impl Into<Register>) -> bool {
+        let mut indices = indices(register.into());
+        !indices.is_empty() && indices.all(|i| self.get(i))
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    pub fn len(self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn union(self, other: RegisterSet) -> RegisterSet {
+        self.zip(other, |a, b| a | b)
+    }
+
+    pub fn intersection(self, other: RegisterSet) -> RegisterSet {
+        self.zip(other, |a, b| a & b)
+    }
+
+    /// The registers in `self` that are not in `other`.
+    pub fn difference(self, other: RegisterSet) -> RegisterSet {
+        self.zip(other, |a, b| a & !b)
+    }
+
+    /// Iterates over the registers in the order GPRs, FPRs, condition bits, FPSCR, MSR, segment
+    /// registers, SPRs.
+    pub fn iter(self) -> impl Iterator<Item = Register> {
+        (0..256).filter(move |&i| self.get(i)).map(register)
+    }
+
+    fn get(self, i: usize) -> bool {
+        self.0[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    fn zip(self, other: RegisterSet, f: impl Fn(u64, u64) -> u64) -> RegisterSet {
+        let mut result = RegisterSet::new();
+        for i in 0..4 {
+            result.0[i] = f(self.0[i], other.0[i]);
+        }
+        result
+    }
+}
+
+/// The bits a register occupies.
+fn indices(register: Register) -> Range<usize> {
+    let single = |i: usize| i..i + 1;
+    match register {
+        Register::Zero => 0..0,
+        Register::GeneralPurpose(gpr) => single(GPRS + gpr.as_u32() as usize),
+        Register::FloatingPoint(fpr) => single(FPRS + fpr.as_u32() as usize),
+        Register::ConditionBit(bit) => single(CONDITION_BITS + bit.get() as usize),
+        Register::ConditionField(crf) => {
+            let first = CONDITION_BITS + 4 * crf.get() as usize;
+            first..first + 4
+        }
+        Register::Fpscr => single(FPSCR),
+        Register::Msr => single(MSR),
+        Register::Segment(sr) => single(SEGMENTS + sr.get() as usize),
+        Register::SpecialPurpose(spr) => single(SPRS + spr as usize),
+    }
+}
+
+/// The inverse of `indices` for a single bit.
+fn register(i: usize) -> Register {
+    match i {
+        GPRS..=31 => Register::GeneralPurpose(Gpr::new((i - GPRS) as u32).unwrap()),
+        FPRS..=63 => Register::FloatingPoint(Fpr::new((i - FPRS) as u32).unwrap()),
+        CONDITION_BITS..=95 => {
+            Register::ConditionBit(ConditionBit::new((i - CONDITION_BITS) as u32).unwrap())
+        }
+        FPSCR => Register::Fpscr,
+        MSR => Register::Msr,
+        SEGMENTS..=113 => Register::Segment(Sr::new((i - SEGMENTS) as u32).unwrap()),
+        _ => Register::SpecialPurpose(Spr::ALL[i - SPRS]),
+    }
+}
+
+impl<R: Into<Register>> FromIterator<R> for RegisterSet {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> RegisterSet {
+        let mut set = RegisterSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<R: Into<Register>> Extend<R> for RegisterSet {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for register in iter {
+            self.insert(register);
+        }
+    }
+}
+
+impl Debug for RegisterSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod register_set_tests {
+    use super::RegisterSet;
+    use crate::{ConditionBit, Crf, Fpr, Gpr, GprOrZero, Register, Spr, Sr};
+
+    #[test]
+    fn insert_and_remove() {
+        let mut set = RegisterSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Gpr::new(3).unwrap()));
+        assert!(!set.insert(Gpr::new(3).unwrap()));
+        assert!(set.contains(Gpr::new(3).unwrap()));
+        assert!(!set.contains(Fpr::new(3).unwrap()));
+        assert_eq!(set.len(), 1);
+        assert!(set.remove(Gpr::new(3).unwrap()));
+        assert!(!set.remove(Gpr::new(3).unwrap()));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn zero_is_never_a_member() {
+        let mut set = RegisterSet::new();
+        assert!(!set.insert(GprOrZero::Zero));
+        assert!(!set.contains(Register::Zero));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn condition_field_is_its_bits() {
+        let cr1 = Crf::new(1).unwrap();
+        let mut set = RegisterSet::new();
+        assert!(set.insert(ConditionBit::new(5).unwrap()));
+        assert!(!set.contains(cr1));
+        assert!(set.insert(cr1));
+        assert!(!set.insert(cr1));
+        assert!(set.contains(cr1));
+        assert_eq!(
+            format!("{:?}", set),
+            "{cr1*4+lt, cr1*4+gt, cr1*4+eq, cr1*4+so}"
+        );
+        assert!(set.remove(ConditionBit::new(6).unwrap()));
+        assert!(!set.contains(cr1));
+        assert!(set.remove(cr1));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn every_register_round_trips() {
+        let registers: Vec<Register> = (0..32)
+            .map(|i| Register::from(Gpr::new(i).unwrap()))
+            .chain((0..32).map(|i| Register::from(Fpr::new(i).unwrap())))
+            .chain((0..32).map(|i| Register::from(ConditionBit::new(i).unwrap())))
+            .chain([Register::Fpscr, Register::Msr])
+            .chain((0..16).map(|i| Register::from(Sr::new(i).unwrap())))
+            .chain(Spr::ALL.iter().map(|&spr| Register::from(spr)))
+            .collect();
+        let set: RegisterSet = registers.iter().copied().collect();
+        assert_eq!(set.len(), registers.len());
+        assert_eq!(set.iter().collect::<Vec<_>>(), registers);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: RegisterSet = vec![Register::from(Gpr::new(1).unwrap()), Spr::Link.into()]
+            .into_iter()
+            .collect();
+        let b: RegisterSet = vec![Register::from(Spr::Link), Register::Fpscr]
+            .into_iter()
+            .collect();
+        assert_eq!(format!("{:?}", a.union(b)), "{r1, fpscr, lr}");
+        assert_eq!(format!("{:?}", a.intersection(b)), "{lr}");
+        assert_eq!(format!("{:?}", a.difference(b)), "{r1}");
+    }
+}
@@ -6,6 +6,48 @@ pub enum Spr {
     IntegerException,
     Link,
     Count,
+    /// The cause of a DSI exception.
+    Dsisr,
+    /// The address that caused a DSI or alignment exception.
+    Dar,
+    Decrementer,
+    /// The page table base and size.
+    Sdr1,
+    /// The address `rfi` returns to.
+    Srr0,
+    /// The MSR that `rfi` restores.
+    Srr1,
+    /// Scratch registers reserved for the operating system.
+    Sprg0,
+    Sprg1,
+    Sprg2,
+    Sprg3,
+    /// The external access register, for `eciwx` and `ecowx`.
+    Ear,
+    /// The time base halves, which are only written through SPRs. `mftb` reads them.
+    TimeBaseLower,
+    TimeBaseUpper,
+    /// The processor version register.
+    Pvr,
+    /// The instruction BAT registers.
+    Ibat0Upper,
+    Ibat0Lower,
+    Ibat1Upper,
+    Ibat1Lower,
+    Ibat2Upper,
+    Ibat2Lower,
+    Ibat3Upper,
+    Ibat3Lower,
+    /// The data BAT registers.
+    Dbat0Upper,
+    Dbat0Lower,
+    Dbat1Upper,
+    Dbat1Lower,
+    Dbat2Upper,
+    Dbat2Lower,
+    Dbat3Upper,
+    Dbat3Lower,
+    Gqr0,
     Gqr1,
     Gqr2,
     Gqr3,
@@ -13,14 +55,159 @@ pub enum Spr {
     Gqr5,
     Gqr6,
     Gqr7,
+    /// Gekko-specific configuration, including paired singles and the locked cache.
+    Hid2,
+    /// The write-gather pipe address.
+    Wpar,
+    /// The locked cache DMA command, upper and lower halves.
+    DmaUpper,
+    DmaLower,
+    /// User-mode read-only views of the performance monitor registers.
+    Ummcr0,
+    Upmc1,
+    Upmc2,
+    Usia,
+    Ummcr1,
+    Upmc3,
+    Upmc4,
+    Usda,
+    /// The performance monitor registers.
+    Mmcr0,
+    Pmc1,
+    Pmc2,
+    Sia,
+    Mmcr1,
+    Pmc3,
+    Pmc4,
+    Sda,
+    /// Hardware implementation dependent registers, such as cache and bus configuration.
+    Hid0,
+    Hid1,
+    /// The instruction address breakpoint.
+    Iabr,
+    /// The data address breakpoint.
+    Dabr,
+    /// The L2 cache control register.
+    L2cr,
+    /// The instruction cache throttling control register.
+    Ictc,
+    /// The thermal management registers.
+    Thrm1,
+    Thrm2,
+    Thrm3,
 }
 
 impl Spr {
+    /// Every SPR, in declaration order, so that `Spr::ALL[spr as usize] == spr`.
+    pub const ALL: [Spr; 70] = [
+        Spr::IntegerException,
+        Spr::Link,
+        Spr::Count,
+        Spr::Dsisr,
+        Spr::Dar,
+        Spr::Decrementer,
+        Spr::Sdr1,
+        Spr::Srr0,
+        Spr::Srr1,
+        Spr::Sprg0,
+        Spr::Sprg1,
+        Spr::Sprg2,
+        Spr::Sprg3,
+        Spr::Ear,
+        Spr::TimeBaseLower,
+        Spr::TimeBaseUpper,
+        Spr::Pvr,
+        Spr::Ibat0Upper,
+        Spr::Ibat0Lower,
+        Spr::Ibat1Upper,
+        Spr::Ibat1Lower,
+        Spr::Ibat2Upper,
+        Spr::Ibat2Lower,
+        Spr::Ibat3Upper,
+        Spr::Ibat3Lower,
+        Spr::Dbat0Upper,
+        Spr::Dbat0Lower,
+        Spr::Dbat1Upper,
+        Spr::Dbat1Lower,
+        Spr::Dbat2Upper,
+        Spr::Dbat2Lower,
+        Spr::Dbat3Upper,
+        Spr::Dbat3Lower,
+        Spr::Gqr0,
+        Spr::Gqr1,
+        Spr::Gqr2,
+        Spr::Gqr3,
+        Spr::Gqr4,
+        Spr::Gqr5,
+        Spr::Gqr6,
+        Spr::Gqr7,
+        Spr::Hid2,
+        Spr::Wpar,
+        Spr::DmaUpper,
+        Spr::DmaLower,
+        Spr::Ummcr0,
+        Spr::Upmc1,
+        Spr::Upmc2,
+        Spr::Usia,
+        Spr::Ummcr1,
+        Spr::Upmc3,
+        Spr::Upmc4,
+        Spr::Usda,
+        Spr::Mmcr0,
+        Spr::Pmc1,
+        Spr::Pmc2,
+        Spr::Sia,
+        Spr::Mmcr1,
+        Spr::Pmc3,
+        Spr::Pmc4,
+        Spr::Sda,
+        Spr::Hid0,
+        Spr::Hid1,
+        Spr::Iabr,
+        Spr::Dabr,
+        Spr::L2cr,
+        Spr::Ictc,
+        Spr::Thrm1,
+        Spr::Thrm2,
+        Spr::Thrm3,
+    ];
+
     pub fn new(spr: u32) -> Option<Spr> {
         match spr {
             0b00000_00001 => Some(Spr::IntegerException),
             0b00000_01000 => Some(Spr::Link),
             0b00000_01001 => Some(Spr::Count),
+            0b00000_10010 => Some(Spr::Dsisr),
+            0b00000_10011 => Some(Spr::Dar),
+            0b00000_10110 => Some(Spr::Decrementer),
+            0b00000_11001 => Some(Spr::Sdr1),
+            0b00000_11010 => Some(Spr::Srr0),
+            0b00000_11011 => Some(Spr::Srr1),
+            0b01000_10000 => Some(Spr::Sprg0),
+            0b01000_10001 => Some(Spr::Sprg1),
+            0b01000_10010 => Some(Spr::Sprg2),
+            0b01000_10011 => Some(Spr::Sprg3),
+            0b01000_11010 => Some(Spr::Ear),
+            0b01000_11100 => Some(Spr::TimeBaseLower),
+            0b01000_11101 => Some(Spr::TimeBaseUpper),
+            0b01000_11111 => Some(Spr::Pvr),
+            0b10000_10000 => Some(Spr::Ibat0Upper),
+            0b10000_10001 => Some(Spr::Ibat0Lower),
+            0b10000_10010 => Some(Spr::Ibat1Upper),
+            0b10000_10011 => Some(Spr::Ibat1Lower),
+            0b10000_10100 => Some(Spr::Ibat2Upper),
+            0b10000_10101 => Some(Spr::Ibat2Lower),
+            0b10000_10110 => Some(Spr::Ibat3Upper),
+            0b10000_10111 => Some(Spr::Ibat3Lower),
+            0b10000_11000 => Some(Spr::Dbat0Upper),
+            0b10000_11001 => Some(Spr::Dbat0Lower),
+            0b10000_11010 => Some(Spr::Dbat1Upper),
+            0b10000_11011 => Some(Spr::Dbat1Lower),
+            0b10000_11100 => Some(Spr::Dbat2Upper),
+            0b10000_11101 => Some(Spr::Dbat2Lower),
+            0b10000_11110 => Some(Spr::Dbat3Upper),
+            0b10000_11111 => Some(Spr::Dbat3Lower),
+            0b11100_10000 => Some(Spr::Gqr0),
             0b11100_10001 => Some(Spr::Gqr1),
             0b11100_10010 => Some(Spr::Gqr2),
             0b11100_10011 => Some(Spr::Gqr3),
@@ -28,9 +215,136 @@ impl Spr {
             0b11100_10101 => Some(Spr::Gqr5),
             0b11100_10110 => Some(Spr::Gqr6),
             0b11100_10111 => Some(Spr::Gqr7),
+            0b11100_11000 => Some(Spr::Hid2),
+            0b11100_11001 => Some(Spr::Wpar),
+            0b11100_11010 => Some(Spr::DmaUpper),
+            0b11100_11011 => Some(Spr::DmaLower),
+            0b11101_01000 => Some(Spr::Ummcr0),
+            0b11101_01001 => Some(Spr::Upmc1),
+            0b11101_01010 => Some(Spr::Upmc2),
+            0b11101_01011 => Some(Spr::Usia),
+            0b11101_01100 => Some(Spr::Ummcr1),
+            0b11101_01101 => Some(Spr::Upmc3),
+            0b11101_01110 => Some(Spr::Upmc4),
+            0b11101_01111 => Some(Spr::Usda),
+            0b11101_11000 => Some(Spr::Mmcr0),
+            0b11101_11001 => Some(Spr::Pmc1),
+            0b11101_11010 => Some(Spr::Pmc2),
+            0b11101_11011 => Some(Spr::Sia),
+            0b11101_11100 => Some(Spr::Mmcr1),
+            0b11101_11101 => Some(Spr::Pmc3),
+            0b11101_11110 => Some(Spr::Pmc4),
+            0b11101_11111 => Some(Spr::Sda),
+            0b11111_10000 => Some(Spr::Hid0),
+            0b11111_10001 => Some(Spr::Hid1),
+            0b11111_10010 => Some(Spr::Iabr),
+            0b11111_10101 => Some(Spr::Dabr),
+            0b11111_11001 => Some(Spr::L2cr),
+            0b11111_11011 => Some(Spr::Ictc),
+            0b11111_11100 => Some(Spr::Thrm1),
+            0b11111_11101 => Some(Spr::Thrm2),
+            0b11111_11110 => Some(Spr::Thrm3),
             _ => None,
         }
     }
+
+    /// The inverse of `new`.
+    pub fn as_u32(self) -> u32 {
+        match self {
+            Spr::IntegerException => 0b00000_00001,
+            Spr::Link => 0b00000_01000,
+            Spr::Count => 0b00000_01001,
+            Spr::Dsisr => 0b00000_10010,
+            Spr::Dar => 0b00000_10011,
+            Spr::Decrementer => 0b00000_10110,
+            Spr::Sdr1 => 0b00000_11001,
+            Spr::Srr0 => 0b00000_11010,
+            Spr::Srr1 => 0b00000_11011,
+            Spr::Sprg0 => 0b01000_10000,
+            Spr::Sprg1 => 0b01000_10001,
+            Spr::Sprg2 => 0b01000_10010,
+            Spr::Sprg3 => 0b01000_10011,
+            Spr::Ear => 0b01000_11010,
+            Spr::TimeBaseLower => 0b01000_11100,
+            Spr::TimeBaseUpper => 0b01000_11101,
+            Spr::Pvr => 0b01000_11111,
+            Spr::Ibat0Upper => 0b10000_10000,
+            Spr::Ibat0Lower => 0b10000_10001,
+            Spr::Ibat1Upper => 0b10000_10010,
+            Spr::Ibat1Lower => 0b10000_10011,
+            Spr::Ibat2Upper => 0b10000_10100,
+            Spr::Ibat2Lower => 0b10000_10101,
+            Spr::Ibat3Upper => 0b10000_10110,
+            Spr::Ibat3Lower => 0b10000_10111,
+            Spr::Dbat0Upper => 0b10000_11000,
+            Spr::Dbat0Lower => 0b10000_11001,
+            Spr::Dbat1Upper => 0b10000_11010,
+            Spr::Dbat1Lower => 0b10000_11011,
+            Spr::Dbat2Upper => 0b10000_11100,
+            Spr::Dbat2Lower => 0b10000_11101,
+            Spr::Dbat3Upper => 0b10000_11110,
+            Spr::Dbat3Lower => 0b10000_11111,
+            Spr::Gqr0 => 0b11100_10000,
+            Spr::Gqr1 => 0b11100_10001,
+            Spr::Gqr2 => 0b11100_10010,
+            Spr::Gqr3 => 0b11100_10011,
+            Spr::Gqr4 => 0b11100_10100,
+            Spr::Gqr5 => 0b11100_10101,
+            Spr::Gqr6 => 0b11100_10110,
+            Spr::Gqr7 => 0b11100_10111,
+            Spr::Hid2 => 0b11100_11000,
+            Spr::Wpar => 0b11100_11001,
+            Spr::DmaUpper => 0b11100_11010,
+            Spr::DmaLower => 0b11100_11011,
+            Spr::Ummcr0 => 0b11101_01000,
+            Spr::Upmc1 => 0b11101_01001,
+            Spr::Upmc2 => 0b11101_01010,
+            Spr::Usia => 0b11101_01011,
+            Spr::Ummcr1 => 0b11101_01100,
+            Spr::Upmc3 => 0b11101_01101,
+            Spr::Upmc4 => 0b11101_01110,
+            Spr::Usda => 0b11101_01111,
+            Spr::Mmcr0 => 0b11101_11000,
+            Spr::Pmc1 => 0b11101_11001,
+            Spr::Pmc2 => 0b11101_11010,
+            Spr::Sia => 0b11101_11011,
+            Spr::Mmcr1 => 0b11101_11100,
+            Spr::Pmc3 => 0b11101_11101,
+            Spr::Pmc4 => 0b11101_11110,
+            Spr::Sda => 0b11101_11111,
+            Spr::Hid0 => 0b11111_10000,
+            Spr::Hid1 => 0b11111_10001,
+            Spr::Iabr => 0b11111_10010,
+            Spr::Dabr => 0b11111_10101,
+            Spr::L2cr => 0b11111_11001,
+            Spr::Ictc => 0b11111_11011,
+            Spr::Thrm1 => 0b11111_11100,
+            Spr::Thrm2 => 0b11111_11101,
+            Spr::Thrm3 => 0b11111_11110,
+        }
+    }
+
+    /// Whether `mfspr` can read the register. The time base is read with `mftb` instead.
+    pub fn is_readable(self) -> bool {
+        !matches!(self, Spr::TimeBaseLower | Spr::TimeBaseUpper)
+    }
+
+    /// Whether `mtspr` can write the register. The processor version and the user-mode views of
+    /// the performance monitor are read-only.
+    pub fn is_writable(self) -> bool {
+        !matches!(
+            self,
+            Spr::Pvr
+                | Spr::Ummcr0
+                | Spr::Upmc1
+                | Spr::Upmc2
+                | Spr::Usia
+                | Spr::Ummcr1
+                | Spr::Upmc3
+                | Spr::Upmc4
+                | Spr::Usda
+        )
+    }
 }
 
 impl Display for Spr {
@@ -39,6 +353,37 @@ impl Display for Spr {
             Spr::IntegerException => write!(f, "xer"),
             Spr::Link => write!(f, "lr"),
             Spr::Count => write!(f, "ctr"),
+            Spr::Dsisr => write!(f, "dsisr"),
+            Spr::Dar => write!(f, "dar"),
+            Spr::Decrementer => write!(f, "dec"),
+            Spr::Sdr1 => write!(f, "sdr1"),
+            Spr::Srr0 => write!(f, "srr0"),
+            Spr::Srr1 => write!(f, "srr1"),
+            Spr::Sprg0 => write!(f, "sprg0"),
+            Spr::Sprg1 => write!(f, "sprg1"),
+            Spr::Sprg2 => write!(f, "sprg2"),
+            Spr::Sprg3 => write!(f, "sprg3"),
+            Spr::Ear => write!(f, "ear"),
+            Spr::TimeBaseLower => write!(f, "tbl"),
+            Spr::TimeBaseUpper => write!(f, "tbu"),
+            Spr::Pvr => write!(f, "pvr"),
+            Spr::Ibat0Upper => write!(f, "ibat0u"),
+            Spr::Ibat0Lower => write!(f, "ibat0l"),
+            Spr::Ibat1Upper => write!(f, "ibat1u"),
+            Spr::Ibat1Lower => write!(f, "ibat1l"),
+            Spr::Ibat2Upper => write!(f, "ibat2u"),
+            Spr::Ibat2Lower => write!(f, "ibat2l"),
+            Spr::Ibat3Upper => write!(f, "ibat3u"),
+            Spr::Ibat3Lower => write!(f, "ibat3l"),
+            Spr::Dbat0Upper => write!(f, "dbat0u"),
+            Spr::Dbat0Lower => write!(f, "dbat0l"),
+            Spr::Dbat1Upper => write!(f, "dbat1u"),
+            Spr::Dbat1Lower => write!(f, "dbat1l"),
+            Spr::Dbat2Upper => write!(f, "dbat2u"),
+            Spr::Dbat2Lower => write!(f, "dbat2l"),
+            Spr::Dbat3Upper => write!(f, "dbat3u"),
+            Spr::Dbat3Lower => write!(f, "dbat3l"),
+            Spr::Gqr0 => write!(f, "gqr0"),
             Spr::Gqr1 => write!(f, "gqr1"),
             Spr::Gqr2 => write!(f, "gqr2"),
             Spr::Gqr3 => write!(f, "gqr3"),
@@ -46,6 +391,35 @@ impl Display for Spr {
             Spr::Gqr5 => write!(f, "gqr5"),
             Spr::Gqr6 => write!(f, "gqr6"),
             Spr::Gqr7 => write!(f, "gqr7"),
+            Spr::Hid2 => write!(f, "hid2"),
+            Spr::Wpar => write!(f, "wpar"),
+            Spr::DmaUpper => write!(f, "dmau"),
+            Spr::DmaLower => write!(f, "dmal"),
+            Spr::Ummcr0 => write!(f, "ummcr0"),
+            Spr::Upmc1 => write!(f, "upmc1"),
+            Spr::Upmc2 => write!(f, "upmc2"),
+            Spr::Usia => write!(f, "usia"),
+            Spr::Ummcr1 => write!(f, "ummcr1"),
+            Spr::Upmc3 => write!(f, "upmc3"),
+            Spr::Upmc4 => write!(f, "upmc4"),
+            Spr::Usda => write!(f, "usda"),
+            Spr::Mmcr0 => write!(f, "mmcr0"),
+            Spr::Pmc1 => write!(f, "pmc1"),
+            Spr::Pmc2 => write!(f, "pmc2"),
+            Spr::Sia => write!(f, "sia"),
+            Spr::Mmcr1 => write!(f, "mmcr1"),
+            Spr::Pmc3 => write!(f, "pmc3"),
+            Spr::Pmc4 => write!(f, "pmc4"),
+            Spr::Sda => write!(f, "sda"),
+            Spr::Hid0 => write!(f, "hid0"),
+            Spr::Hid1 => write!(f, "hid1"),
+            Spr::Iabr => write!(f, "iabr"),
+            Spr::Dabr => write!(f, "dabr"),
+            Spr::L2cr => write!(f, "l2cr"),
+            Spr::Ictc => write!(f, "ictc"),
+            Spr::Thrm1 => write!(f, "thrm1"),
+            Spr::Thrm2 => write!(f, "thrm2"),
+            Spr::Thrm3 => write!(f, "thrm3"),
         }
     }
 }
@@ -0,0 +1,38 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// One of the sixteen segment registers, `sr0..=sr15`.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Sr(u32);
+
+impl Sr {
+    pub fn new(x: u32) -> Option<Sr> {
+        if x < 16 {
+            Some(Sr(x))
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `x` must be in `0..16`.
+    pub const unsafe fn new_unchecked(x: u32) -> Sr {
+        Sr(x)
+    }
+
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl Display for Sr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "sr{}", self.0)
+    }
+}
+
+impl Debug for Sr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        <Sr as Display>::fmt(self, f)
+    }
+}
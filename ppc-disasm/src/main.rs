@@ -0,0 +1,410 @@
+//! Disassembles a DOL executable, the main executable of a GameCube disc image, or a raw
+//! big-endian binary.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::process;
+
+use memmap::MmapOptions;
+use powerpc::{DecodedSection, Formatter, Resolver, Syntax};
+
+const USAGE: &str = "\
+usage: ppc-disasm [options] <file>
+
+options:
+    --dol               read <file> as a DOL executable (the default, unless it ends in .iso
+                        or .gcm)
+    --disc              read <file> as a disc image and disassemble its main executable
+    --raw <address>     read <file> as a raw binary loaded at <address>
+    --section <index>   disassemble only this DOL section (default: every text section)
+    --start <address>   start at this address
+    --end <address>     stop before this address
+    --syntax <syntax>   raw, gnu or cw (default: gnu)
+    --words             show the encoding of each instruction
+    --labels            label branch targets
+
+Addresses are decimal, or hexadecimal with a 0x prefix.";
+
+#[derive(Clone, Copy)]
+enum Format {
+    Dol,
+    Disc,
+    /// A raw binary loaded at the address.
+    Raw(u32),
+}
+
+struct Options {
+    path: String,
+    format: Format,
+    section: Option<usize>,
+    start: u32,
+    /// Where to stop, or `None` to go to the end of the address space.
+    end: Option<u32>,
+    syntax: Syntax,
+    words: bool,
+    labels: bool,
+}
+
+/// Names each branch target that falls inside the disassembly.
+struct Labels(BTreeMap<u32, String>);
+
+impl Resolver for Labels {
+    fn resolve(&self, address: u32) -> Option<(&str, u32)> {
+        Some((self.0.get(&address)?, 0))
+    }
+}
+
+fn main() {
+    let options = match parse_options(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("ppc-disasm: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let file = File::open(&options.path).unwrap_or_else(|e| fail(&options.path, e));
+    let data = unsafe { MmapOptions::new().map(&file) }.unwrap_or_else(|e| fail(&options.path, e));
+
+    let regions = match options.format {
+        Format::Dol => dol::Reader::parse(&data)
+            .map_err(|e| e.to_string())
+            .and_then(|dol| dol_regions(dol, options.section)),
+        Format::Disc => gamecube_disc::Reader::new(&data)
+            .main_executable()
+            .map_err(|e| e.to_string())
+            .and_then(|dol| dol_regions(dol, options.section)),
+        Format::Raw(address) => raw_regions(address, &data),
+    }
+    .unwrap_or_else(|message| fail(&options.path, message));
+
+    let sections: Vec<_> = regions
+        .into_iter()
+        .filter_map(|(address, data)| clip(address, data, options.start, options.end))
+        .map(|(address, data)| (DecodedSection::new(address, data), data))
+        .collect();
+
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    if let Err(e) = disassemble(&mut out, &sections, &options).and_then(|()| out.flush()) {
+        if e.kind() != io::ErrorKind::BrokenPipe {
+            fail("stdout", e);
+        }
+    }
+}
+
+fn fail(context: &str, error: impl std::fmt::Display) -> ! {
+    eprintln!("ppc-disasm: {}: {}", context, error);
+    process::exit(1);
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut path = None;
+    let mut format = None;
+    let mut section = None;
+    let mut start = 0;
+    let mut end = None;
+    let mut syntax = Syntax::Gnu;
+    let mut words = false;
+    let mut labels = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--dol" => format = Some(Format::Dol),
+            "--disc" => format = Some(Format::Disc),
+            "--raw" => format = Some(Format::Raw(parse_number(&value()?)?)),
+            "--section" => section = Some(parse_number(&value()?)? as usize),
+            "--start" => start = parse_number(&value()?)?,
+            "--end" => end = Some(parse_number(&value()?)?),
+            "--syntax" => {
+                syntax = match value()?.as_str() {
+                    "raw" => Syntax::Raw,
+                    "gnu" => Syntax::Gnu,
+                    "cw" => Syntax::CodeWarrior,
+                    other => return Err(format!("unknown syntax: {}", other)),
+                }
+            }
+            "--words" => words = true,
+            "--labels" => labels = true,
+            _ if arg.starts_with("--") => return Err(format!("unknown option: {}", arg)),
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    let path = path.ok_or("missing file")?;
+    let format = format.unwrap_or_else(|| {
+        let lowercase = path.to_lowercase();
+        if lowercase.ends_with(".iso") || lowercase.ends_with(".gcm") {
+            Format::Disc
+        } else {
+            Format::Dol
+        }
+    });
+    if section.is_some() {
+        if let Format::Raw(_) = format {
+            return Err("--section doesn't apply to raw binaries".to_string());
+        }
+    }
+
+    Ok(Options {
+        path,
+        format,
+        section,
+        start,
+        end,
+        syntax,
+        words,
+        labels,
+    })
+}
+
+fn parse_number(text: &str) -> Result<u32, String> {
+    match text.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => text.parse(),
+    }
+    .map_err(|_| format!("bad number: {}", text))
+}
+
+/// Returns the load address and data of the chosen section, or of every non-empty text section.
+fn dol_regions(dol: dol::Reader<'_>, section: Option<usize>) -> Result<Vec<(u32, &[u8])>, String> {
+    let sections: Vec<_> = match section {
+        Some(index) => vec![dol
+            .section(index)
+            .ok_or_else(|| format!("no section {}", index))?],
+        None => dol.iter_text_sections().collect(),
+    };
+
+    let mut regions: Vec<_> = sections
+        .into_iter()
+        .map(|section| (section.load_address, dol.section_data(section)))
+        .collect();
+    regions.sort_unstable_by_key(|(address, _)| *address);
+    Ok(regions)
+}
+
+/// Returns the one region of a raw binary loaded at `address`.
+fn raw_regions(address: u32, data: &[u8]) -> Result<Vec<(u32, &[u8])>, String> {
+    if u64::from(address) + data.len() as u64 > 1 << 32 {
+        return Err(format!(
+            "0x{:x} bytes at 0x{:08x} extend past the end of the address space",
+            data.len(),
+            address
+        ));
+    }
+    Ok(vec![(address, data)])
+}
+
+/// Narrows a region loaded at `address` to the whole words between `start` and `end`, or the end
+/// of the address space.
+fn clip(address: u32, data: &[u8], start: u32, end: Option<u32>) -> Option<(u32, &[u8])> {
+    let region_end = u64::from(address) + data.len() as u64;
+    let skip = (u64::from(start.saturating_sub(address)) + 3) & !3;
+    let take = end
+        .map_or(1 << 32, u64::from)
+        .min(region_end)
+        .checked_sub(u64::from(address) + skip)?
+        & !3;
+    if take == 0 {
+        return None;
+    }
+    let data = &data[skip as usize..][..take as usize];
+    Some((address + skip as u32, data))
+}
+
+/// Writes a listing of each section, which is paired with its data.
+fn disassemble(
+    out: &mut impl Write,
+    sections: &[(DecodedSection, &[u8])],
+    options: &Options,
+) -> io::Result<()> {
+    let mut labels = Labels(BTreeMap::new());
+    if options.labels {
+        let contains = |address: u32| {
+            sections
+                .iter()
+                .any(|(section, _)| section.get(address).is_some())
+        };
+        for (section, _) in sections {
+            for (_, instruction) in section.iter() {
+                let target = instruction
+                    .ok()
+                    .and_then(|instruction| instruction.branch_info())
+                    .and_then(|branch_info| branch_info.target);
+                if let Some(target) = target.filter(|target| contains(*target)) {
+                    labels.0.insert(target, format!("lbl_{:08x}", target));
+                }
+            }
+        }
+    }
+
+    let formatter = Formatter::new(options.syntax);
+    let formatter = if options.labels {
+        formatter.resolver(&labels)
+    } else {
+        formatter
+    };
+
+    for (index, (section, data)) in sections.iter().enumerate() {
+        if index != 0 {
+            writeln!(out)?;
+        }
+        for ((address, instruction), word) in section.iter().zip(data.chunks_exact(4)) {
+            if let Some(label) = labels.0.get(&address) {
+                writeln!(out, "{}:", label)?;
+            }
+            write!(out, "0x{:08x}  ", address)?;
+            if options.words {
+                write!(
+                    out,
+                    "{:02x}{:02x}{:02x}{:02x}  ",
+                    word[0], word[1], word[2], word[3]
+                )?;
+            }
+            match instruction {
+                Ok(instruction) => writeln!(out, "{}", formatter.format(instruction))?,
+                Err(e) => writeln!(out, ".long 0x{:08x}", e.word)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(args: &[&str]) -> Result<Options, String> {
+        parse_options(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse() {
+        let default = options(&["main.dol"]).unwrap();
+        assert_eq!(default.path, "main.dol");
+        assert!(matches!(default.format, Format::Dol));
+        assert_eq!(default.section, None);
+        assert_eq!((default.start, default.end), (0, None));
+        assert_eq!(default.syntax, Syntax::Gnu);
+        assert!(!default.words && !default.labels);
+
+        assert!(matches!(
+            options(&["GAME.ISO"]).unwrap().format,
+            Format::Disc
+        ));
+        assert!(matches!(
+            options(&["--dol", "game.gcm"]).unwrap().format,
+            Format::Dol
+        ));
+
+        let all = options(&[
+            "--raw",
+            "0x80003100",
+            "--start",
+            "0x80003200",
+            "--end",
+            "4096",
+            "--syntax",
+            "cw",
+            "--words",
+            "--labels",
+            "code.bin",
+        ])
+        .unwrap();
+        assert!(matches!(all.format, Format::Raw(0x8000_3100)));
+        assert_eq!((all.start, all.end), (0x8000_3200, Some(4096)));
+        assert_eq!(all.syntax, Syntax::CodeWarrior);
+        assert!(all.words && all.labels);
+
+        let section = options(&["--section", "2", "main.dol"]).unwrap();
+        assert_eq!(section.section, Some(2));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |args: &[&str]| options(args).err().unwrap();
+        assert_eq!(error(&[]), "missing file");
+        assert_eq!(error(&["main.dol", "--start"]), "missing value for --start");
+        assert_eq!(error(&["--start", "0x", "main.dol"]), "bad number: 0x");
+        assert_eq!(error(&["--end", "-1", "main.dol"]), "bad number: -1");
+        assert_eq!(
+            error(&["--raw", "0x100000000", "a"]),
+            "bad number: 0x100000000"
+        );
+        assert_eq!(error(&["--syntax", "att", "a"]), "unknown syntax: att");
+        assert_eq!(error(&["--verbose", "a"]), "unknown option: --verbose");
+        assert_eq!(error(&["a", "b"]), "unexpected argument: b");
+        assert_eq!(
+            error(&["--raw", "0", "--section", "1", "a"]),
+            "--section doesn't apply to raw binaries"
+        );
+    }
+
+    #[test]
+    fn raw() {
+        let data = [0; 0x40];
+        assert_eq!(
+            raw_regions(0xffff_ffc0, &data),
+            Ok(vec![(0xffff_ffc0, &data[..])])
+        );
+        assert_eq!(
+            raw_regions(0xffff_ffc4, &data),
+            Err("0x40 bytes at 0xffffffc4 extend past the end of the address space".to_string())
+        );
+    }
+
+    #[test]
+    fn clip_region() {
+        let data: Vec<u8> = (0..0x20).collect();
+        let clip = |start, end| clip(0x8000_0000, &data, start, end);
+
+        assert_eq!(clip(0, None), Some((0x8000_0000, &data[..])));
+        assert_eq!(
+            clip(0x8000_0008, Some(0x8000_0010)),
+            Some((0x8000_0008, &data[0x8..0x10]))
+        );
+
+        // A start that isn't word-aligned rounds up to the next word, and an end rounds down.
+        assert_eq!(
+            clip(0x8000_0001, Some(0x8000_000b)),
+            Some((0x8000_0004, &data[0x4..0x8]))
+        );
+        assert_eq!(clip(0x8000_0004, Some(0x8000_0007)), None);
+
+        // An end beyond the region stops at the end of the region.
+        assert_eq!(
+            clip(0x8000_0018, Some(0x9000_0000)),
+            Some((0x8000_0018, &data[0x18..]))
+        );
+
+        // Nothing is left when the start or end falls outside the region.
+        assert_eq!(clip(0x8000_0020, None), None);
+        assert_eq!(clip(0x9000_0000, None), None);
+        assert_eq!(clip(0, Some(0x8000_0000)), None);
+        assert_eq!(clip(0, Some(0x7000_0000)), None);
+        assert_eq!(clip(0x8000_0010, Some(0x8000_0008)), None);
+    }
+
+    #[test]
+    fn clip_unaligned_region() {
+        // Trailing bytes past the last whole word are dropped.
+        let data = [0; 6];
+        assert_eq!(clip(0x100, &data, 0, None), Some((0x100, &data[..4])));
+        assert_eq!(clip(0x100, &data, 0x101, None), None);
+
+        // Without an end, a region that reaches the top of the address space is kept whole.
+        let data = [0; 8];
+        assert_eq!(
+            clip(0xffff_fff8, &data, 0, None),
+            Some((0xffff_fff8, &data[..]))
+        );
+        assert_eq!(
+            clip(0xffff_fff8, &data, 0, Some(u32::MAX)),
+            Some((0xffff_fff8, &data[..4]))
+        );
+    }
+}
@@ -0,0 +1,70 @@
+pub(crate) const ID_OFFSET: usize = 0;
+pub(crate) const SECTION_COUNT_OFFSET: usize = 0xc;
+pub(crate) const SECTION_TABLE_OFFSET: usize = 0x10;
+pub(crate) const NAME_OFFSET_OFFSET: usize = 0x14;
+pub(crate) const NAME_SIZE_OFFSET: usize = 0x18;
+pub(crate) const VERSION_OFFSET: usize = 0x1c;
+pub(crate) const BSS_SIZE_OFFSET: usize = 0x20;
+pub(crate) const IMPORT_TABLE_OFFSET: usize = 0x28;
+pub(crate) const IMPORT_TABLE_SIZE_OFFSET: usize = 0x2c;
+pub(crate) const PROLOG_SECTION_OFFSET: usize = 0x30;
+pub(crate) const EPILOG_SECTION_OFFSET: usize = 0x31;
+pub(crate) const UNRESOLVED_SECTION_OFFSET: usize = 0x32;
+pub(crate) const PROLOG_OFFSET: usize = 0x34;
+pub(crate) const EPILOG_OFFSET: usize = 0x38;
+pub(crate) const UNRESOLVED_OFFSET: usize = 0x3c;
+pub(crate) const ALIGN_OFFSET: usize = 0x40;
+pub(crate) const BSS_ALIGN_OFFSET: usize = 0x44;
+
+/// The size in bytes of the header for each version, starting from version 1.
+pub(crate) const HEADER_SIZES: [usize; 3] = [0x40, 0x48, 0x4c];
+
+/// The size in bytes of an entry in the section table.
+pub(crate) const SECTION_ENTRY_SIZE: usize = 8;
+
+/// The contents of a REL header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Header {
+    /// Identifies the module in other modules' import tables. It's never 0, which stands for the
+    /// DOL.
+    pub id: u32,
+    pub version: u32,
+    /// The section table, indexed by the section numbers that relocations use. Unused entries are
+    /// `None`.
+    pub sections: Vec<Option<Section>>,
+    /// Where the module's name is found in the game's separate string table, as an offset and a
+    /// size.
+    pub name_offset: u32,
+    pub name_size: u32,
+    pub bss_size: u32,
+    pub prolog: Option<Location>,
+    pub epilog: Option<Location>,
+    /// The function that calls to unlinked modules are redirected to.
+    pub unresolved: Option<Location>,
+    /// The alignment the module and its BSS must be loaded at, or 1 before version 2.
+    pub align: u32,
+    pub bss_align: u32,
+}
+
+/// An entry in the section table. BSS sections have no data in the file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Section {
+    pub kind: SectionKind,
+    /// The offset of the section's data in the file, or 0 for a BSS section.
+    pub offset: u32,
+    pub size: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SectionKind {
+    Text,
+    Data,
+    Bss,
+}
+
+/// A section number and an offset within that section, which is how a REL refers to its own code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Location {
+    pub section: usize,
+    pub offset: u32,
+}
@@ -0,0 +1,17 @@
+//! Reads REL modules, the relocatable overlays that a GameCube game's DOL links in at runtime, and
+//! relocates them to chosen addresses.
+
+mod header;
+mod loaded_module;
+mod parse_error;
+mod reader;
+mod relocation;
+
+pub use crate::header::{Header, Location, Section, SectionKind};
+pub use crate::loaded_module::{LoadError, LoadedModule};
+pub use crate::parse_error::ParseError;
+pub use crate::reader::Reader;
+pub use crate::relocation::{Import, Relocation, RelocationKind};
+
+/// Imports from this module ID refer to absolute addresses in the DOL.
+pub const DOL_MODULE_ID: u32 = 0;
@@ -0,0 +1,194 @@
+use std::ops::Range;
+
+use dol::Image;
+use thiserror::Error;
+
+use crate::{Location, Reader, SectionKind, DOL_MODULE_ID};
+
+/// A module relocated to the addresses it's loaded at, which can be read like a DOL.
+///
+/// Relocations against the module itself and against the DOL are applied. Those against other
+/// modules are left alone, as if those modules weren't linked yet.
+///
+/// ```no_run
+/// use dol::Image;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let data = std::fs::read("module.rel")?;
+/// let reader = rel::Reader::parse(&data)?;
+/// let module = reader.load(0x80600000, 0x80700000)?;
+/// let prolog = module.prolog().unwrap();
+/// println!("0x{:08x}", (&module).read_u32(prolog).unwrap());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoadedModule {
+    /// The whole file, with relocations applied.
+    memory: Vec<u8>,
+    base: u32,
+    /// The load address of each section in the section table, or `None` for unused entries.
+    section_addresses: Vec<Option<u32>>,
+    /// The sections with data, as [`Image`] sees them, sorted by load address.
+    sections: Vec<dol::Section>,
+    bss: Range<u32>,
+    prolog: Option<u32>,
+    epilog: Option<u32>,
+    unresolved: Option<u32>,
+}
+
+impl LoadedModule {
+    pub(crate) fn new(reader: &Reader, base: u32, bss: u32) -> Result<LoadedModule, LoadError> {
+        let header = &reader.header;
+        for &(address, align) in &[(base, header.align), (bss, header.bss_align)] {
+            if align > 1 && address % align != 0 {
+                return Err(LoadError::Misaligned { address, align });
+            }
+        }
+        if u64::from(base) + reader.data.len() as u64 > u64::from(u32::MAX) {
+            return Err(LoadError::OutOfAddressSpace { address: base });
+        }
+
+        // Code and data stay where they are in the file. BSS sections are packed after `bss`.
+        let mut bss_end = u64::from(bss);
+        let mut section_addresses = Vec::with_capacity(header.sections.len());
+        let mut sections = Vec::new();
+        for section in &header.sections {
+            section_addresses.push(section.map(|section| match section.kind {
+                SectionKind::Bss => {
+                    let address = bss_end.next_multiple_of(u64::from(header.bss_align.max(1)));
+                    bss_end = address + u64::from(section.size);
+                    address as u32
+                }
+                SectionKind::Text | SectionKind::Data => {
+                    sections.push(dol::Section {
+                        kind: if section.kind == SectionKind::Text {
+                            dol::SectionKind::Text
+                        } else {
+                            dol::SectionKind::Data
+                        },
+                        offset: section.offset,
+                        load_address: base + section.offset,
+                        size: section.size,
+                    });
+                    base + section.offset
+                }
+            }));
+        }
+        if bss_end > u64::from(u32::MAX) {
+            return Err(LoadError::OutOfAddressSpace { address: bss });
+        }
+        sections.sort_unstable_by_key(|section| section.load_address);
+
+        let mut memory = reader.data.to_vec();
+        for import in &reader.imports {
+            if import.module_id != DOL_MODULE_ID && import.module_id != header.id {
+                continue;
+            }
+            for relocation in &import.relocations {
+                let target = if import.module_id == DOL_MODULE_ID {
+                    relocation.addend
+                } else {
+                    section_addresses[relocation.target_section]
+                        .unwrap()
+                        .wrapping_add(relocation.addend)
+                };
+                let section = header.sections[relocation.section].unwrap();
+                let position = (section.offset + relocation.offset) as usize;
+                let bytes = &mut memory[position..][..relocation.kind.size() as usize];
+                relocation
+                    .kind
+                    .apply(bytes, base + position as u32, target)
+                    .ok_or(LoadError::RelocationOverflow {
+                        section: relocation.section,
+                        offset: relocation.offset,
+                        target,
+                    })?;
+            }
+        }
+
+        let address = |location: Option<Location>| {
+            location.map(|location| {
+                section_addresses[location.section]
+                    .unwrap()
+                    .wrapping_add(location.offset)
+            })
+        };
+        Ok(LoadedModule {
+            prolog: address(header.prolog),
+            epilog: address(header.epilog),
+            unresolved: address(header.unresolved),
+            memory,
+            base,
+            section_addresses,
+            sections,
+            bss: bss..bss_end as u32,
+        })
+    }
+
+    /// The address the start of the file is loaded at.
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    /// Returns the load address of the section numbered `index`, or `None` if the entry is unused
+    /// or out of range.
+    pub fn section_address(&self, index: usize) -> Option<u32> {
+        self.section_addresses.get(index).copied().flatten()
+    }
+
+    pub fn bss(&self) -> Range<u32> {
+        self.bss.clone()
+    }
+
+    pub fn prolog(&self) -> Option<u32> {
+        self.prolog
+    }
+
+    pub fn epilog(&self) -> Option<u32> {
+        self.epilog
+    }
+
+    pub fn unresolved(&self) -> Option<u32> {
+        self.unresolved
+    }
+}
+
+impl<'a> Image<'a> for &'a LoadedModule {
+    fn sections(&self) -> Vec<dol::Section> {
+        self.sections.clone()
+    }
+
+    fn section_containing(&self, address: u32) -> Option<dol::Section> {
+        let index = self
+            .sections
+            .partition_point(|section| section.load_address <= address);
+        let section = self.sections[..index].last().copied()?;
+        section.contains(address).then_some(section)
+    }
+
+    fn section_data(&self, section: dol::Section) -> &'a [u8] {
+        &self.memory[section.offset as usize..][..section.size as usize]
+    }
+
+    /// The prolog, which is where the game starts running a module, or 0 if there's none.
+    fn entry_point(&self) -> u32 {
+        self.prolog.unwrap_or(0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum LoadError {
+    #[error("0x{address:08x} is not aligned to 0x{align:x} bytes")]
+    Misaligned { address: u32, align: u32 },
+
+    #[error("module loaded at 0x{address:08x} extends past the end of the address space")]
+    OutOfAddressSpace { address: u32 },
+
+    #[error("relocation at offset 0x{offset:x} in section {section} can't reach 0x{target:08x}")]
+    RelocationOverflow {
+        section: usize,
+        offset: u32,
+        target: u32,
+    },
+}
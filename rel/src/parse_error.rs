@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+/// Why a REL can't be read. Sections are numbered by their index in the section table, and
+/// imports by their index in the import table.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ParseError {
+    #[error("truncated header: {len} bytes")]
+    TruncatedHeader { len: usize },
+
+    #[error("unsupported version {version}")]
+    UnsupportedVersion { version: u32 },
+
+    #[error("section table extends past the end of the file")]
+    SectionTableOutOfFile,
+
+    #[error("section {index} extends past the end of the file")]
+    SectionOutOfFile { index: usize },
+
+    #[error("{name} refers to missing section {section}")]
+    BadLocation { name: &'static str, section: usize },
+
+    #[error("import table extends past the end of the file")]
+    ImportTableOutOfFile,
+
+    #[error("relocations for import {import} extend past the end of the file")]
+    RelocationsOutOfFile { import: usize },
+
+    #[error("import {import} has a relocation of unsupported type {kind}")]
+    UnsupportedRelocation { import: usize, kind: u8 },
+
+    #[error("import {import} has a relocation before any section is chosen")]
+    RelocationWithoutSection { import: usize },
+
+    #[error("import {import} refers to section {section}, which is missing or has no data")]
+    BadRelocationSection { import: usize, section: usize },
+
+    #[error("import {import} patches offset 0x{offset:x}, past the end of section {section}")]
+    RelocationOutOfSection {
+        import: usize,
+        section: usize,
+        offset: u32,
+    },
+}
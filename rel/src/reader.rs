@@ -0,0 +1,507 @@
+use std::convert::TryInto;
+
+use crate::header::{
+    ALIGN_OFFSET, BSS_ALIGN_OFFSET, BSS_SIZE_OFFSET, EPILOG_OFFSET, EPILOG_SECTION_OFFSET,
+    HEADER_SIZES, ID_OFFSET, IMPORT_TABLE_OFFSET, IMPORT_TABLE_SIZE_OFFSET, NAME_OFFSET_OFFSET,
+    NAME_SIZE_OFFSET, PROLOG_OFFSET, PROLOG_SECTION_OFFSET, SECTION_COUNT_OFFSET,
+    SECTION_ENTRY_SIZE, SECTION_TABLE_OFFSET, UNRESOLVED_OFFSET, UNRESOLVED_SECTION_OFFSET,
+    VERSION_OFFSET,
+};
+use crate::relocation::{
+    RELOCATION_ENTRY_SIZE, R_DOLPHIN_END, R_DOLPHIN_NOP, R_DOLPHIN_SECTION, R_PPC_NONE,
+};
+use crate::{
+    Header, Import, LoadError, LoadedModule, Location, ParseError, Relocation, RelocationKind,
+    Section, SectionKind,
+};
+
+/// The size in bytes of an entry in the import table.
+const IMPORT_ENTRY_SIZE: usize = 8;
+
+/// Set in a section table entry's offset for sections holding code.
+const SECTION_EXECUTABLE_FLAG: u32 = 1;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reader<'data> {
+    pub(crate) data: &'data [u8],
+    pub(crate) header: Header,
+    pub(crate) imports: Vec<Import>,
+}
+
+impl<'data> Reader<'data> {
+    /// Reads the header, section table, import table and relocations, checking that they lie
+    /// within `data` and that every relocation patches bytes within a section.
+    pub fn parse(data: &'data [u8]) -> Result<Reader<'data>, ParseError> {
+        let truncated = ParseError::TruncatedHeader { len: data.len() };
+        if data.len() < HEADER_SIZES[0] {
+            return Err(truncated);
+        }
+        let version = read_u32(data, VERSION_OFFSET);
+        let header_size = *version
+            .checked_sub(1)
+            .and_then(|index| HEADER_SIZES.get(index as usize))
+            .ok_or(ParseError::UnsupportedVersion { version })?;
+        if data.len() < header_size {
+            return Err(truncated);
+        }
+
+        let sections = read_sections(data)?;
+        let location = |name, section_offset, offset| match data[section_offset] as usize {
+            0 => Ok(None),
+            section => match sections.get(section) {
+                Some(Some(_)) => Ok(Some(Location {
+                    section,
+                    offset: read_u32(data, offset),
+                })),
+                _ => Err(ParseError::BadLocation { name, section }),
+            },
+        };
+        let (align, bss_align) = if version >= 2 {
+            (
+                read_u32(data, ALIGN_OFFSET),
+                read_u32(data, BSS_ALIGN_OFFSET),
+            )
+        } else {
+            (1, 1)
+        };
+        let header = Header {
+            id: read_u32(data, ID_OFFSET),
+            version,
+            name_offset: read_u32(data, NAME_OFFSET_OFFSET),
+            name_size: read_u32(data, NAME_SIZE_OFFSET),
+            bss_size: read_u32(data, BSS_SIZE_OFFSET),
+            prolog: location("prolog", PROLOG_SECTION_OFFSET, PROLOG_OFFSET)?,
+            epilog: location("epilog", EPILOG_SECTION_OFFSET, EPILOG_OFFSET)?,
+            unresolved: location("unresolved", UNRESOLVED_SECTION_OFFSET, UNRESOLVED_OFFSET)?,
+            align,
+            bss_align,
+            sections,
+        };
+        let imports = read_imports(data, &header)?;
+        Ok(Reader {
+            data,
+            header,
+            imports,
+        })
+    }
+
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    pub fn id(&self) -> u32 {
+        self.header.id
+    }
+
+    /// Returns the section numbered `index`, or `None` if the entry is unused or out of range.
+    pub fn section(&self, index: usize) -> Option<Section> {
+        self.header.sections.get(index).copied().flatten()
+    }
+
+    /// The bytes in the file for `section`, which are empty for a BSS section.
+    pub fn section_data(&self, section: Section) -> &'data [u8] {
+        match section.kind {
+            SectionKind::Bss => &[],
+            _ => &self.data[section.offset as usize..][..section.size as usize],
+        }
+    }
+
+    /// The import table, in file order. Each module appears at most once.
+    pub fn imports(&self) -> &[Import] {
+        &self.imports
+    }
+
+    /// Relocates the module's code and data to `base`, which is where the start of the file is
+    /// loaded, and its BSS to `bss`.
+    pub fn load(&self, base: u32, bss: u32) -> Result<LoadedModule, LoadError> {
+        LoadedModule::new(self, base, bss)
+    }
+}
+
+fn read_sections(data: &[u8]) -> Result<Vec<Option<Section>>, ParseError> {
+    let count = read_u32(data, SECTION_COUNT_OFFSET) as usize;
+    let table_offset = read_u32(data, SECTION_TABLE_OFFSET) as usize;
+    let table = count
+        .checked_mul(SECTION_ENTRY_SIZE)
+        .and_then(|size| data.get(table_offset..)?.get(..size))
+        .ok_or(ParseError::SectionTableOutOfFile)?;
+
+    let mut sections = Vec::with_capacity(count);
+    for (index, entry) in table.chunks_exact(SECTION_ENTRY_SIZE).enumerate() {
+        let offset_and_flags = read_u32(entry, 0);
+        let size = read_u32(entry, 4);
+        if size == 0 {
+            sections.push(None);
+            continue;
+        }
+        let offset = offset_and_flags & !SECTION_EXECUTABLE_FLAG;
+        let kind = if offset_and_flags & SECTION_EXECUTABLE_FLAG != 0 {
+            SectionKind::Text
+        } else if offset == 0 {
+            SectionKind::Bss
+        } else {
+            SectionKind::Data
+        };
+        if kind != SectionKind::Bss && u64::from(offset) + u64::from(size) > data.len() as u64 {
+            return Err(ParseError::SectionOutOfFile { index });
+        }
+        sections.push(Some(Section { kind, offset, size }));
+    }
+    Ok(sections)
+}
+
+fn read_imports(data: &[u8], header: &Header) -> Result<Vec<Import>, ParseError> {
+    let table_offset = read_u32(data, IMPORT_TABLE_OFFSET) as usize;
+    let table_size = read_u32(data, IMPORT_TABLE_SIZE_OFFSET) as usize;
+    let table = data
+        .get(table_offset..)
+        .and_then(|table| table.get(..table_size))
+        .ok_or(ParseError::ImportTableOutOfFile)?;
+
+    let section_at = |index: usize| header.sections.get(index).copied().flatten();
+    let mut imports = Vec::new();
+    for (import, entry) in table.chunks_exact(IMPORT_ENTRY_SIZE).enumerate() {
+        let module_id = read_u32(entry, 0);
+        let list_offset = read_u32(entry, 4) as usize;
+        let out_of_file = ParseError::RelocationsOutOfFile { import };
+        let mut entries = data
+            .get(list_offset..)
+            .ok_or(out_of_file)?
+            .chunks_exact(RELOCATION_ENTRY_SIZE);
+
+        let mut relocations = Vec::new();
+        let mut section = None;
+        let mut offset = 0u32;
+        loop {
+            let entry = entries.next().ok_or(out_of_file)?;
+            offset = offset.wrapping_add(u32::from(read_u16(entry, 0)));
+            let raw_kind = entry[2];
+            let entry_section = entry[3] as usize;
+            let addend = read_u32(entry, 4);
+            match raw_kind {
+                R_PPC_NONE | R_DOLPHIN_NOP => continue,
+                R_DOLPHIN_END => break,
+                R_DOLPHIN_SECTION => {
+                    match section_at(entry_section) {
+                        Some(chosen) if chosen.kind != SectionKind::Bss => {}
+                        _ => {
+                            return Err(ParseError::BadRelocationSection {
+                                import,
+                                section: entry_section,
+                            })
+                        }
+                    }
+                    section = Some(entry_section);
+                    offset = 0;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let kind =
+                RelocationKind::from_raw(raw_kind).ok_or(ParseError::UnsupportedRelocation {
+                    import,
+                    kind: raw_kind,
+                })?;
+            let section = section.ok_or(ParseError::RelocationWithoutSection { import })?;
+            let size = section_at(section).unwrap().size;
+            if u64::from(offset) + u64::from(kind.size()) > u64::from(size) {
+                return Err(ParseError::RelocationOutOfSection {
+                    import,
+                    section,
+                    offset,
+                });
+            }
+            if module_id == header.id && section_at(entry_section).is_none() {
+                return Err(ParseError::BadRelocationSection {
+                    import,
+                    section: entry_section,
+                });
+            }
+            relocations.push(Relocation {
+                kind,
+                section,
+                offset,
+                target_section: entry_section,
+                addend,
+            });
+        }
+        imports.push(Import {
+            module_id,
+            relocations,
+        });
+    }
+    Ok(imports)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes(data[offset..offset + 2].try_into().unwrap())
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use dol::Image;
+
+    use super::Reader;
+    use crate::{
+        Header, Import, LoadError, Location, ParseError, Relocation, RelocationKind, Section,
+        SectionKind,
+    };
+
+    fn put(data: &mut [u8], offset: usize, value: u32) {
+        data[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+    }
+
+    const RELOCATIONS: usize = 0xb0;
+
+    /// Builds module 5, with code in section 1, data in section 2 and BSS in section 3. It imports
+    /// from itself, the DOL and module 2.
+    fn rel() -> Vec<u8> {
+        let mut data = vec![0; 0x110];
+        for &(offset, value) in &[
+            (0x00, 5),
+            (0x0c, 4),
+            (0x10, 0x50),
+            (0x1c, 3),
+            (0x20, 0x20),
+            (0x28, 0x98),
+            (0x2c, 0x18),
+            (0x30, 0x0100_0100),
+            (0x3c, 0xc),
+            (0x40, 0x20),
+            (0x44, 0x20),
+            // Section table.
+            (0x58, 0x81),
+            (0x5c, 0x10),
+            (0x60, 0x90),
+            (0x64, 8),
+            (0x6c, 0x20),
+            // Code: `bl 0; lis r3, 0; addi r3, r3, 0; blr`.
+            (0x80, 0x48000001),
+            (0x84, 0x3c600000),
+            (0x88, 0x38630000),
+            (0x8c, 0x4e800020),
+            // Import table.
+            (0x98, 5),
+            (0x9c, RELOCATIONS as u32),
+            (0xa0, 0),
+            (0xa4, RELOCATIONS as u32 + 0x30),
+            (0xa8, 2),
+            (0xac, RELOCATIONS as u32 + 0x48),
+        ] {
+            put(&mut data, offset, value);
+        }
+        // (offset, type, section, addend)
+        let relocations: [(u16, u8, u8, u32); 12] = [
+            (0, 202, 1, 0),
+            (6, 6, 2, 4),
+            (4, 4, 2, 4),
+            (0, 202, 2, 0),
+            (0, 1, 1, 0xc),
+            (0, 203, 0, 0),
+            (0, 202, 1, 0),
+            (0, 10, 0, 0x80005000),
+            (0, 203, 0, 0),
+            (0, 202, 2, 0),
+            (4, 1, 1, 0),
+            (0, 203, 0, 0),
+        ];
+        for (index, &(offset, kind, section, addend)) in relocations.iter().enumerate() {
+            let entry = RELOCATIONS + 8 * index;
+            data[entry..entry + 2].copy_from_slice(&offset.to_be_bytes());
+            data[entry + 2] = kind;
+            data[entry + 3] = section;
+            put(&mut data, entry + 4, addend);
+        }
+        data
+    }
+
+    #[test]
+    fn parse() {
+        let data = rel();
+        let reader = Reader::parse(&data).unwrap();
+        assert_eq!(
+            *reader.header(),
+            Header {
+                id: 5,
+                version: 3,
+                sections: vec![
+                    None,
+                    Some(Section {
+                        kind: SectionKind::Text,
+                        offset: 0x80,
+                        size: 0x10,
+                    }),
+                    Some(Section {
+                        kind: SectionKind::Data,
+                        offset: 0x90,
+                        size: 8,
+                    }),
+                    Some(Section {
+                        kind: SectionKind::Bss,
+                        offset: 0,
+                        size: 0x20,
+                    }),
+                ],
+                name_offset: 0,
+                name_size: 0,
+                bss_size: 0x20,
+                prolog: Some(Location {
+                    section: 1,
+                    offset: 0,
+                }),
+                epilog: None,
+                unresolved: Some(Location {
+                    section: 1,
+                    offset: 0xc,
+                }),
+                align: 0x20,
+                bss_align: 0x20,
+            }
+        );
+        assert_eq!(
+            reader.section_data(reader.section(1).unwrap())[12..],
+            [0x4e, 0x80, 0x00, 0x20]
+        );
+        assert_eq!(reader.section_data(reader.section(3).unwrap()), []);
+        assert_eq!(
+            reader.imports()[1],
+            Import {
+                module_id: 0,
+                relocations: vec![Relocation {
+                    kind: RelocationKind::Rel24,
+                    section: 1,
+                    offset: 0,
+                    target_section: 0,
+                    addend: 0x80005000,
+                }],
+            }
+        );
+        assert_eq!(
+            reader.imports()[0].relocations[1],
+            Relocation {
+                kind: RelocationKind::Addr16Lo,
+                section: 1,
+                offset: 0xa,
+                target_section: 2,
+                addend: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn load() {
+        let data = rel();
+        let reader = Reader::parse(&data).unwrap();
+        let module = reader.load(0x80600000, 0x80700000).unwrap();
+        assert_eq!(module.section_address(1), Some(0x80600080));
+        assert_eq!(module.section_address(3), Some(0x80700000));
+        assert_eq!(module.bss(), 0x80700000..0x80700020);
+        assert_eq!(module.prolog(), Some(0x80600080));
+        assert_eq!(module.unresolved(), Some(0x8060008c));
+
+        let image = &module;
+        assert_eq!(image.entry_point(), 0x80600080);
+        assert_eq!(image.sections().len(), 2);
+        // The call into the DOL, and the address of the second data word.
+        assert_eq!(image.read_u32(0x80600080), Some(0x4ba04f81));
+        assert_eq!(image.read_u32(0x80600084), Some(0x3c608060));
+        assert_eq!(image.read_u32(0x80600088), Some(0x38630094));
+        // A pointer to `blr`, and one into module 2, which isn't linked.
+        assert_eq!(image.read_u32(0x80600090), Some(0x8060008c));
+        assert_eq!(image.read_u32(0x80600094), Some(0));
+        assert_eq!(image.read_u32(0x80700000), None);
+
+        assert_eq!(
+            reader.load(0x80600010, 0x80700000),
+            Err(LoadError::Misaligned {
+                address: 0x80600010,
+                align: 0x20,
+            })
+        );
+        // The DOL is out of reach of a relative branch from here.
+        assert_eq!(
+            reader.load(0x82600000, 0x82700000),
+            Err(LoadError::RelocationOverflow {
+                section: 1,
+                offset: 0,
+                target: 0x80005000,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let edited = |offset: usize, value: u32| {
+            let mut data = rel();
+            put(&mut data, offset, value);
+            Reader::parse(&data).map(|_| ())
+        };
+        assert_eq!(
+            Reader::parse(&rel()[..0x48]),
+            Err(ParseError::TruncatedHeader { len: 0x48 })
+        );
+        assert_eq!(
+            edited(0x1c, 4),
+            Err(ParseError::UnsupportedVersion { version: 4 })
+        );
+        assert_eq!(edited(0x0c, 0x100), Err(ParseError::SectionTableOutOfFile));
+        assert_eq!(
+            edited(0x64, 0x100),
+            Err(ParseError::SectionOutOfFile { index: 2 })
+        );
+        assert_eq!(
+            edited(0x30, 0x0400_0000),
+            Err(ParseError::BadLocation {
+                name: "prolog",
+                section: 4,
+            })
+        );
+        assert_eq!(edited(0x2c, 0x100), Err(ParseError::ImportTableOutOfFile));
+        assert_eq!(
+            Reader::parse(&rel()[..0x108]),
+            Err(ParseError::RelocationsOutOfFile { import: 2 })
+        );
+
+        // Edits to the first import's relocations.
+        let edited_relocation = |offset: usize, byte: u8| {
+            let mut data = rel();
+            data[RELOCATIONS + offset] = byte;
+            Reader::parse(&data).map(|_| ())
+        };
+        assert_eq!(
+            edited_relocation(0xa, 9),
+            Err(ParseError::UnsupportedRelocation { import: 0, kind: 9 })
+        );
+        assert_eq!(
+            edited_relocation(0x2, 0),
+            Err(ParseError::RelocationWithoutSection { import: 0 })
+        );
+        assert_eq!(
+            edited_relocation(0x3, 3),
+            Err(ParseError::BadRelocationSection {
+                import: 0,
+                section: 3,
+            })
+        );
+        assert_eq!(
+            edited_relocation(0xb, 4),
+            Err(ParseError::BadRelocationSection {
+                import: 0,
+                section: 4,
+            })
+        );
+        assert_eq!(
+            edited_relocation(0x9, 0x10),
+            Err(ParseError::RelocationOutOfSection {
+                import: 0,
+                section: 1,
+                offset: 0x10,
+            })
+        );
+    }
+}
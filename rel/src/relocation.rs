@@ -0,0 +1,142 @@
+use std::convert::TryInto;
+
+pub(crate) const R_PPC_NONE: u8 = 0;
+const R_PPC_ADDR32: u8 = 1;
+const R_PPC_ADDR24: u8 = 2;
+const R_PPC_ADDR16: u8 = 3;
+const R_PPC_ADDR16_LO: u8 = 4;
+const R_PPC_ADDR16_HI: u8 = 5;
+const R_PPC_ADDR16_HA: u8 = 6;
+const R_PPC_ADDR14: u8 = 7;
+const R_PPC_REL24: u8 = 10;
+const R_PPC_REL14: u8 = 11;
+pub(crate) const R_DOLPHIN_NOP: u8 = 201;
+pub(crate) const R_DOLPHIN_SECTION: u8 = 202;
+pub(crate) const R_DOLPHIN_END: u8 = 203;
+
+/// The size in bytes of an entry in a relocation list.
+pub(crate) const RELOCATION_ENTRY_SIZE: usize = 8;
+
+/// The relocations a module applies against one module's sections.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Import {
+    /// The module whose sections are referred to, which may be the importing module itself, or
+    /// [`DOL_MODULE_ID`](crate::DOL_MODULE_ID) for the DOL.
+    pub module_id: u32,
+    /// The relocations in file order. The `R_DOLPHIN_*` entries that choose sections and skip
+    /// ahead are folded into the others' locations.
+    pub relocations: Vec<Relocation>,
+}
+
+/// A reference to a symbol in another (or the same) module, which is patched in when the module is
+/// loaded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Relocation {
+    pub kind: RelocationKind,
+    /// The section holding the bytes to patch.
+    pub section: usize,
+    /// The offset of the bytes to patch within `section`.
+    pub offset: u32,
+    /// The section of the imported module holding the target. Ignored for imports from the DOL.
+    pub target_section: usize,
+    /// The target's offset from the start of `target_section`, or its address for imports from
+    /// the DOL.
+    pub addend: u32,
+}
+
+/// The ways a relocation patches in its target. Halfword relocations patch two bytes, the rest a
+/// whole word.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RelocationKind {
+    /// The target's address.
+    Addr32,
+    /// A branch's absolute target address.
+    Addr24,
+    /// The target's address, which must fit in a signed halfword.
+    Addr16,
+    /// The low half of the target's address.
+    Addr16Lo,
+    /// The high half of the target's address.
+    Addr16Hi,
+    /// The high half of the target's address, adjusted for adding a signed low half to it.
+    Addr16Ha,
+    /// A conditional branch's absolute target address.
+    Addr14,
+    /// A branch's target relative to the branch.
+    Rel24,
+    /// A conditional branch's target relative to the branch.
+    Rel14,
+}
+
+impl RelocationKind {
+    pub(crate) fn from_raw(kind: u8) -> Option<RelocationKind> {
+        Some(match kind {
+            R_PPC_ADDR32 => RelocationKind::Addr32,
+            R_PPC_ADDR24 => RelocationKind::Addr24,
+            R_PPC_ADDR16 => RelocationKind::Addr16,
+            R_PPC_ADDR16_LO => RelocationKind::Addr16Lo,
+            R_PPC_ADDR16_HI => RelocationKind::Addr16Hi,
+            R_PPC_ADDR16_HA => RelocationKind::Addr16Ha,
+            R_PPC_ADDR14 => RelocationKind::Addr14,
+            R_PPC_REL24 => RelocationKind::Rel24,
+            R_PPC_REL14 => RelocationKind::Rel14,
+            _ => return None,
+        })
+    }
+
+    /// The number of bytes patched.
+    pub fn size(self) -> u32 {
+        match self {
+            RelocationKind::Addr16
+            | RelocationKind::Addr16Lo
+            | RelocationKind::Addr16Hi
+            | RelocationKind::Addr16Ha => 2,
+            _ => 4,
+        }
+    }
+
+    /// Patches `target` into `bytes`, which are loaded at `address`. Returns `None` if the target
+    /// doesn't fit in the field.
+    pub(crate) fn apply(self, bytes: &mut [u8], address: u32, target: u32) -> Option<()> {
+        let fits =
+            |value: u32, bits: u32| ((value as i32) << (32 - bits) >> (32 - bits)) as u32 == value;
+        let relative = target.wrapping_sub(address);
+        let word = || u32::from_be_bytes(bytes[..4].try_into().unwrap());
+        match self {
+            RelocationKind::Addr32 => bytes.copy_from_slice(&target.to_be_bytes()),
+            RelocationKind::Addr24 => {
+                fits(target, 26).then_some(())?;
+                let word = word() & !0x03fffffc | target & 0x03fffffc;
+                bytes.copy_from_slice(&word.to_be_bytes());
+            }
+            RelocationKind::Addr16 => {
+                fits(target, 16).then_some(())?;
+                bytes.copy_from_slice(&(target as u16).to_be_bytes());
+            }
+            RelocationKind::Addr16Lo => bytes.copy_from_slice(&(target as u16).to_be_bytes()),
+            RelocationKind::Addr16Hi => {
+                bytes.copy_from_slice(&((target >> 16) as u16).to_be_bytes())
+            }
+            RelocationKind::Addr16Ha => {
+                let high = (target.wrapping_add(0x8000) >> 16) as u16;
+                bytes.copy_from_slice(&high.to_be_bytes());
+            }
+            RelocationKind::Addr14 => {
+                fits(target, 16).then_some(())?;
+                let word = word() & !0xfffc | target & 0xfffc;
+                bytes.copy_from_slice(&word.to_be_bytes());
+            }
+            RelocationKind::Rel24 => {
+                fits(relative, 26).then_some(())?;
+                let word = word() & !0x03fffffc | relative & 0x03fffffc;
+                bytes.copy_from_slice(&word.to_be_bytes());
+            }
+            RelocationKind::Rel14 => {
+                fits(relative, 16).then_some(())?;
+                let word = word() & !0xfffc | relative & 0xfffc;
+                bytes.copy_from_slice(&word.to_be_bytes());
+            }
+        }
+        Some(())
+    }
+}
@@ -16,11 +16,7 @@ impl<V: Clone + Eq + Hash> Context<V> {
     }
 
     pub fn is_variable(&self, variable: ExprRef) -> bool {
-        if let Expr::Variable(_) = self.exprs_by_index[variable.0] {
-            true
-        } else {
-            false
-        }
+        matches!(self.exprs_by_index[variable.0], Expr::Variable(_))
     }
 
     pub fn get_variable_assignment(&self, variable: ExprRef) -> Option<ExprRef> {
@@ -47,7 +43,7 @@ impl<V: Clone + Eq + Hash> Context<V> {
         &self.exprs_by_index[index.0]
     }
 
-    pub fn display_expr(&self, index: ExprRef) -> DisplayExpr<V>
+    pub fn display_expr(&self, index: ExprRef) -> DisplayExpr<'_, V>
     where
         V: Display,
     {
@@ -150,7 +146,7 @@ impl<V: Clone + Eq + Hash> Context<V> {
     }
 
     pub fn bit_or_expr(&mut self, exprs: Vec<ExprRef>) -> ExprRef {
-        assert!(exprs.len() > 0);
+        assert!(!exprs.is_empty());
         // Eliminate duplicates.
         let exprs = exprs.iter().copied().collect::<HashSet<ExprRef>>();
         if exprs.len() == 1 {
@@ -165,7 +161,7 @@ impl<V: Clone + Eq + Hash> Context<V> {
     }
 
     pub fn bit_and_expr(&mut self, exprs: Vec<ExprRef>) -> ExprRef {
-        assert!(exprs.len() > 0);
+        assert!(!exprs.is_empty());
         // Eliminate duplicates.
         let exprs = exprs.iter().copied().collect::<HashSet<ExprRef>>();
         if exprs.len() == 1 {
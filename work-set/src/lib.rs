@@ -85,7 +85,7 @@ impl<T> WorkSet<T> {
         T: Eq + Hash,
     {
         let item_in_open_set = self.open.iter().next()?;
-        let item_in_known_set = self.known.get(&item_in_open_set).unwrap();
+        let item_in_known_set = self.known.get(item_in_open_set).unwrap();
         self.open.remove(item_in_known_set);
         Some(item_in_known_set)
     }